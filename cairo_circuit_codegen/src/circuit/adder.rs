@@ -1,178 +1,384 @@
+use crate::curve::Curve;
 use crate::fields::affine::Affine;
 use crate::fields::fq::Fq;
 use crate::fields::fq12::Fq12;
 
 use crate::circuit::builder::CairoCodeBuilder;
+use crate::circuit::circuit::Circuit;
 use crate::fields::fq12_squaring::Krbn2345;
 use crate::fields::fq2::Fq2;
 use crate::fields::fq6::Fq6;
-use crate::fields::{ECOperations, FieldOps};
+use crate::fields::projective::G2Projective;
 use crate::pairing::line::{LineFn, Precompute};
 
 pub trait CairoCodeAdder {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>); 
-}
+    /// Names used when none are supplied
+    fn default_names(&self) -> Vec<&'static str>;
+
+    /// Output circuits, in the same order as the names
+    fn circuits(&self) -> Vec<&Circuit>;
+
+    /// Cairo type of the value in the plonk verifier
+    fn cairo_type(&self) -> &'static str;
+
+    /// Items the Cairo type and its constructor are imported from
+    fn cairo_imports(&self) -> Vec<&'static str>;
+
+    /// Member access paths to the `u384` of every component, in the same order as the circuits
+    fn cairo_paths(&self) -> Vec<String>;
+
+    /// Cairo expression building the value from its components
+    fn cairo_value(&self, components: &[String]) -> String;
 
-impl CairoCodeAdder for Fq {
     fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        // Default if name is not supplied
+        let circuits = self.circuits();
+        // Default if not enough names are supplied
         let names = names
-            .filter(|v| v.len() >= 1)
-            .unwrap_or(vec!["c0"]);
+            .filter(|v| v.len() >= circuits.len())
+            .unwrap_or(self.default_names());
 
-        let c0 = self.c0().format_circuit();
-        
-        builder.assign_variable(names[0], c0);
+        // Assigned together so subexpressions shared between components are emitted once
+        let outputs: Vec<(&str, &Circuit)> = names.into_iter().zip(circuits).collect();
+        builder.assign_variables(&outputs);
     }
 }
 
-impl CairoCodeAdder for Fq2 {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 2)
-            .unwrap_or(vec!["c0", "c1"]);
+impl CairoCodeAdder for Fq {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["c0"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        vec![self.c0()]
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "Fq"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        vec!["plonk_verifier::fields::Fq", "plonk_verifier::fields::fq"]
+    }
 
-        let c0 = self.c0().c0().format_circuit();
-        let c1 = self.c1().c0().format_circuit();
-        
-        builder.assign_variable(names[0], c0);
-        builder.assign_variable(names[1], c1);
+    fn cairo_paths(&self) -> Vec<String> {
+        vec![".c0".to_string()]
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!("fq({})", components[0])
     }
 }
 
+impl<C: Curve> CairoCodeAdder for Fq2<C> {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["c0", "c1"]
+    }
 
-impl CairoCodeAdder for Fq6 {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 6)
-            .unwrap_or(vec!["c0", "c1", "c2", "c3", "c4", "c5"]);
-
-        let c0 = self.c0().c0().c0().format_circuit();
-        let c1 = self.c0().c1().c0().format_circuit();
-        let c2 = self.c1().c0().c0().format_circuit();
-        let c3 = self.c1().c1().c0().format_circuit();
-        let c4 = self.c2().c0().c0().format_circuit();
-        let c5 = self.c2().c1().c0().format_circuit();
-
-        builder.assign_variable(names[0], c0);
-        builder.assign_variable(names[1], c1);
-        builder.assign_variable(names[2], c2);
-        builder.assign_variable(names[3], c3);
-        builder.assign_variable(names[4], c4);
-        builder.assign_variable(names[5], c5);
+    fn circuits(&self) -> Vec<&Circuit> {
+        vec![self.c0().c0(), self.c1().c0()]
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "Fq2"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        vec!["plonk_verifier::fields::Fq2", "plonk_verifier::fields::fq2"]
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        vec![".c0.c0".to_string(), ".c1.c0".to_string()]
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!("fq2({})", components.join(", "))
     }
 }
 
-impl CairoCodeAdder for Fq12 {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 12)
-            .unwrap_or(vec!["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9", "c10", "c11"]);
-
-        let c0 = self.c0().c0().c0().c0().format_circuit();
-        let c1 = self.c0().c0().c1().c0().format_circuit();
-        let c2 = self.c0().c1().c0().c0().format_circuit();
-        let c3 = self.c0().c1().c1().c0().format_circuit();
-        let c4 = self.c0().c2().c0().c0().format_circuit();
-        let c5 = self.c0().c2().c1().c0().format_circuit();
-        let c6 = self.c1().c0().c0().c0().format_circuit();
-        let c7 = self.c1().c0().c1().c0().format_circuit();
-        let c8 = self.c1().c1().c0().c0().format_circuit();
-        let c9 = self.c1().c1().c1().c0().format_circuit();
-        let c10 = self.c1().c2().c0().c0().format_circuit();
-        let c11 = self.c1().c2().c1().c0().format_circuit();
-
-        builder.assign_variable(names[0], c0);
-        builder.assign_variable(names[1], c1);
-        builder.assign_variable(names[2], c2);
-        builder.assign_variable(names[3], c3);
-        builder.assign_variable(names[4], c4);
-        builder.assign_variable(names[5], c5);
-        builder.assign_variable(names[6], c6);
-        builder.assign_variable(names[7], c7);
-        builder.assign_variable(names[8], c8);
-        builder.assign_variable(names[9], c9);
-        builder.assign_variable(names[10], c10);
-        builder.assign_variable(names[11], c11);
+impl<C: Curve> CairoCodeAdder for Fq6<C> {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["c0", "c1", "c2", "c3", "c4", "c5"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        [self.c0(), self.c1(), self.c2()]
+            .into_iter()
+            .flat_map(|c| c.circuits())
+            .collect()
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "Fq6"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        vec!["plonk_verifier::fields::Fq6", "plonk_verifier::fields::fq6"]
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".c0", self.c0());
+        paths.extend(prefixed(".c1", self.c1()));
+        paths.extend(prefixed(".c2", self.c2()));
+        paths
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!("fq6({})", components.join(", "))
+    }
+}
+
+impl<C: Curve> CairoCodeAdder for Fq12<C> {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["c0", "c1", "c2", "c3", "c4", "c5", "c6", "c7", "c8", "c9", "c10", "c11"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        [self.c0(), self.c1()]
+            .into_iter()
+            .flat_map(|c| c.circuits())
+            .collect()
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "Fq12"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        vec!["plonk_verifier::fields::Fq12", "plonk_verifier::fields::fq12"]
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".c0", self.c0());
+        paths.extend(prefixed(".c1", self.c1()));
+        paths
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!("fq12({})", components.join(", "))
     }
 }
 
 impl CairoCodeAdder for Affine<Fq> {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 2)
-            .unwrap_or(vec!["x0", "y0"]);
-        
-        let x0 = self.x().c0().format_circuit();
-        let y0 = self.y().c0().format_circuit();
-        
-        builder.assign_variable(names[0], x0);
-        builder.assign_variable(names[1], y0);
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["x0", "y0"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        vec![self.x().c0(), self.y().c0()]
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "AffineG1"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        vec!["plonk_verifier::curve::groups::AffineG1", "plonk_verifier::curve::groups::affine_fq1"]
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".x", self.x());
+        paths.extend(prefixed(".y", self.y()));
+        paths
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!("affine_fq1({})", components.join(", "))
     }
 }
 
-impl CairoCodeAdder for Affine<Fq2> {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 4)
-            .unwrap_or(vec!["x0", "x1", "y0", "y1"]);
-
-        let x0 = self.x().c0().c0().format_circuit();
-        let x1 = self.x().c1().c0().format_circuit();
-        let y0 = self.y().c0().c0().format_circuit();
-        let y1 = self.y().c1().c0().format_circuit();
-        
-        builder.assign_variable(names[0], x0);
-        builder.assign_variable(names[1], x1);    
-        builder.assign_variable(names[2], y0);
-        builder.assign_variable(names[3], y1);    
-
-        // self.x().add_circuit(builder);
-        // self.y().add_circuit(builder);
+impl<C: Curve> CairoCodeAdder for Affine<Fq2<C>> {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["x0", "x1", "y0", "y1"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        [self.x(), self.y()]
+            .into_iter()
+            .flat_map(|c| c.circuits())
+            .collect()
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "AffineG2"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        vec!["plonk_verifier::curve::groups::AffineG2", "plonk_verifier::curve::groups::affine_fq2"]
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".x", self.x());
+        paths.extend(prefixed(".y", self.y()));
+        paths
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!("affine_fq2({})", components.join(", "))
     }
 }
 
-impl CairoCodeAdder for LineFn {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 4)
-            .unwrap_or(vec!["slope_c0", "slope_c1", "c0", "c1"]);
-
-        let slope_c0 = self.slope().c0().c0().format_circuit();
-        let slope_c1 = self.slope().c1().c0().format_circuit();
-        let c0 = self.c().c0().c0().format_circuit();
-        let c1 = self.c().c1().c0().format_circuit();
-        
-        builder.assign_variable(names[0], slope_c0);
-        builder.assign_variable(names[1], slope_c1);    
-        builder.assign_variable(names[2], c0);
-        builder.assign_variable(names[3], c1);    
+impl<C: Curve> CairoCodeAdder for G2Projective<C> {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["x0", "x1", "y0", "y1", "z0", "z1"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        [self.x(), self.y(), self.z()]
+            .into_iter()
+            .flat_map(|c| c.circuits())
+            .collect()
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "ProjectiveG2"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        vec!["plonk_verifier::curve::groups::ProjectiveG2", "plonk_verifier::curve::groups::projective_fq2"]
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".x", self.x());
+        paths.extend(prefixed(".y", self.y()));
+        paths.extend(prefixed(".z", self.z()));
+        paths
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!("projective_fq2({})", components.join(", "))
+    }
+}
+
+impl<C: Curve> CairoCodeAdder for LineFn<C> {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["slope_c0", "slope_c1", "c0", "c1"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        [self.slope(), self.c()]
+            .into_iter()
+            .flat_map(|c| c.circuits())
+            .collect()
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "LineFn"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        let mut imports = vec!["plonk_verifier::curve::pairing::optimal_ate_utils::LineFn"];
+        imports.extend(self.slope().cairo_imports());
+        imports
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".slope", self.slope());
+        paths.extend(prefixed(".c", self.c()));
+        paths
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!(
+            "LineFn {{ slope: {}, c: {} }}",
+            self.slope().cairo_value(&components[0..2]),
+            self.c().cairo_value(&components[2..4]),
+        )
     }
 }
 
 impl CairoCodeAdder for Precompute {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 12)
-            .unwrap_or(vec!["p_x0", "p_y0", "q_x0", "q_x1", "q_y0", "q_y1", "nq_x0", "nq_x1", "nq_y0", "nq_y1", "neg_x_over_y", "y_inv"]);
-        
-        self.p().add_circuit(builder, Some(names[0..2].to_vec()));
-        self.q().add_circuit(builder, Some(names[2..6].to_vec()));
-        self.neg_q().add_circuit(builder, Some(names[6..10].to_vec()));
-        self.ppc().neg_x_over_y().add_circuit(builder, Some(names[10..11].to_vec()));
-        self.ppc().y_inv().add_circuit(builder, Some(names[10..12].to_vec()));
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["p_x0", "p_y0", "q_x0", "q_x1", "q_y0", "q_y1", "nq_x0", "nq_x1", "nq_y0", "nq_y1", "neg_x_over_y", "y_inv"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        let mut circuits = self.p().circuits();
+        circuits.extend(self.q().circuits());
+        circuits.extend(self.neg_q().circuits());
+        circuits.extend(self.ppc().neg_x_over_y().circuits());
+        circuits.extend(self.ppc().y_inv().circuits());
+        circuits
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "PreCompute"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        let mut imports = vec![
+            "plonk_verifier::curve::pairing::optimal_ate_impls::PreCompute",
+            "plonk_verifier::curve::pairing::optimal_ate_utils::PPrecompute",
+        ];
+        imports.extend(self.p().cairo_imports());
+        imports.extend(self.q().cairo_imports());
+        imports.extend(self.ppc().y_inv().cairo_imports());
+        imports
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".p", self.p());
+        paths.extend(prefixed(".q", self.q()));
+        paths.extend(prefixed(".neg_q", self.neg_q()));
+        paths.extend(prefixed(".ppc.neg_x_over_y", self.ppc().neg_x_over_y()));
+        paths.extend(prefixed(".ppc.y_inv", self.ppc().y_inv()));
+        paths
+    }
+
+    // The modulus is the one the function evaluates with
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!(
+            "PreCompute {{ p: {}, q: {}, neg_q: {}, ppc: PPrecompute {{ neg_x_over_y: {}, y_inv: {} }}, modulus: m }}",
+            self.p().cairo_value(&components[0..2]),
+            self.q().cairo_value(&components[2..6]),
+            self.neg_q().cairo_value(&components[6..10]),
+            self.ppc().neg_x_over_y().cairo_value(&components[10..11]),
+            self.ppc().y_inv().cairo_value(&components[11..12]),
+        )
     }
 }
 
-impl CairoCodeAdder for Krbn2345 {
-    fn add_circuit(&self, builder: &mut CairoCodeBuilder, names: Option<Vec<&str>>) {
-        let names = names
-            .filter(|v| v.len() >= 8)
-            .unwrap_or(vec!["KrbnG2C0", "KrbnG2C1", "KrbnG3C0", "KrbnG3C1", "KrbnG4C0", "KrbnG4C1", "KrbnG5C0", "KrbnG5C1"]);
-        
-        self.g2().add_circuit(builder, Some(names[0..2].to_vec()));
-        self.g3().add_circuit(builder, Some(names[2..4].to_vec()));
-        self.g4().add_circuit(builder, Some(names[4..6].to_vec()));
-        self.g5().add_circuit(builder, Some(names[6..8].to_vec()));
-    }
-}
\ No newline at end of file
+impl<C: Curve> CairoCodeAdder for Krbn2345<C> {
+    fn default_names(&self) -> Vec<&'static str> {
+        vec!["KrbnG2C0", "KrbnG2C1", "KrbnG3C0", "KrbnG3C1", "KrbnG4C0", "KrbnG4C1", "KrbnG5C0", "KrbnG5C1"]
+    }
+
+    fn circuits(&self) -> Vec<&Circuit> {
+        [self.g2(), self.g3(), self.g4(), self.g5()]
+            .into_iter()
+            .flat_map(|c| c.circuits())
+            .collect()
+    }
+
+    fn cairo_type(&self) -> &'static str {
+        "Krbn2345"
+    }
+
+    fn cairo_imports(&self) -> Vec<&'static str> {
+        let mut imports = vec!["plonk_verifier::fields::Krbn2345"];
+        imports.extend(self.g2().cairo_imports());
+        imports
+    }
+
+    fn cairo_paths(&self) -> Vec<String> {
+        let mut paths = prefixed(".g2", self.g2());
+        paths.extend(prefixed(".g3", self.g3()));
+        paths.extend(prefixed(".g4", self.g4()));
+        paths.extend(prefixed(".g5", self.g5()));
+        paths
+    }
+
+    fn cairo_value(&self, components: &[String]) -> String {
+        format!(
+            "Krbn2345 {{ g2: {}, g3: {}, g4: {}, g5: {} }}",
+            self.g2().cairo_value(&components[0..2]),
+            self.g3().cairo_value(&components[2..4]),
+            self.g4().cairo_value(&components[4..6]),
+            self.g5().cairo_value(&components[6..8]),
+        )
+    }
+}
+
+// Paths of a nested value, below the member `prefix`
+fn prefixed(prefix: &str, value: &impl CairoCodeAdder) -> Vec<String> {
+    value.cairo_paths().into_iter().map(|path| format!("{}{}", prefix, path)).collect()
+}
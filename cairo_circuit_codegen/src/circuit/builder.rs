@@ -1,9 +1,25 @@
-use crate::fields::{affine::Affine, fq12::Fq12, FieldOps};
 
-use super::{adder::CairoCodeAdder, circuit::Circuit, constants::get_imports};
+use std::collections::BTreeSet;
 
+use super::{
+    adder::CairoCodeAdder,
+    circuit::Circuit,
+    constants::{get_function_imports, get_imports},
+    emitter::Emitter,
+    function::{constant_item, CairoFunction},
+    optimizer::{gate_count, OptimizeReport, Optimizer, Passes},
+    stats::CircuitStats,
+};
+
+#[derive(Default)]
 pub struct CairoCodeBuilder {
     code: String,
+    functions: bool,
+    uses: BTreeSet<&'static str>,
+    constants: BTreeSet<&'static str>,
+    optimizer: Option<Optimizer>,
+    reports: Vec<OptimizeReport>,
+    stats: Vec<CircuitStats>,
 }
 
 impl CairoCodeBuilder {
@@ -11,18 +27,58 @@ impl CairoCodeBuilder {
     pub fn new() -> Self {
         Self {
             code: String::new(),
+            functions: false,
+            uses: BTreeSet::new(),
+            constants: BTreeSet::new(),
+            optimizer: None,
+            reports: vec![],
+            stats: vec![],
         }
     }
 
+    /// Also emit the Cairo function evaluating each circuit added with `add_function`
+    pub fn with_functions(mut self, functions: bool) -> Self {
+        self.functions = functions;
+        self
+    }
+
+    /// Optimize circuits with these passes before emitting them
+    pub fn with_optimizer(mut self, passes: Option<Passes>) -> Self {
+        self.optimizer = passes.map(Optimizer::new);
+        self
+    }
+
+    /// Gate counts of every optimized circuit, in emission order
+    pub fn reports(&self) -> &[OptimizeReport] {
+        &self.reports
+    }
+
+    /// Size of every emitted circuit, after optimization, in emission order
+    pub fn stats(&self) -> &[CircuitStats] {
+        &self.stats
+    }
+
     /// Get the final generated code as a &str
     pub fn as_str(&self) -> &str {
         &self.code
     }
 
-    pub fn assign_variable(&mut self, name: &str, circuit: Circuit) -> &mut Self {
-        let line = "type ".to_string() + name + " = " + &circuit.inner();
-        self.add_line(line);
-        self
+    pub fn assign_variable(&mut self, name: &str, circuit: &Circuit) -> &mut Self {
+        self.assign_variables(&[(name, circuit)])
+    }
+
+    /// Assign several outputs at once, subexpressions shared between them are only emitted once
+    pub fn assign_variables(&mut self, outputs: &[(&str, &Circuit)]) -> &mut Self {
+        let name = outputs.first().map(|(name, _)| *name).unwrap_or_default();
+        let circuits: Vec<&Circuit> = outputs.iter().map(|(_, circuit)| *circuit).collect();
+        match self.optimize(name, &circuits) {
+            Some(optimized) => {
+                let outputs: Vec<(&str, &Circuit)> =
+                    outputs.iter().map(|(name, _)| *name).zip(optimized.iter()).collect();
+                self.emit(name, &outputs)
+            }
+            None => self.emit(name, outputs),
+        }
     }
 
     /// Add a line of code as-is
@@ -33,7 +89,13 @@ impl CairoCodeBuilder {
     }
 
     pub fn add_imports(&mut self) -> &mut Self {
-        let imports = get_imports(); 
+        let mut imports = get_imports();
+        if !self.uses.is_empty() {
+            imports += &get_function_imports();
+            for item in &self.uses {
+                imports += &format!("use {};\n", item);
+            }
+        }
         self.code = imports + self.as_str();
         self
     }
@@ -43,6 +105,71 @@ impl CairoCodeBuilder {
         self
     }
 
+    /// Add the input layout and circuit types of a function's outputs, followed by the function
+    /// when enabled
+    pub fn add_function(&mut self, function: CairoFunction) -> &mut Self {
+        // The function is wired to the optimized circuits, they may read other constants
+        let circuits: Vec<Circuit> = function.components().into_iter().map(|(_, circuit)| circuit).collect();
+        let function = match self.optimize(function.name(), &circuits.iter().collect::<Vec<_>>()) {
+            Some(optimized) => function.with_circuits(optimized),
+            None => function,
+        };
+
+        for line in function.layout() {
+            self.add_line(format!("// {}", line));
+        }
+
+        let components = function.emitted();
+        let outputs: Vec<(&str, &Circuit)> =
+            components.iter().map(|(name, circuit)| (name.as_str(), circuit)).collect();
+        self.emit(function.name(), &outputs);
+
+        if self.functions {
+            self.uses.extend(function.imports());
+            // Constants are shared by every function, each is only defined once
+            for constant in function.constants() {
+                if self.constants.insert(constant.name) {
+                    self.add_line(constant_item(&constant));
+                }
+            }
+            for line in function.lines() {
+                self.add_line(line);
+            }
+        }
+        self
+    }
+
+    /// Add a Cairo function calling emitted functions, only kept along with the functions
+    pub fn add_entry_point(&mut self, uses: &[&'static str], lines: &[String]) -> &mut Self {
+        if self.functions {
+            self.uses.extend(uses);
+            for line in lines {
+                self.add_line(line);
+            }
+        }
+        self
+    }
+
+    fn emit(&mut self, name: &str, outputs: &[(&str, &Circuit)]) -> &mut Self {
+        let circuits: Vec<&Circuit> = outputs.iter().map(|(_, circuit)| *circuit).collect();
+        self.stats.push(CircuitStats::of(name, &circuits));
+
+        for line in Emitter::emit(outputs) {
+            self.add_line(line);
+        }
+        self
+    }
+
+    fn optimize(&mut self, name: &str, circuits: &[&Circuit]) -> Option<Vec<Circuit>> {
+        let optimized = self.optimizer.as_mut()?.optimize(circuits);
+        self.reports.push(OptimizeReport {
+            name: name.to_string(),
+            before: gate_count(circuits.iter().copied()),
+            after: gate_count(&optimized),
+        });
+        Some(optimized)
+    }
+
     /// Consume the builder and return the final code
     pub fn build(self) -> String {
         self.code
@@ -1,58 +1,286 @@
-use std::fmt::Write;
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::rc::{Rc, Weak};
 
-#[derive(Clone, Default, Debug)]
+use num_bigint::BigUint;
+
+/// A known value fed to the circuit as an input, identified by its Cairo constant name
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Constant {
+    pub name: &'static str,
+    pub value: BigUint,
+}
+
+/// Gate of a circuit node, operands are shared handles to other nodes
+#[derive(Clone, Debug)]
+pub enum Gate {
+    Input(usize),
+    Const(Constant),
+    Add(Circuit, Circuit),
+    Sub(Circuit, Circuit),
+    Mul(Circuit, Circuit),
+    Inv(Circuit),
+}
+
+impl Gate {
+    pub fn operands(&self) -> Vec<&Circuit> {
+        match self {
+            Gate::Input(_) | Gate::Const(_) => vec![],
+            Gate::Add(lhs, rhs) | Gate::Sub(lhs, rhs) | Gate::Mul(lhs, rhs) => vec![lhs, rhs],
+            Gate::Inv(lhs) => vec![lhs],
+        }
+    }
+
+    // Structural key used for hash-consing, operands are identified by node id
+    fn key(&self) -> GateKey {
+        match self {
+            Gate::Input(idx) => GateKey::Input(*idx),
+            Gate::Const(constant) => GateKey::Const(constant.name),
+            Gate::Add(lhs, rhs) => GateKey::Add(lhs.id(), rhs.id()),
+            Gate::Sub(lhs, rhs) => GateKey::Sub(lhs.id(), rhs.id()),
+            Gate::Mul(lhs, rhs) => GateKey::Mul(lhs.id(), rhs.id()),
+            Gate::Inv(lhs) => GateKey::Inv(lhs.id()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum GateKey {
+    Input(usize),
+    Const(&'static str),
+    Add(usize, usize),
+    Sub(usize, usize),
+    Mul(usize, usize),
+    Inv(usize),
+}
+
+struct Node {
+    id: usize,
+    gate: Gate,
+}
+
+impl Node {
+    // Unregisters the node, a later node with the same key may already be interned in its place
+    fn forget(gate: &Gate) {
+        // The thread local may already be gone when the thread exits
+        let _ = NODES.try_with(|nodes| {
+            let mut nodes = nodes.borrow_mut();
+            let key = gate.key();
+            if nodes.get(&key).is_some_and(|node| node.strong_count() == 0) {
+                nodes.remove(&key);
+            }
+        });
+    }
+
+    // Moves the operands out, the gate is left as a leaf that is never registered again
+    fn take_operands(gate: &mut Gate) -> Vec<Circuit> {
+        match std::mem::replace(gate, Gate::Input(0)) {
+            Gate::Add(lhs, rhs) | Gate::Sub(lhs, rhs) | Gate::Mul(lhs, rhs) => vec![lhs, rhs],
+            Gate::Inv(lhs) => vec![lhs],
+            leaf => {
+                *gate = leaf;
+                vec![]
+            }
+        }
+    }
+}
+
+// Dropping operands recursively takes a frame per node of a chain, the operands owned by this
+// node alone are released from a work-list instead
+impl Drop for Node {
+    fn drop(&mut self) {
+        Node::forget(&self.gate);
+        let mut operands = Node::take_operands(&mut self.gate);
+        while let Some(operand) = operands.pop() {
+            if let Ok(mut node) = Rc::try_unwrap(operand.node) {
+                Node::forget(&node.gate);
+                operands.extend(Node::take_operands(&mut node.gate));
+            }
+        }
+    }
+}
+
+thread_local! {
+    static NEXT_ID: Cell<usize> = const { Cell::new(0) };
+    static NODES: RefCell<HashMap<GateKey, Weak<Node>>> = RefCell::new(HashMap::new());
+}
+
+/// Handle to a node of the circuit DAG.
+///
+/// Nodes are hash-consed: building the same gate over the same operands twice returns the same
+/// node, so shared subexpressions are only represented once no matter how they were built.
+#[derive(Clone)]
 pub struct Circuit {
-    inner: String,
+    node: Rc<Node>,
 }
 
 impl Circuit {
-    pub fn new<S: Into<String>>(inner: S) -> Self {
-        Circuit {inner: inner.into()}
+    fn intern(gate: Gate) -> Self {
+        let key = gate.key();
+        let existing = NODES.with(|nodes| nodes.borrow().get(&key).and_then(Weak::upgrade));
+        if let Some(node) = existing {
+            if let (Gate::Const(new), Gate::Const(old)) = (&gate, &node.gate) {
+                assert_eq!(new, old, "constant {} defined with two values", new.name);
+            }
+            return Circuit { node };
+        }
+
+        let id = NEXT_ID.with(|next| {
+            let id = next.get();
+            next.set(id + 1);
+            id
+        });
+        let node = Rc::new(Node { id, gate });
+        NODES.with(|nodes| nodes.borrow_mut().insert(key, Rc::downgrade(&node)));
+
+        Circuit { node }
     }
 
-    // Formats a circuit with the circuit element wrapper
-    pub fn format_circuit(&self) -> Circuit {
-        Circuit::new(format!(r#"CE<{}>;"#, self.inner))
+    /// Unique id of the node, equal circuits share the same id
+    pub fn id(&self) -> usize {
+        self.node.id
+    }
+
+    pub fn gate(&self) -> &Gate {
+        &self.node.gate
     }
 
     pub fn circuit_input(idx: usize) -> Self {
-        Circuit::new(format!(r#"CI<{}>"#, idx))
+        Self::intern(Gate::Input(idx))
+    }
+
+    pub fn circuit_constant(name: &'static str, value: BigUint) -> Self {
+        Self::intern(Gate::Const(Constant { name, value }))
     }
 
-    // Helper functions for building sub circuits (without circuit elemement wrapper and terminating brackets)
     pub fn circuit_add(lhs: &Circuit, rhs: &Circuit) -> Self {
-        // Circuit::new(format!(r#"A::<{}, {}>"#, lhs.inner, rhs.inner))
-        let mut result = String::with_capacity(10 + lhs.inner.len() + rhs.inner.len());
-        write!(&mut result, r#"A<{}, {}>"#, lhs.inner, rhs.inner).unwrap();
-    
-        Circuit::new(result)
+        Self::intern(Gate::Add(lhs.clone(), rhs.clone()))
     }
 
     pub fn circuit_sub(lhs: &Circuit, rhs: &Circuit) -> Self {
-        // Circuit::new(format!(r#"S::<{}, {}>"#, lhs.inner, rhs.inner))
-        let mut result = String::with_capacity(10 + lhs.inner.len() + rhs.inner.len());
-        write!(&mut result, r#"S<{}, {}>"#, lhs.inner, rhs.inner).unwrap();
-    
-        Circuit::new(result)
+        Self::intern(Gate::Sub(lhs.clone(), rhs.clone()))
     }
 
     pub fn circuit_mul(lhs: &Circuit, rhs: &Circuit) -> Self {
-        // Circuit::new(format!(r#"M::<{}, {}>"#, lhs.inner, rhs.inner))
-        let mut result = String::with_capacity(10 + lhs.inner.len() + rhs.inner.len());
-        write!(&mut result, r#"M<{}, {}>"#, lhs.inner, rhs.inner).unwrap();
-    
-        Circuit::new(result)
+        Self::intern(Gate::Mul(lhs.clone(), rhs.clone()))
     }
 
     pub fn circuit_inv(lhs: &Circuit) -> Circuit {
-        // Circuit::new(format!(r#"I::<{}>"#, lhs.inner))
-        let mut result = String::with_capacity(10 + lhs.inner.len());
-        write!(&mut result, r#"I<{}>"#, lhs.inner).unwrap();
-    
-        Circuit::new(result)
+        Self::intern(Gate::Inv(lhs.clone()))
+    }
+
+    /// Nodes reachable from `roots` in post-order, operands left to right and every node once.
+    /// `skip` prunes a node along with its operands, for nodes handled by an earlier walk.
+    ///
+    /// The walk keeps an explicit stack, circuits can be deeper than the call stack allows.
+    pub fn post_order<'a>(roots: impl IntoIterator<Item = &'a Circuit>, mut skip: impl FnMut(&Circuit) -> bool) -> Vec<Circuit> {
+        let mut order = vec![];
+        let mut visited = HashSet::new();
+        let mut stack: Vec<(Circuit, bool)> = roots.into_iter().map(|root| (root.clone(), false)).collect();
+        stack.reverse();
+        while let Some((node, expanded)) = stack.pop() {
+            if expanded {
+                order.push(node);
+                continue;
+            }
+            if visited.contains(&node.id()) || skip(&node) {
+                continue;
+            }
+            visited.insert(node.id());
+            stack.push((node.clone(), true));
+            stack.extend(node.gate().operands().into_iter().rev().map(|operand| (operand.clone(), false)));
+        }
+        order
+    }
+}
+
+impl PartialEq for Circuit {
+    fn eq(&self, other: &Self) -> bool {
+        self.id() == other.id()
+    }
+}
+
+impl Eq for Circuit {}
+
+impl Hash for Circuit {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id().hash(state);
+    }
+}
+
+// Only prints the top gate, printing operands recursively is exponential on shared nodes
+impl fmt::Debug for Circuit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.gate() {
+            Gate::Input(idx) => write!(f, "#{} = CI<{}>", self.id(), idx),
+            Gate::Const(constant) => write!(f, "#{} = {}", self.id(), constant.name),
+            Gate::Add(lhs, rhs) => write!(f, "#{} = A<#{}, #{}>", self.id(), lhs.id(), rhs.id()),
+            Gate::Sub(lhs, rhs) => write!(f, "#{} = S<#{}, #{}>", self.id(), lhs.id(), rhs.id()),
+            Gate::Mul(lhs, rhs) => write!(f, "#{} = M<#{}, #{}>", self.id(), lhs.id(), rhs.id()),
+            Gate::Inv(lhs) => write!(f, "#{} = I<#{}>", self.id(), lhs.id()),
+        }
     }
+}
 
-    pub fn inner(self) -> String {
-        self.inner
+/// Inputs and constants read by a group of outputs
+pub struct Leaves {
+    pub inputs: BTreeSet<usize>,
+    pub constants: BTreeMap<&'static str, Constant>,
+}
+
+impl Leaves {
+    pub fn of<'a>(outputs: impl IntoIterator<Item = &'a Circuit>) -> Self {
+        let mut leaves = Leaves { inputs: BTreeSet::new(), constants: BTreeMap::new() };
+        let mut visited = HashSet::new();
+        let mut stack: Vec<Circuit> = outputs.into_iter().cloned().collect();
+
+        while let Some(circuit) = stack.pop() {
+            if !visited.insert(circuit.id()) {
+                continue;
+            }
+            match circuit.gate() {
+                Gate::Input(idx) => {
+                    leaves.inputs.insert(*idx);
+                }
+                Gate::Const(constant) => {
+                    leaves.constants.insert(constant.name, constant.clone());
+                }
+                gate => stack.extend(gate.operands().into_iter().cloned()),
+            }
+        }
+        leaves
     }
-}
\ No newline at end of file
+
+    /// Constants are fed right after the last input, in name order
+    pub fn constant_slots(&self) -> Vec<(usize, &Constant)> {
+        let start = self.inputs.last().map_or(0, |idx| idx + 1);
+        self.constants.values().enumerate().map(|(k, constant)| (start + k, constant)).collect()
+    }
+
+    /// The outputs with every input renumbered by its rank among the inputs they read, Cairo
+    /// numbers inputs by their position in the `.next()` chain so unread inputs leave no gap
+    pub fn compact(&self, outputs: &[Circuit]) -> Vec<Circuit> {
+        let ranks: HashMap<usize, usize> = self.inputs.iter().enumerate().map(|(rank, &idx)| (idx, rank)).collect();
+        if ranks.iter().all(|(idx, rank)| idx == rank) {
+            return outputs.to_vec();
+        }
+
+        let mut renumbered: HashMap<usize, Circuit> = HashMap::new();
+        for node in Circuit::post_order(outputs, |_| false) {
+            let get = |circuit: &Circuit| &renumbered[&circuit.id()];
+            let circuit = match node.gate() {
+                Gate::Input(idx) => Circuit::circuit_input(ranks[idx]),
+                Gate::Const(_) => node.clone(),
+                Gate::Add(lhs, rhs) => Circuit::circuit_add(get(lhs), get(rhs)),
+                Gate::Sub(lhs, rhs) => Circuit::circuit_sub(get(lhs), get(rhs)),
+                Gate::Mul(lhs, rhs) => Circuit::circuit_mul(get(lhs), get(rhs)),
+                Gate::Inv(lhs) => Circuit::circuit_inv(get(lhs)),
+            };
+            renumbered.insert(node.id(), circuit);
+        }
+
+        outputs.iter().map(|circuit| renumbered[&circuit.id()].clone()).collect()
+    }
+}
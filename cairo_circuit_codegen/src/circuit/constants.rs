@@ -8,4 +8,16 @@ pub fn get_imports() -> String {
             CircuitInput as CI,\n\t\
             CircuitElement as CE,\n\
             };\n".to_string()
-}
\ No newline at end of file
+}
+
+// Traits used by the emitted evaluation functions
+pub fn get_function_imports() -> String {
+    "use core::circuit::{\n\t\
+            AddInputResultTrait,\n\t\
+            CircuitInputs,\n\t\
+            CircuitModulus,\n\t\
+            CircuitOutputsTrait,\n\t\
+            EvalCircuitTrait,\n\t\
+            u384,\n\
+            };\n".to_string()
+}
@@ -0,0 +1,154 @@
+use std::collections::{HashMap, HashSet};
+
+use super::circuit::{Circuit, Gate, Leaves};
+
+/// Renders circuit outputs as Cairo `type` definitions.
+///
+/// Every gate used more than once across the outputs is bound to an intermediate alias named
+/// `<first output>_t<k>` and referenced by name, so the emitted code grows linearly with the
+/// number of gates instead of repeating shared operands. Constants are read from the inputs
+/// following the last `CI` the outputs use, see [`Leaves::constant_slots`].
+pub struct Emitter {
+    constants: HashMap<&'static str, usize>,
+    uses: HashMap<usize, usize>,
+    aliases: HashMap<usize, String>,
+    prefix: String,
+    lines: Vec<String>,
+}
+
+impl Emitter {
+    pub fn emit(outputs: &[(&str, &Circuit)]) -> Vec<String> {
+        let leaves = Leaves::of(outputs.iter().map(|(_, circuit)| *circuit));
+        let mut emitter = Emitter {
+            constants: leaves.constant_slots().into_iter().map(|(idx, constant)| (constant.name, idx)).collect(),
+            uses: HashMap::new(),
+            aliases: HashMap::new(),
+            prefix: outputs.first().map(|(name, _)| name.to_string()).unwrap_or_default(),
+            lines: vec![],
+        };
+
+        let mut visited = HashSet::new();
+        for (_, circuit) in outputs {
+            emitter.count_uses(circuit, &mut visited);
+        }
+
+        for (name, circuit) in outputs {
+            let expr = emitter.expr(circuit);
+            emitter.lines.push(format!("type {} = CE<{}>;", name, expr));
+        }
+
+        emitter.lines
+    }
+
+    // Counts the parents of every node, operands are only walked the first time a node is seen
+    fn count_uses(&mut self, root: &Circuit, visited: &mut HashSet<usize>) {
+        let mut stack = vec![root.clone()];
+        *self.uses.entry(root.id()).or_default() += 1;
+
+        while let Some(circuit) = stack.pop() {
+            if !visited.insert(circuit.id()) {
+                continue;
+            }
+            for operand in circuit.gate().operands() {
+                *self.uses.entry(operand.id()).or_default() += 1;
+                stack.push(operand.clone());
+            }
+        }
+    }
+
+    // Inputs and constants are read in place, never bound to an alias
+    fn leaf(&self, circuit: &Circuit) -> Option<String> {
+        match circuit.gate() {
+            Gate::Input(idx) => Some(format!("CI<{}>", idx)),
+            Gate::Const(constant) => Some(format!("CI<{}>", self.constants[constant.name])),
+            _ => None,
+        }
+    }
+
+    // Aliases are numbered in post-order, operands left to right
+    fn expr(&mut self, root: &Circuit) -> String {
+        let mut exprs: HashMap<usize, String> = HashMap::new();
+        let nodes = Circuit::post_order([root], |node| self.aliases.contains_key(&node.id()) || self.leaf(node).is_some());
+        for node in nodes {
+            // A gate used once is inlined in its only parent
+            let mut get = |circuit: &Circuit| match (self.aliases.get(&circuit.id()), self.leaf(circuit)) {
+                (Some(alias), _) => alias.clone(),
+                (None, Some(leaf)) => leaf,
+                (None, None) => exprs.remove(&circuit.id()).unwrap(),
+            };
+            let expr = match node.gate() {
+                Gate::Add(lhs, rhs) => format!("A<{}, {}>", get(lhs), get(rhs)),
+                Gate::Sub(lhs, rhs) => format!("S<{}, {}>", get(lhs), get(rhs)),
+                Gate::Mul(lhs, rhs) => format!("M<{}, {}>", get(lhs), get(rhs)),
+                Gate::Inv(lhs) => format!("I<{}>", get(lhs)),
+                Gate::Input(_) | Gate::Const(_) => unreachable!("leaves are never expanded"),
+            };
+
+            if self.uses[&node.id()] < 2 {
+                exprs.insert(node.id(), expr);
+                continue;
+            }
+            let alias = format!("{}_t{}", self.prefix, self.aliases.len());
+            self.lines.push(format!("type {} = {};", alias, expr));
+            self.aliases.insert(node.id(), alias);
+        }
+
+        match (self.aliases.get(&root.id()), self.leaf(root)) {
+            (Some(alias), _) => alias.clone(),
+            (None, Some(leaf)) => leaf,
+            (None, None) => exprs.remove(&root.id()).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Emitter;
+    use crate::circuit::circuit::Circuit;
+
+    #[test]
+    fn test_hash_consing() {
+        let (a, b) = (Circuit::circuit_input(0), Circuit::circuit_input(1));
+        let lhs = Circuit::circuit_mul(&a, &b);
+        let rhs = Circuit::circuit_mul(&Circuit::circuit_input(0), &Circuit::circuit_input(1));
+
+        assert_eq!(lhs, rhs);
+        assert_ne!(lhs, Circuit::circuit_mul(&b, &a));
+    }
+
+    #[test]
+    fn test_shared_alias() {
+        let (a, b) = (Circuit::circuit_input(0), Circuit::circuit_input(1));
+        let ab = Circuit::circuit_mul(&a, &b);
+        let out0 = Circuit::circuit_add(&ab, &ab);
+        let out1 = Circuit::circuit_sub(&ab, &a);
+
+        let lines = Emitter::emit(&[("Out0", &out0), ("Out1", &out1)]);
+        assert_eq!(lines, vec![
+            "type Out0_t0 = M<CI<0>, CI<1>>;",
+            "type Out0 = CE<A<Out0_t0, Out0_t0>>;",
+            "type Out1 = CE<S<Out0_t0, CI<0>>>;",
+        ]);
+    }
+
+    #[test]
+    fn test_constant_inputs() {
+        let nine = Circuit::circuit_constant("NINE", 9u64.into());
+        let one = Circuit::circuit_constant("ONE", 1u64.into());
+        let out0 = Circuit::circuit_mul(&Circuit::circuit_input(1), &nine);
+        let out1 = Circuit::circuit_add(&Circuit::circuit_input(0), &one);
+
+        let lines = Emitter::emit(&[("Out0", &out0), ("Out1", &out1)]);
+        assert_eq!(lines, vec!["type Out0 = CE<M<CI<1>, CI<2>>>;", "type Out1 = CE<A<CI<0>, CI<3>>>;"]);
+    }
+
+    #[test]
+    fn test_deep_circuit() {
+        let a = Circuit::circuit_input(0);
+        let out = (0..50_000).fold(a.clone(), |acc, _| Circuit::circuit_add(&acc, &a));
+
+        let lines = Emitter::emit(&[("Out", &out)]);
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].matches("A<").count(), 50_000);
+    }
+}
@@ -0,0 +1,137 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use num_bigint::BigUint;
+use num_traits::Num;
+
+use super::circuit::{Circuit, Gate};
+
+/// BN254 base field modulus
+pub fn bn254_modulus() -> BigUint {
+    BigUint::from_str_radix(
+        "21888242871839275222246405745257275088696311157297823662689037894645226208583",
+        10,
+    )
+    .unwrap()
+}
+
+/// BN254 scalar field modulus, the PLONK verifier scalars live in this field
+pub fn bn254_scalar_modulus() -> BigUint {
+    BigUint::from_str_radix(
+        "21888242871839275222246405745257275088548364400416034343698204186575808495617",
+        10,
+    )
+    .unwrap()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    /// The circuit reads `CI<idx>` but fewer inputs were supplied
+    MissingInput(usize),
+    /// An inverse gate was applied to a value with no inverse modulo the modulus
+    NotInvertible,
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::MissingInput(idx) => write!(f, "missing value for circuit input {}", idx),
+            EvalError::NotInvertible => write!(f, "inverse of a non-invertible value"),
+        }
+    }
+}
+
+/// Evaluates circuits natively on concrete inputs, mirroring the semantics of the Cairo gates.
+///
+/// Values of evaluated nodes are cached by node id, so evaluating several outputs of the same
+/// DAG only computes shared subexpressions once.
+pub struct Evaluator {
+    inputs: Vec<BigUint>,
+    modulus: BigUint,
+    values: HashMap<usize, BigUint>,
+}
+
+impl Evaluator {
+    /// Inputs are reduced modulo `modulus`, `inputs[n]` is the value of `CI<n>`
+    pub fn new(inputs: Vec<BigUint>, modulus: BigUint) -> Self {
+        let inputs = inputs.into_iter().map(|input| input % &modulus).collect();
+        Self { inputs, modulus, values: HashMap::new() }
+    }
+
+    pub fn eval(&mut self, circuit: &Circuit) -> Result<BigUint, EvalError> {
+        // Values are kept across calls, the components of an element share their operands
+        for node in Circuit::post_order([circuit], |node| self.values.contains_key(&node.id())) {
+            let value = self.gate(node.gate())?;
+            self.values.insert(node.id(), value);
+        }
+
+        Ok(self.values[&circuit.id()].clone())
+    }
+
+    /// Evaluates several outputs, for instance the components of a field element
+    pub fn eval_all(&mut self, circuits: &[&Circuit]) -> Result<Vec<BigUint>, EvalError> {
+        circuits.iter().map(|circuit| self.eval(circuit)).collect()
+    }
+
+    // Operands are already evaluated
+    fn gate(&self, gate: &Gate) -> Result<BigUint, EvalError> {
+        let p = &self.modulus;
+        let value = |circuit: &Circuit| &self.values[&circuit.id()];
+
+        Ok(match gate {
+            Gate::Input(idx) => self.inputs.get(*idx).ok_or(EvalError::MissingInput(*idx))?.clone(),
+            Gate::Const(constant) => &constant.value % p,
+            Gate::Add(lhs, rhs) => (value(lhs) + value(rhs)) % p,
+            Gate::Sub(lhs, rhs) => (value(lhs) + p - value(rhs)) % p,
+            Gate::Mul(lhs, rhs) => (value(lhs) * value(rhs)) % p,
+            Gate::Inv(lhs) => value(lhs).modinv(p).ok_or(EvalError::NotInvertible)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use super::{bn254_modulus, EvalError, Evaluator};
+    use crate::circuit::{adder::CairoCodeAdder, circuit::Circuit};
+    use crate::fields::{fq::Fq, fq2::Fq2, FieldOps};
+
+    fn values(values: &[u64]) -> Vec<BigUint> {
+        values.iter().map(|&v| BigUint::from(v)).collect()
+    }
+
+    #[test]
+    fn test_eval_fq() {
+        let p = bn254_modulus();
+        let (a, b) = (Fq::new_input(0), Fq::new_input(1));
+        let mut evaluator = Evaluator::new(values(&[5, 7]), p.clone());
+
+        assert_eq!(evaluator.eval(a.add(&b).c0()), Ok(BigUint::from(12u64)));
+        assert_eq!(evaluator.eval(a.sub(&b).c0()), Ok(&p - 2u64));
+        assert_eq!(evaluator.eval(a.mul(&b).c0()), Ok(BigUint::from(35u64)));
+        assert_eq!(evaluator.eval(a.neg().c0()), Ok(&p - 5u64));
+
+        let quotient = evaluator.eval(a.div(&b).c0()).unwrap();
+        assert_eq!(quotient * 7u64 % &p, BigUint::from(5u64));
+    }
+
+    #[test]
+    fn test_eval_fq2_mul() {
+        // (1 + 2u)(3 + 4u) = 3 - 8 + (4 + 6)u with u² = -1
+        let p = bn254_modulus();
+        let out: Fq2 = &Fq2::new_input([0, 1]) * &Fq2::new_input([2, 3]);
+        let mut evaluator = Evaluator::new(values(&[1, 2, 3, 4]), p.clone());
+
+        assert_eq!(evaluator.eval_all(&out.circuits()), Ok(vec![&p - 5u64, BigUint::from(10u64)]));
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let p = bn254_modulus();
+        let inv = Circuit::circuit_inv(&Circuit::circuit_input(0));
+
+        assert_eq!(Evaluator::new(values(&[0]), p.clone()).eval(&inv), Err(EvalError::NotInvertible));
+        assert_eq!(Evaluator::new(vec![], p).eval(&inv), Err(EvalError::MissingInput(0)));
+    }
+}
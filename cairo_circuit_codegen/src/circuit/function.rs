@@ -0,0 +1,311 @@
+use std::collections::BTreeSet;
+
+use num_bigint::BigUint;
+
+use super::{
+    adder::CairoCodeAdder,
+    circuit::{Circuit, Constant, Leaves},
+    inputs::{InputAllocator, Param},
+};
+
+/// A Cairo function evaluating a circuit, with typed parameters and a typed return value.
+///
+/// Parameters come from the allocator the circuit inputs were taken from, values the outputs
+/// never read are left out of the signature. Inputs are wired with `.next()` in ascending `CI`
+/// order followed by the Cairo constants of the circuit, so the generated glue always matches the
+/// circuit definition. Inputs the outputs never read are not wired and the emitted circuits are
+/// renumbered to close the gaps, see [`CairoFunction::emitted`].
+pub struct CairoFunction<'a> {
+    name: String,
+    inputs: &'a InputAllocator,
+    outputs: Vec<(&'a dyn CairoCodeAdder, Option<Vec<&'a str>>)>,
+    circuits: Option<Vec<Circuit>>,
+}
+
+impl<'a> CairoFunction<'a> {
+    pub fn new(name: &str, inputs: &'a InputAllocator) -> Self {
+        Self { name: name.to_string(), inputs, outputs: vec![], circuits: None }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Adds a return value, several outputs are returned as a tuple
+    pub fn output(self, value: &'a dyn CairoCodeAdder) -> Self {
+        self.named_output(value, None)
+    }
+
+    /// Adds a return value with explicit circuit type names, one per component
+    pub fn named_output(mut self, value: &'a dyn CairoCodeAdder, names: Option<Vec<&'a str>>) -> Self {
+        if let Some(names) = &names {
+            let count = value.circuits().len();
+            assert_eq!(names.len(), count, "{}: {} names given for {} components", self.name, names.len(), count);
+        }
+        self.outputs.push((value, names));
+        self
+    }
+
+    /// Replaces the circuits of the output components, e.g. by their optimized form
+    pub fn with_circuits(mut self, circuits: Vec<Circuit>) -> Self {
+        assert_eq!(circuits.len(), self.components().len(), "{}: one circuit per output component", self.name);
+        self.circuits = Some(circuits);
+        self
+    }
+
+    /// Circuit type names and circuits of every output component, in return order. Circuits read
+    /// the inputs by their allocated index.
+    ///
+    /// Names default to the camel cased function name followed by the default component name
+    pub fn components(&self) -> Vec<(String, Circuit)> {
+        let prefix = camel_case(&self.name);
+        let components = self.outputs.iter().flat_map(|(value, names)| {
+            let circuits = value.circuits();
+            let names: Vec<String> = match names {
+                Some(names) => names.iter().map(|name| name.to_string()).collect(),
+                None => value
+                    .default_names()
+                    .into_iter()
+                    .map(|name| format!("{}{}", prefix, camel_case(name)))
+                    .collect(),
+            };
+            names.into_iter().zip(circuits.into_iter().cloned())
+        });
+
+        match &self.circuits {
+            Some(circuits) => components.map(|(name, _)| name).zip(circuits.iter().cloned()).collect(),
+            None => components.collect(),
+        }
+    }
+
+    /// The components as emitted, inputs are renumbered by their position in the wiring
+    pub fn emitted(&self) -> Vec<(String, Circuit)> {
+        let (names, circuits): (Vec<String>, Vec<Circuit>) = self.components().into_iter().unzip();
+        let circuits = Leaves::of(&circuits).compact(&circuits);
+        names.into_iter().zip(circuits).collect()
+    }
+
+    /// Items to import for the parameter and return types
+    pub fn imports(&self) -> Vec<&'static str> {
+        let leaves = self.leaves();
+        let params = self.used_params(&leaves.inputs).flat_map(|param| param.cairo_imports.clone());
+        params.chain(self.outputs.iter().flat_map(|(value, _)| value.cairo_imports())).collect()
+    }
+
+    /// Constants read by the outputs, in wiring order
+    pub fn constants(&self) -> Vec<Constant> {
+        self.leaves().constants.into_values().collect()
+    }
+
+    /// Inputs read by the outputs, one `CI<idx>: source` line each in emitted numbering
+    pub fn layout(&self) -> Vec<String> {
+        let leaves = self.leaves();
+        let inputs = leaves.inputs.iter().enumerate().map(|(position, &idx)| {
+            let source = self.inputs.describe(idx).unwrap_or_else(|| "unallocated".to_string());
+            format!("CI<{}>: {}", position, source)
+        });
+        // Constants follow the inputs read
+        let constants = leaves.constants.values().enumerate().map(|(k, constant)| {
+            format!("CI<{}>: {} = {}", leaves.inputs.len() + k, constant.name, constant.value)
+        });
+        inputs.chain(constants).collect()
+    }
+
+    /// Renders the function body, the circuit types and constants are expected to be emitted
+    /// separately
+    pub fn lines(&self) -> Vec<String> {
+        let components = self.components();
+        let leaves = self.leaves();
+        let wiring = self.wiring(&leaves);
+
+        let params: Vec<String> = self
+            .used_params(&leaves.inputs)
+            .map(|param| format!("{}: {}", param.name, param.cairo_type))
+            .chain(["m: CircuitModulus".to_string()])
+            .collect();
+        let returns: Vec<&str> = self.outputs.iter().map(|(value, _)| value.cairo_type()).collect();
+
+        let handles: Vec<String> = (0..components.len()).map(|k| format!("o{}", k)).collect();
+        let elements: Vec<String> = components.iter().map(|(name, _)| format!("{} {{}}", name)).collect();
+
+        let mut lines = vec![
+            format!("fn {}({}) -> {} {{", self.name, params.join(", "), tuple(&returns)),
+            format!("    let {} = {};", elements_tuple(&handles), elements_tuple(&elements)),
+            format!("    let outputs = match {}.new_inputs()", elements_tuple(&handles)),
+        ];
+        lines.extend(wiring.iter().map(|input| format!("        .next({})", input)));
+        lines.extend([
+            "        .done().eval(m) {".to_string(),
+            "        Result::Ok(outputs) => { outputs },".to_string(),
+            format!("        Result::Err(_) => {{ panic!(\"{}: circuit evaluation failed\") }}", self.name),
+            "    };".to_string(),
+        ]);
+
+        // Each output rebuilds its value from the next run of components
+        let mut handles = handles.iter().map(|handle| format!("outputs.get_output({})", handle));
+        let values: Vec<String> = self
+            .outputs
+            .iter()
+            .map(|(value, _)| {
+                let components: Vec<String> = handles.by_ref().take(value.circuits().len()).collect();
+                value.cairo_value(&components)
+            })
+            .collect();
+        lines.push(format!("    {}", tuple(&values)));
+        lines.push("}".to_string());
+        lines
+    }
+
+    fn leaves(&self) -> Leaves {
+        Leaves::of(&self.components().into_iter().map(|(_, circuit)| circuit).collect::<Vec<_>>())
+    }
+
+    // Parameters with at least one component read by the outputs
+    fn used_params<'b>(&'b self, used: &'b BTreeSet<usize>) -> impl Iterator<Item = &'b Param> {
+        self.inputs.params().iter().filter(move |param| param.inputs.clone().any(|idx| used.contains(&idx)))
+    }
+
+    // Cairo expression fed to every circuit input read, ordered by input index
+    fn wiring(&self, leaves: &Leaves) -> Vec<String> {
+        let params = self.inputs.params();
+        let inputs = leaves.inputs.iter().map(|idx| {
+            match self.inputs.sources().get(*idx) {
+                Some(source) => format!("{}{}", params[source.param].name, source.path),
+                None => panic!("{}: circuit input {} is not allocated", self.name, idx),
+            }
+        });
+        inputs.chain(leaves.constants.keys().map(|name| name.to_string())).collect()
+    }
+}
+
+/// Cairo constant item holding the value of a circuit constant, `u384` limbs are 96 bits wide
+pub fn constant_item(constant: &Constant) -> String {
+    format!("const {}: u384 = {};", constant.name, u384_literal(&constant.value))
+}
+
+/// Cairo constant item holding an array of `u384`, one element per line
+pub fn constant_array_item(name: &str, values: &[BigUint]) -> String {
+    let mut lines = vec![format!("const {}: [u384; {}] = [", name, values.len())];
+    lines.extend(values.iter().map(|value| format!("    {},", u384_literal(value))));
+    lines.push("];".to_string());
+    lines.join("\n")
+}
+
+pub fn u384_literal(value: &BigUint) -> String {
+    assert!(value.bits() <= 384, "{} does not fit in a u384", value);
+    let mask = (BigUint::from(1u8) << 96) - 1u8;
+    let limbs: Vec<BigUint> = (0..4).map(|k| (value >> (96 * k)) & &mask).collect();
+    format!("u384 {{ limb0: {}, limb1: {}, limb2: {}, limb3: {} }}", limbs[0], limbs[1], limbs[2], limbs[3])
+}
+
+// Single values are returned as is
+fn tuple<S: AsRef<str>>(items: &[S]) -> String {
+    match items {
+        [item] => item.as_ref().to_string(),
+        _ => format!("({})", join(items)),
+    }
+}
+
+// Circuits are always tuples of elements, a single element needs the trailing comma
+fn elements_tuple<S: AsRef<str>>(items: &[S]) -> String {
+    match items {
+        [item] => format!("({},)", item.as_ref()),
+        _ => format!("({})", join(items)),
+    }
+}
+
+fn join<S: AsRef<str>>(items: &[S]) -> String {
+    items.iter().map(|item| item.as_ref()).collect::<Vec<_>>().join(", ")
+}
+
+// `fq2_mul` -> `Fq2Mul`, `slope_c0` -> `SlopeC0`
+pub(crate) fn camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map(|c| c.to_uppercase().chain(chars).collect::<String>()).unwrap_or_default()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{constant_item, u384_literal, CairoFunction};
+    use crate::circuit::circuit::{Circuit, Leaves};
+    use crate::circuit::inputs::InputAllocator;
+    use crate::fields::{fq2::Fq2, FieldOps};
+
+    #[test]
+    fn test_function_wiring() {
+        let mut inputs = InputAllocator::new();
+        let (lhs, rhs) = (inputs.fq2("lhs"), inputs.fq2("rhs"));
+        let out = lhs.mul(&rhs);
+        let function = CairoFunction::new("fq2_mul", &inputs).output(&out);
+
+        let lines = function.lines();
+        assert_eq!(lines[0], "fn fq2_mul(lhs: Fq2, rhs: Fq2, m: CircuitModulus) -> Fq2 {");
+        assert_eq!(lines[1], "    let (o0, o1) = (Fq2MulC0 {}, Fq2MulC1 {});");
+        assert_eq!(lines[3..7], [
+            "        .next(lhs.c0.c0)",
+            "        .next(lhs.c1.c0)",
+            "        .next(rhs.c0.c0)",
+            "        .next(rhs.c1.c0)",
+        ]);
+        assert_eq!(lines[lines.len() - 2], "    fq2(outputs.get_output(o0), outputs.get_output(o1))");
+    }
+
+    #[test]
+    fn test_function_unused_param() {
+        let mut inputs = InputAllocator::new();
+        let (lhs, _rhs) = (inputs.fq2("lhs"), inputs.fq2("rhs"));
+        let out = lhs.mul_by_xi();
+
+        let function = CairoFunction::new("fq2_mul_by_xi", &inputs).output(&out);
+        let lines = function.lines();
+        assert_eq!(lines[0], "fn fq2_mul_by_xi(lhs: Fq2, m: CircuitModulus) -> Fq2 {");
+        assert_eq!(lines[5], "        .next(NINE)");
+        assert_eq!(function.layout()[2], "CI<2>: NINE = 9");
+        assert_eq!(
+            constant_item(&function.constants()[0]),
+            "const NINE: u384 = u384 { limb0: 9, limb1: 0, limb2: 0, limb3: 0 };"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "circuit input 2 is not allocated")]
+    fn test_function_unallocated_input() {
+        let mut inputs = InputAllocator::new();
+        let lhs = inputs.fq2("lhs");
+        let out = lhs.add(&Fq2::new_input([2, 3]));
+        CairoFunction::new("fq2_add", &inputs).output(&out).lines();
+    }
+
+    #[test]
+    fn test_function_unread_input() {
+        let mut inputs = InputAllocator::new();
+        let (lhs, rhs) = (inputs.fq2("lhs"), inputs.fq2("rhs"));
+        let out = rhs.sub(&Fq2::new(lhs.c0().clone(), lhs.c0().clone()));
+        let function = CairoFunction::new("fq2_sub_c0", &inputs).output(&out);
+
+        // lhs.c1 is never read, rhs moves down to CI<1>
+        assert_eq!(function.layout(), ["CI<0>: lhs.c0.c0", "CI<1>: rhs.c0.c0", "CI<2>: rhs.c1.c0"]);
+        assert_eq!(function.lines()[3..6], ["        .next(lhs.c0.c0)", "        .next(rhs.c0.c0)", "        .next(rhs.c1.c0)"]);
+        let emitted: Vec<Circuit> = function.emitted().into_iter().map(|(_, circuit)| circuit).collect();
+        assert_eq!(Leaves::of(&emitted).inputs.into_iter().collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "fq2_mul: 1 names given for 2 components")]
+    fn test_function_missing_names() {
+        let mut inputs = InputAllocator::new();
+        let (lhs, rhs) = (inputs.fq2("lhs"), inputs.fq2("rhs"));
+        let out = lhs.mul(&rhs);
+        CairoFunction::new("fq2_mul", &inputs).named_output(&out, Some(vec!["Fq2MulC0"]));
+    }
+
+    #[test]
+    #[should_panic(expected = "does not fit in a u384")]
+    fn test_u384_literal_overflow() {
+        u384_literal(&(num_bigint::BigUint::from(1u8) << 384usize));
+    }
+}
@@ -0,0 +1,197 @@
+use std::ops::Range;
+
+use super::adder::CairoCodeAdder;
+use crate::curve::Curve;
+use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq12_squaring::Krbn2345, fq2::Fq2, fq6::Fq6, projective::G2Projective};
+use crate::pairing::line::LineFn;
+
+/// What a circuit input is fed with, a component of the parameter at position `param`.
+///
+/// `path` is the member access to its `u384`. Constants are not allocated, they are reserved
+/// after the inputs when the circuit is emitted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputSource {
+    pub param: usize,
+    pub path: String,
+}
+
+/// A typed value read from consecutive circuit inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Param {
+    pub name: String,
+    pub cairo_type: &'static str,
+    pub cairo_imports: Vec<&'static str>,
+    pub inputs: Range<usize>,
+}
+
+/// Hands out fresh `CI` indices and records what every input is fed with.
+///
+/// Values are allocated in order, so the layout is the order of the calls and the emitted Cairo
+/// wiring can be derived from it instead of from hand-written index arrays.
+#[derive(Debug, Default)]
+pub struct InputAllocator {
+    params: Vec<Param>,
+    sources: Vec<InputSource>,
+}
+
+impl InputAllocator {
+    pub fn new() -> Self {
+        Self { params: vec![], sources: vec![] }
+    }
+
+    /// The next value, on any curve
+    pub fn alloc<T: Allocate>(&mut self, name: &str) -> T {
+        let value = T::from_inputs(self.sources.len());
+        self.record(name, value)
+    }
+
+    pub fn fq(&mut self, name: &str) -> Fq {
+        self.alloc(name)
+    }
+
+    pub fn fq2(&mut self, name: &str) -> Fq2 {
+        self.alloc(name)
+    }
+
+    pub fn fq6(&mut self, name: &str) -> Fq6 {
+        self.alloc(name)
+    }
+
+    pub fn fq12(&mut self, name: &str) -> Fq12 {
+        self.alloc(name)
+    }
+
+    pub fn g1(&mut self, name: &str) -> Affine<Fq> {
+        self.alloc(name)
+    }
+
+    pub fn g2(&mut self, name: &str) -> Affine<Fq2> {
+        self.alloc(name)
+    }
+
+    pub fn line(&mut self, name: &str) -> LineFn {
+        self.alloc(name)
+    }
+
+    pub fn krbn2345(&mut self, name: &str) -> Krbn2345 {
+        self.alloc(name)
+    }
+
+    pub fn params(&self) -> &[Param] {
+        &self.params
+    }
+
+    /// `sources()[idx]` feeds `CI<idx>`
+    pub fn sources(&self) -> &[InputSource] {
+        &self.sources
+    }
+
+    /// Readable description of an input, `lhs.c0.c0`
+    pub fn describe(&self, idx: usize) -> Option<String> {
+        self.sources.get(idx).map(|source| format!("{}{}", self.params[source.param].name, source.path))
+    }
+
+    /// One line per input, in `CI` order
+    pub fn layout(&self) -> Vec<String> {
+        (0..self.sources.len())
+            .map(|idx| format!("CI<{}>: {}", idx, self.describe(idx).unwrap()))
+            .collect()
+    }
+
+    fn record<T: CairoCodeAdder>(&mut self, name: &str, value: T) -> T {
+        let (param, start) = (self.params.len(), self.sources.len());
+        for path in value.cairo_paths() {
+            self.sources.push(InputSource { param, path });
+        }
+        self.params.push(Param {
+            name: name.to_string(),
+            cairo_type: value.cairo_type(),
+            cairo_imports: value.cairo_imports(),
+            inputs: start..self.sources.len(),
+        });
+        value
+    }
+}
+
+/// A value read from consecutive circuit inputs
+pub trait Allocate: CairoCodeAdder + Sized {
+    fn from_inputs(start: usize) -> Self;
+}
+
+fn indices<const N: usize>(start: usize) -> [usize; N] {
+    std::array::from_fn(|k| start + k)
+}
+
+impl Allocate for Fq {
+    fn from_inputs(start: usize) -> Self {
+        Fq::new_input(start)
+    }
+}
+
+impl<C: Curve> Allocate for Fq2<C> {
+    fn from_inputs(start: usize) -> Self {
+        Fq2::new_input(indices(start))
+    }
+}
+
+impl<C: Curve> Allocate for Fq6<C> {
+    fn from_inputs(start: usize) -> Self {
+        Fq6::new_input(indices(start))
+    }
+}
+
+impl<C: Curve> Allocate for Fq12<C> {
+    fn from_inputs(start: usize) -> Self {
+        Fq12::new_input(indices(start))
+    }
+}
+
+impl Allocate for Affine<Fq> {
+    fn from_inputs(start: usize) -> Self {
+        Affine::<Fq>::new_input(indices(start))
+    }
+}
+
+impl<C: Curve> Allocate for Affine<Fq2<C>> {
+    fn from_inputs(start: usize) -> Self {
+        Affine::<Fq2<C>>::new_input(indices(start))
+    }
+}
+
+impl<C: Curve> Allocate for G2Projective<C> {
+    fn from_inputs(start: usize) -> Self {
+        G2Projective::new_input(indices(start))
+    }
+}
+
+impl<C: Curve> Allocate for LineFn<C> {
+    fn from_inputs(start: usize) -> Self {
+        LineFn::from_coeffs(Fq2::from_inputs(start), Fq2::from_inputs(start + 2))
+    }
+}
+
+impl<C: Curve> Allocate for Krbn2345<C> {
+    fn from_inputs(start: usize) -> Self {
+        Krbn2345::new_input(indices(start))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::InputAllocator;
+    use crate::circuit::circuit::Circuit;
+
+    #[test]
+    fn test_allocate_inputs() {
+        let mut inputs = InputAllocator::new();
+        let lhs = inputs.fq2("lhs");
+        let pt = inputs.g2("pt");
+
+        assert_eq!(lhs.c1().c0(), &Circuit::circuit_input(1));
+        assert_eq!(pt.y().c0().c0(), &Circuit::circuit_input(4));
+        assert_eq!(inputs.params()[1].name, "pt");
+        assert_eq!(inputs.params()[1].cairo_type, "AffineG2");
+        assert_eq!(inputs.params()[1].inputs, 2..6);
+        assert_eq!(inputs.layout()[..3], ["CI<0>: lhs.c0.c0", "CI<1>: lhs.c1.c0", "CI<2>: pt.x.c0.c0"]);
+    }
+}
@@ -1,5 +1,14 @@
-mod adder;
+pub(crate) mod adder;
 mod constants;
+mod emitter;
+pub mod function;
+pub mod inputs;
+pub mod evaluator;
+pub mod optimizer;
+pub mod regen;
+pub mod stats;
 pub mod builder;
+#[allow(clippy::module_inception)]
 pub mod circuit;
 pub mod utils;
+pub mod verifier;
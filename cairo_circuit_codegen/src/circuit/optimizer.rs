@@ -0,0 +1,459 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use super::circuit::{Circuit, Gate, Leaves};
+use super::evaluator::bn254_scalar_modulus;
+
+// Passes are retried until none of them changes the circuit, in practice two rounds are enough
+const MAX_ROUNDS: usize = 8;
+
+/// Rewrites run by the optimizer, all of them are enabled by default
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Passes {
+    /// Evaluates gates over constants, only where the result does not depend on the modulus: it is
+    /// neither negative nor above the BN254 scalar field, the smallest field of the circuits
+    pub fold: bool,
+    /// Removes `x + 0`, `x - 0`, `x * 1`, `x * 0`, `x - x`, `I<1>` and `I<I<x>>`
+    pub identities: bool,
+    /// Pushes `0 - x` negations outwards, where they cancel or merge into an addition
+    pub negations: bool,
+    /// Orders the operands of `A` and `M` so commuted gates share a node
+    pub commute: bool,
+    /// Merges products of inverses into a single inverse of the product
+    pub inverses: bool,
+}
+
+impl Passes {
+    pub fn none() -> Self {
+        Self { fold: false, identities: false, negations: false, commute: false, inverses: false }
+    }
+
+    // One set per enabled pass, they are applied and checked separately
+    fn split(&self) -> Vec<Passes> {
+        let mut passes = vec![];
+        let mut push = |enabled: bool, set: fn(&mut Passes)| {
+            if enabled {
+                let mut pass = Passes::none();
+                set(&mut pass);
+                passes.push(pass);
+            }
+        };
+        push(self.fold, |pass| pass.fold = true);
+        push(self.identities, |pass| pass.identities = true);
+        push(self.negations, |pass| pass.negations = true);
+        push(self.commute, |pass| pass.commute = true);
+        push(self.inverses, |pass| pass.inverses = true);
+        passes
+    }
+}
+
+impl Default for Passes {
+    fn default() -> Self {
+        Self { fold: true, identities: true, negations: true, commute: true, inverses: true }
+    }
+}
+
+/// Gate counts of a circuit before and after optimization
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OptimizeReport {
+    pub name: String,
+    pub before: usize,
+    pub after: usize,
+}
+
+/// Simplifies circuits between IR construction and emission.
+///
+/// Every pass rebuilds the DAG bottom-up through rewriting constructors, so a simplification
+/// exposed by another one is picked up in the same walk. A pass is only kept when it does not
+/// grow the circuit and still reads the same inputs, the Cairo wiring of a function relies on
+/// its inputs being contiguous.
+pub struct Optimizer {
+    passes: Passes,
+    active: Passes,
+    rewritten: HashMap<usize, Circuit>,
+}
+
+impl Default for Optimizer {
+    fn default() -> Self {
+        Self::new(Passes::default())
+    }
+}
+
+impl Optimizer {
+    pub fn new(passes: Passes) -> Self {
+        Self { passes, active: Passes::none(), rewritten: HashMap::new() }
+    }
+
+    /// Optimizes the outputs of a circuit together, subexpressions shared between them stay shared
+    pub fn optimize(&mut self, outputs: &[&Circuit]) -> Vec<Circuit> {
+        let mut current: Vec<Circuit> = outputs.iter().map(|circuit| (*circuit).clone()).collect();
+        let inputs = Leaves::of(&current).inputs;
+
+        for _ in 0..MAX_ROUNDS {
+            let mut changed = false;
+            for pass in self.passes.split() {
+                self.active = pass;
+                self.rewritten.clear();
+                let next: Vec<Circuit> = current.iter().map(|circuit| self.rewrite(circuit)).collect();
+
+                let smaller = gate_count(&next) <= gate_count(&current);
+                if next != current && smaller && Leaves::of(&next).inputs == inputs {
+                    current = next;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+        self.rewritten.clear();
+
+        // Cairo reads outputs from gates, an output simplified down to a leaf is kept as a gate
+        current
+            .into_iter()
+            .map(|circuit| match circuit.gate() {
+                Gate::Input(_) | Gate::Const(_) => Circuit::circuit_add(&circuit, &zero()),
+                _ => circuit,
+            })
+            .collect()
+    }
+
+    fn rewrite(&mut self, root: &Circuit) -> Circuit {
+        for node in Circuit::post_order([root], |node| self.rewritten.contains_key(&node.id())) {
+            let get = |circuit: &Circuit| self.rewritten[&circuit.id()].clone();
+            let circuit = match node.gate() {
+                Gate::Input(_) | Gate::Const(_) => node.clone(),
+                Gate::Add(lhs, rhs) => self.add(&get(lhs), &get(rhs)),
+                Gate::Sub(lhs, rhs) => self.sub(&get(lhs), &get(rhs)),
+                Gate::Mul(lhs, rhs) => self.mul(&get(lhs), &get(rhs)),
+                Gate::Inv(lhs) => self.inv(&get(lhs)),
+            };
+            self.rewritten.insert(node.id(), circuit);
+        }
+
+        self.rewritten[&root.id()].clone()
+    }
+
+    fn add(&self, lhs: &Circuit, rhs: &Circuit) -> Circuit {
+        let pass = self.active;
+        if let (true, Some(a), Some(b)) = (pass.fold, constant(lhs), constant(rhs)) {
+            if let Some(folded) = fold(lhs, "ADD", rhs, a + b) {
+                return folded;
+            }
+        }
+        if pass.identities {
+            if is_zero(lhs) {
+                return rhs.clone();
+            }
+            if is_zero(rhs) {
+                return lhs.clone();
+            }
+        }
+        if pass.negations {
+            if let Some(x) = negated(rhs) {
+                return self.sub(lhs, &x);
+            }
+            if let Some(x) = negated(lhs) {
+                return self.sub(rhs, &x);
+            }
+        }
+
+        let (lhs, rhs) = self.ordered(lhs, rhs);
+        Circuit::circuit_add(lhs, rhs)
+    }
+
+    fn sub(&self, lhs: &Circuit, rhs: &Circuit) -> Circuit {
+        let pass = self.active;
+        if let (true, Some(a), Some(b)) = (pass.fold, constant(lhs), constant(rhs)) {
+            // Negative results would depend on the modulus
+            if let Some(folded) = (a >= b).then(|| fold(lhs, "SUB", rhs, a - b)).flatten() {
+                return folded;
+            }
+        }
+        if pass.identities {
+            if is_zero(rhs) {
+                return lhs.clone();
+            }
+            if lhs == rhs {
+                return zero();
+            }
+        }
+        if pass.negations {
+            match (is_zero(lhs), negated(rhs), rhs.gate()) {
+                // -(-x) = x
+                (true, Some(x), _) => return x,
+                // -(a - b) = b - a
+                (true, None, Gate::Sub(a, b)) => return self.sub(b, a),
+                // a - (-x) = a + x
+                (false, Some(x), _) => return self.add(lhs, &x),
+                _ => {}
+            }
+            // -x - b = -(x + b)
+            if let Some(x) = negated(lhs) {
+                return self.neg(&self.add(&x, rhs));
+            }
+        }
+
+        Circuit::circuit_sub(lhs, rhs)
+    }
+
+    fn mul(&self, lhs: &Circuit, rhs: &Circuit) -> Circuit {
+        let pass = self.active;
+        if let (true, Some(a), Some(b)) = (pass.fold, constant(lhs), constant(rhs)) {
+            if let Some(folded) = fold(lhs, "MUL", rhs, a * b) {
+                return folded;
+            }
+        }
+        if pass.identities {
+            if is_one(lhs) {
+                return rhs.clone();
+            }
+            if is_one(rhs) {
+                return lhs.clone();
+            }
+            if is_zero(lhs) || is_zero(rhs) {
+                return zero();
+            }
+        }
+        if pass.negations {
+            match (negated(lhs), negated(rhs)) {
+                (Some(a), Some(b)) => return self.mul(&a, &b),
+                (Some(a), None) => return self.neg(&self.mul(&a, rhs)),
+                (None, Some(b)) => return self.neg(&self.mul(lhs, &b)),
+                (None, None) => {}
+            }
+        }
+        if pass.inverses {
+            if let Some(merged) = self.merge_inverses(lhs, rhs).or_else(|| self.merge_inverses(rhs, lhs)) {
+                return merged;
+            }
+        }
+
+        let (lhs, rhs) = self.ordered(lhs, rhs);
+        Circuit::circuit_mul(lhs, rhs)
+    }
+
+    fn inv(&self, lhs: &Circuit) -> Circuit {
+        let pass = self.active;
+        if pass.identities {
+            if is_one(lhs) {
+                return lhs.clone();
+            }
+            // Only differs from the original when x is zero, where the circuit fails anyway
+            if let Gate::Inv(x) = lhs.gate() {
+                return x.clone();
+            }
+        }
+        if pass.negations {
+            if let Some(x) = negated(lhs) {
+                return self.neg(&self.inv(&x));
+            }
+        }
+
+        Circuit::circuit_inv(lhs)
+    }
+
+    fn neg(&self, lhs: &Circuit) -> Circuit {
+        self.sub(&zero(), lhs)
+    }
+
+    // I<a> * I<b> = I<a * b> and (x * I<a>) * I<b> = x * I<a * b>
+    fn merge_inverses(&self, lhs: &Circuit, rhs: &Circuit) -> Option<Circuit> {
+        let Gate::Inv(b) = rhs.gate() else { return None };
+        match lhs.gate() {
+            Gate::Inv(a) => Some(self.inv(&self.mul(a, b))),
+            Gate::Mul(x, inv) | Gate::Mul(inv, x) if matches!(inv.gate(), Gate::Inv(_)) => {
+                let Gate::Inv(a) = inv.gate() else { unreachable!() };
+                Some(self.mul(x, &self.inv(&self.mul(a, b))))
+            }
+            _ => None,
+        }
+    }
+
+    fn ordered<'a>(&self, lhs: &'a Circuit, rhs: &'a Circuit) -> (&'a Circuit, &'a Circuit) {
+        match self.active.commute && lhs.id() > rhs.id() {
+            true => (rhs, lhs),
+            false => (lhs, rhs),
+        }
+    }
+}
+
+/// Number of gates reachable from the outputs, shared gates are counted once
+pub fn gate_count<'a>(outputs: impl IntoIterator<Item = &'a Circuit>) -> usize {
+    let mut visited = HashSet::new();
+    let mut stack: Vec<&Circuit> = outputs.into_iter().collect();
+    let mut count = 0;
+
+    while let Some(circuit) = stack.pop() {
+        if !visited.insert(circuit.id()) {
+            continue;
+        }
+        if !matches!(circuit.gate(), Gate::Input(_) | Gate::Const(_)) {
+            count += 1;
+        }
+        stack.extend(circuit.gate().operands());
+    }
+    count
+}
+
+fn zero() -> Circuit {
+    Circuit::circuit_constant("ZERO", BigUint::zero())
+}
+
+fn constant(circuit: &Circuit) -> Option<&BigUint> {
+    match circuit.gate() {
+        Gate::Const(constant) => Some(&constant.value),
+        _ => None,
+    }
+}
+
+fn is_zero(circuit: &Circuit) -> bool {
+    constant(circuit).is_some_and(|value| value.is_zero())
+}
+
+fn is_one(circuit: &Circuit) -> bool {
+    constant(circuit).is_some_and(|value| value.is_one())
+}
+
+// `0 - x`
+fn negated(circuit: &Circuit) -> Option<Circuit> {
+    match circuit.gate() {
+        Gate::Sub(lhs, rhs) if is_zero(lhs) => Some(rhs.clone()),
+        _ => None,
+    }
+}
+
+// Folded constants are named after the expression they replace, e.g. `NINE_MUL_NINE`. The
+// optimizer does not know the modulus, a value is only folded when it is below the smallest one
+// circuits are evaluated with and so already reduced whatever the field
+fn fold(lhs: &Circuit, op: &str, rhs: &Circuit, value: BigUint) -> Option<Circuit> {
+    if value >= bn254_scalar_modulus() {
+        return None;
+    }
+    let name = |circuit: &Circuit| match circuit.gate() {
+        Gate::Const(constant) => constant.name,
+        _ => unreachable!("only constants are folded"),
+    };
+    Some(Circuit::circuit_constant(static_name(format!("{}_{}_{}", name(lhs), op, name(rhs))), value))
+}
+
+// Constant names are `&'static str`, folded names are interned so each is only leaked once
+fn static_name(name: String) -> &'static str {
+    thread_local! {
+        static NAMES: RefCell<HashSet<&'static str>> = RefCell::new(HashSet::new());
+    }
+    NAMES.with(|names| {
+        if let Some(name) = names.borrow().get(name.as_str()) {
+            return *name;
+        }
+        let name: &'static str = Box::leak(name.into_boxed_str());
+        names.borrow_mut().insert(name);
+        name
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use super::{gate_count, Optimizer, Passes};
+    use crate::circuit::adder::CairoCodeAdder;
+    use crate::circuit::circuit::{Circuit, Gate, Leaves};
+    use crate::circuit::evaluator::{bn254_modulus, Evaluator};
+    use crate::fields::{affine::Affine, fq::Fq, fq2::Fq2, fq6::Fq6, ECOperations, FieldConstants, FieldOps};
+
+    fn optimize(outputs: &[&Circuit]) -> Vec<Circuit> {
+        Optimizer::default().optimize(outputs)
+    }
+
+    #[test]
+    fn test_identities() {
+        let (x, y) = (Fq::new_input(0), Fq::new_input(1));
+        let xy = &x * &y;
+
+        assert_eq!(optimize(&[(&(&xy * &Fq::one()) - &Fq::zero()).c0()]), vec![xy.c0().clone()]);
+        assert_eq!(optimize(&[(-&(-&xy)).c0()]), vec![xy.c0().clone()]);
+        assert_eq!(optimize(&[xy.inv().inv().c0()]), vec![xy.c0().clone()]);
+    }
+
+    #[test]
+    fn test_negations_and_commute() {
+        let (a, b) = (Fq::new_input(0), Fq::new_input(1));
+
+        assert_eq!(optimize(&[(&a + &(-&b)).c0()]), vec![(&a - &b).c0().clone()]);
+        assert_eq!(optimize(&[(&(-&a) * &(-&b)).c0()]), vec![(&a * &b).c0().clone()]);
+        assert_eq!(optimize(&[(&(-&a) - &b).c0()]), vec![(-&(&a + &b)).c0().clone()]);
+
+        let (ab, ba) = (&a * &b, &b * &a);
+        assert_eq!(gate_count(&optimize(&[ab.c0(), ba.c0()])), 1);
+    }
+
+    #[test]
+    fn test_fold_and_inverses() {
+        let (a, b) = (Fq::new_input(0), Fq::new_input(1));
+        let nine = Fq::constant("NINE", 9u64);
+
+        let folded = optimize(&[(&a * &(&nine * &nine)).c0()]);
+        let Gate::Mul(lhs, rhs) = folded[0].gate() else { panic!("expected a product") };
+        let Gate::Const(constant) = [lhs, rhs].into_iter().find(|c| c.id() != a.c0().id()).unwrap().gate() else {
+            panic!("expected a folded constant")
+        };
+        assert_eq!((constant.name, constant.value.clone()), ("NINE_MUL_NINE", BigUint::from(81u64)));
+
+        let out = &a.inv() * &b.inv();
+        assert_eq!(gate_count([out.c0()]), 3);
+        assert_eq!(optimize(&[out.c0()]), vec![(&a * &b).inv().c0().clone()]);
+    }
+
+    #[test]
+    fn test_fold_large_constants() {
+        // Two 254-bit values, their sum and product are above every modulus
+        let a = Fq::new_input(0);
+        let (p1, p2) = (Fq::constant("P_MINUS_ONE", bn254_modulus() - 1u32), Fq::constant("P_MINUS_TWO", bn254_modulus() - 2u32));
+        let sum = &a * &(&p1 + &p2);
+        let product = &a * &(&p1 * &p2);
+        let small = &a * &(&Fq::constant("NINE", 9u64) + &Fq::constant("SEVEN", 7u64));
+
+        for out in [&sum, &product] {
+            let optimized = optimize(&[out.c0()]);
+            let constants = Leaves::of(&optimized).constants;
+            assert!(constants.values().all(|constant| constant.value < bn254_modulus()), "{:?}", constants);
+            let eval = |circuits: &[&Circuit]| Evaluator::new(vec![BigUint::from(5u32)], bn254_modulus()).eval_all(circuits);
+            assert_eq!(eval(&optimized.iter().collect::<Vec<_>>()), eval(&[out.c0()]));
+        }
+        assert_eq!(gate_count(&optimize(&[small.c0()])), 1);
+    }
+
+    #[test]
+    fn test_keeps_inputs() {
+        // x * 0 no longer reads x, the Cairo wiring needs every input
+        let x = Fq::new_input(0);
+        let out = &x * &Fq::zero();
+        assert_eq!(optimize(&[out.c0()]), vec![out.c0().clone()]);
+
+        // Outputs simplified to a leaf are still gates
+        let out = Optimizer::new(Passes { identities: true, ..Passes::none() }).optimize(&[(&x * &Fq::one()).c0()]);
+        assert_eq!(gate_count(&out), 1);
+    }
+
+    #[test]
+    fn test_preserves_values() {
+        let (fq6, fq6_rhs): (Fq6, Fq6) = (Fq6::new_input(std::array::from_fn(|i| i)), Fq6::new_input(std::array::from_fn(|i| 6 + i)));
+        let (p, q) = (Affine::<Fq2>::new_input([0, 1, 2, 3]), Affine::<Fq2>::new_input([4, 5, 6, 7]));
+        let (div, double, neg) = (fq6.div(&fq6_rhs), p.add(&q).double(), FieldOps::neg(&fq6));
+        let outputs = [div.circuits(), double.circuits(), neg.circuits()];
+
+        let inputs: Vec<BigUint> = (0..12u64).map(|k| BigUint::from(k * k + 7)).collect();
+        for circuits in outputs {
+            let optimized = optimize(&circuits);
+            assert!(gate_count(&optimized) <= gate_count(circuits.iter().copied()));
+
+            let expected = Evaluator::new(inputs.clone(), bn254_modulus()).eval_all(&circuits);
+            let actual = Evaluator::new(inputs.clone(), bn254_modulus()).eval_all(&optimized.iter().collect::<Vec<_>>());
+            assert_eq!(actual, expected);
+        }
+    }
+}
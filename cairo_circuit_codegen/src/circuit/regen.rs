@@ -0,0 +1,383 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::{
+    builder::CairoCodeBuilder,
+    optimizer::Passes,
+    utils::find_generator,
+};
+
+/// Manifest checked in next to the crate, paths in it are relative to its directory
+pub const DEFAULT_MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/regen.json");
+
+#[derive(Debug)]
+pub enum RegenError {
+    Io(PathBuf, io::Error),
+    Manifest(serde_json::Error),
+    UnknownGenerator(String),
+    /// The file has no `// @generated <section> begin` and `end` markers
+    MissingSection { file: PathBuf, section: String },
+}
+
+impl fmt::Display for RegenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegenError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            RegenError::Manifest(err) => write!(f, "invalid manifest: {}", err),
+            RegenError::UnknownGenerator(name) => write!(f, "unknown generator {}", name),
+            RegenError::MissingSection { file, section } => {
+                write!(f, "{}: no markers for section {}", file.display(), section)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RegenError {}
+
+/// A block of a checked-in Cairo file produced by running generators in order.
+///
+/// The block sits between a `// @generated <section> begin` line and a `// @generated <section>
+/// end` line, everything outside the markers, imports included, is left untouched.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Section {
+    pub file: PathBuf,
+    pub section: String,
+    pub generators: Vec<String>,
+    /// Emit the Cairo evaluation functions along with the circuit types
+    #[serde(default)]
+    pub functions: bool,
+    #[serde(default)]
+    pub optimize: bool,
+}
+
+impl Section {
+    /// Fresh output of the generators
+    pub fn render(&self) -> Result<String, RegenError> {
+        let mut builder = CairoCodeBuilder::new()
+            .with_functions(self.functions)
+            .with_optimizer(self.optimize.then(Passes::default));
+        for name in &self.generators {
+            let generator = find_generator(name).ok_or_else(|| RegenError::UnknownGenerator(name.clone()))?;
+            (generator.generate)(&mut builder);
+        }
+        Ok(builder.build())
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Manifest {
+    pub sections: Vec<Section>,
+}
+
+impl Manifest {
+    pub fn from_json(json: &str) -> Result<Self, RegenError> {
+        serde_json::from_str(json).map_err(RegenError::Manifest)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, RegenError> {
+        let json = fs::read_to_string(path).map_err(|err| RegenError::Io(path.to_path_buf(), err))?;
+        Self::from_json(&json)
+    }
+
+    /// Regenerates every section of the files under `root` and returns the sections that changed.
+    ///
+    /// With `check` nothing is written, a non-empty result means the checked-in Cairo drifted
+    /// from the generators.
+    pub fn regen(&self, root: &Path, check: bool) -> Result<Vec<&Section>, RegenError> {
+        let mut changed = vec![];
+        for section in &self.sections {
+            let path = root.join(&section.file);
+            let source = fs::read_to_string(&path).map_err(|err| RegenError::Io(path.clone(), err))?;
+            let updated = replace_section(&source, &section.section, &section.render()?)
+                .ok_or_else(|| RegenError::MissingSection { file: path.clone(), section: section.section.clone() })?;
+
+            if updated != source {
+                changed.push(section);
+                if !check {
+                    fs::write(&path, updated).map_err(|err| RegenError::Io(path.clone(), err))?;
+                }
+            }
+        }
+        Ok(changed)
+    }
+}
+
+/// Replaces the body between the markers of `section`, the marker lines themselves are kept
+pub fn replace_section(source: &str, section: &str, body: &str) -> Option<String> {
+    let begin = source.find(&format!("// @generated {} begin", section))?;
+    // Anything after the begin marker on its line is a comment for readers, keep it
+    let body_start = source[begin..].find('\n').map_or(source.len(), |len| begin + len + 1);
+    let body_end = body_start + source[body_start..].find(&format!("// @generated {} end", section))?;
+
+    Some(format!("{}{}{}", &source[..body_start], body, &source[body_end..]))
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::{BTreeSet, HashMap};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use super::{replace_section, Manifest, RegenError, DEFAULT_MANIFEST};
+    use crate::circuit::verifier::PLONK_VERIFIER_DIR;
+
+    #[test]
+    fn test_replace_section() {
+        let source = "use a;\n// @generated fq2 begin, do not edit\ntype Old = CE<CI<0>>;\n// @generated fq2 end\nfn f() {}\n";
+        let updated = replace_section(source, "fq2", "type New = CE<CI<1>>;\n").unwrap();
+
+        assert_eq!(updated, "use a;\n// @generated fq2 begin, do not edit\ntype New = CE<CI<1>>;\n// @generated fq2 end\nfn f() {}\n");
+        assert_eq!(replace_section(&updated, "fq2", "type New = CE<CI<1>>;\n").unwrap(), updated);
+        assert_eq!(replace_section(source, "fq6", ""), None);
+    }
+
+    #[test]
+    fn test_regen_check() {
+        let dir = std::env::temp_dir().join(format!("regen_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let stale = "// header\n// @generated ops begin\ntype Stale = CE<CI<0>>;\n// @generated ops end\n";
+        fs::write(dir.join("ops.cairo"), stale).unwrap();
+
+        let manifest = Manifest::from_json(
+            r#"{ "sections": [{ "file": "ops.cairo", "section": "ops", "generators": ["fq2_field_ops"] }] }"#,
+        )
+        .unwrap();
+
+        // Check reports the drift without touching the file, regen fixes it
+        assert_eq!(manifest.regen(&dir, true).unwrap().len(), 1);
+        assert_eq!(fs::read_to_string(dir.join("ops.cairo")).unwrap(), stale);
+        assert_eq!(manifest.regen(&dir, false).unwrap().len(), 1);
+        assert!(manifest.regen(&dir, true).unwrap().is_empty());
+
+        let regenerated = fs::read_to_string(dir.join("ops.cairo")).unwrap();
+        assert!(regenerated.starts_with("// header\n// @generated ops begin\n"));
+        assert!(regenerated.contains("type Fq2MulC0 = "));
+
+        let unknown = Manifest::from_json(r#"{ "sections": [{ "file": "ops.cairo", "section": "ops", "generators": ["nope"] }] }"#);
+        assert!(matches!(unknown.unwrap().regen(&dir, true), Err(RegenError::UnknownGenerator(_))));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_manifest_sections() {
+        // Every checked-in section is exactly what its generators render, the Cairo callers are
+        // wired to these layouts
+        let manifest = Manifest::load(Path::new(DEFAULT_MANIFEST)).unwrap();
+        let root = Path::new(DEFAULT_MANIFEST).parent().unwrap();
+        for section in &manifest.sections {
+            let source = fs::read_to_string(root.join(&section.file)).unwrap();
+            let rendered = replace_section(&source, &section.section, &section.render().unwrap());
+            assert!(rendered.is_some(), "{}: no markers for {}", section.file.display(), section.section);
+            assert!(
+                rendered.unwrap() == source,
+                "{}: section {} drifted, run `cargo run -- regen`",
+                section.file.display(),
+                section.section
+            );
+        }
+    }
+
+    // Sources without line comments, paired with their path
+    fn cairo_sources(dir: &Path) -> Vec<(PathBuf, String)> {
+        let mut sources = vec![];
+        let mut dirs = vec![dir.to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(&dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path.extension().is_some_and(|ext| ext == "cairo") {
+                    let source = fs::read_to_string(&path).unwrap();
+                    let code = source.lines().map(|line| line.split("//").next().unwrap()).collect::<Vec<_>>().join("\n");
+                    sources.push((path, code));
+                }
+            }
+        }
+        sources
+    }
+
+    // Identifiers and numbers of a Cairo snippet
+    fn tokens(code: &str) -> Vec<&str> {
+        code.split(|c: char| !c.is_alphanumeric() && c != '_').filter(|token| !token.is_empty()).collect()
+    }
+
+    // Splits a comma separated list on the commas outside of brackets
+    fn split_list(list: &str) -> Vec<&str> {
+        let (mut items, mut depth, mut start) = (vec![], 0, 0);
+        for (idx, c) in list.char_indices() {
+            match c {
+                '<' | '(' => depth += 1,
+                '>' | ')' => depth -= 1,
+                ',' if depth == 0 => {
+                    items.push(list[start..idx].trim());
+                    start = idx + 1;
+                }
+                _ => {}
+            }
+        }
+        items.push(list[start..].trim());
+        items.into_iter().filter(|item| !item.is_empty()).collect()
+    }
+
+    // The text between the parenthesis at `open` and its match
+    fn parenthesized(code: &str, open: usize) -> &str {
+        let mut depth = 0;
+        for (idx, c) in code[open..].char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 1 => return &code[open + 1..open + idx],
+                ')' => depth -= 1,
+                _ => {}
+            }
+        }
+        panic!("unbalanced parenthesis")
+    }
+
+    /// `type Name<const P: usize> = body;` aliases of the typedef modules
+    struct Typedefs(HashMap<String, (Vec<String>, String)>);
+
+    impl Typedefs {
+        fn parse(sources: &[(PathBuf, String)]) -> Self {
+            let mut typedefs = HashMap::new();
+            for (_, code) in sources {
+                for (start, _) in code.match_indices("type ") {
+                    let statement = &code[start + "type ".len()..start + code[start..].find(';').unwrap()];
+                    let (head, body) = statement.split_once('=').unwrap();
+                    let mut head = tokens(head).into_iter();
+                    let name = head.next().unwrap().to_string();
+                    let params = head.filter(|&token| token != "const" && token != "usize").map(str::to_string).collect();
+                    typedefs.insert(name, (params, body.to_string()));
+                }
+            }
+            Self(typedefs)
+        }
+
+        /// `CI` indices read by a type such as `Fq2MulC0` or `Add::<0, 2>`
+        fn inputs(&self, ty: &str) -> BTreeSet<usize> {
+            let mut ty = tokens(ty).into_iter();
+            let (params, body) = &self.0[ty.next().unwrap()];
+            let args: HashMap<&str, &str> = params.iter().map(String::as_str).zip(ty).collect();
+
+            let mut inputs = BTreeSet::new();
+            let body = tokens(body);
+            for (k, &token) in body.iter().enumerate() {
+                if token == "CI" {
+                    let idx = body[k + 1];
+                    inputs.insert(args.get(idx).unwrap_or(&idx).parse().unwrap());
+                } else if self.0.contains_key(token) {
+                    inputs.extend(self.inputs(token));
+                }
+            }
+            inputs
+        }
+    }
+
+    #[test]
+    fn test_circuit_wiring() {
+        // Every `.new_inputs()` chain over the outputs of a circuit function feeds exactly the
+        // inputs the typedefs read, the constants coming last. The chains are written by hand
+        // and the typedefs are regenerated, so a generator change that moves an input is caught
+        // here rather than by a failing evaluation in Cairo
+        let src = Path::new(PLONK_VERIFIER_DIR).join("src");
+        let typedef_dir = src.join("circuits/typedefs");
+        let typedefs = Typedefs::parse(&cairo_sources(&typedef_dir));
+
+        // Output types of the `fn name() -> (T0, T1, ..)` of every circuit module
+        let mut circuit_fns: HashMap<(String, String), Vec<String>> = HashMap::new();
+        for (path, code) in cairo_sources(&src.join("circuits")) {
+            if path.starts_with(&typedef_dir) {
+                continue;
+            }
+            let module = path.file_stem().unwrap().to_str().unwrap().to_string();
+            for (start, _) in code.match_indices("fn ") {
+                let signature = &code[start..];
+                let Some(outputs) = signature.find("() -> (").map(|idx| idx + "() -> ".len()) else { continue };
+                let name = tokens(&signature["fn ".len()..])[0].to_string();
+                let types = split_list(parenthesized(signature, outputs)).into_iter().map(str::to_string).collect();
+                circuit_fns.insert((module.clone(), name), types);
+            }
+        }
+
+        let mut checked = 0;
+        for (path, code) in cairo_sources(&src) {
+            if path.starts_with(&typedef_dir) {
+                continue;
+            }
+            let own = path.file_stem().unwrap().to_str().unwrap();
+            let modules: BTreeSet<&str> = tokens(&code)
+                .into_iter()
+                .chain([own])
+                .filter(|module| circuit_fns.keys().any(|(known, _)| known == module))
+                .collect();
+
+            // Statements in source order, a binding lasts until the end of its function
+            let mut statements: Vec<(usize, &str)> = ["fn ", "let (", "match ("]
+                .into_iter()
+                .flat_map(|pattern| code.match_indices(pattern).map(move |(start, _)| (start, pattern)))
+                .collect();
+            statements.sort();
+            statements.push((code.len(), "fn "));
+
+            let mut binding: Option<(HashMap<&str, &str>, &str, bool)> = None;
+            for (start, statement) in statements {
+                let rest = &code[start..];
+                match statement {
+                    "fn " | "let (" => {
+                        if let Some((_, name, used)) = &binding {
+                            assert!(used, "{}: outputs of {} are never evaluated", path.display(), name);
+                        }
+                        binding = None;
+                        if statement == "fn " {
+                            continue;
+                        }
+                        let names = split_list(parenthesized(rest, "let ".len()));
+                        let call = tokens(rest[rest.find(')').unwrap() + 1..].split(';').next().unwrap());
+                        let [name] = call[..] else { continue };
+                        let mut candidates = modules.iter().filter_map(|module| circuit_fns.get(&(module.to_string(), name.to_string())));
+                        let Some(types) = candidates.next() else { continue };
+                        assert!(candidates.next().is_none(), "{}: {} is ambiguous", path.display(), name);
+                        assert_eq!(names.len(), types.len(), "{}: {} has {} outputs", path.display(), name, types.len());
+                        let types = names.into_iter().zip(types.iter().map(String::as_str)).collect();
+                        binding = Some((types, name, false));
+                    }
+                    _ => {
+                        let outputs = split_list(parenthesized(rest, "match ".len()));
+                        let Some((types, name, used)) = &mut binding else { continue };
+                        if !outputs.iter().any(|output| types.contains_key(output)) {
+                            continue;
+                        }
+                        let chain = rest.split(".done()").next().unwrap();
+                        assert!(chain.contains(".new_inputs()"), "{}: outputs of {} evaluated without inputs", path.display(), name);
+                        let args: Vec<&str> = chain.split(".next(").skip(1).map(|arg| arg.split(')').next().unwrap().trim()).collect();
+
+                        let inputs: BTreeSet<usize> = outputs.iter().flat_map(|output| typedefs.inputs(types[output])).collect();
+                        assert_eq!(
+                            inputs,
+                            (0..inputs.len()).collect(),
+                            "{}: outputs of {} read inputs with gaps",
+                            path.display(),
+                            name
+                        );
+                        assert_eq!(args.len(), inputs.len(), "{}: {} reads {} inputs, fed {:?}", path.display(), name, inputs.len(), args);
+                        let is_constant = |arg: &&str| arg.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit());
+                        let first_constant = args.iter().position(is_constant).unwrap_or(args.len());
+                        assert!(
+                            args[first_constant..].iter().all(is_constant),
+                            "{}: constants of {} are not fed last, {:?}",
+                            path.display(),
+                            name,
+                            args
+                        );
+                        *used = true;
+                        checked += 1;
+                    }
+                }
+            }
+        }
+        assert!(checked > 0, "no circuit evaluation found under {}", src.display());
+    }
+}
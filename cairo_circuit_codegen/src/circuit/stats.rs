@@ -0,0 +1,183 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::circuit::{Circuit, Gate, Leaves};
+
+/// Gates of a circuit by kind, shared gates are counted once
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct GateCounts {
+    pub add: usize,
+    pub sub: usize,
+    pub mul: usize,
+    pub inv: usize,
+}
+
+impl GateCounts {
+    pub fn total(&self) -> usize {
+        self.add + self.sub + self.mul + self.inv
+    }
+}
+
+/// Size of an emitted circuit
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CircuitStats {
+    pub name: String,
+    pub gates: GateCounts,
+    /// Distinct `CI` inputs read, constants excluded
+    pub inputs: usize,
+    pub constants: usize,
+    pub outputs: usize,
+    /// Longest chain of gates from an input to an output
+    pub depth: usize,
+}
+
+impl CircuitStats {
+    pub fn of(name: &str, outputs: &[&Circuit]) -> Self {
+        let mut gates = GateCounts::default();
+        let mut depths: HashMap<usize, usize> = HashMap::new();
+
+        for node in Circuit::post_order(outputs.iter().copied(), |_| false) {
+            match node.gate() {
+                Gate::Input(_) | Gate::Const(_) => {}
+                Gate::Add(..) => gates.add += 1,
+                Gate::Sub(..) => gates.sub += 1,
+                Gate::Mul(..) => gates.mul += 1,
+                Gate::Inv(_) => gates.inv += 1,
+            }
+            let depth = match node.gate() {
+                Gate::Input(_) | Gate::Const(_) => 0,
+                gate => 1 + gate.operands().iter().map(|operand| depths[&operand.id()]).max().unwrap_or(0),
+            };
+            depths.insert(node.id(), depth);
+        }
+
+        let leaves = Leaves::of(outputs.iter().copied());
+        Self {
+            name: name.to_string(),
+            gates,
+            inputs: leaves.inputs.len(),
+            constants: leaves.constants.len(),
+            outputs: outputs.len(),
+            depth: outputs.iter().map(|circuit| depths[&circuit.id()]).max().unwrap_or(0),
+        }
+    }
+}
+
+/// Estimated Cairo steps of evaluating a circuit, one weight per gate kind and per value moved.
+///
+/// The defaults are a rough fit of the Fq12 square and multiply benchmarks of the readme, they
+/// are meant to compare formulas against each other rather than to predict exact costs. Missing
+/// fields of a JSON cost model keep their default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostModel {
+    /// Building the circuit and calling `eval`
+    pub base: u64,
+    pub add: u64,
+    pub sub: u64,
+    pub mul: u64,
+    pub inv: u64,
+    /// Every `.next()`, constants included
+    pub input: u64,
+    /// Every `get_output`
+    pub output: u64,
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self { base: 120, add: 1, sub: 1, mul: 1, inv: 2, input: 8, output: 6 }
+    }
+}
+
+impl CostModel {
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    pub fn steps(&self, stats: &CircuitStats) -> u64 {
+        let gates = &stats.gates;
+        let values = |count: usize, weight: u64| count as u64 * weight;
+
+        self.base
+            + values(gates.add, self.add)
+            + values(gates.sub, self.sub)
+            + values(gates.mul, self.mul)
+            + values(gates.inv, self.inv)
+            + values(stats.inputs + stats.constants, self.input)
+            + values(stats.outputs, self.output)
+    }
+
+    pub fn report(&self, stats: &CircuitStats) -> CircuitReport {
+        CircuitReport { stats: stats.clone(), steps: self.steps(stats) }
+    }
+}
+
+/// Circuit size with its estimated cost, one entry of the JSON report
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CircuitReport {
+    #[serde(flatten)]
+    pub stats: CircuitStats,
+    pub steps: u64,
+}
+
+impl CircuitReport {
+    /// Header of the text report, aligned with `line`
+    pub fn header() -> String {
+        format!(
+            "{:<28}{:>6}{:>6}{:>6}{:>6}{:>8}{:>8}{:>8}{:>7}{:>8}",
+            "circuit", "add", "sub", "mul", "inv", "inputs", "consts", "outputs", "depth", "steps"
+        )
+    }
+
+    pub fn line(&self) -> String {
+        let (stats, gates) = (&self.stats, &self.stats.gates);
+        format!(
+            "{:<28}{:>6}{:>6}{:>6}{:>6}{:>8}{:>8}{:>8}{:>7}{:>8}",
+            stats.name,
+            gates.add,
+            gates.sub,
+            gates.mul,
+            gates.inv,
+            stats.inputs,
+            stats.constants,
+            stats.outputs,
+            stats.depth,
+            self.steps
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CircuitStats, CostModel, GateCounts};
+    use crate::circuit::adder::CairoCodeAdder;
+    use crate::fields::{fq::Fq, fq2::Fq2, FieldOps};
+
+    #[test]
+    fn test_circuit_stats() {
+        // (a + b) * I<a> - 9b, a + b is shared
+        let (a, b) = (Fq::new_input(0), Fq::new_input(1));
+        let sum = &a + &b;
+        let out = &(&sum * &a.inv()) - &b.scl_9();
+        let stats = CircuitStats::of("out", &[out.c0(), sum.c0()]);
+
+        assert_eq!(stats.gates, GateCounts { add: 1, sub: 1, mul: 2, inv: 1 });
+        assert_eq!((stats.inputs, stats.constants, stats.outputs, stats.depth), (2, 1, 2, 3));
+    }
+
+    #[test]
+    fn test_cost_model() {
+        let out: Fq2 = &Fq2::new_input([0, 1]) * &Fq2::new_input([2, 3]);
+        let stats = CircuitStats::of("fq2_mul", &out.circuits());
+
+        let model = CostModel::from_json(r#"{ "base": 0, "mul": 10, "input": 0, "output": 0 }"#).unwrap();
+        assert_eq!(model.add, CostModel::default().add);
+        assert_eq!(model.steps(&stats), 10 * stats.gates.mul as u64 + stats.gates.add as u64 + stats.gates.sub as u64);
+
+        let json = serde_json::to_value(CostModel::default().report(&stats)).unwrap();
+        assert_eq!(json["name"], "fq2_mul");
+        assert_eq!(json["gates"]["mul"], stats.gates.mul);
+        assert!(json["steps"].is_u64());
+    }
+}
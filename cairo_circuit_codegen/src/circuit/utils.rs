@@ -1,224 +1,251 @@
 // Helper Functions for Generating Cairo Circuits
-use crate::{fields::{affine::Affine, fq::Fq, fq12::{sqr_offset, Fq12}, fq12_squaring::Krbn2345, fq2::Fq2, fq6::Fq6, sparse::{Fq12Sparse034, Fq6Sparse01}, ECOperations, FieldOps}, pairing::line::LineFn};
+use crate::{
+    curve::{Bls12_381, Bn254, Curve},
+    fields::{
+        affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, fq6::Fq6, projective::G2Projective, sparse::{Fq12Sparse034, Fq6Sparse01},
+        ECOperations, FieldOps,
+    },
+    pairing::{
+        ate_miller::{BitType, ATE_LOOP}, bls12_381, final_exp::{self, Op, MAX_CHUNK_GATES}, fixed, line::{miller_utils, LineFn, PPre},
+        projective::projective_utils,
+    },
+    lagrange, msm,
+    reference::{self, G2Affine},
+};
 
-use super::{builder::CairoCodeBuilder, circuit::Circuit};
+use super::{adder::CairoCodeAdder, builder::CairoCodeBuilder, function::{camel_case, CairoFunction}, inputs::{Allocate, InputAllocator}};
 
-pub fn generate_fq12_field_ops() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-    let idx_0: [usize; 12] = (0..=11).collect::<Vec<usize>>().try_into().unwrap();
-    let idx_1: [usize; 12] = (12..=23).collect::<Vec<usize>>().try_into().unwrap();
-    
-    let lhs = &Fq12::new_input(idx_0);
-    let rhs = &Fq12::new_input(idx_1); 
+/// A named entry point adding a block of Cairo circuit definitions to a builder
+pub struct Generator {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub generate: fn(&mut CairoCodeBuilder),
+}
+
+/// Every generator in this module, in the order they are listed by the CLI
+pub const GENERATORS: &[Generator] = &[
+    Generator { name: "fq2_field_ops", description: "Fq2 add, sub, mul, sqr, div, inv and neg", generate: generate_fq2_field_ops },
+    Generator { name: "fq6_field_ops", description: "Fq6 add, sub, mul, sqr, div, inv and neg", generate: generate_fq6_field_ops },
+    Generator { name: "fq12_field_ops", description: "Fq12 add, sub and neg", generate: generate_fq12_field_ops },
+    Generator { name: "fq12_optimized_field_ops", description: "Fq12 mul and sqr with 9 read from the NINE constant", generate: generate_fq12_optimized_field_ops },
+    Generator { name: "affine_fq2_ops", description: "Affine<Fq2> slope, chord, tangent, add and double", generate: generate_affine_fq2_ops },
+    Generator { name: "line_fn_step_dbl_add", description: "Line functions and accumulator of a double-and-add step", generate: generate_line_fn_step_dbl_add },
+    Generator { name: "step_dbl_add_slopes", description: "Slopes and intermediate x of a double-and-add step", generate: generate_step_dbl_add_slopes },
+    Generator { name: "krbn_sqr", description: "Karabina compressed squaring (g2, g3, g4, g5)", generate: generate_krbn_sqr },
+    Generator { name: "krbn_decompress", description: "Karabina decompression when g2 is zero", generate: generate_krbn_decompress },
+    Generator { name: "krbn_non_zero_decompress", description: "Karabina decompression when g2 is non-zero", generate: generate_krbn_non_zero_decompress },
+    Generator { name: "sparse_mul_034_by_034", description: "Product of two 034 sparse Fq12 elements", generate: generate_sparse_mul_034_by_034 },
+    Generator { name: "sparse_mul_01", description: "Fq6 times a 01 sparse Fq6", generate: generate_sparse_mul_01 },
+    Generator { name: "miller_precompute", description: "Miller loop -Q and the -x/y, 1/y precompute of P", generate: generate_miller_precompute },
+    Generator { name: "miller_first_second", description: "Miller loop bits 64 and 63, starting from f = 1", generate: generate_miller_first_second },
+    Generator { name: "miller_bit_o", description: "Miller loop step of a 0 bit, f is squared by fq12_sqr", generate: generate_miller_bit_o },
+    Generator { name: "miller_bit_dbl_add", description: "Miller loop step of a 1 or -1 bit, q is Q or -Q", generate: generate_miller_bit_dbl_add },
+    Generator { name: "miller_last", description: "Miller loop Frobenius correction step", generate: generate_miller_last },
+    Generator { name: "final_exp_easy", description: "Final exponentiation easy part, f^((p^6 - 1)(p^2 + 1))", generate: generate_final_exp_easy },
+    Generator { name: "final_exp_hard", description: "Final exponentiation hard part, split into circuits within the gate budget", generate: generate_final_exp_hard },
+    Generator { name: "multi_pairing_2", description: "Product of two pairings sharing f, entry point calling miller_precompute and final_exp_*", generate: generate_multi_pairing_2 },
+    Generator { name: "projective_multi_pairing_2", description: "multi_pairing_2 on projective accumulators, Miller steps without inversions", generate: generate_projective_multi_pairing_2 },
+    Generator { name: "fixed_pairing_2", description: "Product of two pairings of fixed G2 points, lines read from constant arrays such as G2_GENERATOR_LINES", generate: generate_fixed_pairing_2 },
+    Generator { name: "bls12_381_field_ops", description: "BLS12-381 Fq2, Fq6 and Fq12 mul, sqr and inv, bls12_381_ prefixed", generate: generate_bls12_381_field_ops },
+    Generator { name: "bls12_381_pairing", description: "BLS12-381 Miller loop steps, final exponentiation chunks and the bls12_381_pairing entry point", generate: generate_bls12_381_pairing },
+    Generator { name: "g1_msm_3_w2", description: "Straus MSM of three G1 points with 2-bit windows, tables, steps and entry point", generate: generate_g1_msm::<3, 2> },
+    Generator { name: "g1_msm_3_w3", description: "Straus MSM of three G1 points with 3-bit windows, tables, steps and entry point", generate: generate_g1_msm::<3, 3> },
+    Generator { name: "g1_msm_3_w4", description: "Straus MSM of three G1 points with 4-bit windows, tables, steps and entry point", generate: generate_g1_msm::<3, 4> },
+    Generator { name: "g1_fixed_msm_5_w3", description: "MSM of five fixed G1 points from 3-bit window tables, steps, entry point and G1_GENERATOR_TABLE_W3", generate: generate_g1_fixed_msm::<5, 3> },
+    Generator { name: "g1_fixed_msm_5_w4", description: "MSM of five fixed G1 points from 4-bit window tables, steps, entry point and G1_GENERATOR_TABLE_W4", generate: generate_g1_fixed_msm::<5, 4> },
+    Generator { name: "compute_d_partial", description: "PLONK verifier D2AB and D3AB scalars", generate: generate_compute_d_partial },
+    Generator { name: "compute_r0", description: "PLONK verifier R0 scalar", generate: generate_compute_r0 },
+    Generator { name: "batch_inverse_8", description: "Inverses of eight values from one inversion, Montgomery's trick", generate: generate_batch_inverse::<8> },
+    Generator { name: "lagrange_pi_1", description: "PLONK verifier zh, L_1 and PI of one public input, batched inversion", generate: generate_lagrange_pi::<1> },
+    Generator { name: "lagrange_pi_5", description: "PLONK verifier zh, L_1 to L_5 and PI of five public inputs, batched inversion", generate: generate_lagrange_pi::<5> },
+];
+
+/// Looks up a generator by name
+pub fn find_generator(name: &str) -> Option<&'static Generator> {
+    GENERATORS.iter().find(|generator| generator.name == name)
+}
+
+pub fn generate_fq12_field_ops(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let lhs = &inputs.fq12("lhs");
+    let rhs = &inputs.fq12("rhs");
     
     builder
         .add_line("// Fq12 Add")
-        .add_circuit(Fq12::add(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq12_add", &inputs).output(&Fq12::add(lhs, rhs)))
         .add_line("// Fq12 Sub")
-        .add_circuit(Fq12::sub(lhs, rhs), None)
-        // .add_line("// Fq12 Mul")
-        // .add_circuit(Fq12::mul(lhs, rhs), None)
-        // .add_line("// Fq12 Sqr")
-        // .add_circuit(Fq12::sqr(lhs), None)
-        // .add_line("// Fq12 Div")
-        // .add_circuit(Fq12::div(lhs, rhs), None)
-        // .add_line("// Fq12 Inv")
-        // .add_circuit(Fq12::inv(lhs), None)
+        .add_function(CairoFunction::new("fq12_sub", &inputs).output(&Fq12::sub(lhs, rhs)))
         .add_line("// Fq12 Neg")
-        .add_circuit(Fq12::neg(lhs), None);
-
-    builder.build()
+        .add_function(CairoFunction::new("fq12_neg", &inputs).output(&Fq12::neg(lhs)));
 }
 
-pub fn generate_fq6_field_ops() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-    let idx_0: [usize; 6] = (0..=5).collect::<Vec<usize>>().try_into().unwrap();
-    let idx_1: [usize; 6] = (6..=11).collect::<Vec<usize>>().try_into().unwrap();
-    
-    let lhs = &Fq6::new_input(idx_0);
-    let rhs = &Fq6::new_input(idx_1); 
+pub fn generate_fq6_field_ops(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let lhs = &inputs.fq6("lhs");
+    let rhs = &inputs.fq6("rhs");
     
     builder
         .add_line("// Fq6 Add")
-        .add_circuit(Fq6::add(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq6_add", &inputs).output(&Fq6::add(lhs, rhs)))
         .add_line("// Fq6 Sub")
-        .add_circuit(Fq6::sub(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq6_sub", &inputs).output(&Fq6::sub(lhs, rhs)))
         .add_line("// Fq6 Mul")
-        .add_circuit(Fq6::mul(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq6_mul", &inputs).output(&Fq6::mul(lhs, rhs)))
         .add_line("// Fq6 Sqr")
-        .add_circuit(Fq6::sqr(lhs), None)
+        .add_function(CairoFunction::new("fq6_sqr", &inputs).output(&Fq6::sqr(lhs)))
         .add_line("// Fq6 Div")
-        .add_circuit(Fq6::div(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq6_div", &inputs).output(&Fq6::div(lhs, rhs)))
         .add_line("// Fq6 Inv")
-        .add_circuit(Fq6::inv(lhs), None)
+        .add_function(CairoFunction::new("fq6_inv", &inputs).output(&Fq6::inv(lhs)))
         .add_line("// Fq6 Neg")
-        .add_circuit(Fq6::neg(lhs), None);
-
-    builder.build()
+        .add_function(CairoFunction::new("fq6_neg", &inputs).output(&Fq6::neg(lhs)));
 }
 
-pub fn generate_fq2_field_ops() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-    let idx_0: [usize; 2] = (0..=1).collect::<Vec<usize>>().try_into().unwrap();
-    let idx_1: [usize; 2] = (2..=3).collect::<Vec<usize>>().try_into().unwrap();
-    
-    let lhs = &Fq2::new_input(idx_0);
-    let rhs = &Fq2::new_input(idx_1); 
+pub fn generate_fq2_field_ops(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let lhs = &inputs.fq2("lhs");
+    let rhs = &inputs.fq2("rhs");
     
     builder
         .add_line("// Fq2 Add")
-        .add_circuit(Fq2::add(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq2_add", &inputs).output(&Fq2::add(lhs, rhs)))
         .add_line("// Fq2 Sub")
-        .add_circuit(Fq2::sub(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq2_sub", &inputs).output(&Fq2::sub(lhs, rhs)))
         .add_line("// Fq2 Mul")
-        .add_circuit(Fq2::mul(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq2_mul", &inputs).output(&Fq2::mul(lhs, rhs)))
         .add_line("// Fq2 Sqr")
-        .add_circuit(Fq2::sqr(lhs), None)
+        .add_function(CairoFunction::new("fq2_sqr", &inputs).output(&Fq2::sqr(lhs)))
         .add_line("// Fq2 Div")
-        .add_circuit(Fq2::div(lhs, rhs), None)
+        .add_function(CairoFunction::new("fq2_div", &inputs).output(&Fq2::div(lhs, rhs)))
         .add_line("// Fq2 Inv")
-        .add_circuit(Fq2::inv(lhs), None)
+        .add_function(CairoFunction::new("fq2_inv", &inputs).output(&Fq2::inv(lhs)))
         .add_line("// Fq2 Neg")
-        .add_circuit(Fq2::neg(lhs), None);
-
-    builder.build()
+        .add_function(CairoFunction::new("fq2_neg", &inputs).output(&Fq2::neg(lhs)));
 }
 
-pub fn generate_affine_fq2_ops() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
+pub fn generate_affine_fq2_ops(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let lhs = inputs.g2("lhs");
+    let rhs = inputs.g2("rhs");
 
-    let lhs: Affine<Fq2> = Affine::<Fq2>::new_input([0, 1, 2, 3]);
-    let rhs: Affine<Fq2> = Affine::<Fq2>::new_input([4, 5, 6, 7]);
-    let slope: Fq2 = Fq2::new_input([4, 5]);
-    let x: Fq2 = Fq2::new_input([6, 7]);    
+    let mut slope_inputs = InputAllocator::new();
+    let pt = slope_inputs.g2("pt");
+    let slope = slope_inputs.fq2("slope");
+    let x = slope_inputs.fq2("x");
 
     builder
         .add_line("// Affine Fq2")
         .add_line("// x_on_slope")
-        .add_circuit(lhs.x_on_slope(&slope, &x), None)
+        .add_function(CairoFunction::new("affine_fq2_x_on_slope", &slope_inputs).output(&pt.x_on_slope(&slope, &x)))
         .add_line("// y_on_slope")
-        .add_circuit(lhs.y_on_slope(&slope, &x), None)
+        .add_function(CairoFunction::new("affine_fq2_y_on_slope", &slope_inputs).output(&pt.y_on_slope(&slope, &x)))
         .add_line("// pt_on_slope")
-        .add_circuit(lhs.pt_on_slope(&slope, &x), None)
+        .add_function(CairoFunction::new("affine_fq2_pt_on_slope", &slope_inputs).output(&pt.pt_on_slope(&slope, &x)))
         .add_line("// chord")
-        .add_circuit(lhs.chord(&rhs), None)
+        .add_function(CairoFunction::new("affine_fq2_chord", &inputs).output(&lhs.chord(&rhs)))
         .add_line("// add")
-        .add_circuit(lhs.add(&rhs), None)
+        .add_function(CairoFunction::new("affine_fq2_add", &inputs).output(&lhs.add(&rhs)))
         .add_line("// tangent")
-        .add_circuit(lhs.tangent(), None)
+        .add_function(CairoFunction::new("affine_fq2_tangent", &inputs).output(&lhs.tangent()))
         .add_line("// double")
-        .add_circuit(lhs.double(), None);
-        
-    builder.build()
+        .add_function(CairoFunction::new("affine_fq2_double", &inputs).output(&lhs.double()));
 }
 
-pub fn generate_fq12_optimized_field_ops() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-    
-    // idx are offset by 1 to use the offset scaling function
-    let idx_0: [usize; 12] = (1..=12).collect::<Vec<usize>>().try_into().unwrap();
-    let idx_1: [usize; 12] = (13..=24).collect::<Vec<usize>>().try_into().unwrap();
-    
-    let lhs = &Fq12::new_input(idx_0);
-    let rhs = &Fq12::new_input(idx_1); 
+pub fn generate_fq12_optimized_field_ops(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let lhs = &inputs.fq12("lhs");
+    let rhs = &inputs.fq12("rhs");
     
     builder
-        // .add_line("// Fq12 Add")
-        // .add_circuit(Fq12::add(lhs, rhs), None)
-        // .add_line("// Fq12 Sub")
-        // .add_circuit(Fq12::sub(lhs, rhs), None)
-        // .add_line("// Fq12 Mul")
-        // .add_circuit(Fq12::mul(lhs, rhs), None)
+        .add_line("// Fq12 Mul")
+        .add_function(CairoFunction::new("fq12_mul", &inputs).output(&Fq12::mul(lhs, rhs)))
         .add_line("// Fq12 Sqr")
-        .add_circuit(sqr_offset(lhs), None);
-        // .add_line("// Fq12 Div")
-        // .add_circuit(Fq12::div(lhs, rhs), None)
-        // .add_line("// Fq12 Inv")
-        // .add_circuit(Fq12::inv(lhs), None)
-        // .add_line("// Fq12 Neg")
-        // .add_circuit(Fq12::neg(lhs), None);
-
-    builder.build()
+        .add_function(CairoFunction::new("fq12_sqr", &inputs).output(&lhs.sqr()));
 }
 
-pub fn generate_line_fn_step_dbl_add() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
+pub fn generate_line_fn_step_dbl_add(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let mut acc = inputs.g2("acc");
+    let q = inputs.g2("q");
 
-    let mut acc: Affine<Fq2> = Affine::<Fq2>::new_input([0, 1, 2, 3]);
-    let q: Affine<Fq2> = Affine::<Fq2>::new_input([4, 5, 6, 7]);
-    
     let (lf1, lf2) = LineFn::step_dbl_add(&mut acc, &q);
     builder
         .add_line("// step_dbl_add")
-        .add_line("// lf1")
-        .add_circuit(lf1, Some(vec!["Lf1SlopeC0", "Lf1SlopeC1", "Lf1C0", "Lf1C1"]))
-        .add_line("// lf1")
-        .add_circuit(lf2, Some(vec!["Lf2SlopeC0", "Lf2SlopeC1", "Lf2C0", "Lf2C1"]))
-        .add_line("// acc ")
-        .add_circuit(acc, None);
-        
-    builder.build()
+        .add_function(
+            CairoFunction::new("step_dbl_add", &inputs)
+                .named_output(&lf1, Some(vec!["Lf1SlopeC0", "Lf1SlopeC1", "Lf1C0", "Lf1C1"]))
+                .named_output(&lf2, Some(vec!["Lf2SlopeC0", "Lf2SlopeC1", "Lf2C0", "Lf2C1"]))
+                .named_output(&acc, Some(vec!["AccX0", "AccX1", "AccY0", "AccY1"])),
+        );
 }
 
-pub fn generate_krbn_sqr() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-
-    let kr = Krbn2345::new_input([0, 1, 2, 3, 4, 5, 6, 7]);;
+pub fn generate_krbn_sqr(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let kr = inputs.krbn2345("kr");
     
     builder
         .add_line("// krbn_sqr2345")
-        .add_circuit(kr.sqr_krbn(), None);
-        
-    builder.build()
+        .add_function(
+            CairoFunction::new("krbn_sqr", &inputs)
+                .named_output(&kr.sqr_krbn(), Some(vec!["KrbnG2C0", "KrbnG2C1", "KrbnG3C0", "KrbnG3C1", "KrbnG4C0", "KrbnG4C1", "KrbnG5C0", "KrbnG5C1"])),
+        );
 }
 
-pub fn generate_krbn_decompress() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-
-    let kr = Krbn2345::new_input([0, 1, 2, 3, 4, 5, 6, 7]);;
+pub fn generate_krbn_decompress(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let kr = inputs.krbn2345("kr");
     let (g0, g1) = kr.krbn_decompress_if_zero();
     builder
         .add_line("// krbn_decompress")
-        .add_line("// g0")
-        .add_circuit(g0, Some(["KbrnDecompZeroG0C0", "KbrnDecompZeroG0C1"].to_vec()))
-        .add_line("// g1")
-        .add_circuit(g1, Some(["KbrnDecompZeroG1C0", "KbrnDecompZeroG1C1"].to_vec()));
-        
-    builder.build()
+        .add_function(
+            CairoFunction::new("krbn_decompress_zero", &inputs)
+                .named_output(&g0, Some(vec!["KbrnDecompZeroG0C0", "KbrnDecompZeroG0C1"]))
+                .named_output(&g1, Some(vec!["KbrnDecompZeroG1C0", "KbrnDecompZeroG1C1"])),
+        );
 }
 
-pub fn generate_krbn_non_zero_decompress() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-
-    let kr = Krbn2345::new_input([0, 1, 2, 3, 4, 5, 6, 7]);;
+pub fn generate_krbn_non_zero_decompress(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let kr = inputs.krbn2345("kr");
     let (g0, g1) = kr.krbn_decompress_else();
     builder
         .add_line("// krbn_decompress")
-        .add_line("// g0")
-        .add_circuit(g0, Some(["KbrnDecompNonZeroG0C0", "KbrnDecompNonZeroG0C1"].to_vec()))
-        .add_line("// g1")
-        .add_circuit(g1, Some(["KbrnDecompNonZeroG1C0", "KbrnDecompNonZeroG1C1"].to_vec()));
-        
-    builder.build()
-}
-
-pub fn generate_compute_D_partial() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-
-    let beta: Fq = Fq::new_input(0);
-    let xi: Fq = Fq::new_input(1);
-    let eval_a: Fq = Fq::new_input(2);
-    let gamma: Fq = Fq::new_input(3);
-    let vk_k1: Fq = Fq::new_input(4);
-    let eval_b: Fq = Fq::new_input(5);
-    let vk_k2: Fq = Fq::new_input(6);
-    let eval_c: Fq = Fq::new_input(7);
-    let alpha: Fq = Fq::new_input(8);
-    let l1: Fq = Fq::new_input(9);
-    let u: Fq = Fq::new_input(10);
-    let eval_s1: Fq = Fq::new_input(11);
-    let eval_s2: Fq = Fq::new_input(12);
-    let eval_zw: Fq = Fq::new_input(13);
+        .add_function(
+            CairoFunction::new("krbn_decompress_non_zero", &inputs)
+                .named_output(&g0, Some(vec!["KbrnDecompNonZeroG0C0", "KbrnDecompNonZeroG0C1"]))
+                .named_output(&g1, Some(vec!["KbrnDecompNonZeroG1C0", "KbrnDecompNonZeroG1C1"])),
+        );
+}
+
+pub fn generate_compute_d_partial(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let (d2ab, d3ab) = compute_d_partial_circuits(&mut inputs);
+
+    builder
+        .add_line("// d_partial")
+        .add_function(
+            CairoFunction::new("compute_d_partial", &inputs)
+                .named_output(&d2ab, Some(vec!["D2AB"]))
+                .named_output(&d3ab, Some(vec!["D3AB"])),
+        );
+}
+
+// D2AB and D3AB scalars of the PLONK verifier, over the scalar field
+pub(crate) fn compute_d_partial_circuits(inputs: &mut InputAllocator) -> (Fq, Fq) {
+    let beta: Fq = inputs.fq("beta");
+    let xi: Fq = inputs.fq("xi");
+    let eval_a: Fq = inputs.fq("eval_a");
+    let gamma: Fq = inputs.fq("gamma");
+    let vk_k1: Fq = inputs.fq("vk_k1");
+    let eval_b: Fq = inputs.fq("eval_b");
+    let vk_k2: Fq = inputs.fq("vk_k2");
+    let eval_c: Fq = inputs.fq("eval_c");
+    let alpha: Fq = inputs.fq("alpha");
+    let l1: Fq = inputs.fq("l1");
+    let u: Fq = inputs.fq("u");
+    let eval_s1: Fq = inputs.fq("eval_s1");
+    let eval_s2: Fq = inputs.fq("eval_s2");
+    let eval_zw: Fq = inputs.fq("eval_zw");
 
     let betaxi = Fq::mul(&beta, &xi);
     let mut d2a1 = Fq::add(&eval_a, &betaxi);
@@ -250,31 +277,31 @@ pub fn generate_compute_D_partial() -> String {
     let d3c = Fq::mul(&Fq::mul(&alpha, &beta), &eval_zw);
     let d3ab = Fq::mul(&Fq::mul(&d3a, &d3b), &d3c);
 
-    builder
-        .add_line("// d_partial")
-        .add_line("// D2AB")
-        .add_circuit(d2ab, Some(["D2AB"].to_vec()))
-        .add_line("// D3AB")
-        .add_circuit(d3ab, Some(["D3AB"].to_vec()));
-    
-    builder.build()
+    (d2ab, d3ab)
 }
 
+pub fn generate_compute_r0(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let r0 = compute_r0_circuit(&mut inputs);
 
-pub fn generate_compute_R0() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
+    builder
+        .add_line("// compute R0")
+        .add_function(CairoFunction::new("compute_r0", &inputs).named_output(&r0, Some(vec!["R0_C0"])));
+}
 
-    let pi: Fq = Fq::new_input(0);
-    let l1: Fq = Fq::new_input(1);
-    let alpha: Fq = Fq::new_input(2);
-    let eval_a: Fq = Fq::new_input(3);
-    let beta: Fq = Fq::new_input(4);
-    let eval_s1: Fq = Fq::new_input(5);
-    let gamma: Fq = Fq::new_input(6);
-    let eval_b: Fq = Fq::new_input(7);
-    let eval_s2: Fq = Fq::new_input(8);
-    let eval_c: Fq = Fq::new_input(9);
-    let eval_zw: Fq = Fq::new_input(10);
+// R0 scalar of the PLONK verifier, over the scalar field
+pub(crate) fn compute_r0_circuit(inputs: &mut InputAllocator) -> Fq {
+    let pi: Fq = inputs.fq("pi");
+    let l1: Fq = inputs.fq("l1");
+    let alpha: Fq = inputs.fq("alpha");
+    let eval_a: Fq = inputs.fq("eval_a");
+    let beta: Fq = inputs.fq("beta");
+    let eval_s1: Fq = inputs.fq("eval_s1");
+    let gamma: Fq = inputs.fq("gamma");
+    let eval_b: Fq = inputs.fq("eval_b");
+    let eval_s2: Fq = inputs.fq("eval_s2");
+    let eval_c: Fq = inputs.fq("eval_c");
+    let eval_zw: Fq = inputs.fq("eval_zw");
 
     let e1 = pi;
     let e2 = Fq::mul(&l1, &Fq::sqr(&alpha));
@@ -285,79 +312,981 @@ pub fn generate_compute_R0() -> String {
     let mut e3b = Fq::add(&eval_b, &Fq::mul(&beta, &eval_s2));
     e3b = Fq::add(&e3b, &gamma);
 
-    let mut e3c = Fq::add(&eval_c, &gamma);
+    let e3c = Fq::add(&eval_c, &gamma);
 
     let mut e3 = Fq::mul(&Fq::mul(&e3a, &e3b), &e3c);
     e3 = Fq::mul(&e3, &eval_zw);
     e3 = Fq::mul(&e3, &alpha);
 
-    let r0 = Fq::sub(&Fq::sub(&e1, &e2), &e3);
+    Fq::sub(&Fq::sub(&e1, &e2), &e3)
+}
 
+pub fn generate_batch_inverse<const N: usize>(builder: &mut CairoCodeBuilder) {
+    let name = format!("batch_inverse_{}", N);
+    let mut inputs = InputAllocator::new();
+    let inverses = batch_inverse_circuit(&mut inputs, N);
+    let names: Vec<String> = (0..N).map(|i| camel_case(&format!("{}_inv{}", name, i))).collect();
 
-    builder
-        .add_line("// compute R0")
-        .add_line("// r0")
-        .add_circuit(r0, Some(["R0_C0"].to_vec()));
+    let mut function = CairoFunction::new(&name, &inputs);
+    for (inverse, name) in inverses.iter().zip(&names) {
+        function = function.named_output(inverse, Some(vec![name.as_str()]));
+    }
+    builder.add_line(format!("// {}", name)).add_function(function);
+}
 
-    
-    builder.build()
+// 1/vᵢ of N values from a single inversion
+pub(crate) fn batch_inverse_circuit(inputs: &mut InputAllocator, n: usize) -> Vec<Fq> {
+    let values: Vec<Fq> = (0..n).map(|i| inputs.fq(&format!("v{}", i))).collect();
+    lagrange::batch_inverse(&values)
+}
+
+pub fn generate_lagrange_pi<const N: usize>(builder: &mut CairoCodeBuilder) {
+    add_lagrange_pi(builder, N);
+}
+
+/// Adds `lagrange_pi_<n>`, (zh, L_1, …, L_n, PI) of a key with n public inputs over the scalar
+/// field. Without public inputs it only returns zh and L_1, PI is zero
+pub(crate) fn add_lagrange_pi(builder: &mut CairoCodeBuilder, n_public: usize) {
+    let name = format!("lagrange_pi_{}", n_public);
+    let mut inputs = InputAllocator::new();
+    let (zh, evaluations, pi) = lagrange_pi_circuit(&mut inputs, n_public);
+
+    let mut names = vec![camel_case(&format!("{}_zh", name))];
+    names.extend((1..=evaluations.len()).map(|i| camel_case(&format!("{}_l{}", name, i))));
+    names.push(camel_case(&format!("{}_pi", name)));
+
+    let mut function = CairoFunction::new(&name, &inputs).named_output(&zh, Some(vec![names[0].as_str()]));
+    for (l, name) in evaluations.iter().zip(&names[1..]) {
+        function = function.named_output(l, Some(vec![name.as_str()]));
+    }
+    if let Some(pi) = &pi {
+        function = function.named_output(pi, Some(vec![names[names.len() - 1].as_str()]));
+    }
+    builder.add_line(format!("// {}", name)).add_function(function);
 }
 
-pub fn generate_sparse_mul_034_by_034() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
+// ξⁿ is squared by the caller, the key's power is not a parameter of the circuit. L_1 alone does
+// not read w, it is only an input from two public inputs on
+pub(crate) fn lagrange_pi_circuit(inputs: &mut InputAllocator, n_public: usize) -> (Fq, Vec<Fq>, Option<Fq>) {
+    let xi: Fq = inputs.fq("xi");
+    let xin: Fq = inputs.fq("xin");
+    let n: Fq = inputs.fq("n");
+    // w is only read from L_2 on, the function drops it with a single public signal
+    let w: Fq = inputs.fq("w");
+    let signals: Vec<Fq> = (0..n_public).map(|i| inputs.fq(&format!("s{}", i))).collect();
+
+    let (zh, evaluations) = lagrange::lagrange_evaluations(&xi, &xin, &n, &w, n_public.max(1));
+    let pi = (n_public > 0).then(|| lagrange::public_input(&signals, &evaluations));
+    (zh, evaluations, pi)
+}
 
-    let lhs: Fq12Sparse034 = Fq12Sparse034::new(Fq2::new_input([0, 1]), Fq2::new_input([2, 3]));
-    let rhs: Fq12Sparse034 = Fq12Sparse034::new(Fq2::new_input([4, 5]), Fq2::new_input([6, 7]));
+pub fn generate_sparse_mul_034_by_034(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let (c3, c4) = (inputs.fq2("c3"), inputs.fq2("c4"));
+    let (d3, d4) = (inputs.fq2("d3"), inputs.fq2("d4"));
+    let lhs: Fq12Sparse034 = Fq12Sparse034::new(c3.clone(), c4.clone());
+    let rhs: Fq12Sparse034 = Fq12Sparse034::new(d3.clone(), d4.clone());
    
     let out = lhs.mul_034_by_034(&rhs);
 
     builder
         .add_line("// Sparse mul_034_by_034")
-        .add_line("// Fq6")
-        .add_circuit(out.c0().clone(), Some(["M034034_zC0B0C0", "M034034_zC0B0C1", "M034034_C3D3C0", "M034034_C3D3C1", "M034034_X34C0", "M034034_X34C1"].to_vec()))
-        .add_line("// Fq6Sparse01")
-        .add_circuit(out.c1().c0().clone(), Some(["M034034_X03C0", "M034034_X03C1"].to_vec()))
-        .add_circuit(out.c1().c1().clone(), Some(["M034034_X04C0", "M034034_X04C1"].to_vec()));
-    
-    builder.build()
+        .add_function(
+            CairoFunction::new("mul_034_by_034", &inputs)
+                .named_output(out.c0(), Some(vec!["M034034_zC0B0C0", "M034034_zC0B0C1", "M034034_C3D3C0", "M034034_C3D3C1", "M034034_X34C0", "M034034_X34C1"]))
+                .named_output(out.c1().c0(), Some(vec!["M034034_X03C0", "M034034_X03C1"]))
+                .named_output(out.c1().c1(), Some(vec!["M034034_X04C0", "M034034_X04C1"])),
+        );
 }
 
-pub fn generate_sparse_mul_01() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-
-    let lhs: Fq6 = Fq6::new_input([0, 1, 2, 3, 4, 5]);
-    let rhs: Fq6Sparse01 = Fq6Sparse01::new(Fq2::new_input([6, 7]), Fq2::new_input([8, 9]));
+pub fn generate_sparse_mul_01(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let lhs: Fq6 = inputs.fq6("lhs");
+    let (b0, b1) = (inputs.fq2("b0"), inputs.fq2("b1"));
+    let rhs: Fq6Sparse01 = Fq6Sparse01::new(b0.clone(), b1.clone());
    
     let out = lhs.mul_01(&rhs);
 
     builder
         .add_line("// Sparse mul_01")
-        .add_line("// Fq6")
-        .add_circuit(out, Some(["M01_C0C0", "M01_C0C1", "M01_C1C0", "M01_C1C1", "M01_C2C0", "M01_C2C1"].to_vec()));
-    
-    builder.build()
+        .add_function(
+            CairoFunction::new("mul_01", &inputs)
+                .named_output(&out, Some(vec!["M01_C0C0", "M01_C0C1", "M01_C1C0", "M01_C1C1", "M01_C2C0", "M01_C2C1"])),
+        );
 }
 
-pub fn generate_step_dbl_add_slopes() -> String {
-    let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-
-    let acc: Affine<Fq2> = Affine::<Fq2>::new_input([0, 1, 2, 3]);
-    let q: Affine<Fq2> = Affine::<Fq2>::new_input([4, 5, 6, 7]);
+pub fn generate_step_dbl_add_slopes(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let acc: Affine<Fq2> = inputs.g2("acc");
+    let q: Affine<Fq2> = inputs.g2("q");
 
     let slope1 = acc.chord(&q);
-    let x1 = acc.x_on_slope(&slope1, &q.x());
+    let x1 = acc.x_on_slope(&slope1, q.x());
     
-    let slope2 = slope1.neg().sub(&(&acc.y().add(&acc.y())).div(&x1.sub(&acc.x()))); // rm div -> circuit
+    let slope2 = slope1.neg().sub(&acc.y().add(acc.y()).div(&x1.sub(acc.x()))); // rm div -> circuit
     
     builder
         .add_line("// step_dbl_add_slopes")
-        .add_line("// Fq2")
-        .add_circuit(slope1, Some(["Slope1_C0", "Slope1_C1"].to_vec()))
-        .add_line("// Fq2")
-        .add_circuit(x1, Some(["X1_C0", "X1_C1"].to_vec()))
-        .add_line("// Fq2")
-        .add_circuit(slope2, Some(["Slope2_C0", "Slope2_C1"].to_vec()));
+        .add_function(
+            CairoFunction::new("step_dbl_add_slopes", &inputs)
+                .named_output(&slope1, Some(vec!["Slope1_C0", "Slope1_C1"]))
+                .named_output(&x1, Some(vec!["X1_C0", "X1_C1"]))
+                .named_output(&slope2, Some(vec!["Slope2_C0", "Slope2_C1"])),
+        );
+}
+
+// Outputs are read from gates, the coordinates passed through (P, Q and the x of -Q) are left
+// to the caller
+pub fn generate_miller_precompute(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let p = inputs.g1("p");
+    let q_y = inputs.fq2("q_y");
+    let ppc = PPre::p_precompute(&p);
+    let neg_q_y = q_y.neg();
+
+    builder
+        .add_line("// Miller precompute")
+        .add_function(
+            CairoFunction::new("miller_precompute", &inputs)
+                .named_output(&neg_q_y, Some(vec!["MillerPrecomputeNegQY0", "MillerPrecomputeNegQY1"]))
+                .named_output(ppc.neg_x_over_y(), Some(vec!["MillerPrecomputeNegXOverY"]))
+                .named_output(ppc.y_inv(), Some(vec!["MillerPrecomputeYInv"])),
+        );
+}
+
+pub fn generate_miller_first_second(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let (f, acc) = miller_first_second_circuits(&mut inputs);
+
+    builder
+        .add_line("// Miller bits 64 and 63")
+        .add_function(CairoFunction::new("miller_first_second", &inputs).output(&f).output(&acc));
+}
+
+pub fn generate_miller_bit_o(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let (f, acc) = miller_bit_o_circuits(&mut inputs);
+
+    builder
+        .add_line("// Miller 0 bit")
+        .add_function(CairoFunction::new("miller_bit_o", &inputs).output(&f).output(&acc));
+}
+
+pub fn generate_miller_bit_dbl_add(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let (f, acc) = miller_bit_dbl_add_circuits(&mut inputs);
+
+    builder
+        .add_line("// Miller 1 and -1 bits")
+        .add_function(CairoFunction::new("miller_bit_dbl_add", &inputs).output(&f).output(&acc));
+}
+
+pub fn generate_miller_last(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let f = miller_last_circuit(&mut inputs);
+
+    builder
+        .add_line("// Miller correction step")
+        .add_function(CairoFunction::new("miller_last", &inputs).output(&f));
+}
+
+pub fn generate_final_exp_easy(builder: &mut CairoCodeBuilder) {
+    builder.add_line("// Final exponentiation easy part");
+    add_final_exp_chunks::<Bn254>(builder, "final_exp_easy", &final_exp::easy_part());
+}
+
+pub fn generate_final_exp_hard(builder: &mut CairoCodeBuilder) {
+    builder.add_line("// Final exponentiation hard part");
+    add_final_exp_chunks::<Bn254>(builder, "final_exp_hard", &final_exp::hard_part());
+}
+
+// One function `<name>_<i>` per chunk, it takes the registers the chunk reads and returns the
+// ones read after it, the caller chains them in order
+fn add_final_exp_chunks<C: Curve>(builder: &mut CairoCodeBuilder, name: &str, ops: &[Op]) {
+    for (i, chunk) in final_exp::chunks::<C>(ops, final_exp::F, MAX_CHUNK_GATES).iter().enumerate() {
+        let function = format!("{}_{}", name, i);
+        let mut inputs = InputAllocator::new();
+        let outputs = chunk.circuits::<C>(&mut inputs);
+        let names: Vec<Vec<String>> = chunk
+            .outputs
+            .iter()
+            .zip(&outputs)
+            .map(|(reg, value)| {
+                value.default_names().iter().map(|c| camel_case(&format!("{}_{}_{}", function, reg, c))).collect()
+            })
+            .collect();
+
+        let mut cairo = CairoFunction::new(&function, &inputs);
+        for (value, names) in outputs.iter().zip(&names) {
+            cairo = cairo.named_output(value, Some(names.iter().map(String::as_str).collect()));
+        }
+        builder
+            .add_line(format!("// {}: ({}) -> ({})", function, chunk.inputs.join(", "), chunk.outputs.join(", ")))
+            .add_function(cairo);
+    }
+}
+
+// e(A1, X2)·e(B1, G2) of the PLONK final check
+pub fn generate_multi_pairing_2(builder: &mut CairoCodeBuilder) {
+    add_multi_pairing::<Affine<Fq2>>(builder, 2);
+}
+
+// The same pairing with projective accumulators, its steps have no inversion
+pub fn generate_projective_multi_pairing_2(builder: &mut CairoCodeBuilder) {
+    add_multi_pairing::<G2Projective>(builder, 2);
+}
+
+/// Accumulator of the Miller step circuits, the functions of a generator are prefixed by the
+/// coordinates it uses
+pub(crate) trait MillerAcc: Allocate + Clone {
+    const PREFIX: &'static str;
+
+    /// The accumulator of the first step, Q is passed in affine coordinates
+    fn from_affine(q: Affine<Fq2>) -> Self;
+    fn first_second(&mut self, ppc: &PPre, neg_q: &Affine<Fq2>) -> Fq12;
+    fn double_to_f(&mut self, f: &mut Fq12, ppc: &PPre);
+    fn dbl_add_to_f(&mut self, f: &mut Fq12, ppc: &PPre, q: &Affine<Fq2>);
+    fn correction_to_f(&mut self, f: &mut Fq12, ppc: &PPre, q: &Affine<Fq2>);
+}
+
+impl MillerAcc for Affine<Fq2> {
+    const PREFIX: &'static str = "";
+
+    fn from_affine(q: Affine<Fq2>) -> Self {
+        q
+    }
+
+    fn first_second(&mut self, ppc: &PPre, neg_q: &Affine<Fq2>) -> Fq12 {
+        miller_utils::miller_first_second(self, ppc, neg_q)
+    }
+
+    fn double_to_f(&mut self, f: &mut Fq12, ppc: &PPre) {
+        miller_utils::step_double_to_f(self, f, ppc);
+    }
+
+    fn dbl_add_to_f(&mut self, f: &mut Fq12, ppc: &PPre, q: &Affine<Fq2>) {
+        miller_utils::step_dbl_add_to_f(self, f, ppc, q);
+    }
+
+    fn correction_to_f(&mut self, f: &mut Fq12, ppc: &PPre, q: &Affine<Fq2>) {
+        miller_utils::correction_step_to_f(self, f, ppc, q);
+    }
+}
+
+impl MillerAcc for G2Projective {
+    const PREFIX: &'static str = "projective_";
+
+    fn from_affine(q: Affine<Fq2>) -> Self {
+        G2Projective::from_affine(&q)
+    }
+
+    fn first_second(&mut self, ppc: &PPre, neg_q: &Affine<Fq2>) -> Fq12 {
+        projective_utils::miller_first_second(self, ppc, neg_q)
+    }
+
+    fn double_to_f(&mut self, f: &mut Fq12, ppc: &PPre) {
+        projective_utils::step_double_to_f(self, f, ppc);
+    }
+
+    fn dbl_add_to_f(&mut self, f: &mut Fq12, ppc: &PPre, q: &Affine<Fq2>) {
+        projective_utils::step_dbl_add_to_f(self, f, ppc, q);
+    }
+
+    fn correction_to_f(&mut self, f: &mut Fq12, ppc: &PPre, q: &Affine<Fq2>) {
+        projective_utils::correction_step_to_f(self, f, ppc, q);
+    }
+}
+
+// Step functions of the multi-Miller loop over n pairs and the `multi_pairing_<n>` entry point
+// running them with a single final exponentiation
+fn add_multi_pairing<A: MillerAcc>(builder: &mut CairoCodeBuilder, n: usize) {
+    let prefix = A::PREFIX;
+    let mut inputs = InputAllocator::new();
+    let (f, accs) = multi_miller_first_second_circuits::<A>(&mut inputs, n);
+    add_multi_miller_step(builder, &format!("{}multi_miller_first_second_{}", prefix, n), &inputs, &f, &accs);
+
+    for (bit, name) in [(BitType::O, "bit_o"), (BitType::P, "bit_dbl_add")] {
+        let mut inputs = InputAllocator::new();
+        let (f, accs) = multi_miller_bit_circuits::<A>(&mut inputs, n, bit);
+        add_multi_miller_step(builder, &format!("{}multi_miller_{}_{}", prefix, name, n), &inputs, &f, &accs);
+    }
+
+    let mut inputs = InputAllocator::new();
+    let f = multi_miller_last_circuit::<A>(&mut inputs, n);
+    let function = format!("{}multi_miller_last_{}", prefix, n);
+    builder.add_line(format!("// {}", function)).add_function(CairoFunction::new(&function, &inputs).output(&f));
+
+    builder.add_entry_point(
+        &["plonk_verifier::curve::groups::Affine", "plonk_verifier::curve::groups::AffineG1", "plonk_verifier::curve::groups::AffineG2"],
+        &multi_pairing_entry_point(n, prefix),
+    );
+}
+
+// The accumulators are named by pair so their circuit types do not collide
+fn add_multi_miller_step<C: Curve, A: CairoCodeAdder>(builder: &mut CairoCodeBuilder, function: &str, inputs: &InputAllocator, f: &Fq12<C>, accs: &[A]) {
+    let names: Vec<Vec<String>> = accs
+        .iter()
+        .enumerate()
+        .map(|(i, acc)| acc.default_names().iter().map(|c| camel_case(&format!("{}_acc{}_{}", function, i, c))).collect())
+        .collect();
+    let mut cairo = CairoFunction::new(function, inputs).output(f);
+    for (acc, names) in accs.iter().zip(&names) {
+        cairo = cairo.named_output(acc, Some(names.iter().map(String::as_str).collect()));
+    }
+    builder.add_line(format!("// {}", function)).add_function(cairo);
+}
+
+// Cairo arguments of pair i, in the order the multi step circuits allocate them
+fn pair_args(n: usize, last: &str) -> String {
+    let args: Vec<String> =
+        (0..n).map(|i| format!("neg_x_over_y{i}, y_inv{i}, {}", last.replace("{i}", &i.to_string()))).collect();
+    args.join(", ")
+}
+
+fn multi_pairing_entry_point(n: usize, prefix: &str) -> Vec<String> {
+    let params: Vec<String> = (0..n).map(|i| format!("p{i}: AffineG1, q{i}: AffineG2")).collect();
+    let accs: Vec<String> = (0..n).map(|i| format!("acc{i}")).collect();
+    let state = format!("(f, {})", accs.join(", "));
+
+    let mut lines = vec![format!("fn {}multi_pairing_{}({}, m: CircuitModulus) -> Fq12 {{", prefix, n, params.join(", "))];
+    for i in 0..n {
+        lines.push(format!("    let (neg_q_y{i}, neg_x_over_y{i}, y_inv{i}) = miller_precompute(p{i}, q{i}.y, m);"));
+        lines.push(format!("    let neg_q{i} = Affine {{ x: q{i}.x, y: neg_q_y{i} }};"));
+    }
+    lines.push(format!("    let {} = {}multi_miller_first_second_{}({}, m);", state, prefix, n, pair_args(n, "q{i}, neg_q{i}")));
+    for i in (0..63).rev() {
+        let call = match ATE_LOOP[i] {
+            BitType::O => format!("{}multi_miller_bit_o_{}({}, f, m)", prefix, n, pair_args(n, "acc{i}")),
+            BitType::P => format!("{}multi_miller_bit_dbl_add_{}({}, f, m)", prefix, n, pair_args(n, "acc{i}, q{i}")),
+            BitType::N => format!("{}multi_miller_bit_dbl_add_{}({}, f, m)", prefix, n, pair_args(n, "acc{i}, neg_q{i}")),
+        };
+        lines.push(format!("    let {} = {}; // bit {}", state, call, i));
+    }
+    lines.push(format!("    let f = {}multi_miller_last_{}({}, f, m);", prefix, n, pair_args(n, "acc{i}, q{i}")));
+    lines.extend(final_exp_calls::<Bn254>("final_exp_easy", &final_exp::easy_part()));
+    lines.extend(final_exp_calls::<Bn254>("final_exp_hard", &final_exp::hard_part()));
+    lines.extend(["    f".to_string(), "}".to_string()]);
+    lines
+}
+
+// Calls of the chunks emitted by `add_final_exp_chunks`, registers are Cairo variables
+fn final_exp_calls<C: Curve>(name: &str, ops: &[Op]) -> Vec<String> {
+    final_exp::chunks::<C>(ops, final_exp::F, MAX_CHUNK_GATES)
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| {
+            let outputs = match chunk.outputs.as_slice() {
+                [output] => output.to_string(),
+                outputs => format!("({})", outputs.join(", ")),
+            };
+            format!("    let {} = {}_{}({}, m);", outputs, name, i, chunk.inputs.join(", "))
+        })
+        .collect()
+}
+
+// e(A1, X2)·e(B1, G2) when both G2 points are known, the lines of the generator are emitted
+// here and those of X2 come with the verification key
+pub fn generate_fixed_pairing_2(builder: &mut CairoCodeBuilder) {
+    add_fixed_pairing(builder, 2);
+}
+
+// Steps of the multi-Miller loop over n fixed G2 points, reading precomputed lines instead of
+// accumulators, and the `fixed_pairing_<n>` entry point
+fn add_fixed_pairing(builder: &mut CairoCodeBuilder, n: usize) {
+    let mut inputs = InputAllocator::new();
+    let ppc = PPre::p_precompute(&inputs.g1("p"));
+    builder.add_line("// fixed_miller_precompute").add_function(
+        CairoFunction::new("fixed_miller_precompute", &inputs)
+            .named_output(ppc.neg_x_over_y(), Some(vec!["FixedMillerPrecomputeNegXOverY"]))
+            .named_output(ppc.y_inv(), Some(vec!["FixedMillerPrecomputeYInv"])),
+    );
+
+    for step in ["first_second", "bit_o", "bit_dbl_add", "last"] {
+        let mut inputs = InputAllocator::new();
+        let f = match step {
+            "first_second" => fixed_miller_first_second_circuit(&mut inputs, n),
+            "bit_o" => fixed_miller_bit_circuit(&mut inputs, n, BitType::O),
+            "bit_dbl_add" => fixed_miller_bit_circuit(&mut inputs, n, BitType::P),
+            _ => fixed_miller_last_circuit(&mut inputs, n),
+        };
+        let function = format!("fixed_miller_{}_{}", step, n);
+        builder.add_line(format!("// {}", function)).add_function(CairoFunction::new(&function, &inputs).output(&f));
+    }
+
+    builder.add_entry_point(
+        &[
+            "plonk_verifier::curve::groups::AffineG1",
+            "plonk_verifier::curve::pairing::optimal_ate_utils::LineFn",
+            "plonk_verifier::fields::fq2",
+        ],
+        &fixed_pairing_entry_point(n),
+    );
+}
+
+fn fixed_pairing_entry_point(n: usize) -> Vec<String> {
+    let mut lines = vec![
+        fixed::lines_constant("G2_GENERATOR_LINES", &G2Affine::generator()),
+        String::new(),
+        "// Line k of a constant array of line coefficients".to_string(),
+        "fn fixed_line(lines: Span<u384>, k: usize) -> LineFn {".to_string(),
+        "    LineFn { slope: fq2(*lines[4 * k], *lines[4 * k + 1]), c: fq2(*lines[4 * k + 2], *lines[4 * k + 3]) }".to_string(),
+        "}".to_string(),
+        String::new(),
+    ];
+
+    let params: Vec<String> = (0..n).map(|i| format!("p{i}: AffineG1, lines{i}: Span<u384>")).collect();
+    lines.push(format!("fn fixed_pairing_{}({}, m: CircuitModulus) -> Fq12 {{", n, params.join(", ")));
+    for i in 0..n {
+        lines.push(format!("    let (neg_x_over_y{i}, y_inv{i}) = fixed_miller_precompute(p{i}, m);"));
+    }
+    // Every pair reads the same line of its array at each step
+    let mut next = 0;
+    let mut step_args = |count: usize| {
+        let args: Vec<String> = (0..n)
+            .map(|i| {
+                let lines: Vec<String> = (next..next + count).map(|k| format!("fixed_line(lines{i}, {k})")).collect();
+                format!("neg_x_over_y{i}, y_inv{i}, {}", lines.join(", "))
+            })
+            .collect();
+        next += count;
+        args.join(", ")
+    };
+    lines.push(format!("    let f = fixed_miller_first_second_{}({}, m);", n, step_args(3)));
+    for i in (0..63).rev() {
+        let call = match ATE_LOOP[i] {
+            BitType::O => format!("fixed_miller_bit_o_{}({}, f, m)", n, step_args(1)),
+            BitType::P | BitType::N => format!("fixed_miller_bit_dbl_add_{}({}, f, m)", n, step_args(2)),
+        };
+        lines.push(format!("    let f = {}; // bit {}", call, i));
+    }
+    lines.push(format!("    let f = fixed_miller_last_{}({}, f, m);", n, step_args(2)));
+    debug_assert_eq!(next, fixed::line_count());
+    lines.extend(final_exp_calls::<Bn254>("final_exp_easy", &final_exp::easy_part()));
+    lines.extend(final_exp_calls::<Bn254>("final_exp_hard", &final_exp::hard_part()));
+    lines.extend(["    f".to_string(), "}".to_string()]);
+    lines
+}
+
+// The tower of BLS12-381 only differs from the BN254 one by ξ = 1 + u, the functions take the
+// BLS12-381 modulus in m
+pub fn generate_bls12_381_field_ops(builder: &mut CairoCodeBuilder) {
+    let mut fq2_inputs = InputAllocator::new();
+    let (fq2_lhs, fq2_rhs): (Fq2<Bls12_381>, Fq2<Bls12_381>) = (fq2_inputs.alloc("lhs"), fq2_inputs.alloc("rhs"));
+    let mut fq6_inputs = InputAllocator::new();
+    let (fq6_lhs, fq6_rhs): (Fq6<Bls12_381>, Fq6<Bls12_381>) = (fq6_inputs.alloc("lhs"), fq6_inputs.alloc("rhs"));
+    let mut fq12_inputs = InputAllocator::new();
+    let (fq12_lhs, fq12_rhs): (Fq12<Bls12_381>, Fq12<Bls12_381>) = (fq12_inputs.alloc("lhs"), fq12_inputs.alloc("rhs"));
+
+    builder
+        .add_line("// BLS12-381 Fq2 Mul")
+        .add_function(CairoFunction::new("bls12_381_fq2_mul", &fq2_inputs).output(&fq2_lhs.mul(&fq2_rhs)))
+        .add_line("// BLS12-381 Fq2 Sqr")
+        .add_function(CairoFunction::new("bls12_381_fq2_sqr", &fq2_inputs).output(&fq2_lhs.sqr()))
+        .add_line("// BLS12-381 Fq2 Inv")
+        .add_function(CairoFunction::new("bls12_381_fq2_inv", &fq2_inputs).output(&fq2_lhs.inv()))
+        .add_line("// BLS12-381 Fq6 Mul")
+        .add_function(CairoFunction::new("bls12_381_fq6_mul", &fq6_inputs).output(&fq6_lhs.mul(&fq6_rhs)))
+        .add_line("// BLS12-381 Fq6 Sqr")
+        .add_function(CairoFunction::new("bls12_381_fq6_sqr", &fq6_inputs).output(&fq6_lhs.sqr()))
+        .add_line("// BLS12-381 Fq6 Inv")
+        .add_function(CairoFunction::new("bls12_381_fq6_inv", &fq6_inputs).output(&fq6_lhs.inv()))
+        .add_line("// BLS12-381 Fq12 Mul")
+        .add_function(CairoFunction::new("bls12_381_fq12_mul", &fq12_inputs).output(&fq12_lhs.mul(&fq12_rhs)))
+        .add_line("// BLS12-381 Fq12 Sqr")
+        .add_function(CairoFunction::new("bls12_381_fq12_sqr", &fq12_inputs).output(&fq12_lhs.sqr()))
+        .add_line("// BLS12-381 Fq12 Inv")
+        .add_function(CairoFunction::new("bls12_381_fq12_inv", &fq12_inputs).output(&fq12_lhs.inv()));
+}
+
+// e(P, Q) on BLS12-381, one function per kind of Miller step, the final exponentiation chunks
+// and the `bls12_381_pairing` entry point chaining them
+pub fn generate_bls12_381_pairing(builder: &mut CairoCodeBuilder) {
+    let mut inputs = InputAllocator::new();
+    let ppc = PPre::p_precompute(&inputs.g1("p"));
+    builder.add_line("// bls12_381_miller_precompute").add_function(
+        CairoFunction::new("bls12_381_miller_precompute", &inputs)
+            .named_output(ppc.neg_x_over_y(), Some(vec!["Bls12381MillerPrecomputeNegXOverY"]))
+            .named_output(ppc.y_inv(), Some(vec!["Bls12381MillerPrecomputeYInv"])),
+    );
+
+    for step in ["first_second", "bit_o", "bit_dbl_add"] {
+        let mut inputs = InputAllocator::new();
+        let (f, acc) = match step {
+            "first_second" => bls12_381_miller_first_second_circuits(&mut inputs),
+            "bit_o" => bls12_381_miller_bit_circuits(&mut inputs, BitType::O),
+            _ => bls12_381_miller_bit_circuits(&mut inputs, BitType::P),
+        };
+        add_multi_miller_step(builder, &format!("bls12_381_miller_{}", step), &inputs, &f, &[acc]);
+    }
+    let mut inputs = InputAllocator::new();
+    let f = bls12_381_miller_last_circuit(&mut inputs);
+    builder.add_line("// bls12_381_miller_last").add_function(CairoFunction::new("bls12_381_miller_last", &inputs).output(&f));
+
+    builder.add_line("// BLS12-381 final exponentiation easy part");
+    add_final_exp_chunks::<Bls12_381>(builder, "bls12_381_final_exp_easy", &final_exp::easy_part());
+    builder.add_line("// BLS12-381 final exponentiation hard part");
+    add_final_exp_chunks::<Bls12_381>(builder, "bls12_381_final_exp_hard", &Bls12_381::hard_part());
+
+    builder.add_entry_point(
+        &["plonk_verifier::curve::groups::AffineG1", "plonk_verifier::curve::groups::AffineG2"],
+        &bls12_381_pairing_entry_point(),
+    );
+}
+
+fn bls12_381_pairing_entry_point() -> Vec<String> {
+    let digits = Bls12_381::loop_digits();
+    let mut lines = vec![
+        "fn bls12_381_pairing(p: AffineG1, q: AffineG2, m: CircuitModulus) -> Fq12 {".to_string(),
+        "    let (neg_x_over_y, y_inv) = bls12_381_miller_precompute(p, m);".to_string(),
+        "    let (f, acc) = bls12_381_miller_first_second(neg_x_over_y, y_inv, q, m);".to_string(),
+    ];
+    for i in (1..bls12_381::FIRST_BIT - 1).rev() {
+        let call = match digits[i] {
+            BitType::O => "bls12_381_miller_bit_o(neg_x_over_y, y_inv, acc, f, m)",
+            _ => "bls12_381_miller_bit_dbl_add(neg_x_over_y, y_inv, acc, q, f, m)",
+        };
+        lines.push(format!("    let (f, acc) = {}; // bit {}", call, i));
+    }
+    lines.push("    let f = bls12_381_miller_last(neg_x_over_y, y_inv, acc, f, m);".to_string());
+    lines.extend(final_exp_calls::<Bls12_381>("bls12_381_final_exp_easy", &final_exp::easy_part()));
+    lines.extend(final_exp_calls::<Bls12_381>("bls12_381_final_exp_hard", &Bls12_381::hard_part()));
+    lines.extend(["    f".to_string(), "}".to_string()]);
+    lines
+}
+
+// Σ sᵢ·Pᵢ over N variable bases with W-bit windows: the table of every base, the first window and
+// the steps, and the `g1_msm_<n>_w<w>` entry point
+pub fn generate_g1_msm<const N: usize, const W: usize>(builder: &mut CairoCodeBuilder) {
+    let table = format!("g1_window_table_w{}", W);
+    let mut inputs = InputAllocator::new();
+    // Entry 0 is P itself, the Cairo table starts with the base
+    let entries = g1_window_table_circuit(&mut inputs, W).split_off(1);
+    let mut function = CairoFunction::new(&table, &inputs);
+    let names: Vec<Vec<String>> = (2..=1 << W)
+        .map(|d| ["x0", "y0"].iter().map(|c| camel_case(&format!("{}_{}p_{}", table, d, c))).collect())
+        .collect();
+    for (entry, names) in entries.iter().zip(&names) {
+        function = function.named_output(entry, Some(names.iter().map(String::as_str).collect()));
+    }
+    builder.add_line(format!("// {}", table)).add_function(function);
+
+    let first = format!("g1_msm_first_{}_w{}", N, W);
+    let mut inputs = InputAllocator::new();
+    let acc = g1_sum_circuit(&mut inputs, N);
+    builder.add_line(format!("// {}", first)).add_function(CairoFunction::new(&first, &inputs).output(&acc));
+
+    let step = format!("g1_msm_step_{}_w{}", N, W);
+    let mut inputs = InputAllocator::new();
+    let acc = g1_straus_step_circuit(&mut inputs, N, W);
+    builder.add_line(format!("// {}", step)).add_function(CairoFunction::new(&step, &inputs).output(&acc));
+
+    builder.add_entry_point(
+        &["plonk_verifier::curve::groups::AffineG1", "plonk_verifier::curve::groups::zero_free_digits"],
+        &g1_msm_entry_point(N, W),
+    );
+}
+
+// Σ sᵢ·Pᵢ over N fixed bases, their tables are constant arrays and every window only adds. The
+// `g1_fixed_msm_<n>_w<w>` entry point takes the tables, G1_GENERATOR_TABLE_W<w> is the one of G1
+pub fn generate_g1_fixed_msm<const N: usize, const W: usize>(builder: &mut CairoCodeBuilder) {
+    let first = format!("g1_fixed_msm_first_{}_w{}", N, W);
+    let mut inputs = InputAllocator::new();
+    let acc = g1_sum_circuit(&mut inputs, N);
+    builder.add_line(format!("// {}", first)).add_function(CairoFunction::new(&first, &inputs).output(&acc));
+
+    let step = format!("g1_fixed_msm_step_{}_w{}", N, W);
+    let mut inputs = InputAllocator::new();
+    let acc = g1_sum_circuit(&mut inputs, N + 1);
+    builder.add_line(format!("// {}", step)).add_function(CairoFunction::new(&step, &inputs).output(&acc));
+
+    let mut lines = vec![msm::fixed_table_constant(&format!("G1_GENERATOR_TABLE_W{}", W), &reference::G1Affine::generator(), W), String::new()];
+    lines.extend(g1_fixed_msm_entry_point(N, W));
+    builder.add_entry_point(
+        &["plonk_verifier::curve::groups::AffineG1", "plonk_verifier::curve::groups::fixed_table_entry", "plonk_verifier::curve::groups::zero_free_digits"],
+        &lines,
+    );
+}
+
+// Digits of every scalar, the windows are then run from the top
+fn g1_msm_digit_lines(n: usize, w: usize) -> Vec<String> {
+    let (count, offset) = (msm::window_count(w), msm::digit_offset(w));
+    let mut lines: Vec<String> = (0..n).map(|i| format!("    let d{i} = zero_free_digits(s{i}, {w}, {count}, {offset});")).collect();
+    lines.push(format!("    let mut j = {};", count - 1));
+    lines
+}
+
+fn g1_msm_entry_point(n: usize, w: usize) -> Vec<String> {
+    let params: Vec<String> = (0..n).map(|i| format!("p{i}: AffineG1, s{i}: u384")).collect();
+    let mut lines = vec![format!("fn g1_msm_{}_w{}({}, m: CircuitModulus) -> AffineG1 {{", n, w, params.join(", "))];
+    for i in 0..n {
+        let entries: Vec<String> = (2..=1 << w).map(|d| format!("t{i}_{d}")).collect();
+        lines.push(format!("    let ({}) = g1_window_table_w{}(p{i}, m);", entries.join(", "), w));
+        lines.push(format!("    let t{i} = array![p{i}, {}];", entries.join(", ")));
+    }
+    lines.extend(g1_msm_digit_lines(n, w));
+    let args = pair_entries(n, |i| format!("*t{i}[*d{i}[j] - 1]"));
+    lines.push(format!("    let mut acc = g1_msm_first_{}_w{}({}, m);", n, w, args));
+    lines.extend([
+        "    while j != 0 {".to_string(),
+        "        j -= 1;".to_string(),
+        format!("        acc = g1_msm_step_{}_w{}(acc, {}, m);", n, w, args),
+        "    };".to_string(),
+        "    acc".to_string(),
+        "}".to_string(),
+    ]);
+    lines
+}
+
+fn g1_fixed_msm_entry_point(n: usize, w: usize) -> Vec<String> {
+    let params: Vec<String> = (0..n).map(|i| format!("table{i}: Span<u384>, s{i}: u384")).collect();
+    let mut lines = vec![format!("fn g1_fixed_msm_{}_w{}({}, m: CircuitModulus) -> AffineG1 {{", n, w, params.join(", "))];
+    lines.extend(g1_msm_digit_lines(n, w));
+    let args = pair_entries(n, |i| format!("fixed_table_entry(table{i}, {}, j, *d{i}[j])", 1 << w));
+    lines.extend([
+        format!("    let mut acc = g1_fixed_msm_first_{}_w{}({}, m);", n, w, args),
+        "    while j != 0 {".to_string(),
+        "        j -= 1;".to_string(),
+        format!("        acc = g1_fixed_msm_step_{}_w{}(acc, {}, m);", n, w, args),
+        "    };".to_string(),
+        "    acc".to_string(),
+        "}".to_string(),
+    ]);
+    lines
+}
+
+fn pair_entries(n: usize, entry: impl Fn(usize) -> String) -> String {
+    (0..n).map(entry).collect::<Vec<_>>().join(", ")
+}
+
+// f and the accumulator after bits 62 and 61, the accumulator starts at Q
+pub(crate) fn bls12_381_miller_first_second_circuits(inputs: &mut InputAllocator) -> (bls12_381::Gt, bls12_381::G2) {
+    let ppc = ppc_input(inputs);
+    let q: bls12_381::G2 = inputs.alloc("q");
+    let mut acc = q.clone();
+
+    let f = bls12_381::miller_first_second(&mut acc, &ppc, &q);
+    (f, acc)
+}
+
+// f is squared first, q is only read by 1 bits
+pub(crate) fn bls12_381_miller_bit_circuits(inputs: &mut InputAllocator, bit: BitType) -> (bls12_381::Gt, bls12_381::G2) {
+    let ppc = ppc_input(inputs);
+    let mut acc: bls12_381::G2 = inputs.alloc("acc");
+    let q: Option<bls12_381::G2> = (bit != BitType::O).then(|| inputs.alloc("q"));
+    let f: bls12_381::Gt = inputs.alloc("f");
 
-    builder.build()
+    let f = match q {
+        Some(q) => bls12_381::miller_bit_p(&mut acc, &f, &ppc, &q),
+        None => bls12_381::miller_bit_o(&mut acc, &f, &ppc),
+    };
+    (f, acc)
 }
 
+// Bit 0 of |x| is a 0 bit, f is conjugated after it as x is negative
+pub(crate) fn bls12_381_miller_last_circuit(inputs: &mut InputAllocator) -> bls12_381::Gt {
+    debug_assert_eq!(Bls12_381::loop_digits()[0], BitType::O);
+    let (f, _) = bls12_381_miller_bit_circuits(inputs, BitType::O);
+    f.conjugate()
+}
+
+// Lines of pair i follow its precompute, f comes after every pair
+fn fixed_line_inputs(inputs: &mut InputAllocator, n: usize, count: usize) -> Vec<(PPre, Vec<LineFn>)> {
+    (0..n)
+        .map(|i| {
+            let ppc = multi_ppc_input(inputs, i);
+            (ppc, (0..count).map(|k| inputs.line(&format!("line{}_{}", i, k))).collect())
+        })
+        .collect()
+}
+
+pub(crate) fn fixed_miller_first_second_circuit(inputs: &mut InputAllocator, n: usize) -> Fq12 {
+    fixed_line_inputs(inputs, n, 3)
+        .iter()
+        .map(|(ppc, lines)| miller_utils::lines_first_second(ppc, &lines[0], &lines[1], &lines[2]))
+        .reduce(|f, g| f.mul(&g))
+        .unwrap()
+}
+
+// f is squared first, P and N bits read the same two lines
+pub(crate) fn fixed_miller_bit_circuit(inputs: &mut InputAllocator, n: usize, bit: BitType) -> Fq12 {
+    let pairs = fixed_line_inputs(inputs, n, if bit == BitType::O { 1 } else { 2 });
+    let mut f = inputs.fq12("f").sqr();
+    for (ppc, lines) in &pairs {
+        match lines.as_slice() {
+            [line] => f = f.mul_034(&line.line_fn_at_p(ppc)),
+            [l1, l2] => miller_utils::lines_to_f(&mut f, ppc, l1, l2),
+            _ => unreachable!(),
+        }
+    }
+    f
+}
+
+pub(crate) fn fixed_miller_last_circuit(inputs: &mut InputAllocator, n: usize) -> Fq12 {
+    let pairs = fixed_line_inputs(inputs, n, 2);
+    let mut f = inputs.fq12("f");
+    for (ppc, lines) in &pairs {
+        miller_utils::lines_to_f(&mut f, ppc, &lines[0], &lines[1]);
+    }
+    f
+}
+
+// P is only read through its precompute
+fn ppc_input(inputs: &mut InputAllocator) -> PPre {
+    PPre::new(inputs.fq("neg_x_over_y"), inputs.fq("y_inv"))
+}
+
+// Returns f and the accumulator, the Miller loop state after bits 64 and 63
+pub(crate) fn miller_first_second_circuits(inputs: &mut InputAllocator) -> (Fq12, Affine<Fq2>) {
+    let ppc = ppc_input(inputs);
+    let mut acc = inputs.g2("acc");
+    let neg_q = inputs.g2("neg_q");
+
+    let f = miller_utils::miller_first_second(&mut acc, &ppc, &neg_q);
+    (f, acc)
+}
+
+pub(crate) fn miller_bit_o_circuits(inputs: &mut InputAllocator) -> (Fq12, Affine<Fq2>) {
+    let ppc = ppc_input(inputs);
+    let mut acc = inputs.g2("acc");
+    let mut f = inputs.fq12("f");
+
+    miller_utils::step_double_to_f(&mut acc, &mut f, &ppc);
+    (f, acc)
+}
+
+pub(crate) fn miller_bit_dbl_add_circuits(inputs: &mut InputAllocator) -> (Fq12, Affine<Fq2>) {
+    let ppc = ppc_input(inputs);
+    let mut acc = inputs.g2("acc");
+    let q = inputs.g2("q");
+    let mut f = inputs.fq12("f");
+
+    miller_utils::step_dbl_add_to_f(&mut acc, &mut f, &ppc, &q);
+    (f, acc)
+}
+
+// The accumulator is not needed after the last step
+pub(crate) fn miller_last_circuit(inputs: &mut InputAllocator) -> Fq12 {
+    let ppc = ppc_input(inputs);
+    let mut acc = inputs.g2("acc");
+    let q = inputs.g2("q");
+    let mut f = inputs.fq12("f");
+
+    miller_utils::correction_step_to_f(&mut acc, &mut f, &ppc, &q);
+    f
+}
+
+// Inputs of pair i are suffixed by i, f comes after every pair
+fn multi_ppc_input(inputs: &mut InputAllocator, i: usize) -> PPre {
+    PPre::new(inputs.fq(&format!("neg_x_over_y{}", i)), inputs.fq(&format!("y_inv{}", i)))
+}
+
+// f and the accumulators after bits 64 and 63, the product of every pair's first lines
+pub(crate) fn multi_miller_first_second_circuits<A: MillerAcc>(inputs: &mut InputAllocator, n: usize) -> (Fq12, Vec<A>) {
+    let mut f: Option<Fq12> = None;
+    let mut accs = vec![];
+    for i in 0..n {
+        let ppc = multi_ppc_input(inputs, i);
+        let mut acc = A::from_affine(inputs.g2(&format!("acc{}", i)));
+        let neg_q = inputs.g2(&format!("neg_q{}", i));
+        let lines = acc.first_second(&ppc, &neg_q);
+        f = Some(match f {
+            Some(f) => f.mul(&lines),
+            None => lines,
+        });
+        accs.push(acc);
+    }
+    (f.unwrap(), accs)
+}
+
+// One bit for every pair, f is squared first. P and N share the circuit, q is Q or -Q
+pub(crate) fn multi_miller_bit_circuits<A: MillerAcc>(inputs: &mut InputAllocator, n: usize, bit: BitType) -> (Fq12, Vec<A>) {
+    let mut pairs = vec![];
+    for i in 0..n {
+        let ppc = multi_ppc_input(inputs, i);
+        let acc: A = inputs.alloc(&format!("acc{}", i));
+        let q = (bit != BitType::O).then(|| inputs.g2(&format!("q{}", i)));
+        pairs.push((ppc, acc, q));
+    }
+    let mut f = inputs.fq12("f").sqr();
+
+    for (ppc, acc, q) in pairs.iter_mut() {
+        match q {
+            Some(q) => acc.dbl_add_to_f(&mut f, ppc, q),
+            None => acc.double_to_f(&mut f, ppc),
+        }
+    }
+    (f, pairs.into_iter().map(|(_, acc, _)| acc).collect())
+}
+
+pub(crate) fn multi_miller_last_circuit<A: MillerAcc>(inputs: &mut InputAllocator, n: usize) -> Fq12 {
+    let mut pairs = vec![];
+    for i in 0..n {
+        let ppc = multi_ppc_input(inputs, i);
+        let acc: A = inputs.alloc(&format!("acc{}", i));
+        let q = inputs.g2(&format!("q{}", i));
+        pairs.push((ppc, acc, q));
+    }
+    let mut f = inputs.fq12("f");
+
+    for (ppc, acc, q) in pairs.iter_mut() {
+        acc.correction_to_f(&mut f, ppc, q);
+    }
+    f
+}
+
+// [1, 2^w]·P of a variable base
+pub(crate) fn g1_window_table_circuit(inputs: &mut InputAllocator, w: usize) -> Vec<Affine<Fq>> {
+    msm::window_table(&inputs.g1("p"), w)
+}
+
+pub(crate) fn g1_sum_circuit(inputs: &mut InputAllocator, n: usize) -> Affine<Fq> {
+    let points: Vec<Affine<Fq>> = (0..n).map(|i| inputs.g1(&format!("p{}", i))).collect();
+    msm::sum(&points)
+}
+
+// A window of the Straus MSM, acc doubled w times then one entry of every base added
+pub(crate) fn g1_straus_step_circuit(inputs: &mut InputAllocator, n: usize, w: usize) -> Affine<Fq> {
+    let acc = inputs.g1("acc");
+    let entries: Vec<Affine<Fq>> = (0..n).map(|i| inputs.g1(&format!("p{}", i))).collect();
+    msm::straus_step(&acc, &entries, w)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_generator, GENERATORS};
+    use crate::circuit::{builder::CairoCodeBuilder, optimizer::Passes, stats::CostModel};
+    use crate::msm;
+    use crate::pairing::fixed;
+    use crate::utils::utils::assert_calls_emitted;
+
+    #[test]
+    fn test_generate_functions() {
+        // Input wiring is validated while the functions are rendered
+        for generator in GENERATORS {
+            let mut builder = CairoCodeBuilder::new().with_functions(true);
+            (generator.generate)(&mut builder);
+            let code = builder.build();
+            assert!(code.contains("m: CircuitModulus"), "{} emitted no function", generator.name);
+        }
+    }
+
+    #[test]
+    fn test_generate_optimized() {
+        for generator in GENERATORS {
+            let mut builder = CairoCodeBuilder::new().with_functions(true).with_optimizer(Some(Passes::default()));
+            (generator.generate)(&mut builder);
+            assert!(!builder.reports().is_empty(), "{} optimized nothing", generator.name);
+            for report in builder.reports() {
+                assert!(report.after <= report.before, "{} grew {:?}", generator.name, report);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_pairing_entry_point() {
+        // Every function the entry point calls is emitted by the generators it relies on
+        let mut builder = CairoCodeBuilder::new().with_functions(true);
+        for name in ["miller_precompute", "final_exp_easy", "final_exp_hard", "multi_pairing_2"] {
+            (find_generator(name).unwrap().generate)(&mut builder);
+        }
+        let code = builder.build();
+        let calls = assert_calls_emitted(&code, "multi_pairing_2", &[]);
+        assert_eq!(calls.iter().filter(|call| call.starts_with("multi_miller_bit_")).count(), 63);
+    }
+
+    #[test]
+    fn test_projective_multi_pairing() {
+        let mut builder = CairoCodeBuilder::new().with_functions(true);
+        for name in ["miller_precompute", "final_exp_easy", "final_exp_hard", "projective_multi_pairing_2"] {
+            (find_generator(name).unwrap().generate)(&mut builder);
+        }
+        let stats = builder.stats().to_vec();
+        let code = builder.build();
+        assert_calls_emitted(&code, "projective_multi_pairing_2", &[]);
+
+        // An inversion is a single gate of the circuit, the default costs favour the affine steps.
+        // Weighted as an inversion outside a circuit, about a hundred products, they do not
+        let mut affine = CairoCodeBuilder::new().with_functions(true);
+        (find_generator("multi_pairing_2").unwrap().generate)(&mut affine);
+        let (model, inv_model) = (CostModel::default(), CostModel { inv: 200, ..CostModel::default() });
+        for affine in affine.stats() {
+            let projective = stats.iter().find(|s| s.name == format!("projective_{}", affine.name)).unwrap();
+            assert!(affine.gates.inv > 0 && projective.gates.inv == 0, "{}", projective.name);
+            assert!(projective.gates.mul > affine.gates.mul);
+            assert!(model.steps(projective) > model.steps(affine));
+            assert!(inv_model.steps(projective) < inv_model.steps(affine), "{}", projective.name);
+        }
+    }
+
+    #[test]
+    fn test_g1_msm_entry_points() {
+        for name in ["g1_msm_3_w3", "g1_fixed_msm_5_w4"] {
+            let mut builder = CairoCodeBuilder::new().with_functions(true);
+            (find_generator(name).unwrap().generate)(&mut builder);
+            let code = builder.build();
+            assert_calls_emitted(&code, name, &["zero_free_digits", "array!["]);
+        }
+    }
+
+    #[test]
+    fn test_g1_msm_gates() {
+        // A whole MSM of three variable bases: 4-bit windows double as often as 2-bit ones but
+        // add half as many entries, for 2^4 - 1 table entries per base
+        let model = CostModel::default();
+        let msm_stats = |w: usize| {
+            let mut builder = CairoCodeBuilder::new().with_functions(true);
+            (find_generator(&format!("g1_msm_3_w{}", w)).unwrap().generate)(&mut builder);
+            let stats = builder.stats().to_vec();
+            let gates = msm::msm_gates(Some(&stats[0]), &stats[1], &stats[2], 3, w);
+            let count = msm::window_count(w);
+            assert_eq!(gates.inv, 3 * ((1 << w) - 1) + 2 + (count - 1) * (w + 3));
+            (gates, (count - 1) as u64 * model.steps(&stats[2]))
+        };
+        let (w2, w4) = (msm_stats(2), msm_stats(4));
+        assert!(w4.0.total() < w2.0.total() && w4.1 < w2.1);
+
+        // Fixed bases only add, one entry per window
+        let mut builder = CairoCodeBuilder::new().with_functions(true);
+        (find_generator("g1_fixed_msm_5_w4").unwrap().generate)(&mut builder);
+        let stats = builder.stats().to_vec();
+        let fixed = msm::msm_gates(None, &stats[0], &stats[1], 5, 4);
+        assert_eq!(fixed.inv, 5 * msm::window_count(4) - 1);
+        assert!(fixed.total() < w4.0.total());
+    }
+
+    #[test]
+    fn test_lagrange_pi_gates() {
+        // One inversion whatever the number of public inputs, each one more costs the w power,
+        // the denominator, three batch multiplications, L_i and its PI term
+        let gates = |name: &str| {
+            let mut builder = CairoCodeBuilder::new().with_functions(true);
+            (find_generator(name).unwrap().generate)(&mut builder);
+            builder.stats()[0].clone()
+        };
+        let (one, five) = (gates("lagrange_pi_1"), gates("lagrange_pi_5"));
+        assert_eq!((one.gates.inv, five.gates.inv), (1, 1));
+        assert_eq!(gates("batch_inverse_8").gates.inv, 1);
+        assert!(five.gates.mul - one.gates.mul <= 4 * 8);
+
+        // A whole circuit costs less than the five divisions of the Cairo loop, one circuit each
+        let model = CostModel::default();
+        assert!(model.steps(&five) < 5 * model.base);
+    }
+
+    #[test]
+    fn test_fixed_pairing_entry_point() {
+        let mut builder = CairoCodeBuilder::new().with_functions(true);
+        for name in ["final_exp_easy", "final_exp_hard", "fixed_pairing_2"] {
+            (find_generator(name).unwrap().generate)(&mut builder);
+        }
+        let code = builder.build();
+        let entry = code.split("fn fixed_pairing_2(").nth(1).unwrap();
+        assert_calls_emitted(&code, "fixed_pairing_2", &[]);
+
+        // Four u384 per line, the last line of both arrays is read by fixed_miller_last_2
+        let count = fixed::line_count();
+        assert!(code.contains(&format!("const G2_GENERATOR_LINES: [u384; {}] = [", 4 * count)));
+        let last = entry.lines().find(|line| line.contains("fixed_miller_last_2(")).unwrap();
+        assert!(last.contains(&format!("fixed_line(lines1, {})", count - 1)));
+    }
+}
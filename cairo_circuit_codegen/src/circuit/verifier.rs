@@ -0,0 +1,548 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use num_bigint::BigUint;
+use num_traits::One;
+use serde::{Deserialize, Deserializer};
+
+use super::{
+    builder::CairoCodeBuilder,
+    evaluator::{bn254_modulus, bn254_scalar_modulus},
+    function::u384_literal,
+    optimizer::Passes,
+    utils::{add_lagrange_pi, find_generator},
+};
+use crate::pairing::fixed;
+use crate::reference::{Fq, Fq2, G1Affine, G2Affine};
+
+#[derive(Debug)]
+pub enum VerifierError {
+    Io(PathBuf, io::Error),
+    Json(serde_json::Error),
+    /// Only snarkjs PLONK keys on BN254 are supported
+    Unsupported { protocol: String, curve: String },
+    /// A number that is not a decimal integer below the field modulus
+    InvalidNumber(&'static str),
+    /// A commitment not in affine form or not on its curve
+    InvalidPoint(&'static str),
+    /// w is not a primitive root of unity of order 2^power, or n differs from 2^power
+    InvalidDomain,
+}
+
+impl fmt::Display for VerifierError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VerifierError::Io(path, err) => write!(f, "{}: {}", path.display(), err),
+            VerifierError::Json(err) => write!(f, "invalid verification key: {}", err),
+            VerifierError::Unsupported { protocol, curve } => {
+                write!(f, "unsupported verification key: {} on {}, expected plonk on bn128", protocol, curve)
+            }
+            VerifierError::InvalidNumber(field) => write!(f, "invalid number in {}", field),
+            VerifierError::InvalidPoint(field) => write!(f, "{} is not an affine point of its curve", field),
+            VerifierError::InvalidDomain => write!(f, "w is not a root of unity of order 2^power"),
+        }
+    }
+}
+
+impl std::error::Error for VerifierError {}
+
+// snarkjs writes some integers as JSON numbers and the others as strings
+fn number_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    match serde_json::Value::deserialize(deserializer)? {
+        serde_json::Value::Number(num) => Ok(num.to_string()),
+        serde_json::Value::String(s) => Ok(s),
+        _ => Err(serde::de::Error::custom("expected a number or a string")),
+    }
+}
+
+fn optional_number_or_string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    number_or_string(deserializer).map(Some)
+}
+
+// `verification_key.json` as written by `snarkjs plonk setup`, points in projective coordinates
+#[derive(Debug, Deserialize)]
+struct SnarkjsKey {
+    protocol: String,
+    curve: String,
+    #[serde(rename = "nPublic", deserialize_with = "number_or_string")]
+    n_public: String,
+    #[serde(deserialize_with = "number_or_string")]
+    power: String,
+    #[serde(default, deserialize_with = "optional_number_or_string")]
+    n: Option<String>,
+    #[serde(deserialize_with = "number_or_string")]
+    k1: String,
+    #[serde(deserialize_with = "number_or_string")]
+    k2: String,
+    #[serde(rename = "Qm")]
+    qm: [String; 3],
+    #[serde(rename = "Ql")]
+    ql: [String; 3],
+    #[serde(rename = "Qr")]
+    qr: [String; 3],
+    #[serde(rename = "Qo")]
+    qo: [String; 3],
+    #[serde(rename = "Qc")]
+    qc: [String; 3],
+    #[serde(rename = "S1")]
+    s1: [String; 3],
+    #[serde(rename = "S2")]
+    s2: [String; 3],
+    #[serde(rename = "S3")]
+    s3: [String; 3],
+    #[serde(rename = "X_2")]
+    x_2: [[String; 2]; 3],
+    #[serde(deserialize_with = "number_or_string")]
+    w: String,
+}
+
+/// A PLONK verification key with its values checked, the commitments are affine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerificationKey {
+    pub power: u32,
+    pub n_public: usize,
+    pub k1: BigUint,
+    pub k2: BigUint,
+    pub w: BigUint,
+    /// Qm, Ql, Qr, Qo, Qc, S1, S2 and S3, in the order of `COMMITMENTS`
+    pub commitments: Vec<G1Affine>,
+    pub x_2: G2Affine,
+}
+
+/// Names of the commitments of a key, as in the JSON and in `PlonkVerificationKey`
+pub const COMMITMENTS: [&str; 8] = ["Qm", "Ql", "Qr", "Qo", "Qc", "S1", "S2", "S3"];
+
+fn parse(field: &'static str, value: &str, modulus: &BigUint) -> Result<BigUint, VerifierError> {
+    value.parse::<BigUint>().ok().filter(|v| v < modulus).ok_or(VerifierError::InvalidNumber(field))
+}
+
+impl VerificationKey {
+    pub fn from_json(json: &str) -> Result<Self, VerifierError> {
+        let key: SnarkjsKey = serde_json::from_str(json).map_err(VerifierError::Json)?;
+        if key.protocol != "plonk" || key.curve != "bn128" {
+            return Err(VerifierError::Unsupported { protocol: key.protocol, curve: key.curve });
+        }
+        let (p, r) = (bn254_modulus(), bn254_scalar_modulus());
+        let small = |field: &'static str, value: &str| value.parse::<u32>().map_err(|_| VerifierError::InvalidNumber(field));
+
+        let power = small("power", &key.power)?;
+        let n_public = small("nPublic", &key.n_public)? as usize;
+        if power == 0 || power > 28 || key.n.is_some_and(|n| n.parse::<BigUint>().ok() != Some(BigUint::one() << power)) {
+            return Err(VerifierError::InvalidDomain);
+        }
+
+        // w has order exactly 2^power
+        let w = parse("w", &key.w, &r)?;
+        let half = w.modpow(&(BigUint::one() << (power - 1)), &r);
+        if half.modpow(&BigUint::from(2u32), &r) != BigUint::one() || half.is_one() {
+            return Err(VerifierError::InvalidDomain);
+        }
+
+        let fields = [&key.qm, &key.ql, &key.qr, &key.qo, &key.qc, &key.s1, &key.s2, &key.s3];
+        let commitments = COMMITMENTS
+            .iter()
+            .zip(fields)
+            .map(|(name, [x, y, z])| {
+                let point = G1Affine::new(Fq::new(parse(name, x, &p)?), Fq::new(parse(name, y, &p)?));
+                match z == "1" && point.is_on_curve() {
+                    true => Ok(point),
+                    false => Err(VerifierError::InvalidPoint(name)),
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let fq2 = |[c0, c1]: &[String; 2]| -> Result<Fq2, VerifierError> {
+            Ok(Fq2::new(Fq::new(parse("X_2", c0, &p)?), Fq::new(parse("X_2", c1, &p)?)))
+        };
+        let x_2 = G2Affine { x: fq2(&key.x_2[0])?, y: fq2(&key.x_2[1])? };
+        if key.x_2[2] != ["1", "0"] || !x_2.is_on_curve() {
+            return Err(VerifierError::InvalidPoint("X_2"));
+        }
+
+        Ok(Self {
+            power,
+            n_public,
+            k1: parse("k1", &key.k1, &r)?,
+            k2: parse("k2", &key.k2, &r)?,
+            w,
+            commitments,
+            x_2,
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, VerifierError> {
+        let json = fs::read_to_string(path).map_err(|err| VerifierError::Io(path.to_path_buf(), err))?;
+        Self::from_json(&json)
+    }
+
+    /// Size of the evaluation domain, 2^power
+    pub fn n(&self) -> BigUint {
+        BigUint::one() << self.power
+    }
+
+    /// L₁ is needed by R0 and D even without public inputs
+    pub fn lagrange_count(&self) -> usize {
+        self.n_public.max(1)
+    }
+}
+
+// The generated contract reuses the transcript, commitment and pairing code of this package, it
+// is built against the version next to this crate
+const PLONK_VERIFIER_MANIFEST: &str = include_str!("../../../plonk-verifier/Scarb.toml");
+
+/// Checkout of the `plonk_verifier` package the generated contracts are written against
+pub const PLONK_VERIFIER_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../plonk-verifier");
+
+/// Largest Sierra contract class Starknet accepts, in bytes of its JSON
+pub const MAX_CONTRACT_CLASS_SIZE: usize = 4_089_446;
+
+/// Largest compiled (CASM) contract bytecode Starknet accepts, in felts
+pub const MAX_BYTECODE_SIZE: usize = 81_920;
+
+// First `key = "value"` of the plonk_verifier manifest, its [package] table comes first
+fn manifest_value(key: &str) -> &'static str {
+    PLONK_VERIFIER_MANIFEST
+        .lines()
+        .find_map(|line| line.strip_prefix(key)?.trim_start().strip_prefix('=')?.trim().strip_prefix('"')?.strip_suffix('"'))
+        .unwrap_or_else(|| panic!("plonk_verifier/Scarb.toml has no {}", key))
+}
+
+/// Version of `plonk_verifier` the generated contracts need, they use its internals
+pub fn plonk_verifier_version() -> &'static str {
+    manifest_value("version")
+}
+
+/// `Scarb.toml` of a package holding a generated verifier, `plonk_verifier` is pinned to the exact
+/// version the code was generated for
+pub fn scarb_manifest(name: &str, plonk_verifier: &Path) -> String {
+    [
+        "[package]".to_string(),
+        format!("name = \"{}\"", name),
+        "version = \"0.1.0\"".to_string(),
+        format!("cairo-version = \"{}\"", manifest_value("cairo-version")),
+        String::new(),
+        "[[target.starknet-contract]]".to_string(),
+        "sierra = true".to_string(),
+        "casm = true".to_string(),
+        String::new(),
+        "[dependencies]".to_string(),
+        format!("starknet = \">={}\"", manifest_value("cairo-version")),
+        format!(
+            "plonk_verifier = {{ path = \"{}\", version = \"={}\" }}",
+            plonk_verifier.display(),
+            plonk_verifier_version()
+        ),
+        String::new(),
+    ]
+    .join("\n")
+}
+
+/// Writes a Scarb package with the verifier as its `src/lib.cairo`, depending on the
+/// `plonk_verifier` checkout at `plonk_verifier`
+pub fn write_package(dir: &Path, name: &str, code: &str, plonk_verifier: &Path) -> io::Result<()> {
+    if !plonk_verifier.join("Scarb.toml").is_file() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{} is not a plonk_verifier checkout, it has no Scarb.toml", plonk_verifier.display()),
+        ));
+    }
+    // The package may be written anywhere, it depends on the checkout by absolute path
+    let plonk_verifier = plonk_verifier.canonicalize()?;
+    fs::create_dir_all(dir.join("src"))?;
+    fs::write(dir.join("Scarb.toml"), scarb_manifest(name, &plonk_verifier))?;
+    fs::write(dir.join("src").join("lib.cairo"), code)
+}
+
+// Named u384 constant of the generated contract
+fn constant(name: &str, value: &BigUint) -> String {
+    format!("const {}: u384 = {};", name, u384_literal(value))
+}
+
+/// A Starknet verifier contract for one key.
+///
+/// The key is baked into constants and the X_2 lines into a constant array, the Lagrange
+/// evaluations and PI are one circuit specialized to `nPublic` and the final check is a
+/// fixed-argument pairing.
+/// The contract only takes a proof and its public signals, the transcript and commitments steps
+/// are the ones of `plonk_verifier::plonk::verify`. It is not standalone, it builds in a package
+/// depending on `plonk_verifier` at `plonk_verifier_version`, see `write_package`.
+pub fn generate_verifier(vk: &VerificationKey) -> String {
+    let mut builder = CairoCodeBuilder::new().with_functions(true).with_optimizer(Some(Passes::default()));
+    for name in ["final_exp_easy", "final_exp_hard", "fixed_pairing_2"] {
+        (find_generator(name).unwrap().generate)(&mut builder);
+    }
+    add_lagrange_pi(&mut builder, vk.n_public);
+    builder.add_entry_point(
+        &[
+            "plonk_verifier::circuits::fq_circuits::mul_c",
+            "plonk_verifier::curve::groups::ECOperationsCircuitFq",
+            "plonk_verifier::curve::groups::affine_fq1",
+            "plonk_verifier::curve::groups::affine_fq2",
+            "plonk_verifier::fields::Fq12Utils",
+            "plonk_verifier::fields::FqUtils",
+            "plonk_verifier::fields::fq_generics::TFqPartialEq",
+            "plonk_verifier::plonk::types::PlonkChallenge",
+            "plonk_verifier::plonk::types::PlonkProof",
+            "plonk_verifier::plonk::types::PlonkVerificationKey",
+            "plonk_verifier::plonk::utils::field_modulus",
+            "plonk_verifier::plonk::utils::order_modulus",
+            "plonk_verifier::plonk::verify::PlonkVerifier",
+        ],
+        &verifier_lines(vk),
+    );
+    builder.add_imports();
+    format!(
+        "// Verifier of a single PLONK circuit, generated by cairo_circuit_codegen from its verification key\n\
+         // Needs plonk_verifier = \"={}\" as a dependency of its Scarb package\n{}",
+        plonk_verifier_version(),
+        builder.build()
+    )
+}
+
+fn verifier_lines(vk: &VerificationKey) -> Vec<String> {
+    let mut lines = vec![
+        String::new(),
+        format!("const N_PUBLIC: usize = {};", vk.n_public),
+        constant("N", &vk.n()),
+        constant("POWER", &BigUint::from(vk.power)),
+        constant("N_PUBLIC_U384", &BigUint::from(vk.n_public)),
+        constant("K1", &vk.k1),
+        constant("K2", &vk.k2),
+        constant("W", &vk.w),
+    ];
+    for (name, point) in COMMITMENTS.iter().zip(&vk.commitments) {
+        lines.push(constant(&format!("{}_X", name.to_uppercase()), &point.x.0));
+        lines.push(constant(&format!("{}_Y", name.to_uppercase()), &point.y.0));
+    }
+    let x_2 = [&vk.x_2.x.c0, &vk.x_2.x.c1, &vk.x_2.y.c0, &vk.x_2.y.c1];
+    for (name, value) in ["X_2_X0", "X_2_X1", "X_2_Y0", "X_2_Y1"].into_iter().zip(x_2) {
+        lines.push(constant(name, &value.0));
+    }
+    lines.push(fixed::lines_constant("X_2_LINES", &vk.x_2));
+    lines.push(String::new());
+
+    let commitment = |name: &str| format!("affine_fq1({0}_X, {0}_Y)", name.to_uppercase());
+    lines.extend([
+        "fn verification_key() -> PlonkVerificationKey {".to_string(),
+        "    PlonkVerificationKey {".to_string(),
+        "        n: fq(N),".to_string(),
+        "        power: fq(POWER),".to_string(),
+        "        k1: fq(K1),".to_string(),
+        "        k2: fq(K2),".to_string(),
+        "        nPublic: fq(N_PUBLIC_U384),".to_string(),
+        "        nLagrange: fq(N_PUBLIC_U384),".to_string(),
+    ]);
+    lines.extend(COMMITMENTS.iter().map(|name| format!("        {}: {},", name, commitment(name))));
+    lines.extend([
+        "        X_2: affine_fq2(X_2_X0, X_2_X1, X_2_Y0, X_2_Y1),".to_string(),
+        "        w: fq(W),".to_string(),
+        "    }".to_string(),
+        "}".to_string(),
+        String::new(),
+    ]);
+
+    lines.extend(lagrange_lines(vk));
+    lines.extend(verify_lines());
+    lines
+}
+
+// ξⁿ by `power` squarings, then zh, the Lagrange evaluations and PI from `lagrange_pi_<nPublic>`
+// and its single inversion
+fn lagrange_lines(vk: &VerificationKey) -> Vec<String> {
+    let mut lines = vec![
+        "// Lagrange evaluations and PI at xi, L[0] is unused so that L[i] is L_i".to_string(),
+        "fn lagrange_evaluations(".to_string(),
+        "    mut challenges: PlonkChallenge, public_signals: @Array<u384>, m_o: CircuitModulus".to_string(),
+        ") -> (Array<Fq>, Fq, PlonkChallenge) {".to_string(),
+        "    let xin = challenges.xi.c0;".to_string(),
+    ];
+    lines.extend((0..vk.power).map(|_| "    let xin = mul_c(xin, xin, m_o);".to_string()));
+
+    let ls: Vec<String> = (1..=vk.lagrange_count()).map(|i| format!("l{}", i)).collect();
+    let signals: Vec<String> = (0..vk.n_public).map(|i| format!("fq(*public_signals[{}])", i)).collect();
+    // w is only read from L_2 on
+    let w = (vk.n_public > 1).then(|| "fq(W)".to_string());
+    let args = ["challenges.xi".to_string(), "fq(xin)".to_string(), "fq(N)".to_string()];
+    let args = args.into_iter().chain(w).chain(signals).chain(["m_o".to_string()]).collect::<Vec<_>>().join(", ");
+    let (outputs, pi) = match vk.n_public {
+        0 => (format!("zh, {}", ls.join(", ")), "FqUtils::zero()"),
+        _ => (format!("zh, {}, pi", ls.join(", ")), "pi"),
+    };
+    lines.extend([
+        format!("    let ({}) = lagrange_pi_{}({});", outputs, vk.n_public, args),
+        "    challenges.xin = fq(xin);".to_string(),
+        "    challenges.zh = zh;".to_string(),
+        format!("    (array![FqUtils::zero(), {}], {}, challenges)", ls.join(", "), pi),
+        "}".to_string(),
+        String::new(),
+    ]);
+    lines
+}
+
+// The steps of `PlonkVerifier::verify` on the constant key, e(A1, X_2) = e(B1, [1]₂) checked as
+// e(A1, X_2)·e(-B1, [1]₂) = 1 from the lines of both G2 points
+fn verify_lines() -> Vec<String> {
+    let proof_points = ["A", "B", "C", "Z", "T1", "T2", "T3", "Wxi", "Wxiw"];
+    let evals = ["eval_a", "eval_b", "eval_c", "eval_s1", "eval_s2", "eval_zw"];
+    let mut lines = vec![
+        "fn verify(proof: PlonkProof, public_signals: Array<u384>) -> bool {".to_string(),
+        "    if public_signals.len() != N_PUBLIC {".to_string(),
+        "        return false;".to_string(),
+        "    }".to_string(),
+        "    let m = field_modulus();".to_string(),
+        "    let m_o = order_modulus();".to_string(),
+        "    let vk = verification_key();".to_string(),
+        String::new(),
+    ];
+    let checks: Vec<String> = proof_points
+        .iter()
+        .map(|p| format!("PlonkVerifier::is_on_curve(proof.{}, m)", p))
+        .chain(evals.iter().map(|e| format!("PlonkVerifier::is_in_field(proof.{})", e)))
+        .collect();
+    lines.push(format!("    let valid = {};", checks.join("\n        && ")));
+    lines.extend([
+        "    if !valid {".to_string(),
+        "        return false;".to_string(),
+        "    }".to_string(),
+        String::new(),
+        "    let challenges = PlonkVerifier::compute_challenges(vk, proof, @public_signals, m_o);".to_string(),
+        "    let (L, PI, challenges) = lagrange_evaluations(challenges, @public_signals, m_o);".to_string(),
+        "    let R0 = PlonkVerifier::compute_R0(proof, challenges, @PI, L[1], m_o);".to_string(),
+        "    let D = PlonkVerifier::compute_D(proof, challenges, vk, L[1], m, m_o);".to_string(),
+        "    let F = PlonkVerifier::compute_F(proof, challenges, vk, D, m);".to_string(),
+        "    let E = PlonkVerifier::compute_E(proof, challenges, R0, m, m_o);".to_string(),
+        "    let (A1, _, B1, _) = PlonkVerifier::compute_pairing(proof, challenges, vk, E, F, m, m_o);".to_string(),
+        String::new(),
+        "    let f = fixed_pairing_2(A1, X_2_LINES.span(), B1.neg(m), G2_GENERATOR_LINES.span(), m);".to_string(),
+        "    f == Fq12Utils::one()".to_string(),
+        "}".to_string(),
+        String::new(),
+        "#[starknet::interface]".to_string(),
+        "trait IVerifier<T> {".to_string(),
+        "    fn verify(self: @T, proof: PlonkProof, public_signals: Array<u384>) -> bool;".to_string(),
+        "}".to_string(),
+        String::new(),
+        "#[starknet::contract]".to_string(),
+        "mod Verifier {".to_string(),
+        "    use core::circuit::u384;".to_string(),
+        "    use plonk_verifier::plonk::types::PlonkProof;".to_string(),
+        String::new(),
+        "    #[storage]".to_string(),
+        "    struct Storage {}".to_string(),
+        String::new(),
+        "    #[abi(embed_v0)]".to_string(),
+        "    impl VerifierImpl of super::IVerifier<ContractState> {".to_string(),
+        "        fn verify(self: @ContractState, proof: PlonkProof, public_signals: Array<u384>) -> bool {".to_string(),
+        "            super::verify(proof, public_signals)".to_string(),
+        "        }".to_string(),
+        "    }".to_string(),
+        "}".to_string(),
+    ]);
+    lines
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::Path;
+    use std::process::Command;
+
+    use num_bigint::BigUint;
+
+    use super::{
+        generate_verifier, plonk_verifier_version, scarb_manifest, write_package, VerificationKey, VerifierError,
+        MAX_BYTECODE_SIZE, MAX_CONTRACT_CLASS_SIZE, PLONK_VERIFIER_DIR,
+    };
+    use crate::circuit::evaluator::bn254_scalar_modulus;
+    use crate::utils::utils::assert_calls_emitted;
+
+    const KEY: &str = include_str!("../../data/verification_key.json");
+
+    #[test]
+    fn test_load_key() {
+        let vk = VerificationKey::load(Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/data/verification_key.json"))).unwrap();
+        assert_eq!((vk.power, vk.n_public, vk.lagrange_count()), (12, 5, 5));
+        assert_eq!(vk.n(), BigUint::from(4096u32));
+        assert_eq!(vk.w.modpow(&vk.n(), &bn254_scalar_modulus()), BigUint::from(1u32));
+
+        // Keys the verifier cannot be specialized to
+        let cases = [
+            (KEY.replace("\"plonk\"", "\"groth16\""), "unsupported"),
+            (KEY.replace("\"power\": 12", "\"power\": 11"), "root of unity"),
+            (KEY.replace("\"nPublic\": 5", "\"nPublic\": -1"), "nPublic"),
+            (KEY.replacen("\"1\"\n ]", "\"2\"\n ]", 1), "Qm"),
+            (KEY.replace("2046742093474138364318819827031777645206433195128565824360788617741298981525", "1"), "X_2"),
+        ];
+        for (json, message) in cases {
+            let err = VerificationKey::from_json(&json).unwrap_err();
+            assert!(err.to_string().contains(message), "{}", err);
+        }
+        assert!(matches!(VerificationKey::from_json("{}"), Err(VerifierError::Json(_))));
+    }
+
+    #[test]
+    fn test_generate_verifier() {
+        let mut vk = VerificationKey::from_json(KEY).unwrap();
+        let code = generate_verifier(&vk);
+        let library = [
+            "field_modulus", "order_modulus", "PlonkVerifier::is_on_curve", "PlonkVerifier::compute_challenges",
+            "PlonkVerifier::compute_R0", "PlonkVerifier::compute_D", "PlonkVerifier::compute_F",
+            "PlonkVerifier::compute_E", "PlonkVerifier::compute_pairing",
+        ];
+        let calls = assert_calls_emitted(&code, "verify", &library);
+        assert!(calls.contains(&"lagrange_evaluations") && calls.contains(&"fixed_pairing_2"));
+        assert!(code.contains("fn lagrange_pi_5(") && code.contains("fn final_exp_easy_0("));
+        assert!(code.contains("const X_2_LINES: [u384; ") && code.contains("const G2_GENERATOR_LINES: [u384; "));
+        assert!(code.contains("const N_PUBLIC: usize = 5;"));
+        assert!(code.contains(&format!("// Needs plonk_verifier = \"={}\"", plonk_verifier_version())));
+        assert_eq!(code.matches("    let xin = mul_c(xin, xin, m_o);").count(), 12);
+        assert!(code.contains("let (zh, l1, l2, l3, l4, l5, pi) = lagrange_pi_5(") && code.contains("fq(*public_signals[4]), m_o);"));
+        // A single inversion for the five Lagrange denominators
+        let lagrange = code.split("// lagrange_pi_5").nth(1).unwrap().split("fn lagrange_pi_5(").next().unwrap();
+        assert_eq!(lagrange.matches("I<").count() - lagrange.matches("CI<").count(), 1);
+
+        // Without public inputs L_1 is still computed and PI is zero
+        vk.n_public = 0;
+        let code = generate_verifier(&vk);
+        assert!(code.contains("let (zh, l1) = lagrange_pi_0(challenges.xi, fq(xin), fq(N), m_o);"));
+        assert!(!code.contains("public_signals[0]"));
+    }
+
+    #[test]
+    fn test_scarb_manifest() {
+        let manifest = scarb_manifest("verifier", Path::new("../plonk-verifier"));
+        assert!(!plonk_verifier_version().is_empty());
+        assert!(manifest.starts_with("[package]\nname = \"verifier\"\n"));
+        assert!(manifest.contains(&format!(
+            "plonk_verifier = {{ path = \"../plonk-verifier\", version = \"={}\" }}",
+            plonk_verifier_version()
+        )));
+
+        let dir = std::env::temp_dir().join(format!("verifier_package_{}", std::process::id()));
+        let missing = write_package(&dir, "verifier", "", &dir.join("plonk-verifier")).unwrap_err();
+        assert!(missing.to_string().contains("not a plonk_verifier checkout"), "{}", missing);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    #[ignore = "needs scarb"]
+    fn test_verifier_compiles() {
+        // The contract must build and fit in a declared class
+        let dir = std::env::temp_dir().join(format!("verifier_{}", std::process::id()));
+        let code = generate_verifier(&VerificationKey::from_json(KEY).unwrap());
+        write_package(&dir, "verifier", &code, Path::new(PLONK_VERIFIER_DIR)).unwrap();
+
+        let build = Command::new("scarb").arg("build").current_dir(&dir).output().unwrap();
+        assert!(build.status.success(), "{}", String::from_utf8_lossy(&build.stdout));
+
+        let class = fs::read(dir.join("target/dev/verifier_Verifier.contract_class.json")).unwrap();
+        assert!(class.len() <= MAX_CONTRACT_CLASS_SIZE, "Sierra class of {} bytes", class.len());
+        let casm = fs::read_to_string(dir.join("target/dev/verifier_Verifier.compiled_contract_class.json")).unwrap();
+        let casm: serde_json::Value = serde_json::from_str(&casm).unwrap();
+        let bytecode = casm["bytecode"].as_array().unwrap().len();
+        assert!(bytecode <= MAX_BYTECODE_SIZE, "CASM bytecode of {} felts", bytecode);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
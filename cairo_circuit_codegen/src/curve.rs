@@ -0,0 +1,234 @@
+// Parameters of the pairing-friendly curves the circuits are generated for
+use std::fmt::Debug;
+
+use num_bigint::BigUint;
+use num_traits::{Num, One, Zero};
+
+use crate::circuit::evaluator::{bn254_modulus, bn254_scalar_modulus};
+use crate::fields::{fq::Fq, frobenius::frobenius_names};
+use crate::pairing::{ate_miller::{BitType, ATE_LOOP}, final_exp::{self, Op}};
+
+/// Sextic twist carrying G2, it decides where the line coefficients land in Fq12
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Twist {
+    /// y² = x³ + b/ξ, lines are sparse in positions 0, 3 and 4
+    D,
+    /// y² = x³ + b·ξ, lines are sparse in positions 0, 1 and 4
+    M,
+}
+
+/// Cairo names of ξ^(e(pᵏ - 1)/6) for k = 1, 2, 3 and e = 1 to 4
+pub type FrobeniusNames = [[[&'static str; 2]; 4]; 3];
+
+/// A BN or BLS12 curve y² = x³ + b over Fq with the tower Fq2 = Fq[u]/(u² + 1),
+/// Fq6 = Fq2[v]/(v³ - ξ) and Fq12 = Fq6[w]/(w² - v)
+pub trait Curve: Clone + Copy + Debug + Default + PartialEq + Eq + 'static {
+    const NAME: &'static str;
+
+    /// ξ = XI_C0 + u
+    const XI_C0: u64;
+
+    const TWIST: Twist;
+
+    /// b of the G1 equation
+    const B: u64;
+
+    /// The Miller loop parameter is negative, f is conjugated after the loop
+    const LOOP_NEGATIVE: bool;
+
+    const FROBENIUS_NAMES: FrobeniusNames;
+
+    /// Cairo names of the components of the twist coefficient
+    const G2_B_NAMES: [&'static str; 2];
+
+    fn modulus() -> BigUint;
+
+    fn scalar_modulus() -> BigUint;
+
+    /// Digits of the absolute value of the Miller loop parameter indexed by bit, the leading one is
+    /// the initial T = Q and is left out
+    fn loop_digits() -> Vec<BitType>;
+
+    /// c·XI_C0, the Fq part of a multiplication by ξ
+    fn mul_by_xi_c0(c: &Fq) -> Fq;
+
+    /// Program of the hard part of the final exponentiation, on the cyclotomic f
+    fn hard_part() -> Vec<Op>;
+
+    /// ξ^(e(pᵏ - 1)/6), the coefficient of wᵉ in the Frobenius map of Fq12
+    fn frobenius_coeff(k: usize, e: u32) -> [BigUint; 2] {
+        assert!((1..=3).contains(&k) && (1..=4).contains(&e), "no Frobenius coefficient for k = {}, e = {}", k, e);
+        let p = Self::modulus();
+        fq2_pow(&Self::xi(), &((p.pow(k as u32) - 1u32) / 6u32 * e), &p)
+    }
+
+    fn xi() -> [BigUint; 2] {
+        [BigUint::from(Self::XI_C0), BigUint::one()]
+    }
+
+    /// Coefficient of the twist y² = x³ + b', b/ξ on a D-twist and b·ξ on an M-twist
+    fn g2_b() -> [BigUint; 2] {
+        let (p, b) = (Self::modulus(), [BigUint::from(Self::B), BigUint::zero()]);
+        match Self::TWIST {
+            Twist::D => fq2_mul(&b, &fq2_inv(&Self::xi(), &p), &p),
+            Twist::M => fq2_mul(&b, &Self::xi(), &p),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bn254;
+
+impl Curve for Bn254 {
+    const NAME: &'static str = "bn254";
+    const XI_C0: u64 = 9;
+    const TWIST: Twist = Twist::D;
+    const B: u64 = 3;
+    const LOOP_NEGATIVE: bool = false;
+    const FROBENIUS_NAMES: FrobeniusNames = frobenius_names!("");
+    const G2_B_NAMES: [&'static str; 2] = ["G2_B_C0", "G2_B_C1"];
+
+    fn modulus() -> BigUint {
+        bn254_modulus()
+    }
+
+    fn scalar_modulus() -> BigUint {
+        bn254_scalar_modulus()
+    }
+
+    /// NAF of 6u + 2
+    fn loop_digits() -> Vec<BitType> {
+        ATE_LOOP.to_vec()
+    }
+
+    fn mul_by_xi_c0(c: &Fq) -> Fq {
+        c.scl_9()
+    }
+
+    fn hard_part() -> Vec<Op> {
+        final_exp::hard_part()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bls12_381;
+
+impl Bls12_381 {
+    /// |x| of the curve parameter x = -0xd201000000010000
+    pub const X: u64 = 0xd201000000010000;
+}
+
+impl Curve for Bls12_381 {
+    const NAME: &'static str = "bls12_381";
+    const XI_C0: u64 = 1;
+    const TWIST: Twist = Twist::M;
+    const B: u64 = 4;
+    const LOOP_NEGATIVE: bool = true;
+    const FROBENIUS_NAMES: FrobeniusNames = frobenius_names!("BLS12_381_");
+    const G2_B_NAMES: [&'static str; 2] = ["BLS12_381_G2_B_C0", "BLS12_381_G2_B_C1"];
+
+    fn modulus() -> BigUint {
+        BigUint::from_str_radix(
+            "1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+            16,
+        )
+        .unwrap()
+    }
+
+    fn scalar_modulus() -> BigUint {
+        BigUint::from_str_radix("73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001", 16).unwrap()
+    }
+
+    /// Binary digits of |x|, the loop runs on x so it has no correction step
+    fn loop_digits() -> Vec<BitType> {
+        (0..63).map(|i| if Self::X >> i & 1 == 1 { BitType::P } else { BitType::O }).collect()
+    }
+
+    fn mul_by_xi_c0(c: &Fq) -> Fq {
+        c.clone()
+    }
+
+    fn hard_part() -> Vec<Op> {
+        final_exp::bls12_hard_part()
+    }
+}
+
+// Fq2 = Fq[u]/(u² + 1) on known values, enough to derive the curve constants
+fn fq2_mul(a: &[BigUint; 2], b: &[BigUint; 2], p: &BigUint) -> [BigUint; 2] {
+    let (t0, t1) = (&a[0] * &b[0], &a[1] * &b[1]);
+    [(t0 + p * p - t1) % p, (&a[0] * &b[1] + &a[1] * &b[0]) % p]
+}
+
+fn fq2_pow(a: &[BigUint; 2], exp: &BigUint, p: &BigUint) -> [BigUint; 2] {
+    let mut acc = [BigUint::one(), BigUint::zero()];
+    for i in (0..exp.bits()).rev() {
+        acc = fq2_mul(&acc, &acc, p);
+        if exp.bit(i) {
+            acc = fq2_mul(&acc, a, p);
+        }
+    }
+    acc
+}
+
+// (c0 - c1·u)/(c0² + c1²)
+fn fq2_inv(a: &[BigUint; 2], p: &BigUint) -> [BigUint; 2] {
+    let norm = (&a[0] * &a[0] + &a[1] * &a[1]) % p;
+    let norm_inv = norm.modpow(&(p - 2u32), p);
+    [&a[0] * &norm_inv % p, (p - &a[1] * &norm_inv % p) % p]
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::{BigInt, BigUint};
+
+    use super::{fq2_mul, Bls12_381, Bn254, Curve};
+    use crate::circuit::evaluator::bn254_modulus;
+    use crate::pairing::ate_miller::BitType;
+    use crate::reference::{self, Field};
+
+    fn loop_value<C: Curve>() -> BigInt {
+        let digits = C::loop_digits();
+        digits.iter().enumerate().fold(BigInt::from(1) << digits.len(), |acc, (i, bit)| match bit {
+            BitType::O => acc,
+            BitType::P => acc + (BigInt::from(1) << i),
+            BitType::N => acc - (BigInt::from(1) << i),
+        })
+    }
+
+    #[test]
+    fn test_curve_parameters() {
+        // BN254: p and r are polynomials in u, the loop runs on 6u + 2
+        let u = BigInt::from(4965661367192848881u64);
+        let (p, r) = (BigInt::from(Bn254::modulus()), BigInt::from(Bn254::scalar_modulus()));
+        assert_eq!(p, 36 * u.pow(4) + 36 * u.pow(3) + 24 * u.pow(2) + 6 * &u + 1);
+        assert_eq!(r, 36 * u.pow(4) + 36 * u.pow(3) + 18 * u.pow(2) + 6 * &u + 1);
+        assert_eq!(loop_value::<Bn254>(), 6 * &u + 2);
+
+        // BLS12-381: r = x⁴ - x² + 1 and p = (x - 1)²r/3 + x
+        let x = -BigInt::from(Bls12_381::X);
+        let (p, r) = (BigInt::from(Bls12_381::modulus()), BigInt::from(Bls12_381::scalar_modulus()));
+        assert_eq!(r, x.pow(4) - x.pow(2) + 1);
+        assert_eq!(p, (&x - BigInt::from(1)).pow(2) * &r / 3 + &x);
+        assert_eq!(-loop_value::<Bls12_381>(), x);
+    }
+
+    #[test]
+    fn test_curve_constants() {
+        // Same values as the reference arithmetic for BN254
+        let value = |v: &reference::Fq2| [v.c0.0.clone(), v.c1.0.clone()];
+        assert_eq!(Bn254::g2_b(), value(&reference::G2Affine::b()));
+        let exp = (bn254_modulus() - 1u32) / 6u32 * 2u32;
+        assert_eq!(Bn254::frobenius_coeff(1, 2), value(&reference::Fq2::xi().pow(&exp)));
+
+        // ξ^((p² - 1)/2) = -1 on both curves, the Fq6 coefficients of k = 2 are in Fq
+        for (p, coeff) in [(Bn254::modulus(), Bn254::frobenius_coeff(2, 3)), (Bls12_381::modulus(), Bls12_381::frobenius_coeff(2, 3))] {
+            assert_eq!(coeff, [&p - 1u32, BigUint::from(0u32)]);
+        }
+        assert_eq!(Bls12_381::frobenius_coeff(2, 2)[1], BigUint::from(0u32));
+
+        // 4(1 + u)
+        let p = Bls12_381::modulus();
+        assert_eq!(Bls12_381::g2_b(), fq2_mul(&[4u32.into(), 0u32.into()], &Bls12_381::xi(), &p));
+        assert_eq!(Bls12_381::g2_b(), [4u32.into(), 4u32.into()]);
+    }
+}
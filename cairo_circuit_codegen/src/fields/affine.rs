@@ -1,14 +1,12 @@
-use std::ops::Sub;
-
 use crate::fields::{FieldOps, fq::Fq};
 use crate::fields::fq2::Fq2;
-use crate::fields::ECOperations;
+use crate::fields::{frobenius::mul_coeff, ECOperations, Frobenius};
+use crate::curve::{Bn254, Curve};
 
 #[derive(Debug, Clone)]
 pub struct Affine<F: FieldOps> {
     x: F,
     y: F,
-    inp: Option<[usize; 4]>, // Switch to vec
 }
 
 impl<F: FieldOps> Affine<F> {
@@ -22,31 +20,48 @@ impl<F: FieldOps> Affine<F> {
 }
 
 impl Affine<Fq> {
-    pub fn new(x: Fq, y: Fq, inp: [usize; 2]) -> Self{
-        Self {x, y, inp: Some([inp[0], inp[1], 0, 0])}
+    pub fn new(x: Fq, y: Fq) -> Self {
+        Self { x, y }
     }
 
     pub fn new_input(idx: [usize; 2]) -> Self {
         Self {
             x: Fq::new_input(idx[0]), 
-            y: Fq::new_input(idx[1]), 
-            inp: Some([idx[0], idx[1], 0, 0])
+            y: Fq::new_input(idx[1]),
         }
     }
+
+    /// y² - x³ - 3, zero when the point is on the curve
+    pub fn on_curve(&self) -> Fq {
+        let b = Fq::constant("G1_B", 3u64);
+        &(&self.y.sqr() - &(&self.x.sqr() * &self.x)) - &b
+    }
 }
 
-impl Affine<Fq2> {
-    pub fn new(x: Fq2, y: Fq2, inp: [usize; 4]) -> Self{
-        Self {x, y, inp: Some([inp[0], inp[1], inp[2], inp[3]])}
+impl<C: Curve> Affine<Fq2<C>> {
+    pub fn new(x: Fq2<C>, y: Fq2<C>) -> Self {
+        Self { x, y }
     }
 
     pub fn new_input(idx: [usize; 4]) -> Self {
         Self {
             x: Fq2::new_input([idx[0], idx[1]]), 
-            y: Fq2::new_input([idx[2], idx[3]]), 
-            inp: Some(idx)
+            y: Fq2::new_input([idx[2], idx[3]]),
         }
     }
+
+    /// y² - x³ - b', zero when the point is on the twist
+    pub fn on_curve(&self) -> Fq2<C> {
+        let b = Fq2::constant(C::G2_B_NAMES, C::g2_b());
+        &(&self.y.sqr() - &(&self.x.sqr() * &self.x)) - &b
+    }
+}
+
+// ψᵏ(x, y) = (x^(pᵏ)·ξ^((pᵏ - 1)/3), y^(pᵏ)·ξ^((pᵏ - 1)/2)), untwist-Frobenius-twist on the D-twist
+impl Frobenius for Affine<Fq2<Bn254>> {
+    fn frobenius(&self, k: usize) -> Self {
+        Self { x: mul_coeff(&self.x.frobenius(k), k, 2), y: mul_coeff(&self.y.frobenius(k), k, 3) }
+    }
 }
 
 impl<F: FieldOps> ECOperations<F> for Affine<F> 
@@ -66,7 +81,7 @@ impl<F: FieldOps> ECOperations<F> for Affine<F>
         let x = self.x_on_slope(slope, x2);
         let y = self.y_on_slope(slope, &x);
 
-        Affine {x, y, inp: None}
+        Affine {x, y}
     }
 
     fn chord(&self, rhs: &Self) -> F {
@@ -92,7 +107,7 @@ impl<F: FieldOps> ECOperations<F> for Affine<F>
     }
 
     fn neg(&self) -> Self {
-        Self { x: self.x.clone(), y: self.y.neg(), inp: None }
+        Self { x: self.x.clone(), y: self.y.neg() }
     }
 }
 
@@ -106,8 +121,8 @@ mod test {
         let in1 = &Affine::<Fq2>::new_input([4, 5, 6, 7]);
         
         let out = in0.chord(in1);
-        let out_0 = out.c0().c0().format_circuit();
-        let out_1 = out.c1().c0().format_circuit();
+        let out_0 = out.c0().c0();
+        let out_1 = out.c1().c0();
         let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
         builder.assign_variable("out_0", out_0);
         builder.assign_variable("out_1", out_1);
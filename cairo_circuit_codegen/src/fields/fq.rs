@@ -1,85 +1,78 @@
 use std::ops::{Add, Sub, Mul, Div, Neg};
+use num_bigint::BigUint;
 use crate::circuit::circuit::Circuit;
 
 use super::{FieldConstants, FieldOps};
 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 pub struct Fq {
     c0: Circuit,
-    inp: Option<usize>,
 }
 
 impl Fq {
-    pub fn new(c0: Circuit, inp: Option<usize>) -> Self {
-        Fq {c0, inp }
+    pub fn new(c0: Circuit) -> Self {
+        Fq { c0 }
     }
 
     pub fn new_input(idx: usize) -> Self {
         Self {
-            c0: Circuit::circuit_input(idx), 
-            inp: Some(idx)
+            c0: Circuit::circuit_input(idx),
         } 
     }
 
+    /// A known value, fed to the circuit as the Cairo constant `name`
+    pub fn constant(name: &'static str, value: impl Into<BigUint>) -> Self {
+        Self {
+            c0: Circuit::circuit_constant(name, value.into()),
+        }
+    }
+
     pub fn c0(&self) -> &Circuit {
         &self.c0
     }
 
     pub fn scl_9(&self) -> Self {
-        let two = &(self + self);
-        let four = &(two + two);
-        let eight = &(four + four);
-        eight + self
-    }
-
-    // A scale 9 function without any additions, instead passes 9 in as a circuit input 0
-    // Note: In order to use this function, your circuit inputs must be offset by 1.
-    pub fn scl_9_no_add(&self) -> Self {
-        self * &Self::new_input(0)
+        self * &Self::constant("NINE", 9u64)
     }
 }
 
 impl FieldOps for Fq {
     fn add(&self, rhs: &Self) -> Self {
-        Self { c0: Circuit::circuit_add(&self.c0, &rhs.c0), inp: None }
+        Self { c0: Circuit::circuit_add(&self.c0, &rhs.c0) }
     }
 
     fn sub(&self, rhs: &Self) -> Self {
-        Self { c0: Circuit::circuit_sub(&self.c0, &rhs.c0), inp: None }
+        Self { c0: Circuit::circuit_sub(&self.c0, &rhs.c0) }
     }
 
     fn mul(&self, rhs: &Self) -> Self {
-        Self { c0: Circuit::circuit_mul(&self.c0, &rhs.c0), inp: None }
+        Self { c0: Circuit::circuit_mul(&self.c0, &rhs.c0) }
     }
 
     fn div(&self, rhs: &Self) -> Self {
-        Self { c0: Circuit::circuit_mul(&self.c0, &Circuit::circuit_inv(&rhs.c0)), inp: None }
+        Self { c0: Circuit::circuit_mul(&self.c0, &Circuit::circuit_inv(&rhs.c0)) }
     }
 
     fn sqr(&self) -> Self {
-        Self { c0: Circuit::circuit_mul(&self.c0, &self.c0), inp: None }
+        Self { c0: Circuit::circuit_mul(&self.c0, &self.c0) }
     }
 
     fn neg(&self) -> Self {
-        let tmp = Self::new_input(0); // Guaranteed because valid circuits have atleast 1 input
-        Self { c0: Circuit::circuit_sub(&Circuit::circuit_sub(&tmp.c0, &tmp.c0), &self.c0), inp: None }
+        &Self::zero() - self
     }
 
     fn inv(&self) -> Self {
-        Self { c0: Circuit::circuit_inv(&self.c0), inp: None }
+        Self { c0: Circuit::circuit_inv(&self.c0) }
     }
 }
 
 impl FieldConstants for Fq {
-    // DANGEROUS, ASSUMES C0 IS NON-ZERO
     fn one() -> Self {
-        let c0 = Self::new_input(0); 
-        &c0 / &c0
+        Self::constant("ONE", 1u64)
     }
 
     fn zero() -> Self {
-        let c0 = Self::new_input(0); 
-        &c0 - &c0
+        Self::constant("ZERO", 0u64)
     }
 }
 
@@ -91,7 +84,7 @@ impl Add for Fq {
     }
 }
 
-impl<'a, 'b> Add<&'b Fq> for &'a Fq {
+impl<'b> Add<&'b Fq> for &Fq {
     type Output = Fq;
 
     fn add(self, rhs: &'b Fq) -> Fq {
@@ -107,7 +100,7 @@ impl Sub for Fq {
     }
 }
 
-impl<'a, 'b> Sub<&'b Fq> for &'a Fq {
+impl<'b> Sub<&'b Fq> for &Fq {
     type Output = Fq;
 
     fn sub(self, rhs: &'b Fq) -> Fq {
@@ -123,7 +116,7 @@ impl Mul for Fq {
     }
 }
 
-impl<'a, 'b> Mul<&'b Fq> for &'a Fq {
+impl<'b> Mul<&'b Fq> for &Fq {
     type Output = Fq;
 
     fn mul(self, rhs: &'b Fq) -> Fq {
@@ -139,7 +132,7 @@ impl Div for Fq {
     }
 }
 
-impl<'a, 'b> Div<&'b Fq> for &'a Fq {
+impl<'b> Div<&'b Fq> for &Fq {
     type Output = Fq;
 
     fn div(self, rhs: &'b Fq) -> Fq {
@@ -155,7 +148,7 @@ impl Neg for Fq {
     }
 }
 
-impl<'a> Neg for &'a Fq {
+impl Neg for &Fq {
     type Output = Fq;
 
     fn neg(self) -> Fq {
@@ -173,9 +166,9 @@ mod test {
         let in0 = Fq::new_input(0);
         let in1 = Fq::new_input(1); 
 
-        let out = (in0 + in1).c0().format_circuit();
+        let out = in0 + in1;
         let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
-        builder.assign_variable("out", out);
+        builder.assign_variable("out", out.c0());
         
         let code = builder.build();
         write_stdout("out.cairo", code);
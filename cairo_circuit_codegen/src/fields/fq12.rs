@@ -1,56 +1,71 @@
-use super::{fq::Fq, fq2::Fq2, fq6::Fq6, sparse::{Fq12Sparse01234, Fq12Sparse034, Fq6Sparse01}, FieldConstants, FieldOps};
+use super::{fq::Fq, fq2::Fq2, fq6::Fq6, frobenius::mul_coeff, sparse::{Fq12Sparse01234, Fq12Sparse014, Fq12Sparse034, Fq12Sparse034Scaled, Fq6Sparse01}, FieldConstants, FieldOps, Frobenius};
+use crate::curve::{Bn254, Curve};
 
-#[derive(Clone, Debug, Default)]
-pub struct Fq12 {
-    c0: Fq6,
-    c1: Fq6,
-    inp: Option<[usize; 12]>,
+
+#[derive(Clone, Debug)]
+pub struct Fq12<C: Curve = Bn254> {
+    c0: Fq6<C>,
+    c1: Fq6<C>,
 }
 
-impl Fq12 {
-    pub fn new(c0: Fq6, c1: Fq6, inp: Option<[usize; 12]>) -> Self {
-        Self { c0, c1, inp }
+impl<C: Curve> Fq12<C> {
+    pub fn new(c0: Fq6<C>, c1: Fq6<C>) -> Self {
+        Self { c0, c1 }
     }
 
     pub fn new_input(idx: [usize; 12]) -> Self {
         Self {
             c0: Fq6::new_input(idx[0..6].try_into().unwrap()), 
-            c1: Fq6::new_input(idx[6..12].try_into().unwrap()), 
-            inp: Some(idx)
+            c1: Fq6::new_input(idx[6..12].try_into().unwrap()),
         }
     }
 
-    pub fn c0(&self) -> &Fq6 {
+    pub fn c0(&self) -> &Fq6<C> {
         &self.c0
     }
 
-    pub fn c1(&self) -> &Fq6 {
+    pub fn c1(&self) -> &Fq6<C> {
         &self.c1
     }
 
-    pub fn mul_034(&self, rhs: &Fq12Sparse034) -> Fq12 {
+    pub fn mul_034(&self, rhs: &Fq12Sparse034<C>) -> Fq12<C> {
         let (a0, a1) = (self.c0(), self.c1());
         let (c3, c4) = (rhs.c3(), rhs.c4());
 
         let b = a1.mul_01(&Fq6Sparse01::new(c3.clone(), c4.clone())); // todo: remove clone
         
-        // Circuit div(x/x) = 1 // assumes circuit input is non-zero
+        // c3 + 1, the sparse element has an implicit one in c0
         let tmp = c3.c0() + &Fq::one();
-        let c3 = Fq2::new(tmp, c3.c1().clone(), None);
+        let c3 = Fq2::new(tmp, c3.c1().clone());
         let d = a0 + a1;
         let d = d.mul_01(&Fq6Sparse01::new(c3, c4.clone()));
 
-        let c1 = d - (&b + &a0);
+        let c1 = d - (&b + a0);
         let c0 = &b.mul_by_v() + a0;
 
-        Fq12 { c0, c1, inp: None }
+        Fq12 { c0, c1 }
+    }
+
+    /// Same Karatsuba product as `mul_034` with c0 read instead of one
+    pub fn mul_034_scaled(&self, rhs: &Fq12Sparse034Scaled<C>) -> Fq12<C> {
+        let (a0, a1) = (self.c0(), self.c1());
+        let (c0, c3, c4) = (rhs.c0(), rhs.c3(), rhs.c4());
+
+        let b = a1.mul_01(&Fq6Sparse01::new(c3.clone(), c4.clone()));
+        let a = Fq6::new(a0.c0() * c0, a0.c1() * c0, a0.c2() * c0);
+        let d = (a0 + a1).mul_01(&Fq6Sparse01::new(c0 + c3, c4.clone()));
+
+        let c1 = d - (&b + &a);
+        let c0 = &b.mul_by_v() + &a;
+
+        Fq12 { c0, c1 }
     }
 
-    pub fn mul_01234(&self, rhs: Fq12Sparse01234) -> Self {
+    pub fn mul_01234(&self, rhs: Fq12Sparse01234<C>) -> Self {
         let (a0, a1) = (self.c0(), self.c1());
         let (b0, b1) = (rhs.c0(), rhs.c1());
 
-        let b = Fq6::new(b0.c0() + b1.c0(), b0.c1() + b1.c1(), b0.c2().clone(), None);
+        let b = Fq6::new(b0.c0() + b1.c0(), b0.c1() + b1.c1(), b0.c2().clone());
         let c1 = (a0 + a1) * b;
 
         let u = a0 * b0;
@@ -59,17 +74,68 @@ impl Fq12 {
         let c0 = &v.mul_by_v() + &u;
         let c1 = c1 - (u + v);
 
-        Self { c0, c1, inp: None }
+        Self { c0, c1 }
+    }
+
+    /// f·(a + v·w) = f₀·a + f₁·v² + (f₀·v + f₁·a)·w, an M-twist line needs no Karatsuba as v is free
+    pub fn mul_014(&self, rhs: &Fq12Sparse014<C>) -> Self {
+        let (a0, a1) = (self.c0(), self.c1());
+        let a = Fq6Sparse01::new(rhs.c0().clone(), rhs.c1().clone());
+
+        let c0 = &a0.mul_01(&a) + &a1.mul_by_v().mul_by_v();
+        let c1 = &a0.mul_by_v() + &a1.mul_01(&a);
+
+        Self { c0, c1 }
+    }
+
+    /// f^(p⁶), the inverse of a unitary element
+    pub fn conjugate(&self) -> Self {
+        Self { c0: self.c0.clone(), c1: -&self.c1 }
+    }
+
+    /// Granger-Scott squaring, only valid in the cyclotomic subgroup, i.e. after the easy part of
+    /// the final exponentiation
+    pub fn cyclotomic_sqr(&self) -> Self {
+        let (c0, c1) = (self.c0(), self.c1());
+        let (z0, z4, z3) = (c0.c0(), c0.c1(), c0.c2());
+        let (z2, z1, z5) = (c1.c0(), c1.c1(), c1.c2());
+
+        // (a + b·w³)² in Fq4 = Fq2[w³], w⁶ = ξ
+        let fp4_sqr = |a: &Fq2<C>, b: &Fq2<C>| {
+            let (t0, t1) = (a.sqr(), b.sqr());
+            (&t1.mul_by_xi() + &t0, &(&(a + b).sqr() - &t0) - &t1)
+        };
+        // 3t - 2z and 3t + 2z
+        let sub = |t: &Fq2<C>, z: &Fq2<C>| &(&(t - z) + &(t - z)) + t;
+        let add = |t: &Fq2<C>, z: &Fq2<C>| &(&(t + z) + &(t + z)) + t;
+
+        let (t0, t1) = fp4_sqr(z0, z1);
+        let (t2, t3) = fp4_sqr(z2, z3);
+        let (t4, t5) = fp4_sqr(z4, z5);
+        let t5 = t5.mul_by_xi();
+
+        Self {
+            c0: Fq6::new(sub(&t0, z0), sub(&t2, z4), sub(&t4, z3)),
+            c1: Fq6::new(add(&t5, z2), add(&t1, z1), add(&t3, z5)),
+        }
+    }
+}
+
+impl<C: Curve> Frobenius for Fq12<C> {
+    fn frobenius(&self, k: usize) -> Self {
+        let c1 = self.c1.frobenius(k);
+        let w = |x: &Fq2<C>| mul_coeff(x, k, 1);
+        Self { c0: self.c0.frobenius(k), c1: Fq6::new(w(c1.c0()), w(c1.c1()), w(c1.c2())) }
     }
 }
 
-impl FieldOps for Fq12 {
+impl<C: Curve> FieldOps for Fq12<C> {
     fn add(&self, rhs: &Self) -> Self {
-        Self { c0: &self.c0 + &rhs.c0, c1: &self.c1 + &rhs.c1, inp: None }
+        Self { c0: &self.c0 + &rhs.c0, c1: &self.c1 + &rhs.c1 }
     }
 
     fn sub(&self, rhs: &Self) -> Self {
-        Self { c0: &self.c0 - &rhs.c0, c1: &self.c1 - &rhs.c1, inp: None }
+        Self { c0: &self.c0 - &rhs.c0, c1: &self.c1 - &rhs.c1 }
     }
 
     fn mul(&self, rhs: &Self) -> Self {
@@ -81,7 +147,7 @@ impl FieldOps for Fq12 {
         let c0 = &v.mul_by_v() + &u;
         let c1 = (a0 + a1) * (b0 + b1) - u - v;
 
-        Self { c0, c1, inp: None }
+        Self { c0, c1 }
     }
 
     fn div(&self, rhs: &Self) -> Self {
@@ -94,40 +160,34 @@ impl FieldOps for Fq12 {
         let c0 = &(&((a0 + a1) * (a0 + &a1.mul_by_v())) - &v) - &v.mul_by_v();
         let c1 = &v + &v;
 
-        Fq12 { c0, c1, inp: None }
+        Fq12 { c0, c1 }
     }
     
     fn neg(&self,) -> Self {
-        Self { c0: -&self.c0, c1: -&self.c1, inp: None }
+        Self { c0: -&self.c0, c1: -&self.c1 }
     }
 
     fn inv(&self,) -> Self {
         let t = (&self.c0().sqr() - &self.c1().sqr().mul_by_v()).inv();
 
-        Self { c0: self.c0() * &t, c1: -(self.c1() * &t), inp: None }
+        Self { c0: self.c0() * &t, c1: -(self.c1() * &t) }
     }
 }
 
-// Modified mul_by_vi function where circuitinput[0] = 9
-pub fn sqr_offset(lhs: &Fq12) -> Fq12 {
-    let (a0, a1) = (lhs.c0(), lhs.c1()); 
-    let v = a0 * a1;
-    let c0 = &(&((a0 + a1) * (a0 + &a1.mul_by_v_offset())) - &v) - &v.mul_by_v_offset();
-    let c1 = &v + &v;
-
-    Fq12 { c0, c1, inp: None }
-}
-
 #[cfg(test)]
 mod test {
-    use super::{Fq12, Fq2};
+    use super::Fq12;
+    use crate::curve::Bn254;
     use crate::{circuit::builder::CairoCodeBuilder, fields::FieldOps, utils::utils::write_stdout}; 
+    use crate::reference::Field;
+    use crate::utils::utils::{eval, fq12_input, random_cyclotomic, rng, ROUNDS};
+
     #[test]
     pub fn test_fq12() {
         let idx_0: [usize; 12] = (0..=11).collect::<Vec<usize>>().try_into().unwrap();
         let idx_1: [usize; 12] = (12..=23).collect::<Vec<usize>>().try_into().unwrap();
         
-        let in0 = &Fq12::new_input(idx_0);
+        let in0 = &Fq12::<Bn254>::new_input(idx_0);
         let in1 = &Fq12::new_input(idx_1); 
         
         let out = Fq12::mul(in0, in1);
@@ -143,7 +203,7 @@ mod test {
     pub fn test_fq12_sqr() {
         let idx_0: [usize; 12] = (0..=11).collect::<Vec<usize>>().try_into().unwrap();
         
-        let in0 = &Fq12::new_input(idx_0);
+        let in0 = &Fq12::<Bn254>::new_input(idx_0);
         let out = Fq12::sqr(in0);
 
         let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
@@ -152,4 +212,15 @@ mod test {
         let code = builder.build();
         write_stdout("out.cairo", code);
     }
+
+    #[test]
+    fn test_cyclotomic_sqr() {
+        let mut rng = rng();
+        let (sqr, x) = (fq12_input(0).cyclotomic_sqr(), fq12_input(0));
+        for _ in 0..ROUNDS {
+            let c = random_cyclotomic(&mut rng);
+            assert_eq!(eval(&sqr, &[c.values()]), c.sqr().values());
+            assert_eq!(eval(&x.conjugate(), &[c.values()]), c.inv().values());
+        }
+    }
 }
@@ -1,17 +1,17 @@
-use super::{fq::Fq, fq2::Fq2, fq6::Fq6, sparse::{Fq12Sparse01234, Fq12Sparse034, Fq6Sparse01}, FieldConstants, FieldOps, FieldUtils};
-
-#[derive(Clone, Debug, Default)]
-pub struct Krbn2345 {
-    g2: Fq2,
-    g3: Fq2,
-    g4: Fq2,
-    g5: Fq2,
-    inp: Option<[usize; 8]>,
+use super::{fq2::Fq2, FieldOps};
+use crate::curve::{Bn254, Curve};
+
+#[derive(Clone, Debug)]
+pub struct Krbn2345<C: Curve = Bn254> {
+    g2: Fq2<C>,
+    g3: Fq2<C>,
+    g4: Fq2<C>,
+    g5: Fq2<C>,
 }
 
-impl Krbn2345 {
-    pub fn new(g2: Fq2, g3: Fq2, g4: Fq2, g5: Fq2, inp: Option<[usize; 8]>) -> Self {
-        Self { g2, g3, g4, g5, inp }
+impl<C: Curve> Krbn2345<C> {
+    pub fn new(g2: Fq2<C>, g3: Fq2<C>, g4: Fq2<C>, g5: Fq2<C>) -> Self {
+        Self { g2, g3, g4, g5 }
     }
 
     pub fn new_input(idx: [usize; 8]) -> Self {
@@ -20,23 +20,22 @@ impl Krbn2345 {
             g3: Fq2::new_input([idx[2], idx[3]]), 
             g4: Fq2::new_input([idx[4], idx[5]]),
             g5: Fq2::new_input([idx[6], idx[7]]),
-            inp: Some(idx)
         }
     }
 
-    pub fn g2(&self) -> &Fq2 {
+    pub fn g2(&self) -> &Fq2<C> {
         &self.g2
     }
 
-    pub fn g3(&self) -> &Fq2 {
+    pub fn g3(&self) -> &Fq2<C> {
         &self.g3
     }
 
-    pub fn g4(&self) -> &Fq2 {
+    pub fn g4(&self) -> &Fq2<C> {
         &self.g4
     }
 
-    pub fn g5(&self) -> &Fq2 {
+    pub fn g5(&self) -> &Fq2<C> {
         &self.g5
     }
 
@@ -45,37 +44,37 @@ impl Krbn2345 {
 
         let (g2, g3, g4, g5)= (self.g2(), self.g3(), self.g4(), self.g5());
 
-        let S2 = g2.sqr();
-        let S3 = g3.sqr();
-        let S4 = g4.sqr();
-        let S5 = g5.sqr();
-        let S4_5 = g4.add(g5).sqr();
-        let S2_3 = g2.add(g3).sqr();
+        let s2 = g2.sqr();
+        let s3 = g3.sqr();
+        let s4 = g4.sqr();
+        let s5 = g5.sqr();
+        let s4_5 = g4.add(g5).sqr();
+        let s2_3 = g2.add(g3).sqr();
 
-        let Tmp = S4_5.sub(&S4.add(&S5)).mul_by_xi();
-        let h2 = Tmp.add(g2);
-        let h2 = &h2 + &h2 + Tmp;
+        let tmp = s4_5.sub(&s4.add(&s5)).mul_by_xi();
+        let h2 = tmp.add(g2);
+        let h2 = &h2 + &h2 + tmp;
 
-        let Tmp = S4.add(&S5.mul_by_xi());
-        let h3 = Tmp.sub(g3);
-        let h3 = &h3 + &h3 + Tmp;
+        let tmp = s4.add(&s5.mul_by_xi());
+        let h3 = tmp.sub(g3);
+        let h3 = &h3 + &h3 + tmp;
 
-        let Tmp = S2.add(&S3.mul_by_xi());
-        let h4 = Tmp.sub(g4);
-        let h4 = &h4 + &h4 + Tmp;
+        let tmp = s2.add(&s3.mul_by_xi());
+        let h4 = tmp.sub(g4);
+        let h4 = &h4 + &h4 + tmp;
 
-        let Tmp = S2_3.sub(&S2).sub(&S3);
-        let h5 = Tmp.add(g5);
-        let h5 = &h5 + &h5 + Tmp;
+        let tmp = s2_3.sub(&s2).sub(&s3);
+        let h5 = tmp.add(g5);
+        let h5 = &h5 + &h5 + tmp;
 
-        Self { g2: h2, g3: h3, g4: h4, g5: h5, inp: None }
+        Self { g2: h2, g3: h3, g4: h4, g5: h5 }
     }
 
     // Decompress krbn into fq12 except final g0 add 1 (Fq12 { c0: Fq6 { c0: g0, c1: g4, c2: g3 }, c1: Fq6 { c0: g2, c1: g1, c2: g5 } })
     // Note:
     // 1. Does not use g2, offset all circuit inputs by fq2
     // 2. Scale is replaced by addition circuit
-    pub fn krbn_decompress_if_zero(&self) -> (Fq2, Fq2) {
+    pub fn krbn_decompress_if_zero(&self) -> (Fq2<C>, Fq2<C>) {
         let (g3, g4, g5)= (self.g2(), self.g3(), self.g4());
 
         let g4mg5 = g4.mul(g5);
@@ -83,11 +82,11 @@ impl Krbn2345 {
         let g1 = tg24g5.mul(&g3.inv());
 
         // g0 = (2S1 - 3g3g4)ξ + 1
-        let S1 = g1.sqr();
-        let T_g3g4 = g3.mul(g4);
-        let Tmp = (S1.sub(&T_g3g4));
-        let Tmp = &Tmp + &Tmp; //.scale(TWO);
-        let g0 = Tmp.sub(&T_g3g4.mul_by_xi());
+        let s1 = g1.sqr();
+        let t_g3g4 = g3.mul(g4);
+        let tmp = s1.sub(&t_g3g4) ;
+        let tmp = &tmp + &tmp; //.scale(TWO);
+        let g0 = tmp.sub(&t_g3g4).mul_by_xi();
 
         (g0, g1)
     }
@@ -95,23 +94,23 @@ impl Krbn2345 {
 
     // Note:
     // 1. Scale is replaced by addition circuit
-    pub fn krbn_decompress_else(&self) -> (Fq2, Fq2) {
+    pub fn krbn_decompress_else(&self) -> (Fq2<C>, Fq2<C>) {
         let (g2, g3, g4, g5)= (self.g2(), self.g3(), self.g4(), self.g5());
 
-        let S5xi = g5.sqr().mul_by_xi();
-        let S4 = g4.sqr();
-        let Tmp = S4.sub(g3);
-        let g1 = S5xi.add(&S4.add(&Tmp.add(&Tmp)));
+        let s5xi = g5.sqr().mul_by_xi();
+        let s4 = g4.sqr();
+        let tmp = s4.sub(g3);
+        let g1 = s5xi.add(&s4.add(&tmp.add(&tmp)));
         let x4g2 = &(&(g2 + g2) + g2) + g2;
         let g1 = g1.mul(&x4g2.inv());
 
         // // g0 = (2S1 + g2g5 - 3g3g4)ξ + 1
-        let S1 = g1.sqr();
-        let T_g3g4 = g3.mul(g4);
-        let T_g2g5 = g2.mul(g5);
-        let s1sg3g4 = S1.sub(&T_g3g4);
-        let Tmp = &s1sg3g4 + &s1sg3g4;
-        let g0 = Tmp.add(&T_g2g5.sub(&T_g3g4)).mul_by_xi();
+        let s1 = g1.sqr();
+        let t_g3g4 = g3.mul(g4);
+        let t_g2g5 = g2.mul(g5);
+        let s1sg3g4 = s1.sub(&t_g3g4);
+        let tmp = &s1sg3g4 + &s1sg3g4;
+        let g0 = tmp.add(&t_g2g5.sub(&t_g3g4)).mul_by_xi();
 
         (g0, g1)
     }
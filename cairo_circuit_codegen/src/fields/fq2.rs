@@ -1,24 +1,30 @@
+use std::marker::PhantomData;
 use std::ops::{Add, Sub, Mul, Div, Neg};
-use super::{fq::Fq, FieldOps, FieldUtils};
+use num_bigint::BigUint;
+use super::{fq::Fq, FieldOps, FieldUtils, Frobenius};
+use crate::curve::{Bn254, Curve};
 
-#[derive(Clone, Debug, Default)]
-pub struct Fq2 {
+/// Fq[u]/(u² + 1), `C` decides the non-residue ξ of the rest of the tower
+#[derive(Clone, Debug)]
+pub struct Fq2<C: Curve = Bn254> {
     c0: Fq,
     c1: Fq,
-    inp: Option<[usize; 2]>, 
+    curve: PhantomData<C>,
 }
 
-impl Fq2 {
-    pub fn new(c0: Fq, c1: Fq, inp: Option<[usize; 2]>) -> Self {
-        Fq2 {c0, c1, inp }
+impl<C: Curve> Fq2<C> {
+    pub fn new(c0: Fq, c1: Fq) -> Self {
+        Fq2 { c0, c1, curve: PhantomData }
     }
 
     pub fn new_input(idx: [usize; 2]) -> Self {
-        Self {
-            c0: Fq::new_input(idx[0]), 
-            c1: Fq::new_input(idx[1]), 
-            inp: Some(idx)
-        }
+        Self::new(Fq::new_input(idx[0]), Fq::new_input(idx[1]))
+    }
+
+    /// A known value, its components are fed to the circuit as the Cairo constants `names`
+    pub fn constant(names: [&'static str; 2], value: [BigUint; 2]) -> Self {
+        let [c0, c1] = value;
+        Self::new(Fq::constant(names[0], c0), Fq::constant(names[1], c1))
     }
 
     pub fn c0(&self) -> &Fq {
@@ -29,46 +35,40 @@ impl Fq2 {
         &self.c1
     }
     
-    // Todo: Optimize by passing in 9 as circuit input and remove scaling 9 function
+    /// ξ·(c0 + c1·u) with ξ = XI_C0 + u
     pub fn mul_by_xi(&self) -> Self {
-        Self { c0: &self.c0().scl_9() - &self.c1(), c1: &self.c1().scl_9() + &self.c0(), inp: None }
+        Self::new(&C::mul_by_xi_c0(self.c0()) - self.c1(), &C::mul_by_xi_c0(self.c1()) + self.c0())
     }
 
-    // Modified mul_by_xi function where circuitinput[0] = 9
-    pub fn mul_by_xi_offset(&self) -> Self {
-        Self { c0: &self.c0().scl_9_no_add() - &self.c1(), c1: &self.c1().scl_9_no_add() + &self.c0(), inp: None }
-    }
-
-    // For πₚ frobeneus map
-    pub fn fq2_mul_nr(&self, inp: [usize; 2]) -> Self {
-        self * &Fq2::new_input(inp)
-    }
-
-    // For πₚ² frobeneus map
-    pub fn fq2_scale_nr(&self, inp: usize) -> Self {
-        self.scale(&Fq::new_input(inp))
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0().clone(), -self.c1())
     }
+}
 
-    pub fn conjugate(&self) -> Self {
-        Self { c0: self.c0().clone(), c1: -self.c1(), inp: None }
+impl<C: Curve> Frobenius for Fq2<C> {
+    fn frobenius(&self, k: usize) -> Self {
+        match k % 2 {
+            0 => self.clone(),
+            _ => self.conjugate(),
+        }
     }
 }
 
-impl FieldUtils for Fq2 {
+impl<C: Curve> FieldUtils for Fq2<C> {
     type FieldChild = Fq; 
 
     fn scale(&self, by: &Self::FieldChild) -> Self {
-        Self {c0: self.c0() * by, c1: self.c1() * by, inp: None }
+        Self::new(self.c0() * by, self.c1() * by)
     }
 }
 
-impl FieldOps for Fq2 {
+impl<C: Curve> FieldOps for Fq2<C> {
     fn add(&self, rhs: &Self) -> Self {
-        Self { c0: &self.c0 + &rhs.c0, c1: &self.c1 + &rhs.c1, inp: None }
+        Self::new(&self.c0 + &rhs.c0, &self.c1 + &rhs.c1)
     }
 
     fn sub(&self, rhs: &Self) -> Self {
-        Self { c0: &self.c0 - &rhs.c0, c1: &self.c1 - &rhs.c1, inp: None }
+        Self::new(&self.c0 - &rhs.c0, &self.c1 - &rhs.c1)
     }
 
     fn mul(&self, rhs: &Self) -> Self {
@@ -81,7 +81,7 @@ impl FieldOps for Fq2 {
         let t3 = t2 -&(t0 + t1);
         let t4 = t0 - t1;
 
-        Self { c0: t4, c1: t3, inp: None }
+        Self::new(t4, t3)
     }
 
     fn div(&self, rhs: &Self) -> Self {
@@ -96,97 +96,97 @@ impl FieldOps for Fq2 {
         let t0 = (a0 + a1) * (a0 - a1);
         let t1 = &(a0 + a0) * a1;
 
-        Self { c0: t0, c1: t1, inp: None }
+        Self::new(t0, t1)
 
     }
     
     fn neg(&self,) -> Self {
-        Self { c0: -&self.c0, c1: -&self.c1, inp: None }
+        Self::new(-&self.c0, -&self.c1)
     }
 
     fn inv(&self) -> Self {
         let t = &(&self.c0.sqr() + &self.c1.sqr()).inv();
-        Self { c0: &self.c0 * t, c1: &self.c1 * &(-t), inp: None }
+        Self::new(&self.c0 * t, &self.c1 * &(-t))
     }
     
 }
 
-impl Add for Fq2 {
-    type Output = Fq2;
+impl<C: Curve> Add for Fq2<C> {
+    type Output = Fq2<C>;
 
     fn add(self, rhs: Self) -> Self::Output {
         FieldOps::add(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Add<&'b Fq2> for &'a Fq2 {
-    type Output = Fq2;
+impl<'b, C: Curve> Add<&'b Fq2<C>> for &Fq2<C> {
+    type Output = Fq2<C>;
 
-    fn add(self, rhs: &'b Fq2) -> Fq2 {
+    fn add(self, rhs: &'b Fq2<C>) -> Fq2<C> {
         FieldOps::add(self, rhs)
     }
 }
 
-impl Sub for Fq2 {
-    type Output = Fq2;
+impl<C: Curve> Sub for Fq2<C> {
+    type Output = Fq2<C>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         FieldOps::sub(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Sub<&'b Fq2> for &'a Fq2 {
-    type Output = Fq2;
+impl<'b, C: Curve> Sub<&'b Fq2<C>> for &Fq2<C> {
+    type Output = Fq2<C>;
 
-    fn sub(self, rhs: &'b Fq2) -> Fq2 {
+    fn sub(self, rhs: &'b Fq2<C>) -> Fq2<C> {
         FieldOps::sub(self, rhs)
     }
 }
 
-impl Mul for Fq2 {
-    type Output = Fq2;
+impl<C: Curve> Mul for Fq2<C> {
+    type Output = Fq2<C>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         FieldOps::mul(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Mul<&'b Fq2> for &'a Fq2 {
-    type Output = Fq2;
+impl<'b, C: Curve> Mul<&'b Fq2<C>> for &Fq2<C> {
+    type Output = Fq2<C>;
 
-    fn mul(self, rhs: &'b Fq2) -> Fq2 {
+    fn mul(self, rhs: &'b Fq2<C>) -> Fq2<C> {
         FieldOps::mul(self, rhs)
     }
 }
 
-impl Div for Fq2 {
-    type Output = Fq2;
+impl<C: Curve> Div for Fq2<C> {
+    type Output = Fq2<C>;
 
     fn div(self, rhs: Self) -> Self::Output {
         FieldOps::div(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Div<&'b Fq2> for &'a Fq2 {
-    type Output = Fq2;
+impl<'b, C: Curve> Div<&'b Fq2<C>> for &Fq2<C> {
+    type Output = Fq2<C>;
 
-    fn div(self, rhs: &'b Fq2) -> Fq2 {
+    fn div(self, rhs: &'b Fq2<C>) -> Fq2<C> {
         FieldOps::div(self, rhs)
     }
 }
 
-impl Neg for Fq2 {
-    type Output = Fq2;
+impl<C: Curve> Neg for Fq2<C> {
+    type Output = Fq2<C>;
 
     fn neg(self) -> Self::Output {
         FieldOps::neg(&self)
     }
 }
 
-impl<'a> Neg for &'a Fq2 {
-    type Output = Fq2;
+impl<C: Curve> Neg for &Fq2<C> {
+    type Output = Fq2<C>;
 
-    fn neg(self) -> Fq2 {
+    fn neg(self) -> Fq2<C> {
         FieldOps::neg(self)
     }
 }
@@ -194,15 +194,16 @@ impl<'a> Neg for &'a Fq2 {
 #[cfg(test)]
 mod test {
     use super::Fq2;
+    use crate::curve::Bn254;
     use crate::{circuit::builder::CairoCodeBuilder, utils::utils::write_stdout}; 
     #[test]
     pub fn test_fq2() {
-        let in0 = &Fq2::new_input([0, 1]);
+        let in0 = &Fq2::<Bn254>::new_input([0, 1]);
         let in1 = &Fq2::new_input([2, 3]); 
         
-        let mut out = in0 * in1;
-        let out_0 = out.c0().c0().format_circuit();
-        let out_1 = out.c1().c0().format_circuit();
+        let out = in0 * in1;
+        let out_0 = out.c0().c0();
+        let out_1 = out.c1().c0();
         let mut builder: CairoCodeBuilder = CairoCodeBuilder::new();
         builder.assign_variable("out_0", out_0);
         builder.assign_variable("out_1", out_1);
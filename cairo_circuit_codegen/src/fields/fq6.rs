@@ -1,18 +1,18 @@
 use std::ops::{Add, Sub, Mul, Div, Neg};
-use super::{fq::Fq, fq2::Fq2, FieldOps};
+use super::{fq2::Fq2, frobenius::mul_coeff, FieldOps, Frobenius};
 use super::sparse::Fq6Sparse01;
+use crate::curve::{Bn254, Curve};
 
-#[derive(Clone, Debug, Default)]
-pub struct Fq6 {
-    c0: Fq2,
-    c1: Fq2,
-    c2: Fq2,
-    inp: Option<[usize; 6]>,
+#[derive(Clone, Debug)]
+pub struct Fq6<C: Curve = Bn254> {
+    c0: Fq2<C>,
+    c1: Fq2<C>,
+    c2: Fq2<C>,
 }
 
-impl Fq6 {
-    pub fn new(c0: Fq2, c1: Fq2, c2: Fq2, inp: Option<[usize; 6]>) -> Self {
-        Self { c0, c1, c2, inp }
+impl<C: Curve> Fq6<C> {
+    pub fn new(c0: Fq2<C>, c1: Fq2<C>, c2: Fq2<C>) -> Self {
+        Self { c0, c1, c2 }
     }
 
     pub fn new_input(idx: [usize; 6]) -> Self {
@@ -20,23 +20,22 @@ impl Fq6 {
             c0: Fq2::new_input([idx[0], idx[1]]), 
             c1: Fq2::new_input([idx[2], idx[3]]), 
             c2: Fq2::new_input([idx[4], idx[5]]),
-            inp: Some(idx)
         }
     }
 
-    pub fn c0(&self) -> &Fq2 {
+    pub fn c0(&self) -> &Fq2<C> {
         &self.c0
     }
 
-    pub fn c1(&self) -> &Fq2 {
+    pub fn c1(&self) -> &Fq2<C> {
         &self.c1
     }
 
-    pub fn c2(&self) -> &Fq2 {
+    pub fn c2(&self) -> &Fq2<C> {
         &self.c2
     }
 
-    pub fn mul_01(&self, rhs: &Fq6Sparse01) -> Fq6 {
+    pub fn mul_01(&self, rhs: &Fq6Sparse01<C>) -> Fq6<C> {
         let (a0, a1, a2) = (self.c0(), self.c1(), self.c2());
         let (b0, b1) = (rhs.c0(), rhs.c1());
 
@@ -46,26 +45,31 @@ impl Fq6 {
         let c1 = &(&((a0 + a1) * (b0 + b1)) - &v0) - &v1;
         let c2 = (a2 * b0) + v1; 
 
-        Self { c0, c1, c2, inp: None}
+        Self { c0, c1, c2}
     }
 
     pub fn mul_by_v(&self) -> Self {
-        Self { c0: self.c2().mul_by_xi(), c1: self.c0().clone(), c2: self.c1().clone(), inp: None } // todo: remove clone
+        Self { c0: self.c2().mul_by_xi(), c1: self.c0().clone(), c2: self.c1().clone() } // todo: remove clone
     }
+}
 
-    // Modified mul_by_vi function where circuitinput[0] = 9
-    pub fn mul_by_v_offset(&self) -> Self {
-        Self { c0: self.c2().mul_by_xi_offset(), c1: self.c0().clone(), c2: self.c1().clone(), inp: None } 
+impl<C: Curve> Frobenius for Fq6<C> {
+    fn frobenius(&self, k: usize) -> Self {
+        Self {
+            c0: self.c0.frobenius(k),
+            c1: mul_coeff(&self.c1.frobenius(k), k, 2),
+            c2: mul_coeff(&self.c2.frobenius(k), k, 4),
+        }
     }
 }
 
-impl FieldOps for Fq6 {
+impl<C: Curve> FieldOps for Fq6<C> {
     fn add(&self, rhs: &Self) -> Self {
-        Self { c0: &self.c0 + &rhs.c0, c1: &self.c1 + &rhs.c1, c2: &self.c2 + &rhs.c2, inp: None }
+        Self { c0: &self.c0 + &rhs.c0, c1: &self.c1 + &rhs.c1, c2: &self.c2 + &rhs.c2 }
     }
 
     fn sub(&self, rhs: &Self) -> Self {
-        Self { c0: &self.c0 - &rhs.c0, c1: &self.c1 - &rhs.c1, c2: &self.c2 - &rhs.c2, inp: None }
+        Self { c0: &self.c0 - &rhs.c0, c1: &self.c1 - &rhs.c1, c2: &self.c2 - &rhs.c2 }
     }
 
     // Todo: Clean code by using helper function for consecutive operations
@@ -79,7 +83,7 @@ impl FieldOps for Fq6 {
         let c1 = &(&((a0 + a1) * (b0 + b1)) - v0) - v1 + v2.mul_by_xi();
         let c2 = &(&(&((a0 + a2) * (b0 + b2)) - v0) + v1) - v2;
 
-        Self {c0, c1, c2, inp: None }
+        Self {c0, c1, c2 }
     }
 
     fn sqr(&self,) -> Self {
@@ -97,11 +101,11 @@ impl FieldOps for Fq6 {
         let c1 = &s1 + &s4.mul_by_xi();
         let c2 = s1 + s2 + s3 - s0 - s4;
 
-        Self {c0, c1, c2, inp: None } 
+        Self {c0, c1, c2 } 
     }
 
     fn neg(&self,) -> Self {
-        Self { c0: -&self.c0, c1: -&self.c1, c2: -&self.c2, inp: None }
+        Self { c0: -&self.c0, c1: -&self.c1, c2: -&self.c2 }
     }
 
 
@@ -113,7 +117,7 @@ impl FieldOps for Fq6 {
         let v2 = c1.sqr() - (c0 * c2);
         let t = (((c2 * &v1) + (c1 * &v2)).mul_by_xi() + (c0 * &v0)).inv();
 
-        Self {c0: &v0 * &t, c1: &v1 * &t, c2: &v2 * &t, inp: None } 
+        Self {c0: &v0 * &t, c1: &v1 * &t, c2: &v2 * &t } 
     }
     
     fn div(&self, rhs: &Self) -> Self {
@@ -122,82 +126,82 @@ impl FieldOps for Fq6 {
 }
 
 
-impl Add for Fq6 {
-    type Output = Fq6;
+impl<C: Curve> Add for Fq6<C> {
+    type Output = Fq6<C>;
 
     fn add(self, rhs: Self) -> Self::Output {
         FieldOps::add(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Add<&'b Fq6> for &'a Fq6 {
-    type Output = Fq6;
+impl<'b, C: Curve> Add<&'b Fq6<C>> for &Fq6<C> {
+    type Output = Fq6<C>;
 
-    fn add(self, rhs: &'b Fq6) -> Fq6 {
+    fn add(self, rhs: &'b Fq6<C>) -> Fq6<C> {
         FieldOps::add(self, rhs)
     }
 }
 
-impl Sub for Fq6 {
-    type Output = Fq6;
+impl<C: Curve> Sub for Fq6<C> {
+    type Output = Fq6<C>;
 
     fn sub(self, rhs: Self) -> Self::Output {
         FieldOps::sub(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Sub<&'b Fq6> for &'a Fq6 {
-    type Output = Fq6;
+impl<'b, C: Curve> Sub<&'b Fq6<C>> for &Fq6<C> {
+    type Output = Fq6<C>;
 
-    fn sub(self, rhs: &'b Fq6) -> Fq6 {
+    fn sub(self, rhs: &'b Fq6<C>) -> Fq6<C> {
         FieldOps::sub(self, rhs)
     }
 }
 
-impl Mul for Fq6 {
-    type Output = Fq6;
+impl<C: Curve> Mul for Fq6<C> {
+    type Output = Fq6<C>;
 
     fn mul(self, rhs: Self) -> Self::Output {
         FieldOps::mul(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Mul<&'b Fq6> for &'a Fq6 {
-    type Output = Fq6;
+impl<'b, C: Curve> Mul<&'b Fq6<C>> for &Fq6<C> {
+    type Output = Fq6<C>;
 
-    fn mul(self, rhs: &'b Fq6) -> Fq6 {
+    fn mul(self, rhs: &'b Fq6<C>) -> Fq6<C> {
         FieldOps::mul(self, rhs)
     }
 }
 
-impl Div for Fq6 {
-    type Output = Fq6;
+impl<C: Curve> Div for Fq6<C> {
+    type Output = Fq6<C>;
 
     fn div(self, rhs: Self) -> Self::Output {
         FieldOps::div(&self, &rhs)
     }
 }
 
-impl<'a, 'b> Div<&'b Fq6> for &'a Fq6 {
-    type Output = Fq6;
+impl<'b, C: Curve> Div<&'b Fq6<C>> for &Fq6<C> {
+    type Output = Fq6<C>;
 
-    fn div(self, rhs: &'b Fq6) -> Fq6 {
+    fn div(self, rhs: &'b Fq6<C>) -> Fq6<C> {
         FieldOps::div(self, rhs)
     }
 }
 
-impl Neg for Fq6 {
-    type Output = Fq6;
+impl<C: Curve> Neg for Fq6<C> {
+    type Output = Fq6<C>;
 
     fn neg(self) -> Self::Output {
         FieldOps::neg(&self)
     }
 }
 
-impl<'a> Neg for &'a Fq6 {
-    type Output = Fq6;
+impl<C: Curve> Neg for &Fq6<C> {
+    type Output = Fq6<C>;
 
-    fn neg(self) -> Fq6 {
+    fn neg(self) -> Fq6<C> {
         FieldOps::neg(self)
     }
 }
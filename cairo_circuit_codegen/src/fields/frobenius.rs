@@ -0,0 +1,92 @@
+// Frobenius coefficients, named after the BN254 constants of plonk-verifier/src/fields/frobenius.cairo
+use num_traits::Zero;
+
+use super::{fq::Fq, fq2::Fq2, FieldOps, FieldUtils};
+use crate::curve::Curve;
+
+// Cairo names of ξ^(e(pᵏ - 1)/6) for k = 1, 2, 3 and e = 1 to 4, after the frobenius.cairo
+// constant holding that value: fp12 Q_k, fp6 Q_k, pi QkX3 and fp6 Q2_k. Curves other than BN254
+// prefix them
+macro_rules! frobenius_names {
+    ($prefix:literal) => {
+        [
+            [
+                [concat!($prefix, "FP12_Q_1_C0"), concat!($prefix, "FP12_Q_1_C1")],
+                [concat!($prefix, "FP6_Q_1_C0"), concat!($prefix, "FP6_Q_1_C1")],
+                [concat!($prefix, "PI_Q1X3_C0"), concat!($prefix, "PI_Q1X3_C1")],
+                [concat!($prefix, "FP6_Q2_1_C0"), concat!($prefix, "FP6_Q2_1_C1")],
+            ],
+            [
+                [concat!($prefix, "FP12_Q_2_C0"), concat!($prefix, "FP12_Q_2_C1")],
+                [concat!($prefix, "FP6_Q_2_C0"), concat!($prefix, "FP6_Q_2_C1")],
+                [concat!($prefix, "PI_Q2X3_C0"), concat!($prefix, "PI_Q2X3_C1")],
+                [concat!($prefix, "FP6_Q2_2_C0"), concat!($prefix, "FP6_Q2_2_C1")],
+            ],
+            [
+                [concat!($prefix, "FP12_Q_3_C0"), concat!($prefix, "FP12_Q_3_C1")],
+                [concat!($prefix, "FP6_Q_3_C0"), concat!($prefix, "FP6_Q_3_C1")],
+                [concat!($prefix, "PI_Q3X3_C0"), concat!($prefix, "PI_Q3X3_C1")],
+                [concat!($prefix, "FP6_Q2_3_C0"), concat!($prefix, "FP6_Q2_3_C1")],
+            ],
+        ]
+    };
+}
+pub(crate) use frobenius_names;
+
+/// x·ξ^(e(pᵏ - 1)/6), the coefficient is a Cairo constant. Coefficients in Fq (k = 2) only scale
+/// and -1 negates
+pub fn mul_coeff<C: Curve>(x: &Fq2<C>, k: usize, e: u32) -> Fq2<C> {
+    let [c0, c1] = C::frobenius_coeff(k, e);
+    let names = C::FROBENIUS_NAMES[k - 1][e as usize - 1];
+    if c1.is_zero() && c0 == C::modulus() - 1u32 {
+        x.neg()
+    } else if c1.is_zero() {
+        x.scale(&Fq::constant(names[0], c0))
+    } else {
+        x * &Fq2::constant(names, [c0, c1])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use crate::circuit::evaluator::{bn254_modulus, bn254_scalar_modulus};
+    use crate::fields::Frobenius;
+    use crate::reference::{Field, G2Affine};
+    use crate::utils::utils::{eval, fq12_input, fq2_input, fq6_input, g2_input, random_fq12, random_fq2, random_fq6, rng};
+
+    // [n]Q by double and add, n is non-zero
+    fn g2_mul(q: &G2Affine, n: &BigUint) -> G2Affine {
+        let mut acc = q.clone();
+        for i in (0..n.bits() - 1).rev() {
+            acc = acc.double();
+            if n.bit(i) {
+                acc = acc.add(q);
+            }
+        }
+        acc
+    }
+
+    #[test]
+    fn test_frobenius() {
+        // The reference powers are slow, one element of each field is enough for the constants
+        let mut rng = rng();
+        let p = bn254_modulus();
+        let (a, b, c) = (random_fq2(&mut rng), random_fq6(&mut rng), random_fq12(&mut rng));
+        let (mut a_p, mut b_p, mut c_p) = (a.clone(), b.clone(), c.clone());
+        for k in 1..=3 {
+            (a_p, b_p, c_p) = (a_p.pow(&p), b_p.pow(&p), c_p.pow(&p));
+            assert_eq!(eval(&fq2_input(0).frobenius(k), &[a.values()]), a_p.values(), "fq2 {}", k);
+            assert_eq!(eval(&fq6_input(0).frobenius(k), &[b.values()]), b_p.values(), "fq6 {}", k);
+            assert_eq!(eval(&fq12_input(0).frobenius(k), &[c.values()]), c_p.values(), "fq12 {}", k);
+        }
+
+        // ψ acts on G2 as the multiplication by p
+        let (g, r) = (G2Affine::generator(), bn254_scalar_modulus());
+        for k in 1..=3u32 {
+            let expected = g2_mul(&g, &(p.pow(k) % &r));
+            assert_eq!(eval(&g2_input(0).frobenius(k as usize), &[g.values()]), expected.values(), "psi {}", k);
+        }
+    }
+}
@@ -3,7 +3,9 @@ pub(crate) mod fq2;
 pub(crate) mod fq6;
 pub(crate) mod fq12;
 pub(crate) mod fq12_squaring;
+pub(crate) mod frobenius;
 pub(crate) mod affine;
+pub(crate) mod projective;
 pub(crate) mod sparse;
 
 pub trait FieldUtils {
@@ -21,6 +23,11 @@ pub trait FieldOps {
     fn inv(&self,) -> Self;
 }
 
+/// x^(pᵏ) for k = 1, 2, 3, ψᵏ on the twist, the coefficients are Cairo constants
+pub trait Frobenius {
+    fn frobenius(&self, k: usize) -> Self;
+}
+
 pub trait FieldConstants {
     fn one() -> Self;
     fn zero() -> Self; 
@@ -0,0 +1,51 @@
+use super::{affine::Affine, fq::Fq, fq2::Fq2, FieldConstants, FieldOps};
+use crate::curve::{Bn254, Curve};
+
+/// Homogeneous projective point of the twist, x = X/Z and y = Y/Z
+#[derive(Debug, Clone)]
+pub struct G2Projective<C: Curve = Bn254> {
+    x: Fq2<C>,
+    y: Fq2<C>,
+    z: Fq2<C>,
+}
+
+impl<C: Curve> G2Projective<C> {
+    pub fn new(x: Fq2<C>, y: Fq2<C>, z: Fq2<C>) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn new_input(idx: [usize; 6]) -> Self {
+        Self {
+            x: Fq2::new_input([idx[0], idx[1]]),
+            y: Fq2::new_input([idx[2], idx[3]]),
+            z: Fq2::new_input([idx[4], idx[5]]),
+        }
+    }
+
+    /// (x, y, 1), Z is read from the ONE and ZERO constants
+    pub fn from_affine(p: &Affine<Fq2<C>>) -> Self {
+        Self { x: p.x().clone(), y: p.y().clone(), z: Fq2::new(Fq::one(), Fq::zero()) }
+    }
+
+    pub fn x(&self) -> &Fq2<C> {
+        &self.x
+    }
+
+    pub fn y(&self) -> &Fq2<C> {
+        &self.y
+    }
+
+    pub fn z(&self) -> &Fq2<C> {
+        &self.z
+    }
+
+    /// (X/Z, Y/Z), the one inversion of a point kept in projective coordinates
+    pub fn to_affine(&self) -> Affine<Fq2<C>> {
+        let z_inv = self.z.inv();
+        Affine::<Fq2<C>>::new(&self.x * &z_inv, &self.y * &z_inv)
+    }
+
+    pub fn neg(&self) -> Self {
+        Self { x: self.x.clone(), y: -&self.y, z: self.z.clone() }
+    }
+}
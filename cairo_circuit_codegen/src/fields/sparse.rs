@@ -1,43 +1,59 @@
 use super::{fq::Fq, fq12::Fq12, fq2::Fq2, fq6::Fq6, FieldConstants, FieldOps};
+use crate::curve::{Bn254, Curve};
 
-#[derive(Clone, Debug, Default)]
-pub struct Fq12Sparse034 {
-    c3: Fq2,
-    c4: Fq2,
+#[derive(Clone, Debug)]
+pub struct Fq12Sparse034<C: Curve = Bn254> {
+    c3: Fq2<C>,
+    c4: Fq2<C>,
 }
 
-#[derive(Clone, Debug, Default)]
+/// c0 + c3·w + c4·vw, a 034 line of projective coordinates whose c0 is not divided out
+#[derive(Clone, Debug)]
+pub struct Fq12Sparse034Scaled<C: Curve = Bn254> {
+    c0: Fq2<C>,
+    c3: Fq2<C>,
+    c4: Fq2<C>,
+}
+
+#[derive(Clone, Debug)]
+
+pub struct Fq12Sparse01234<C: Curve = Bn254> {
+    c0: Fq6<C>,
+    c1: Fq6Sparse01<C>,
+}
 
-pub struct Fq12Sparse01234 {
-    c0: Fq6,
-    c1: Fq6Sparse01,
+/// c0 + c1·v + v·w, a line on an M-twist
+#[derive(Clone, Debug)]
+pub struct Fq12Sparse014<C: Curve = Bn254> {
+    c0: Fq2<C>,
+    c1: Fq2<C>,
 }
 
-#[derive(Clone, Debug, Default)]
-pub struct Fq6Sparse01 {
-    c0: Fq2,
-    c1: Fq2,
+#[derive(Clone, Debug)]
+pub struct Fq6Sparse01<C: Curve = Bn254> {
+    c0: Fq2<C>,
+    c1: Fq2<C>,
 }
 
-impl Fq12Sparse01234 {
-    pub fn new(c0: Fq6, c1: Fq6Sparse01) -> Self {
+impl<C: Curve> Fq12Sparse01234<C> {
+    pub fn new(c0: Fq6<C>, c1: Fq6Sparse01<C>) -> Self {
         Self { c0, c1 }
     }
 
-    pub fn c0(&self) -> &Fq6 {
+    pub fn c0(&self) -> &Fq6<C> {
         &self.c0
     }
 
-    pub fn c1(&self) -> &Fq6Sparse01 {
+    pub fn c1(&self) -> &Fq6Sparse01<C> {
         &self.c1
     }
 
-    pub fn mul_01234_01234(&self, rhs: &Self) -> Fq12 {
+    pub fn mul_01234_01234(&self, rhs: &Self) -> Fq12<C> {
         let (a0, a1) = (self.c0(), self.c1());
         let (b0, b1) = (rhs.c0(), rhs.c1());
         
-        let b = Fq6::new(b0.c0() + b1.c0(), b0.c1() + b1.c1(), b0.c2().clone(), None);
-        let c1 = Fq6::new(a0.c0() + a1.c0(), a0.c1() + a1.c1(), a0.c2().clone(), None);
+        let b = Fq6::new(b0.c0() + b1.c0(), b0.c1() + b1.c1(), b0.c2().clone());
+        let c1 = Fq6::new(a0.c0() + a1.c0(), a0.c1() + a1.c1(), a0.c2().clone());
         let c1 = c1 * b;
 
         let u = a0 * b0;
@@ -46,24 +62,24 @@ impl Fq12Sparse01234 {
         let c0 = &v.mul_by_v() + &u;
         let c1 = c1 - (u + v);
 
-        Fq12::new(c0, c1, None)
+        Fq12::new(c0, c1)
     }
 }
 
-impl Fq6Sparse01 {
-    pub fn new(c0: Fq2, c1: Fq2) -> Self {
+impl<C: Curve> Fq6Sparse01<C> {
+    pub fn new(c0: Fq2<C>, c1: Fq2<C>) -> Self {
         Self { c0, c1 }
     }
 
-    pub fn c0(&self) -> &Fq2 {
+    pub fn c0(&self) -> &Fq2<C> {
         &self.c0
     }
 
-    pub fn c1(&self) -> &Fq2 {
+    pub fn c1(&self) -> &Fq2<C> {
         &self.c1
     }
 
-    pub fn mul_01_by_01(&self, rhs: &Self) -> Fq6 {
+    pub fn mul_01_by_01(&self, rhs: &Self) -> Fq6<C> {
         let (a0, a1)  = (self.c0(), self.c1());
         let (b0, b1)  = (rhs.c0(), rhs.c1());
 
@@ -71,25 +87,25 @@ impl Fq6Sparse01 {
         let v1 = a1 * b1;
         let c1 = &(&((a0 + a1) * (b0 + b1)) - &v0) - &v1;
 
-        Fq6::new(v0, c1, v1, None)
+        Fq6::new(v0, c1, v1)
            
     }
 }
 
-impl Fq12Sparse034 {
-    pub fn new(c3: Fq2, c4: Fq2) -> Self {
+impl<C: Curve> Fq12Sparse034<C> {
+    pub fn new(c3: Fq2<C>, c4: Fq2<C>) -> Self {
         Self { c3, c4 }
     }
 
-    pub fn c3(&self) -> &Fq2 {
+    pub fn c3(&self) -> &Fq2<C> {
         &self.c3
     }
 
-    pub fn c4(&self) -> &Fq2 {
+    pub fn c4(&self) -> &Fq2<C> {
         &self.c4
     }
 
-    pub fn mul_034_by_034(&self, rhs: &Self) -> Fq12Sparse01234 {
+    pub fn mul_034_by_034(&self, rhs: &Self) -> Fq12Sparse01234<C> {
         let (c3, c4) = (self.c3(), self.c4());
         let (d3, d4) = (rhs.c3(), rhs.c4());
         
@@ -102,12 +118,12 @@ impl Fq12Sparse034 {
         let x34 = &x34 - &c4d4;
 
         let zc0b0 = c4d4.mul_by_xi();
-        let zc0b0 = Fq2::new(zc0b0.c0().clone(), zc0b0.c1().clone(), None); // Need to add one (zc0b0.c0() + &Fq::one())
+        let zc0b0 = Fq2::new(zc0b0.c0().clone(), zc0b0.c1().clone()); // Need to add one (zc0b0.c0() + &Fq::one())
         
-        Fq12Sparse01234::new(Fq6::new(zc0b0, c3d3, x34, None), Fq6Sparse01::new(x03, x04))        
+        Fq12Sparse01234::new(Fq6::new(zc0b0, c3d3, x34), Fq6Sparse01::new(x03, x04))        
     }
 
-    pub fn sqr_034(&self) -> Fq12Sparse01234 {
+    pub fn sqr_034(&self) -> Fq12Sparse01234<C> {
         let (c3, c4) = (self.c3(), self.c4());
 
         let c3_sq = c3.sqr();
@@ -119,9 +135,52 @@ impl Fq12Sparse034 {
         let x34 = &x34 - &c4_sq;
 
         let zc0b0 = c4_sq.mul_by_xi();
-        let zc0b0 = Fq2::new(zc0b0.c0() + &Fq::one(), zc0b0.c1().clone(), None);
+        let zc0b0 = Fq2::new(zc0b0.c0() + &Fq::one(), zc0b0.c1().clone());
         
-        Fq12Sparse01234::new(Fq6::new(zc0b0, c3_sq, x34, None), Fq6Sparse01::new(x03, x04))
+        Fq12Sparse01234::new(Fq6::new(zc0b0, c3_sq, x34), Fq6Sparse01::new(x03, x04))
     } 
 }
 
+impl<C: Curve> Fq12Sparse034Scaled<C> {
+    pub fn new(c0: Fq2<C>, c3: Fq2<C>, c4: Fq2<C>) -> Self {
+        Self { c0, c3, c4 }
+    }
+
+    pub fn c0(&self) -> &Fq2<C> {
+        &self.c0
+    }
+
+    pub fn c3(&self) -> &Fq2<C> {
+        &self.c3
+    }
+
+    pub fn c4(&self) -> &Fq2<C> {
+        &self.c4
+    }
+}
+
+impl<C: Curve> Fq12Sparse014<C> {
+    pub fn new(c0: Fq2<C>, c1: Fq2<C>) -> Self {
+        Self { c0, c1 }
+    }
+
+    pub fn c0(&self) -> &Fq2<C> {
+        &self.c0
+    }
+
+    pub fn c1(&self) -> &Fq2<C> {
+        &self.c1
+    }
+
+    /// (a + v·w)(b + v·w) = a·b + v³ + (a + b)·v·w with v³ = ξ
+    pub fn mul_014_by_014(&self, rhs: &Self) -> Fq12<C> {
+        let (a, b) = (Fq6Sparse01::new(self.c0.clone(), self.c1.clone()), Fq6Sparse01::new(rhs.c0.clone(), rhs.c1.clone()));
+        let ab = a.mul_01_by_01(&b);
+        let xi = Fq2::new(C::mul_by_xi_c0(&Fq::one()), Fq::one());
+        let zero = Fq2::new(Fq::zero(), Fq::zero());
+
+        let c0 = Fq6::new(ab.c0() + &xi, ab.c1().clone(), ab.c2().clone());
+        let c1 = Fq6::new(zero, self.c0() + rhs.c0(), self.c1() + rhs.c1());
+        Fq12::new(c0, c1)
+    }
+}
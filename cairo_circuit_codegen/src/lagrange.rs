@@ -0,0 +1,124 @@
+// Lagrange evaluations and public input of the PLONK verifier. L_i = wⁱ⁻¹·(ξⁿ - 1) / (n·(ξ - wⁱ⁻¹))
+// divides once per public input, the denominators are inverted together by Montgomery's trick:
+// prefix products, a single inversion of the full product and a walk back that peels one
+// denominator off per step. N inversions become one and 3(N - 1) multiplications.
+use crate::fields::{fq::Fq, FieldConstants, FieldOps};
+
+/// Inverses of every value from one inversion. A zero value zeroes the product, the circuit then
+/// fails as a single inversion would
+pub fn batch_inverse<F: FieldOps + Clone>(values: &[F]) -> Vec<F> {
+    let (first, rest) = values.split_first().expect("a batch of at least one value");
+    // prefix[i] = v₀·…·vᵢ
+    let mut prefix = vec![first.clone()];
+    for value in rest {
+        prefix.push(prefix[prefix.len() - 1].mul(value));
+    }
+
+    // inv = 1/(v₀·…·vᵢ) going down, 1/vᵢ = inv·(v₀·…·vᵢ₋₁)
+    let mut inv = prefix[prefix.len() - 1].inv();
+    let mut inverses = vec![];
+    for i in (1..values.len()).rev() {
+        inverses.push(inv.mul(&prefix[i - 1]));
+        inv = inv.mul(&values[i]);
+    }
+    inverses.push(inv);
+    inverses.reverse();
+    inverses
+}
+
+/// ξⁿ - 1 and L_1 to L_count at ξ, from ξⁿ, n and w. wⁱ is built by multiplications as the key
+/// is an input, w⁰ = 1 is never multiplied
+pub fn lagrange_evaluations(xi: &Fq, xin: &Fq, n: &Fq, w: &Fq, count: usize) -> (Fq, Vec<Fq>) {
+    let zh = xin - &Fq::one();
+    let mut w_pows = vec![None, Some(w.clone())];
+    while w_pows.len() < count {
+        let last = w_pows[w_pows.len() - 1].as_ref().unwrap();
+        w_pows.push(Some(last * w));
+    }
+    w_pows.truncate(count);
+
+    let one = Fq::one();
+    let denominators: Vec<Fq> = w_pows.iter().map(|w_pow| n * &(xi - w_pow.as_ref().unwrap_or(&one))).collect();
+    let evaluations = w_pows
+        .iter()
+        .zip(batch_inverse(&denominators))
+        .map(|(w_pow, inv)| match w_pow {
+            Some(w_pow) => &(w_pow * &zh) * &inv,
+            None => &zh * &inv,
+        })
+        .collect();
+    (zh, evaluations)
+}
+
+/// PI = -Σ sᵢ·L_(i+1), one evaluation per public signal
+pub fn public_input(signals: &[Fq], evaluations: &[Fq]) -> Fq {
+    let (first, rest) = signals.split_first().expect("at least one public signal");
+    let pi = (first * &evaluations[0]).neg();
+    rest.iter().zip(&evaluations[1..]).fold(pi, |pi, (s, l)| &pi - &(s * l))
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use super::{batch_inverse, lagrange_evaluations, public_input};
+    use crate::circuit::{adder::CairoCodeAdder, evaluator::{bn254_scalar_modulus, Evaluator}, stats::CircuitStats};
+    use crate::fields::fq::Fq;
+
+    fn eval(out: &[Fq], inputs: Vec<BigUint>) -> Vec<BigUint> {
+        let circuits: Vec<_> = out.iter().flat_map(|value| value.circuits()).collect();
+        Evaluator::new(inputs, bn254_scalar_modulus()).eval_all(&circuits).unwrap()
+    }
+
+    fn inv(value: &BigUint) -> BigUint {
+        let r = bn254_scalar_modulus();
+        value.modpow(&(&r - 2u32), &r)
+    }
+
+    #[test]
+    fn test_batch_inverse() {
+        for count in [1, 2, 5] {
+            let values: Vec<Fq> = (0..count).map(Fq::new_input).collect();
+            let inputs: Vec<BigUint> = (0..count).map(|i| BigUint::from(3u32).pow(i as u32 + 40) + 7u32).collect();
+            let inverses = batch_inverse(&values);
+
+            let circuits: Vec<_> = inverses.iter().map(|value| value.c0()).collect();
+            assert_eq!(CircuitStats::of("batch_inverse", &circuits).gates.inv, 1);
+            assert_eq!(eval(&inverses, inputs.clone()), inputs.iter().map(inv).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn test_lagrange_evaluations() {
+        // A domain of 2^12 points and five public signals
+        let r = bn254_scalar_modulus();
+        let w = BigUint::parse_bytes(b"4158865282786404163413953114870269622875596290766033564087307867933865333818", 10).unwrap();
+        let (n, xi) = (BigUint::from(4096u32), BigUint::from(0x1234_5678_9abc_def0u64).pow(3) % &r);
+        let xin = xi.modpow(&n, &r);
+        let signals: Vec<BigUint> = (0..5u32).map(|i| BigUint::from(1000 + i)).collect();
+
+        let [xi_in, xin_in, n_in, w_in] = [0, 1, 2, 3].map(Fq::new_input);
+        let signal_inputs: Vec<Fq> = (4..9).map(Fq::new_input).collect();
+        let (zh, ls) = lagrange_evaluations(&xi_in, &xin_in, &n_in, &w_in, 5);
+        let pi = public_input(&signal_inputs, &ls);
+
+        let mut outputs = vec![zh];
+        outputs.extend(ls);
+        outputs.push(pi);
+        let mut inputs = vec![xi.clone(), xin.clone(), n.clone(), w.clone()];
+        inputs.extend(signals.iter().cloned());
+        let values = eval(&outputs, inputs);
+
+        let zh = (&xin + &r - 1u32) % &r;
+        let mut expected = vec![zh.clone()];
+        let mut pi = BigUint::from(0u32);
+        for (i, s) in signals.iter().enumerate() {
+            let w_pow = w.modpow(&BigUint::from(i), &r);
+            let l = &w_pow * &zh % &r * inv(&(&n * ((&xi + &r - &w_pow) % &r) % &r)) % &r;
+            pi = (pi + &r - s * &l % &r) % &r;
+            expected.push(l);
+        }
+        expected.push(pi);
+        assert_eq!(values, expected);
+    }
+}
@@ -1,12 +1,21 @@
 pub mod circuit;
-mod pairing;
+// Native pairing drivers, generators emit the Miller loop one step at a time and the final
+// exponentiation in bounded chunks
+pub mod pairing;
+#[cfg(test)]
 mod utils;
-mod fields; 
+pub mod fields;
+// BN254 and BLS12-381 parameters of the field tower and the pairing
+pub mod curve;
+// G1 multi-scalar multiplication with windowed tables
+pub mod msm;
+// Batched inversion of the PLONK Lagrange denominators
+mod lagrange;
+// Concrete BN254 values, constants of the generated code and checks of the circuits
+mod reference;
 
 #[cfg(test)]
 mod tests {
-    use super::*;
-
     #[test]
     fn test_generate_cairo_code() {
 
@@ -1,23 +1,265 @@
-use std::fs;
+use std::{fs, io, path::{Path, PathBuf}, process::ExitCode, time::Instant};
+
+use cairo_circuit_codegen::circuit::{
+    builder::CairoCodeBuilder,
+    optimizer::Passes,
+    regen::{Manifest, DEFAULT_MANIFEST},
+    stats::{CircuitReport, CircuitStats, CostModel},
+    utils::{find_generator, Generator, GENERATORS},
+    verifier::{generate_verifier, plonk_verifier_version, write_package, VerificationKey, PLONK_VERIFIER_DIR},
+};
+use clap::{builder::PossibleValuesParser, Parser, Subcommand};
 use sysinfo::{System, SystemExt};
 
-fn main() {
-    let mut system = System::new_all();
-    system.refresh_all();
+#[derive(Parser)]
+#[command(version, about = "Generates Cairo circuit type definitions", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// List the available generators
+    List,
+
+    /// Run one or more generators
+    Generate {
+        /// Generators to run, in order
+        #[arg(
+            required_unless_present = "all",
+            value_parser = PossibleValuesParser::new(GENERATORS.iter().map(|g| g.name)),
+        )]
+        names: Vec<String>,
+
+        /// Run every generator
+        #[arg(long, conflicts_with = "names")]
+        all: bool,
+
+        /// Output file, `-` for stdout
+        #[arg(short, long, default_value = "out.cairo")]
+        output: PathBuf,
+
+        /// Write one `<name>.cairo` file per generator to this directory instead of a single file
+        #[arg(long, conflicts_with = "output")]
+        out_dir: Option<PathBuf>,
+
+        /// Prepend the `core::circuit` imports
+        #[arg(long)]
+        imports: bool,
+
+        /// Also emit a Cairo function evaluating each circuit on typed values
+        #[arg(long)]
+        functions: bool,
+
+        /// Simplify the circuits before emitting them and print the gate counts before and after
+        #[arg(long)]
+        optimize: bool,
+
+        /// Print gate counts, depth and estimated steps of every circuit
+        #[arg(long)]
+        report: bool,
+
+        /// Write the circuit report as JSON to this file, `-` for stdout
+        #[arg(long)]
+        report_json: Option<PathBuf>,
+
+        /// JSON cost model used to estimate steps, missing weights keep their default
+        #[arg(long)]
+        cost_model: Option<PathBuf>,
+
+        /// Print generation time and memory usage
+        #[arg(long)]
+        stats: bool,
+    },
+
+    /// Rewrite the generated sections of the checked-in Cairo files
+    Regen {
+        /// Manifest mapping generators to Cairo file sections
+        #[arg(long, default_value = DEFAULT_MANIFEST)]
+        manifest: PathBuf,
 
-    // let code = generate_fq12_field_ops();
-    let code = cairo_circuit_codegen::circuit::utils::generate_step_dbl_add_slopes();
+        /// Only compare, fail if a section differs from freshly generated output
+        #[arg(long)]
+        check: bool,
+    },
 
-    // Write the generated code to out.cairo
-    
-    println!("Total memory: {} KB", system.total_memory());
-    println!("Used memory: {} KB", system.used_memory());
-    println!("Free memory: {} KB", system.free_memory());
+    /// Generate a verifier contract for a snarkjs PLONK verification key, it builds against the
+    /// plonk_verifier package of this checkout
+    Verifier {
+        /// `verification_key.json` written by snarkjs
+        vk: PathBuf,
 
-    fs::write("out.cairo", code).expect("Unable to write file");
-    println!("Cairo code generated successfully and written to out.cairo");   
+        /// Output file, `-` for stdout
+        #[arg(short, long, default_value = "verifier.cairo")]
+        output: PathBuf,
+
+        /// Write a Scarb package depending on plonk_verifier to this directory instead
+        #[arg(long, conflicts_with = "output")]
+        package: Option<PathBuf>,
+
+        /// Checkout of plonk_verifier the package depends on
+        #[arg(long, value_name = "PATH", default_value = PLONK_VERIFIER_DIR, requires = "package")]
+        plonk_verifier: PathBuf,
+    },
+}
+
+fn main() -> io::Result<ExitCode> {
+    match Cli::parse().command {
+        Commands::List => {
+            for generator in GENERATORS {
+                println!("{:<28}{}", generator.name, generator.description);
+            }
+        }
+        Commands::Generate {
+            names,
+            all,
+            output,
+            out_dir,
+            imports,
+            functions,
+            optimize,
+            report,
+            report_json,
+            cost_model,
+            stats,
+        } => {
+            let generators: Vec<&Generator> = match all {
+                true => GENERATORS.iter().collect(),
+                // Names are validated by clap
+                false => names.iter().map(|name| find_generator(name).unwrap()).collect(),
+            };
+
+            // Read first so a bad cost model fails before anything is written
+            let cost_model = match cost_model {
+                Some(path) => CostModel::from_json(&fs::read_to_string(path)?)?,
+                None => CostModel::default(),
+            };
+
+            let start = Instant::now();
+            let mut circuits = vec![];
+            match out_dir {
+                Some(dir) => {
+                    fs::create_dir_all(&dir)?;
+                    for generator in generators {
+                        let path = dir.join(format!("{}.cairo", generator.name));
+                        let (code, stats) = generate(&[generator], imports, functions, optimize, stats);
+                        fs::write(&path, code)?;
+                        circuits.extend(stats);
+                        eprintln!("Wrote {}", path.display());
+                    }
+                }
+                None => {
+                    let (code, stats) = generate(&generators, imports, functions, optimize, stats);
+                    circuits.extend(stats);
+                    if output.as_os_str() == "-" {
+                        print!("{}", code);
+                    } else {
+                        fs::write(&output, code)?;
+                        eprintln!("Cairo code generated successfully and written to {}", output.display());
+                    }
+                }
+            }
+
+            let reports: Vec<CircuitReport> = circuits.iter().map(|stats| cost_model.report(stats)).collect();
+            if report {
+                eprintln!("{}", CircuitReport::header());
+                for report in &reports {
+                    eprintln!("{}", report.line());
+                }
+            }
+            if let Some(path) = report_json {
+                let json = serde_json::to_string_pretty(&reports)?;
+                if path.as_os_str() == "-" {
+                    println!("{}", json);
+                } else {
+                    fs::write(&path, json)?;
+                    eprintln!("Circuit report written to {}", path.display());
+                }
+            }
+
+            if stats {
+                let mut system = System::new_all();
+                system.refresh_all();
+
+                eprintln!("Total time: {:?}", start.elapsed());
+                eprintln!("Total memory: {} KB", system.total_memory());
+                eprintln!("Used memory: {} KB", system.used_memory());
+                eprintln!("Free memory: {} KB", system.free_memory());
+            }
+        }
+        Commands::Regen { manifest, check } => {
+            // Paths in the manifest are relative to it
+            let root = manifest.parent().unwrap_or(Path::new("."));
+            let manifest = Manifest::load(&manifest).map_err(io::Error::other)?;
+            let changed = manifest.regen(root, check).map_err(io::Error::other)?;
+
+            for section in &changed {
+                let status = if check { "Out of date" } else { "Regenerated" };
+                eprintln!("{}: {} ({})", status, section.file.display(), section.section);
+            }
+            if check && !changed.is_empty() {
+                eprintln!("Run `cargo run -- regen` to update the Cairo sources");
+                return Ok(ExitCode::FAILURE);
+            }
+        }
+        Commands::Verifier { vk, output, package, plonk_verifier } => {
+            let vk = VerificationKey::load(&vk).map_err(io::Error::other)?;
+            let code = generate_verifier(&vk);
+            if let Some(dir) = package {
+                let name = dir.file_name().and_then(|name| name.to_str()).unwrap_or("verifier").replace('-', "_");
+                write_package(&dir, &name, &code, &plonk_verifier)?;
+                eprintln!(
+                    "Verifier for {} public inputs written to {}, against plonk_verifier {}",
+                    vk.n_public,
+                    dir.display(),
+                    plonk_verifier_version()
+                );
+            } else if output.as_os_str() == "-" {
+                print!("{}", code);
+            } else {
+                fs::write(&output, code)?;
+                eprintln!(
+                    "Verifier for {} public inputs written to {}, it needs plonk_verifier {}",
+                    vk.n_public,
+                    output.display(),
+                    plonk_verifier_version()
+                );
+            }
+        }
+    }
+
+    Ok(ExitCode::SUCCESS)
 }
 
+/// Runs the generators into a single block of code, along with the size of every circuit
+fn generate(
+    generators: &[&Generator],
+    imports: bool,
+    functions: bool,
+    optimize: bool,
+    stats: bool,
+) -> (String, Vec<CircuitStats>) {
+    let mut builder = CairoCodeBuilder::new()
+        .with_functions(functions)
+        .with_optimizer(optimize.then(Passes::default));
 
+    for generator in generators {
+        let (start, len) = (Instant::now(), builder.as_str().len());
+        (generator.generate)(&mut builder);
+        if stats {
+            eprintln!("{}: {:?}, {} bytes", generator.name, start.elapsed(), builder.as_str().len() - len);
+        }
+    }
 
- 
\ No newline at end of file
+    for report in builder.reports() {
+        eprintln!("{}: {} -> {} gates", report.name, report.before, report.after);
+    }
+
+    if imports {
+        builder.add_imports();
+    }
+
+    let circuits = builder.stats().to_vec();
+    (builder.build(), circuits)
+}
@@ -0,0 +1,179 @@
+// Multi-scalar multiplication on G1 for the PLONK linearization. Circuits cannot branch on the
+// scalars, they only add multiples of the bases: the scalars are split into w-bit windows by the
+// Cairo entry point, which picks the table entries the circuits add.
+//
+// Digits are zero-free, every window adds a point and the affine formulas never meet the point at
+// infinity: k - O mod r is split into plain digits and O = 2^(w(W - 1)) + Σ 2^(wj) adds one to
+// every digit and two to the top one. The top digit is then at least 2, so no addition of a step
+// has equal operands.
+use num_bigint::BigUint;
+use num_traits::One;
+
+use crate::circuit::evaluator::bn254_scalar_modulus;
+use crate::circuit::function::constant_array_item;
+use crate::circuit::stats::{CircuitStats, GateCounts};
+use crate::fields::{affine::Affine, fq::Fq, ECOperations};
+use crate::reference;
+
+/// Windows above this size make tables of more points than the MSMs of a proof add
+pub const MAX_WINDOW: usize = 8;
+
+fn check_window(w: usize) {
+    assert!((1..=MAX_WINDOW).contains(&w), "window of {} bits, expected 1 to {}", w, MAX_WINDOW);
+}
+
+/// Number of windows W: plain digits below 2^w and a top digit below 2^w - 1 hold any k < r
+pub fn window_count(w: usize) -> usize {
+    check_window(w);
+    let r = bn254_scalar_modulus();
+    let top = (BigUint::one() << w) - 1u32;
+    (1..).find(|count| &top << (w * (count - 1)) >= r).unwrap()
+}
+
+/// O mod r, the value the zero-free digits add to k - O
+pub fn digit_offset(w: usize) -> BigUint {
+    let count = window_count(w);
+    let offset = (0..count).fold(BigUint::one() << (w * (count - 1)), |acc, j| acc + (BigUint::one() << (w * j)));
+    offset % bn254_scalar_modulus()
+}
+
+/// Zero-free digits of k, least significant first: Σ dⱼ·2^(wj) = k mod r with dⱼ in [1, 2^w] and
+/// the top digit in [2, 2^w]
+pub fn digits(k: &BigUint, w: usize) -> Vec<usize> {
+    let r = bn254_scalar_modulus();
+    let mut rest = (k % &r + &r - digit_offset(w)) % &r;
+    let count = window_count(w);
+    let mask = (BigUint::one() << w) - 1u32;
+    (0..count)
+        .map(|j| {
+            let digit = (&rest & &mask).to_u64_digits().first().copied().unwrap_or(0) as usize;
+            rest >>= w;
+            digit + if j == count - 1 { 2 } else { 1 }
+        })
+        .collect()
+}
+
+/// [1, 2^w]·P, the table of a variable base. Entry d - 1 is d·P
+pub fn window_table(p: &Affine<Fq>, w: usize) -> Vec<Affine<Fq>> {
+    check_window(w);
+    let mut table = vec![p.clone(), p.double()];
+    while table.len() < 1 << w {
+        table.push(table[table.len() - 1].add(p));
+    }
+    table
+}
+
+/// The sum of points, each one added in turn
+pub fn sum(points: &[Affine<Fq>]) -> Affine<Fq> {
+    let (first, rest) = points.split_first().expect("a sum of at least one point");
+    rest.iter().fold(first.clone(), |acc, p| acc.add(p))
+}
+
+/// 2^w·acc plus one table entry of every base, a window of the Straus MSM
+pub fn straus_step(acc: &Affine<Fq>, entries: &[Affine<Fq>], w: usize) -> Affine<Fq> {
+    let acc = (0..w).fold(acc.clone(), |acc, _| acc.double());
+    entries.iter().fold(acc, |acc, p| acc.add(p))
+}
+
+/// d·2^(wj)·P for every window j and digit d, window by window. The table of a fixed base turns
+/// its scalar multiplication into W additions
+pub fn fixed_table(p: &reference::G1Affine, w: usize) -> Vec<reference::G1Affine> {
+    let mut base = p.clone();
+    let mut table = vec![];
+    for _ in 0..window_count(w) {
+        let mut entry = base.clone();
+        table.push(entry.clone());
+        for _ in 1..1 << w {
+            entry = if entry == base { entry.double() } else { entry.add(&base) };
+            table.push(entry.clone());
+        }
+        base = (0..w).fold(base, |base, _| base.double());
+    }
+    table
+}
+
+/// Cairo constant array of the fixed table of P, x then y of every entry
+pub fn fixed_table_constant(name: &str, p: &reference::G1Affine, w: usize) -> String {
+    let values: Vec<BigUint> = fixed_table(p, w).into_iter().flat_map(|entry| [entry.x.0, entry.y.0]).collect();
+    constant_array_item(name, &values)
+}
+
+/// Gates of a whole MSM over n bases, from the stats of the circuits it runs: the tables of
+/// variable bases, the first window and W - 1 steps
+pub fn msm_gates(tables: Option<&CircuitStats>, first: &CircuitStats, step: &CircuitStats, n: usize, w: usize) -> GateCounts {
+    let steps = window_count(w) - 1;
+    let tables = tables.map(|t| t.gates).unwrap_or_default();
+    let scaled = |f: fn(&GateCounts) -> usize| n * f(&tables) + f(&first.gates) + steps * f(&step.gates);
+    GateCounts { add: scaled(|g| g.add), sub: scaled(|g| g.sub), mul: scaled(|g| g.mul), inv: scaled(|g| g.inv) }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+    use num_traits::One;
+
+    use super::{digit_offset, digits, fixed_table, straus_step, sum, window_count, window_table, MAX_WINDOW};
+    use crate::circuit::{adder::CairoCodeAdder, evaluator::{bn254_modulus, bn254_scalar_modulus, Evaluator}};
+    use crate::fields::{affine::Affine, fq::Fq};
+    use crate::reference::G1Affine;
+
+    fn eval<A: CairoCodeAdder>(out: &A, inputs: &[Vec<BigUint>]) -> Vec<BigUint> {
+        Evaluator::new(inputs.concat(), bn254_modulus()).eval_all(&out.circuits()).unwrap()
+    }
+
+    fn g1_input(idx: usize) -> Affine<Fq> {
+        Affine::<Fq>::new_input([idx, idx + 1])
+    }
+
+    #[test]
+    fn test_digits() {
+        let r = bn254_scalar_modulus();
+        for w in 1..=MAX_WINDOW {
+            let count = window_count(w);
+            assert!(w * count >= r.bits() as usize, "{} windows of {} bits", count, w);
+            for k in [BigUint::one(), BigUint::from(0xdeadbeefu64), &r - 1u32, BigUint::from(3u32) << 250, digit_offset(w)] {
+                let ds = digits(&k, w);
+                assert!(ds.iter().all(|d| (1..=1 << w).contains(d)) && ds[count - 1] >= 2);
+                let value = ds.iter().rev().fold(BigUint::from(0u32), |acc, d| (acc << w) + *d);
+                assert_eq!(value % &r, &k % &r, "k = {}, w = {}", k, w);
+            }
+        }
+    }
+
+    #[test]
+    fn test_straus() {
+        let (w, g) = (3, G1Affine::generator());
+        let bases = [g.double(), g.mul(&BigUint::from(0x1234567u64))];
+        let scalars = [BigUint::from(0xfedcba9876543210u64), bn254_scalar_modulus() - 5u32];
+        let inputs: Vec<Vec<BigUint>> = bases.iter().map(|p| p.values()).collect();
+
+        let tables: Vec<Vec<Affine<Fq>>> = (0..2).map(|i| window_table(&g1_input(2 * i), w)).collect();
+        for (d, entry) in tables[1].iter().enumerate() {
+            assert_eq!(eval(entry, &inputs), bases[1].mul(&BigUint::from(d + 1)).values());
+        }
+
+        // The windows from the top, as the entry point runs them
+        let ds: Vec<Vec<usize>> = scalars.iter().map(|k| digits(k, w)).collect();
+        let entries = |j: usize| -> Vec<Affine<Fq>> { (0..2).map(|i| tables[i][ds[i][j] - 1].clone()).collect() };
+        let top = window_count(w) - 1;
+        let mut acc = sum(&entries(top));
+        for j in (0..top).rev() {
+            acc = straus_step(&acc, &entries(j), w);
+        }
+        let expected = bases[0].mul(&scalars[0]).add(&bases[1].mul(&scalars[1]));
+        assert_eq!(eval(&acc, &inputs), expected.values());
+    }
+
+    #[test]
+    fn test_fixed_table() {
+        let (w, g) = (4, G1Affine::generator());
+        let table = fixed_table(&g, w);
+        assert_eq!(table.len(), window_count(w) << w);
+        assert_eq!(table[(2 << w) + 5], g.mul(&(BigUint::from(6u32) << (2 * w))));
+
+        let k = BigUint::from(0x0123456789abcdefu64) << 100;
+        let points: Vec<G1Affine> = digits(&k, w).iter().enumerate().map(|(j, d)| table[(j << w) + d - 1].clone()).collect();
+        let total = points.iter().rev().skip(1).fold(points[points.len() - 1].clone(), |acc, p| acc.add(p));
+        assert_eq!(total, g.mul(&k));
+    }
+}
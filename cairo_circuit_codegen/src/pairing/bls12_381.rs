@@ -0,0 +1,233 @@
+// Optimal ate pairing on BLS12-381. The loop runs on the bits of |x| with lines of the M-twist,
+// it has no correction step and f is conjugated at the end as x is negative
+use crate::curve::{Bls12_381, Curve};
+use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, sparse::Fq12Sparse014, FieldOps};
+
+use super::ate_miller::BitType;
+use super::final_exp::final_exponentiation;
+use super::line::{LineFn, PPre};
+
+pub type G2 = Affine<Fq2<Bls12_381>>;
+pub type Gt = Fq12<Bls12_381>;
+
+/// Bit 62 of |x| is the first one the loop runs on, the leading one is the initial T = Q
+pub const FIRST_BIT: usize = 62;
+
+pub fn step_double(acc: &mut G2, p_pre: &PPre) -> Fq12Sparse014<Bls12_381> {
+    LineFn::step_double(acc).line_fn_at_p_m(p_pre)
+}
+
+pub fn step_dbl_add(acc: &mut G2, p_pre: &PPre, q: &G2) -> (Fq12Sparse014<Bls12_381>, Fq12Sparse014<Bls12_381>) {
+    let (l1, l2) = LineFn::step_dbl_add(acc, q);
+    (l1.line_fn_at_p_m(p_pre), l2.line_fn_at_p_m(p_pre))
+}
+
+/// Bits 62 (1) and 61 (0) from f = 1, the first square is skipped: f = (l₁·l₂)²·l₃. T = Q at bit
+/// 62 so its lines are the tangent at Q and the chord of 2Q and Q
+pub fn miller_first_second(acc: &mut G2, p_pre: &PPre, q: &G2) -> Gt {
+    debug_assert_eq!(&Bls12_381::loop_digits()[FIRST_BIT - 1..=FIRST_BIT], &[BitType::O, BitType::P]);
+    let l1 = step_double(acc, p_pre);
+    let l2 = LineFn::step_add(acc, q).line_fn_at_p_m(p_pre);
+    let f = l1.mul_014_by_014(&l2).sqr();
+    f.mul_014(&step_double(acc, p_pre))
+}
+
+/// f² times the tangent line at T, T = 2T
+pub fn miller_bit_o(acc: &mut G2, f: &Gt, p_pre: &PPre) -> Gt {
+    f.sqr().mul_014(&step_double(acc, p_pre))
+}
+
+/// f² times the lines of T + Q and 2T + Q, T = 2T + Q
+pub fn miller_bit_p(acc: &mut G2, f: &Gt, p_pre: &PPre, q: &G2) -> Gt {
+    let (l1, l2) = step_dbl_add(acc, p_pre, q);
+    f.sqr().mul_014(&l1).mul_014(&l2)
+}
+
+/// Product of the Miller loops of every pair, f is squared once per bit
+pub fn multi_miller_loop(pairs: &[(Affine<Fq>, G2)]) -> Gt {
+    assert!(!pairs.is_empty(), "at least one pair");
+    let digits = Bls12_381::loop_digits();
+    let ppcs: Vec<PPre> = pairs.iter().map(|(p, _)| PPre::p_precompute(p)).collect();
+    let mut accs: Vec<G2> = pairs.iter().map(|(_, q)| q.clone()).collect();
+
+    let mut f = ppcs
+        .iter()
+        .zip(accs.iter_mut())
+        .zip(pairs)
+        .map(|((ppc, acc), (_, q))| miller_first_second(acc, ppc, q))
+        .reduce(|f, g| f.mul(&g))
+        .unwrap();
+
+    for i in (0..FIRST_BIT - 1).rev() {
+        f = f.sqr();
+        for ((ppc, acc), (_, q)) in ppcs.iter().zip(accs.iter_mut()).zip(pairs) {
+            f = match digits[i] {
+                BitType::O => f.mul_014(&step_double(acc, ppc)),
+                BitType::P => {
+                    let (l1, l2) = step_dbl_add(acc, ppc, q);
+                    f.mul_014(&l1).mul_014(&l2)
+                }
+                BitType::N => unreachable!("the loop runs on the binary digits of |x|"),
+            };
+        }
+    }
+    f.conjugate()
+}
+
+pub fn miller_loop(p: Affine<Fq>, q: G2) -> Gt {
+    multi_miller_loop(&[(p, q)])
+}
+
+pub fn pairing(p: Affine<Fq>, q: G2) -> Gt {
+    final_exponentiation(&miller_loop(p, q))
+}
+
+/// Product of the pairings of every pair, with a single final exponentiation
+pub fn multi_pairing(pairs: &[(Affine<Fq>, G2)]) -> Gt {
+    final_exponentiation(&multi_miller_loop(pairs))
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::{BigInt, BigUint};
+    use num_traits::{Num, One, Zero};
+
+    use super::{miller_loop, multi_pairing, pairing, Gt, G2, FIRST_BIT};
+    use crate::circuit::{adder::CairoCodeAdder, evaluator::Evaluator, inputs::InputAllocator};
+    use crate::circuit::utils::{bls12_381_miller_bit_circuits, bls12_381_miller_first_second_circuits, bls12_381_miller_last_circuit};
+    use crate::curve::{Bls12_381, Curve};
+    use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, fq6::Fq6, ECOperations, FieldOps, Frobenius};
+    use crate::pairing::{ate_miller::BitType, final_exp, line::PPre};
+
+    fn hex(value: &str) -> BigUint {
+        BigUint::from_str_radix(value, 16).unwrap()
+    }
+
+    // Generators of G1 and G2 as given by the IETF pairing-friendly curves draft
+    fn g1_generator() -> Vec<BigUint> {
+        vec![
+            hex("17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb"),
+            hex("08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1"),
+        ]
+    }
+
+    fn g2_generator() -> Vec<BigUint> {
+        vec![
+            hex("024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8"),
+            hex("13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e"),
+            hex("0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801"),
+            hex("0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be"),
+        ]
+    }
+
+    fn eval<A: CairoCodeAdder>(out: &A, inputs: &[Vec<BigUint>]) -> Vec<BigUint> {
+        Evaluator::new(inputs.concat(), Bls12_381::modulus()).eval_all(&out.circuits()).unwrap()
+    }
+
+    fn one() -> Vec<BigUint> {
+        (0..12).map(|i| if i == 0 { BigUint::one() } else { BigUint::zero() }).collect()
+    }
+
+    fn g1_input(idx: usize) -> Affine<Fq> {
+        Affine::<Fq>::new_input([idx, idx + 1])
+    }
+
+    fn g2_input(idx: usize) -> G2 {
+        G2::new_input([idx, idx + 1, idx + 2, idx + 3])
+    }
+
+    fn fq12_input(idx: usize) -> Gt {
+        Fq12::new_input(std::array::from_fn(|i| idx + i))
+    }
+
+    // Square and multiply from the leading one
+    fn pow(f: &Gt, exp: &BigUint) -> Gt {
+        (0..exp.bits() - 1).rev().fold(f.clone(), |acc, i| if exp.bit(i) { acc.sqr().mul(f) } else { acc.sqr() })
+    }
+
+    #[test]
+    fn test_generators_on_curve() {
+        let p = Bls12_381::modulus();
+        let [x, y] = [&g1_generator()[0], &g1_generator()[1]];
+        assert_eq!(y.modpow(&2u32.into(), &p), (x.pow(3) + 4u32) % &p);
+
+        let on_curve = g2_input(0).on_curve();
+        assert_eq!(eval(&on_curve, &[g2_generator()]), vec![BigUint::zero(); 2]);
+    }
+
+    #[test]
+    fn test_tower() {
+        // v³ = ξ = 1 + u
+        let v = Fq6::<Bls12_381>::new_input(std::array::from_fn(|i| i));
+        let mut inputs = vec![BigUint::zero(); 6];
+        inputs[2] = BigUint::one();
+        let xi = [BigUint::one(), BigUint::one()];
+        assert_eq!(eval(&(&(&v * &v) * &v), &[inputs]), [&xi[..], &[BigUint::zero(), BigUint::zero(), BigUint::zero(), BigUint::zero()]].concat());
+        let xi = Fq2::<Bls12_381>::new_input([0, 1]).mul_by_xi();
+        assert_eq!(eval(&xi, &[vec![3u32.into(), 5u32.into()]]), vec![Bls12_381::modulus() - 2u32, 8u32.into()]);
+
+        // The Frobenius map is f^p
+        let f: Vec<BigUint> = (0..12u32).map(|i| BigUint::from(i * i + 7)).collect();
+        let frob = fq12_input(0).frobenius(1);
+        assert_eq!(eval(&frob, std::slice::from_ref(&f)), eval(&pow(&fq12_input(0), &Bls12_381::modulus()), &[f]));
+    }
+
+    #[test]
+    fn test_hard_part_power() {
+        // 3(p⁴ - p² + 1)/r = (x - 1)²(x + p)(x² + p² - 1) + 3
+        let x = -BigInt::from(Bls12_381::X);
+        let (p, r) = (BigInt::from(Bls12_381::modulus()), BigInt::from(Bls12_381::scalar_modulus()));
+        let power = 3 * (p.pow(4) - p.pow(2) + 1) / &r;
+        assert_eq!(power, (&x - BigInt::one()).pow(2) * (&x + &p) * (x.pow(2) + p.pow(2) - 1) + 3);
+
+        // f^x on a cyclotomic element
+        let f: Vec<BigUint> = (0..12u32).map(|i| BigUint::from(3 * i + 2)).collect();
+        let g = final_exp::final_exponentiation(&fq12_input(0));
+        let g = eval(&g, &[f]);
+        let mut regs = std::collections::HashMap::from([(final_exp::F, fq12_input(0))]);
+        final_exp::run(&final_exp::exp_by_x("a", final_exp::F), &mut regs);
+        let g_x = eval(&regs["a"], std::slice::from_ref(&g));
+        let expected = pow(&fq12_input(0), &BigUint::from(Bls12_381::X)).conjugate();
+        assert_eq!(g_x, eval(&expected, &[g]));
+    }
+
+    #[test]
+    fn test_pairing() {
+        let (g1, g2) = (g1_generator(), g2_generator());
+        let e = pairing(g1_input(0), g2_input(2));
+        let e1 = eval(&e, &[g1.clone(), g2.clone()]);
+        assert_ne!(e1, one());
+
+        // e(P, Q)^r = 1
+        let r = Bls12_381::scalar_modulus();
+        assert_eq!(eval(&pow(&fq12_input(0), &r), std::slice::from_ref(&e1)), one());
+
+        // e(P, 2Q) = e(P, Q)²
+        let q2 = eval(&g2_input(0).double(), std::slice::from_ref(&g2));
+        assert_eq!(eval(&e, &[g1.clone(), q2]), eval(&fq12_input(0).sqr(), &[e1]));
+
+        // e(P, Q)·e(-P, Q) = 1 with a single final exponentiation, the Miller loop alone is not one
+        let neg_g1 = vec![g1[0].clone(), Bls12_381::modulus() - &g1[1]];
+        let e = multi_pairing(&[(g1_input(0), g2_input(2)), (g1_input(6), g2_input(8))]);
+        assert_eq!(eval(&e, &[g1.clone(), g2.clone(), neg_g1, g2.clone()]), one());
+        assert_ne!(eval(&miller_loop(g1_input(0), g2_input(2)), &[g1, g2]), one());
+    }
+
+    #[test]
+    fn test_miller_steps() {
+        // The step circuits of the generator chained bit by bit give the Miller loop
+        let (g1, g2) = (g1_generator(), g2_generator());
+        let ppc = PPre::p_precompute(&g1_input(0));
+        let ppc = [eval(ppc.neg_x_over_y(), std::slice::from_ref(&g1)), eval(ppc.y_inv(), std::slice::from_ref(&g1))].concat();
+        let step = |(f, acc): (Gt, G2), inputs: &[Vec<BigUint>]| (eval(&f, inputs), eval(&acc, inputs));
+
+        let (mut f, mut acc) = step(bls12_381_miller_first_second_circuits(&mut InputAllocator::new()), &[ppc.clone(), g2.clone()]);
+        let digits = Bls12_381::loop_digits();
+        for i in (1..FIRST_BIT - 1).rev() {
+            let q = if digits[i] == BitType::P { g2.clone() } else { vec![] };
+            (f, acc) = step(bls12_381_miller_bit_circuits(&mut InputAllocator::new(), digits[i]), &[ppc.clone(), acc, q, f]);
+        }
+        let f = eval(&bls12_381_miller_last_circuit(&mut InputAllocator::new()), &[ppc, acc, f]);
+        assert_eq!(f, eval(&miller_loop(g1_input(0), g2_input(2)), &[g1, g2]));
+    }
+}
@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+
+use crate::circuit::{adder::CairoCodeAdder, inputs::InputAllocator, optimizer::gate_count};
+use crate::curve::{Bls12_381, Curve};
+use crate::fields::{fq12::Fq12, FieldOps, Frobenius};
+
+/// Fq12 register of the final exponentiation program
+pub type Reg = &'static str;
+
+/// Register holding the Miller loop output, then the result of each part
+pub const F: Reg = "f";
+
+/// Gate budget of a final exponentiation circuit.
+///
+/// Bigger circuits make the Sierra of the caller grow past what a contract class can declare,
+/// around 2000 gates keeps one chunk in the range of an Fq12 inverse followed by a multiplication.
+pub const MAX_CHUNK_GATES: usize = 2000;
+
+/// One step of the final exponentiation, registers are written after every source is read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Op {
+    /// dst = src², only valid in the cyclotomic subgroup
+    Sqr(Reg, Reg),
+    Mul(Reg, Reg, Reg),
+    Conj(Reg, Reg),
+    /// dst = src^(pᵏ)
+    Frob(Reg, Reg, usize),
+    Inv(Reg, Reg),
+}
+
+impl Op {
+    pub fn dst(&self) -> Reg {
+        match *self {
+            Op::Sqr(dst, _) | Op::Mul(dst, _, _) | Op::Conj(dst, _) | Op::Frob(dst, _, _) | Op::Inv(dst, _) => dst,
+        }
+    }
+
+    pub fn srcs(&self) -> Vec<Reg> {
+        match *self {
+            Op::Mul(_, a, b) => vec![a, b],
+            Op::Sqr(_, src) | Op::Conj(_, src) | Op::Frob(_, src, _) | Op::Inv(_, src) => vec![src],
+        }
+    }
+
+    pub fn apply<C: Curve>(&self, regs: &mut HashMap<Reg, Fq12<C>>) {
+        let value = match *self {
+            Op::Sqr(_, src) => regs[src].cyclotomic_sqr(),
+            Op::Mul(_, a, b) => regs[a].mul(&regs[b]),
+            Op::Conj(_, src) => regs[src].conjugate(),
+            Op::Frob(_, src, k) => regs[src].frobenius(k),
+            Op::Inv(_, src) => regs[src].inv(),
+        };
+        regs.insert(self.dst(), value);
+    }
+
+    // Some components of the result are the source components, possibly negated, a circuit
+    // ending on this op would output bare inputs
+    fn passes_inputs(&self) -> bool {
+        matches!(self, Op::Conj(..) | Op::Frob(..))
+    }
+}
+
+pub fn run<C: Curve>(ops: &[Op], regs: &mut HashMap<Reg, Fq12<C>>) {
+    for op in ops {
+        op.apply(regs);
+    }
+}
+
+// dst = src^(2ⁿ)
+fn sqr_n(ops: &mut Vec<Op>, dst: Reg, src: Reg, n: usize) {
+    ops.push(Op::Sqr(dst, src));
+    ops.extend((1..n).map(|_| Op::Sqr(dst, dst)));
+}
+
+/// dst = src^(-t) with t = 4965661367192848881, the addition chain of `addchain_exp_by_neg_t` in
+/// fq_12_exponentiation.cairo: 62 squarings and 17 multiplications.
+///
+/// The temporaries t0 to t6 and tz are clobbered
+pub fn exp_by_neg_t(dst: Reg, src: Reg) -> Vec<Op> {
+    use Op::Mul;
+    let mut ops = vec![Op::Sqr("t3", src), Op::Sqr("t5", "t3"), Op::Sqr("tz", "t5"), Op::Sqr("t0", "tz")];
+    ops.extend([
+        Mul("t2", src, "t0"),
+        Mul("t0", "t3", "t2"),
+        Mul("t1", src, "t0"),
+        Mul("t4", "tz", "t2"),
+        Op::Sqr("t6", "t2"),
+        Mul("t1", "t0", "t1"),
+        Mul("t0", "t3", "t1"),
+    ]);
+    sqr_n(&mut ops, "t6", "t6", 6);
+    ops.extend([Mul("t5", "t5", "t6"), Mul("t5", "t4", "t5")]);
+    sqr_n(&mut ops, "t5", "t5", 7);
+    ops.push(Mul("t4", "t4", "t5"));
+    sqr_n(&mut ops, "t4", "t4", 8);
+    ops.extend([Mul("t4", "t0", "t4"), Mul("t3", "t3", "t4")]);
+    sqr_n(&mut ops, "t3", "t3", 6);
+    ops.push(Mul("t2", "t2", "t3"));
+    sqr_n(&mut ops, "t2", "t2", 8);
+    ops.push(Mul("t2", "t0", "t2"));
+    sqr_n(&mut ops, "t2", "t2", 6);
+    ops.push(Mul("t2", "t0", "t2"));
+    sqr_n(&mut ops, "t2", "t2", 10);
+    ops.push(Mul("t1", "t1", "t2"));
+    sqr_n(&mut ops, "t1", "t1", 6);
+    ops.extend([Mul("t0", "t0", "t1"), Mul("tz", "tz", "t0"), Op::Conj(dst, "tz")]);
+    ops
+}
+
+/// f = f^((p⁶ - 1)(p² + 1)), conjugate(f)·f⁻¹ then f^(p²)·f, the result is cyclotomic
+pub fn easy_part() -> Vec<Op> {
+    vec![Op::Inv("f_inv", F), Op::Conj(F, F), Op::Mul(F, F, "f_inv"), Op::Frob("f_p2", F, 2), Op::Mul(F, "f_p2", F)]
+}
+
+/// f = f^((p⁴ - p² + 1)/r), the Fuentes-Castañeda and Duquesne-Ghammam formula of
+/// `final_exponentiation_hard_part` in fq_12_exponentiation.cairo, with the same register names
+/// but e_sqr for its f
+pub fn hard_part() -> Vec<Op> {
+    use Op::{Conj, Frob, Mul, Sqr};
+    let mut ops = exp_by_neg_t("a", F);
+    ops.extend([Sqr("b", "a"), Sqr("c", "b"), Mul("d", "c", "b")]);
+    ops.extend(exp_by_neg_t("e", "d"));
+    ops.push(Sqr("e_sqr", "e"));
+    ops.extend(exp_by_neg_t("g", "e_sqr"));
+    ops.extend([
+        Conj("h", "d"),
+        Conj("i", "g"),
+        Mul("j", "i", "e"),
+        Mul("k", "j", "h"),
+        Mul("l", "k", "b"),
+        Mul("m_tmp", "k", "e"),
+        Mul("n", F, "m_tmp"),
+        Frob("o", "l", 1),
+        Mul("p", "o", "n"),
+        Frob("q", "k", 2),
+        Mul("r", "q", "p"),
+        Conj("s", F),
+        Mul("t", "s", "l"),
+        Frob("u", "t", 3),
+        Mul(F, "u", "r"),
+    ]);
+    ops
+}
+
+/// dst = src^x for the BLS12-381 parameter x = -0xd201000000010000, square and multiply on the
+/// bits of |x| then a conjugate. The temporary tx is clobbered
+pub fn exp_by_x(dst: Reg, src: Reg) -> Vec<Op> {
+    let mut ops = vec![Op::Sqr("tx", src)];
+    for i in (0..63).rev() {
+        if Bls12_381::X >> i & 1 == 1 {
+            ops.push(Op::Mul("tx", "tx", src));
+        }
+        if i > 0 {
+            ops.push(Op::Sqr("tx", "tx"));
+        }
+    }
+    ops.push(Op::Conj(dst, "tx"));
+    ops
+}
+
+/// f = f^(3(p⁴ - p² + 1)/r) for BLS12 curves, the Hayashida-Hayasaka-Teruya decomposition
+/// (x - 1)²·(x + p)·(x² + p² - 1) + 3 of that power
+pub fn bls12_hard_part() -> Vec<Op> {
+    use Op::{Conj, Frob, Mul, Sqr};
+    let mut ops = vec![];
+    // a = f^(x - 1), b = a^(x - 1)
+    for (dst, src) in [("a", F), ("b", "a")] {
+        ops.extend(exp_by_x("t0", src));
+        ops.extend([Conj("t1", src), Mul(dst, "t0", "t1")]);
+    }
+    // c = b^(x + p)
+    ops.extend(exp_by_x("t0", "b"));
+    ops.extend([Frob("t1", "b", 1), Mul("c", "t0", "t1")]);
+    // d = c^(x² + p² - 1)
+    ops.extend(exp_by_x("t0", "c"));
+    ops.extend(exp_by_x("t1", "t0"));
+    ops.extend([Frob("t2", "c", 2), Mul("t0", "t1", "t2"), Conj("t2", "c"), Mul("d", "t0", "t2")]);
+    // d·f³
+    ops.extend([Sqr("t0", F), Mul("t0", "t0", F), Mul(F, "d", "t0")]);
+    ops
+}
+
+/// f^((p¹² - 1)/r), raised to 2u(6u² + 3u + 1) by the BN254 hard part as in the Cairo verifier
+/// and to 3 by the BLS12 one, both powers are coprime to r so the pairing stays bilinear and
+/// non-degenerate
+pub fn final_exponentiation<C: Curve>(f: &Fq12<C>) -> Fq12<C> {
+    let mut regs = HashMap::from([(F, f.clone())]);
+    run(&easy_part(), &mut regs);
+    run(&C::hard_part(), &mut regs);
+    regs.remove(F).unwrap()
+}
+
+/// Run of consecutive ops evaluated by one circuit
+#[derive(Debug, Clone)]
+pub struct Chunk {
+    pub ops: Vec<Op>,
+    /// Registers read before they are written, in the order they are first read
+    pub inputs: Vec<Reg>,
+    /// Registers written and still read after the chunk
+    pub outputs: Vec<Reg>,
+}
+
+impl Chunk {
+    /// Values of the output registers, the input registers are allocated from `inputs` in order
+    pub fn circuits<C: Curve>(&self, inputs: &mut InputAllocator) -> Vec<Fq12<C>> {
+        let mut regs: HashMap<Reg, Fq12<C>> = HashMap::new();
+        for &reg in &self.inputs {
+            regs.insert(reg, inputs.alloc(reg));
+        }
+        run(&self.ops, &mut regs);
+        self.outputs.iter().map(|reg| regs[reg].clone()).collect()
+    }
+}
+
+/// Splits `ops` greedily into circuits of at most `max_gates` gates, `result` is the register
+/// read after the last op.
+///
+/// A chunk never ends on a conjugate or a Frobenius map, their result is merged with the next op
+/// so every output is read from a gate. A single op over the budget gets a chunk of its own.
+pub fn chunks<C: Curve>(ops: &[Op], result: Reg, max_gates: usize) -> Vec<Chunk> {
+    let mut chunks = vec![];
+    let mut start = 0;
+    while start < ops.len() {
+        let mut inputs = InputAllocator::new();
+        let mut regs: HashMap<Reg, Fq12<C>> = HashMap::new();
+        let mut end = start;
+        while end < ops.len() {
+            let op = &ops[end];
+            let mut next = regs.clone();
+            for src in op.srcs() {
+                next.entry(src).or_insert_with(|| inputs.alloc(src));
+            }
+            op.apply(&mut next);
+
+            let written: Vec<Reg> = ops[start..=end].iter().map(Op::dst).collect();
+            let gates = gate_count(written.iter().flat_map(|reg| next[reg].circuits()));
+            let forced = end == start || ops[end - 1].passes_inputs();
+            if gates > max_gates && !forced {
+                break;
+            }
+            regs = next;
+            end += 1;
+        }
+        chunks.push(chunk(ops, start, end, result));
+        start = end;
+    }
+    chunks
+}
+
+fn chunk(ops: &[Op], start: usize, end: usize, result: Reg) -> Chunk {
+    let mut inputs = vec![];
+    let mut written = vec![];
+    for op in &ops[start..end] {
+        for src in op.srcs() {
+            if !written.contains(&src) && !inputs.contains(&src) {
+                inputs.push(src);
+            }
+        }
+        if !written.contains(&op.dst()) {
+            written.push(op.dst());
+        }
+    }
+
+    // Live if read later before being overwritten, the result is read after the last op
+    let live = |reg: Reg| {
+        for op in &ops[end..] {
+            if op.srcs().contains(&reg) {
+                return true;
+            }
+            if op.dst() == reg {
+                return false;
+            }
+        }
+        reg == result
+    };
+    let outputs = written.into_iter().filter(|&reg| live(reg)).collect();
+    Chunk { ops: ops[start..end].to_vec(), inputs, outputs }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use num_bigint::BigUint;
+
+    use super::{chunks, easy_part, exp_by_neg_t, final_exponentiation, hard_part, run, Op, F, MAX_CHUNK_GATES};
+    use crate::circuit::{adder::CairoCodeAdder, evaluator::{bn254_modulus, bn254_scalar_modulus}, inputs::InputAllocator, optimizer::gate_count};
+    use crate::curve::Bn254;
+    use crate::reference::Field;
+    use crate::utils::utils::{eval, fq12_input, random_cyclotomic, random_fq12, rng};
+
+    // 2u(6u² + 3u + 1), the power of the reduced pairing the hard part actually computes
+    fn hard_part_power() -> BigUint {
+        let u = BigUint::from(4965661367192848881u64);
+        BigUint::from(2u32) * &u * (BigUint::from(6u32) * &u * &u + 3u32 * &u + 1u32)
+    }
+
+    #[test]
+    fn test_exp_by_neg_t() {
+        let mut regs = HashMap::from([(F, fq12_input(0))]);
+        run(&exp_by_neg_t("a", F), &mut regs);
+
+        let c = random_cyclotomic(&mut rng());
+        let expected = c.pow(&BigUint::from(4965661367192848881u64)).inv();
+        assert_eq!(eval(&regs["a"], &[c.values()]), expected.values());
+    }
+
+    #[test]
+    fn test_final_exponentiation() {
+        let f = random_fq12(&mut rng());
+        let out = final_exponentiation(&fq12_input(0));
+        let exp = (bn254_modulus().pow(12) - 1u32) / bn254_scalar_modulus();
+        assert_eq!(eval(&out, &[f.values()]), f.pow(&exp).pow(&hard_part_power()).values());
+    }
+
+    #[test]
+    fn test_final_exp_chunks() {
+        // The chunks of the generators chained through concrete registers give the whole result
+        let f = random_fq12(&mut rng());
+        let mut regs: HashMap<&str, Vec<BigUint>> = HashMap::from([(F, f.values())]);
+        for ops in [easy_part(), hard_part()] {
+            for chunk in chunks::<Bn254>(&ops, F, MAX_CHUNK_GATES) {
+                let outputs = chunk.circuits::<Bn254>(&mut InputAllocator::new());
+                let inputs: Vec<Vec<BigUint>> = chunk.inputs.iter().map(|reg| regs[reg].clone()).collect();
+                // Only a single op or one merged into a conjugate or Frobenius map goes over budget
+                let merged = chunk.ops.len() == 1 || matches!(chunk.ops[chunk.ops.len() - 2], Op::Conj(..) | Op::Frob(..));
+                assert!(merged || gate_count(outputs.iter().flat_map(|out| out.circuits())) <= MAX_CHUNK_GATES);
+                for (reg, out) in chunk.outputs.iter().zip(&outputs) {
+                    regs.insert(reg, eval(out, &inputs));
+                }
+            }
+        }
+        assert_eq!(regs[F], eval(&final_exponentiation(&fq12_input(0)), &[f.values()]));
+    }
+}
@@ -0,0 +1,205 @@
+use std::collections::VecDeque;
+
+use num_bigint::BigUint;
+
+use super::ate_miller::{ate_miller_loop_steps, multi_miller_loop_steps, BitType, ATE_LOOP};
+use super::line::{miller_utils, LineFn, PPre};
+use super::MillerSteps;
+use crate::circuit::{adder::CairoCodeAdder, evaluator::{bn254_modulus, Evaluator}, function::constant_array_item};
+use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, ECOperations, FieldConstants, FieldOps};
+use crate::reference;
+
+/// Every line of the Miller loop of Q, in the order `ate_miller_loop_steps` evaluates them: three
+/// for bits 64 and 63, one per 0 bit, two per 1 or -1 bit and two for the correction step
+pub fn lines(q: &Affine<Fq2>) -> Vec<LineFn> {
+    let neg_q = q.neg();
+    let mut acc = q.clone();
+    let mut lines = vec![LineFn::step_double(&mut acc)];
+    let (l1, l2) = LineFn::step_dbl_add(&mut acc, &neg_q);
+    lines.extend([l1, l2]);
+
+    for i in (0..63).rev() {
+        match ATE_LOOP[i] {
+            BitType::O => lines.push(LineFn::step_double(&mut acc)),
+            BitType::P | BitType::N => {
+                let q = if ATE_LOOP[i] == BitType::P { q } else { &neg_q };
+                let (l1, l2) = LineFn::step_dbl_add(&mut acc, q);
+                lines.extend([l1, l2]);
+            }
+        }
+    }
+    let (d, e) = LineFn::correction_step(&mut acc, q);
+    lines.extend([d, e]);
+    lines
+}
+
+/// Number of lines of a Miller loop, the length of `lines`
+pub fn line_count() -> usize {
+    3 + (0..63).map(|i| if ATE_LOOP[i] == BitType::O { 1 } else { 2 }).sum::<usize>() + 2
+}
+
+/// (slope, c) of every line of a known Q, computed with the same formulas as the circuits
+pub fn line_coeffs(q: &reference::G2Affine) -> Vec<(reference::Fq2, reference::Fq2)> {
+    let lines = lines(&Affine::<Fq2>::new_input([0, 1, 2, 3]));
+    let inputs = [&q.x.c0, &q.x.c1, &q.y.c0, &q.y.c1].iter().map(|c| c.0.clone()).collect();
+    let circuits: Vec<_> = lines.iter().flat_map(|line| line.circuits()).collect();
+    let values = Evaluator::new(inputs, bn254_modulus()).eval_all(&circuits).expect("Q is a point of order r");
+
+    let fq2 = |k: usize| reference::Fq2::new(reference::Fq::new(values[k].clone()), reference::Fq::new(values[k + 1].clone()));
+    (0..lines.len()).map(|k| (fq2(4 * k), fq2(4 * k + 2))).collect()
+}
+
+/// Cairo constant array of the line coefficients of Q, slope.c0, slope.c1, c.c0 then c.c1 of
+/// every line
+pub fn lines_constant(name: &str, q: &reference::G2Affine) -> String {
+    let values: Vec<BigUint> = line_coeffs(q)
+        .into_iter()
+        .flat_map(|(slope, c)| [slope.c0.0, slope.c1.0, c.c0.0, c.c1.0])
+        .collect();
+    constant_array_item(name, &values)
+}
+
+/// Miller steps of a fixed Q, its lines are known and only evaluated at P.
+///
+/// The accumulator is never read, the lines already went through every multiple of Q
+#[derive(Debug, Clone)]
+pub struct FixedLines {
+    ppc: PPre,
+    lines: VecDeque<LineFn>,
+}
+
+impl FixedLines {
+    pub fn new(ppc: PPre, lines: Vec<LineFn>) -> Self {
+        assert_eq!(lines.len(), line_count(), "one line per step of the loop");
+        Self { ppc, lines: lines.into() }
+    }
+
+    fn next(&mut self) -> LineFn {
+        self.lines.pop_front().expect("every line is evaluated once")
+    }
+
+    fn next_two_to_f(&mut self, f: &mut Fq12) {
+        let (l1, l2) = (self.next(), self.next());
+        miller_utils::lines_to_f(f, &self.ppc, &l1, &l2);
+    }
+}
+
+impl MillerSteps for FixedLines {
+    type Acc = Affine<Fq2>;
+
+    fn sqr_target(&mut self, _i: u32, _acc: &mut Self::Acc, f: &mut Fq12) {
+        *f = f.sqr();
+    }
+
+    fn miller_first_second(&mut self, _i1: u32, _i2: u32, _acc: &mut Self::Acc) -> Fq12 {
+        let (l0, l1, l2) = (self.next(), self.next(), self.next());
+        miller_utils::lines_first_second(&self.ppc, &l0, &l1, &l2)
+    }
+
+    fn miller_bit_o(&mut self, _i: u32, _acc: &mut Self::Acc, f: &mut Fq12) {
+        let line = self.next();
+        *f = f.mul_034(&line.line_fn_at_p(&self.ppc));
+    }
+
+    fn miller_bit_p(&mut self, _i: u32, _acc: &mut Self::Acc, f: &mut Fq12) {
+        self.next_two_to_f(f);
+    }
+
+    fn miller_bit_n(&mut self, _i: u32, _acc: &mut Self::Acc, f: &mut Fq12) {
+        self.next_two_to_f(f);
+    }
+
+    fn miller_last(&mut self, _acc: &mut Self::Acc, f: &mut Fq12) {
+        self.next_two_to_f(f);
+    }
+}
+
+// Stands in for the accumulator the steps of a fixed Q ignore
+fn unused_acc() -> Affine<Fq2> {
+    let zero = Fq2::new(Fq::zero(), Fq::zero());
+    Affine::<Fq2>::new(zero.clone(), zero)
+}
+
+/// Miller loop of P and a fixed Q given by its `lines`
+pub fn fixed_miller_loop(p: &Affine<Fq>, lines: Vec<LineFn>) -> Fq12 {
+    let mut steps = FixedLines::new(PPre::p_precompute(p), lines);
+    ate_miller_loop_steps(&mut steps, &mut unused_acc())
+}
+
+/// Product of the Miller loops of every P and fixed Q, f is squared once per bit
+pub fn multi_fixed_miller_loop(pairs: Vec<(Affine<Fq>, Vec<LineFn>)>) -> Fq12 {
+    let mut steps: Vec<FixedLines> =
+        pairs.into_iter().map(|(p, lines)| FixedLines::new(PPre::p_precompute(&p), lines)).collect();
+    let mut accs = vec![unused_acc(); steps.len()];
+    multi_miller_loop_steps(&mut steps, &mut accs)
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+
+    use super::{fixed_miller_loop, line_coeffs, line_count, multi_fixed_miller_loop};
+    use crate::circuit::inputs::InputAllocator;
+    use crate::circuit::utils::{fixed_miller_bit_circuit, fixed_miller_first_second_circuit, fixed_miller_last_circuit};
+    use crate::pairing::ate_miller::{ate_miller_loop, multi_miller_loop, BitType, ATE_LOOP};
+    use crate::pairing::line::LineFn;
+    use crate::reference::{Field, Fq, G2Affine};
+    use crate::utils::utils::{eval, fq2_input, g1_input, g2_input, multi_pairs, two_pairs};
+
+    // Lines of a fixed Q read from consecutive inputs starting at idx, and the values they are fed with
+    fn fixed_line_inputs(idx: usize, q: &G2Affine) -> (Vec<LineFn>, Vec<BigUint>) {
+        let lines = (0..line_count()).map(|k| LineFn::from_coeffs(fq2_input(idx + 4 * k), fq2_input(idx + 4 * k + 2)));
+        let values = line_coeffs(q).iter().flat_map(|(slope, c)| [slope.values(), c.values()].concat()).collect();
+        (lines.collect(), values)
+    }
+
+    #[test]
+    fn test_fixed_miller_loop() {
+        let q = G2Affine::generator();
+        let q1 = q.double();
+        let (x, y) = (Fq::from_u64(1), Fq::from_u64(2));
+        let (lines0, values0) = fixed_line_inputs(2, &q);
+        let idx1 = 2 + values0.len();
+        let (lines1, values1) = fixed_line_inputs(idx1 + 2, &q1);
+
+        let single = fixed_miller_loop(&g1_input(0), lines0.clone());
+        let expected = eval(&ate_miller_loop(g1_input(0), g2_input(2)), &[x.values(), y.values(), q.values()]);
+        assert_eq!(eval(&single, &[x.values(), y.values(), values0.clone()]), expected);
+
+        let f = multi_fixed_miller_loop(vec![(g1_input(0), lines0), (g1_input(idx1), lines1)]);
+        let inputs = [x.values(), y.values(), values0, x.values(), y.neg().values(), values1];
+        assert_eq!(eval(&f, &inputs), eval(&multi_miller_loop(multi_pairs()), &two_pairs(true, &q, &q1)));
+    }
+
+    #[test]
+    fn test_fixed_miller_steps() {
+        // The fixed_pairing_2 step functions chained as its Cairo entry point runs them
+        let (x, y, q) = (Fq::from_u64(1), Fq::from_u64(2), G2Affine::generator());
+        let q1 = q.double();
+        let ppc = |y: &Fq| [x.neg().div(y).values(), y.inv().values()].concat();
+        let ppcs = [ppc(&y), ppc(&y.neg())];
+        let coeffs = [line_coeffs(&q), line_coeffs(&q1)];
+        let mut next = 0;
+        let mut pair_inputs = |count: usize| -> Vec<Vec<BigUint>> {
+            let inputs = (0..2)
+                .flat_map(|i| {
+                    let lines = coeffs[i][next..next + count].iter().flat_map(|(slope, c)| [slope.values(), c.values()]);
+                    std::iter::once(ppcs[i].clone()).chain(lines)
+                })
+                .collect();
+            next += count;
+            inputs
+        };
+
+        let mut f = eval(&fixed_miller_first_second_circuit(&mut InputAllocator::new(), 2), &pair_inputs(3));
+        for i in (0..63).rev() {
+            let count = if ATE_LOOP[i] == BitType::O { 1 } else { 2 };
+            let inputs = [pair_inputs(count), vec![f]].concat();
+            f = eval(&fixed_miller_bit_circuit(&mut InputAllocator::new(), 2, ATE_LOOP[i]), &inputs);
+        }
+        let inputs = [pair_inputs(2), vec![f]].concat();
+        let f = eval(&fixed_miller_last_circuit(&mut InputAllocator::new(), 2), &inputs);
+
+        assert_eq!(f, eval(&multi_miller_loop(multi_pairs()), &two_pairs(true, &q, &q1)));
+    }
+}
@@ -1,5 +1,6 @@
-use miller_utils::step_dbl_add;
-use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, sparse::Fq12Sparse034, ECOperations, FieldOps, FieldUtils};
+use crate::curve::{Bn254, Curve};
+use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, sparse::{Fq12Sparse014, Fq12Sparse034}, ECOperations, FieldOps, FieldUtils, Frobenius};
+use super::ate_miller::{BitType, ATE_LOOP};
 use super::{MillerPrecompute, MillerSteps};
 
 #[derive(Debug, Clone)]
@@ -8,7 +9,6 @@ pub struct Precompute {
     q: Affine<Fq2>,
     neg_q: Affine<Fq2>,
     ppc: PPre,
-    inp: Option<[usize; 6]>,
 }
 
 #[derive(Debug, Clone)]
@@ -17,10 +17,11 @@ pub struct PPre {
     y_inv: Fq,
 }
 
+/// Line y = slope·x - c through points of the twist
 #[derive(Debug, Clone)]
-pub struct LineFn {
-    slope: Fq2,
-    c: Fq2,
+pub struct LineFn<C: Curve = Bn254> {
+    slope: Fq2<C>,
+    c: Fq2<C>,
 }
 
 impl Precompute {
@@ -43,14 +44,18 @@ impl Precompute {
 
 impl MillerPrecompute for Precompute {
     type Precompute = Precompute;
-    fn precompute(p: Affine<Fq>, q: Affine<Fq2>, inp: Option<[usize; 6]>) -> (Self, Affine<Fq2>) {
+    fn precompute(p: Affine<Fq>, q: Affine<Fq2>) -> (Self, Affine<Fq2>) {
         let ppc = PPre::p_precompute(&p);
-        let precompute = Self { p, q: q.clone(), neg_q: q.neg(), ppc, inp }; // refactor clones
+        let precompute = Self { p, q: q.clone(), neg_q: q.neg(), ppc }; // refactor clones
         (precompute, q)
     }
 }
 
 impl PPre {
+    pub fn new(neg_x_over_y: Fq, y_inv: Fq) -> Self {
+        Self { neg_x_over_y, y_inv }
+    }
+
     pub fn neg_x_over_y(&self) -> &Fq {
         &self.neg_x_over_y
     }
@@ -65,137 +70,181 @@ impl PPre {
     }
 }
 
+// The bit indices only check the steps are called in the order of the loop constant
 impl MillerSteps for Precompute {    
-    fn sqr_target(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12) {
+    type Acc = Affine<Fq2>;
+
+    fn sqr_target(&mut self, i: u32, _acc: &mut Self::Acc, f: &mut Fq12) {
+        debug_assert!(i < 63, "bit {} is not squared, f starts at one", i);
         *f = f.sqr();
     }
 
-    fn miller_first_second(&mut self, i1: u32, i2: u32, acc: &mut Affine<Fq2>) -> Fq12 {
-        let l0 = miller_utils::step_double(acc, &self.ppc, &self.p);
-        let f_01234 = l0.sqr_034();
-
-        let (l1, l2) = step_dbl_add(acc, &self.ppc, &self.p, &self.neg_q);
-        // f_01234.mul_01234_01234(&l1.mul_034_by_034(&l2))
-        Fq12::default()
+    fn miller_first_second(&mut self, i1: u32, i2: u32, acc: &mut Self::Acc) -> Fq12 {
+        debug_assert_eq!((ATE_LOOP[i1 as usize], ATE_LOOP[i2 as usize]), (BitType::O, BitType::N));
+        miller_utils::miller_first_second(acc, &self.ppc, &self.neg_q)
     }
 
-    fn miller_bit_o(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12) {
-        miller_utils::step_double_to_f(acc, f, &self.ppc, &self.p);
+    fn miller_bit_o(&mut self, i: u32, acc: &mut Self::Acc, f: &mut Fq12) {
+        debug_assert_eq!(ATE_LOOP[i as usize], BitType::O);
+        miller_utils::step_double_to_f(acc, f, &self.ppc);
     }
     
-    fn miller_bit_p(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12) {
-        miller_utils::step_dbl_add_to_f(acc, f, &self.ppc, &self.p, &self.q);
+    fn miller_bit_p(&mut self, i: u32, acc: &mut Self::Acc, f: &mut Fq12) {
+        debug_assert_eq!(ATE_LOOP[i as usize], BitType::P);
+        miller_utils::step_dbl_add_to_f(acc, f, &self.ppc, &self.q);
     }
     
-    fn miller_bit_n(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12) {
-        miller_utils::step_dbl_add_to_f(acc, f, &self.ppc, &self.p, &self.neg_q);
+    fn miller_bit_n(&mut self, i: u32, acc: &mut Self::Acc, f: &mut Fq12) {
+        debug_assert_eq!(ATE_LOOP[i as usize], BitType::N);
+        miller_utils::step_dbl_add_to_f(acc, f, &self.ppc, &self.neg_q);
     }
     
-    fn miller_last(&mut self, acc: &mut Affine<Fq2>, f: &mut Fq12, pi_idx: [usize; 6]) {
-        miller_utils::correction_step_to_f(acc, f, &self.ppc, &self.p, &self.q, pi_idx);
+    fn miller_last(&mut self, acc: &mut Self::Acc, f: &mut Fq12) {
+        miller_utils::correction_step_to_f(acc, f, &self.ppc, &self.q);
     }    
 }
 
-impl LineFn {
-    pub fn slope(&self) -> &Fq2 {
+impl<C: Curve> LineFn<C> {
+    pub fn slope(&self) -> &Fq2<C> {
         &self.slope
     }
 
-    pub fn c(&self) -> &Fq2 {
+    pub fn c(&self) -> &Fq2<C> {
         &self.c
     }
 
-    pub fn line_fn(slope: &Fq2, s: &Affine<Fq2>) -> LineFn {
+    pub fn new(slope: &Fq2<C>, s: &Affine<Fq2<C>>) -> Self {
         LineFn { slope: slope.clone(), c: &(slope * s.x()) - s.y() } 
     }
 
-    pub fn line_fn_at_p(&self, p_pre: &PPre) -> Fq12Sparse034 {
-        Fq12Sparse034::new(self.slope().scale(&p_pre.neg_x_over_y()), self.c.scale(&p_pre.y_inv()))
+    /// Line y = slope·x - c given by its coefficients, those of a precomputed line
+    pub fn from_coeffs(slope: Fq2<C>, c: Fq2<C>) -> Self {
+        LineFn { slope, c }
     }
 
-    // Fix the clone
-    pub fn step_double(acc: &mut Affine<Fq2>) -> LineFn {
-        // λ = (yS−yQ)/(xS−xQ)
+    /// The line evaluated at P on a D-twist and divided by y: 1 + slope·(-x/y)·w + c/y·vw
+    pub fn line_fn_at_p(&self, p_pre: &PPre) -> Fq12Sparse034<C> {
+        Fq12Sparse034::new(self.slope().scale(p_pre.neg_x_over_y()), self.c.scale(p_pre.y_inv()))
+    }
+
+    /// The line evaluated at P on an M-twist, times w³ and divided by y: c/y + slope·(-x/y)·v + vw
+    pub fn line_fn_at_p_m(&self, p_pre: &PPre) -> Fq12Sparse014<C> {
+        Fq12Sparse014::new(self.c.scale(p_pre.y_inv()), self.slope().scale(p_pre.neg_x_over_y()))
+    }
+
+    // Lines go through the accumulator before it is updated, the updated point is the negated
+    // third intersection and is not on them
+    pub fn step_double(acc: &mut Affine<Fq2<C>>) -> Self {
+        // λ = 3x²/2y
         let slope = acc.tangent(); 
+        let line = Self::new(&slope, acc);
         // p = (λ²-2x, λ(x-xr)-y)
-        let new_point = acc.pt_on_slope(&slope, &acc.x().clone());
-        *acc = new_point; 
-        Self::line_fn(&slope, acc) 
+        *acc = acc.pt_on_slope(&slope, acc.x());
+        line
     }
 
-    pub fn step_add(acc: &mut Affine<Fq2>, q: &Affine<Fq2>) -> LineFn {
+    pub fn step_add(acc: &mut Affine<Fq2<C>>, q: &Affine<Fq2<C>>) -> Self {
         // λ = (yS−yQ)/(xS−xQ)
         let slope = acc.chord(q);
+        let line = Self::new(&slope, acc);
         // p = (λ²-2x, λ(x-xr)-y)
-        let new_point = acc.pt_on_slope(&slope, &q.x());
-        *acc = new_point; 
-
-        Self::line_fn(&slope, acc)
+        *acc = acc.pt_on_slope(&slope, q.x());
+        line
     }    
 
-    pub fn step_dbl_add(acc: &mut Affine<Fq2>, q: &Affine<Fq2>) -> (LineFn, LineFn) {
+    pub fn step_dbl_add(acc: &mut Affine<Fq2<C>>, q: &Affine<Fq2<C>>) -> (Self, Self) {
         let slope1 = acc.chord(q);
-        let x1 = acc.x_on_slope(&slope1, &q.x());
-        let line1 = Self::line_fn(&slope1, acc); 
+        let x1 = acc.x_on_slope(&slope1, q.x());
+        let line1 = Self::new(&slope1, acc); 
 
+        // Both lines go through acc, the second one through acc + q as well
         let slope2 = -slope1 - (acc.y() + acc.y()) / (&x1 - acc.x());
+        let line2 = Self::new(&slope2, acc); 
         *acc = acc.pt_on_slope(&slope2, &x1);
-        let line2 = Self::line_fn(&slope2, acc); 
 
         (line1, line2)
     }
+}
 
-    pub fn correction_step(acc: &mut Affine<Fq2>, q: &Affine<Fq2>, pi_idx: [usize; 6]) -> (LineFn, LineFn) {
-        // Circuit input indexes for constants
-        let q1x2_idx: [usize; 2] = [pi_idx[0], pi_idx[1]];
-        let q1x3_idx: [usize; 2] = [pi_idx[2], pi_idx[3]];
-        let q2x2_idx: usize = pi_idx[4];
-        let q2x3_idx: usize = pi_idx[5];
-        let affine_q1_idx: [usize; 4] = [pi_idx[0], pi_idx[1], pi_idx[2], pi_idx[3]];
-        let affine_q2_idx: [usize; 4] = [pi_idx[4], pi_idx[5], 0, 0];
-
-        let q1 =  Affine::<Fq2>::new(q.x().conjugate().fq2_mul_nr(q1x2_idx), q.y().conjugate().fq2_mul_nr(q1x3_idx), affine_q1_idx);
-        let q2 = Affine::<Fq2>::new(q.x().fq2_scale_nr(q2x2_idx), q.y().fq2_scale_nr(q2x3_idx).neg(), affine_q2_idx);
+impl LineFn {
+    // Lines through ψ(Q) and -ψ²(Q)
+    pub fn correction_step(acc: &mut Affine<Fq2>, q: &Affine<Fq2>) -> (LineFn, LineFn) {
+        let q1 = q.frobenius(1);
+        let q2 = q.frobenius(2).neg();
 
         let d = Self::step_add(acc, &q1);
         let slope = acc.chord(&q2);
-        let e = LineFn::line_fn(&slope, acc);
+        let e = LineFn::new(&slope, acc);
 
         (d, e)
     }
 }
 
-mod miller_utils {
-    use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, sparse::Fq12Sparse034};
+pub(crate) mod miller_utils {
+    use crate::fields::{
+        affine::Affine,
+        fq::Fq,
+        fq12::Fq12,
+        fq2::Fq2,
+        fq6::Fq6,
+        sparse::{Fq12Sparse01234, Fq12Sparse034},
+        FieldConstants,
+    };
     use super::{LineFn, PPre};
 
-    pub fn step_double_to_f(acc: &mut Affine<Fq2>, f: &mut Fq12, p_pre: &PPre, p: &Affine<Fq>) {
-        *f = f.mul_034(&step_double(acc, p_pre, p));
+    /// Bits 64 (O) and 63 (N) from f = 1, the first square is skipped: f = l₀²·l₁·l₂
+    pub fn miller_first_second(acc: &mut Affine<Fq2>, p_pre: &PPre, neg_q: &Affine<Fq2>) -> Fq12 {
+        let l0 = step_double(acc, p_pre);
+        let (l1, l2) = step_dbl_add(acc, p_pre, neg_q);
+        l0.sqr_034().mul_01234_01234(&mul_lines(&l1, &l2))
+    }
+
+    /// `miller_first_second` from lines already computed
+    pub fn lines_first_second(p_pre: &PPre, l0: &LineFn, l1: &LineFn, l2: &LineFn) -> Fq12 {
+        let (l1, l2) = (l1.line_fn_at_p(p_pre), l2.line_fn_at_p(p_pre));
+        l0.line_fn_at_p(p_pre).sqr_034().mul_01234_01234(&mul_lines(&l1, &l2))
+    }
+
+    /// f·l₁(P)·l₂(P) for lines already computed
+    pub fn lines_to_f(f: &mut Fq12, p_pre: &PPre, l1: &LineFn, l2: &LineFn) {
+        *f = f.mul_01234(mul_lines(&l1.line_fn_at_p(p_pre), &l2.line_fn_at_p(p_pre)));
     }
 
-    pub fn step_double(acc: &mut Affine<Fq2>, p_pre: &PPre, p: &Affine<Fq>) -> Fq12Sparse034 {
+    // `mul_034_by_034` leaves the one of the product to its Cairo caller, lines need it here
+    fn mul_lines(l1: &Fq12Sparse034, l2: &Fq12Sparse034) -> Fq12Sparse01234 {
+        let product = l1.mul_034_by_034(l2);
+        let c0 = product.c0();
+        let one = Fq2::new(c0.c0().c0() + &Fq::one(), c0.c0().c1().clone());
+        Fq12Sparse01234::new(Fq6::new(one, c0.c1().clone(), c0.c2().clone()), product.c1().clone())
+    }
+
+    pub fn step_double_to_f(acc: &mut Affine<Fq2>, f: &mut Fq12, p_pre: &PPre) {
+        *f = f.mul_034(&step_double(acc, p_pre));
+    }
+
+    pub fn step_double(acc: &mut Affine<Fq2>, p_pre: &PPre) -> Fq12Sparse034 {
         let lf = LineFn::step_double(acc);
-        LineFn::line_fn_at_p(&lf, &p_pre)
+        LineFn::line_fn_at_p(&lf, p_pre)
     }
 
-    pub fn step_dbl_add(acc: &mut Affine<Fq2>, p_pre: &PPre, p: &Affine<Fq>, q: &Affine<Fq2>) -> (Fq12Sparse034, Fq12Sparse034) {
+    pub fn step_dbl_add(acc: &mut Affine<Fq2>, p_pre: &PPre, q: &Affine<Fq2>) -> (Fq12Sparse034, Fq12Sparse034) {
         let (lf1, lf2) = LineFn::step_dbl_add(acc, q);
         (LineFn::line_fn_at_p(&lf1, p_pre), LineFn::line_fn_at_p(&lf2, p_pre))
     }
 
-    pub fn step_dbl_add_to_f(acc: &mut Affine<Fq2>, f: &mut Fq12, p_pre: &PPre, p: &Affine<Fq>, q: &Affine<Fq2>) {
-        let (l1, l2) = step_dbl_add(acc, p_pre, p, q);
-        *f = f.mul_01234(l1.mul_034_by_034(&l2));
+    pub fn step_dbl_add_to_f(acc: &mut Affine<Fq2>, f: &mut Fq12, p_pre: &PPre, q: &Affine<Fq2>) {
+        let (l1, l2) = step_dbl_add(acc, p_pre, q);
+        *f = f.mul_01234(mul_lines(&l1, &l2));
     }
 
-    pub fn correction_step(acc: &mut Affine<Fq2>, p_pre: &PPre, p: &Affine<Fq>, q: &Affine<Fq2>, pi_idx: [usize; 6]) -> (Fq12Sparse034, Fq12Sparse034) {
-        let (lf1, lf2) = LineFn::correction_step(acc, q, pi_idx);
+    pub fn correction_step(acc: &mut Affine<Fq2>, p_pre: &PPre, q: &Affine<Fq2>) -> (Fq12Sparse034, Fq12Sparse034) {
+        let (lf1, lf2) = LineFn::correction_step(acc, q);
         (LineFn::line_fn_at_p(&lf1, p_pre), LineFn::line_fn_at_p(&lf2, p_pre))
     }
 
-    pub fn correction_step_to_f(acc: &mut Affine<Fq2>, f: &mut Fq12, p_pre: &PPre, p: &Affine<Fq>, q: &Affine<Fq2>, pi_idx: [usize; 6]) {
-        let (l1, l2) = correction_step(acc, p_pre, p, q, pi_idx);
-        *f = f.mul_01234(l1.mul_034_by_034(&l2));
+    pub fn correction_step_to_f(acc: &mut Affine<Fq2>, f: &mut Fq12, p_pre: &PPre, q: &Affine<Fq2>) {
+        let (l1, l2) = correction_step(acc, p_pre, q);
+        *f = f.mul_01234(mul_lines(&l1, &l2));
     }
 }
 
@@ -205,10 +254,8 @@ mod test {
 
     #[test]
     fn ate_miller_test() {
-        let inp: [usize; 6] = (0..=5).collect::<Vec<usize>>().try_into().unwrap();
-
-        let p: Affine<Fq> = Affine::<Fq>::new_input([0, 1]);
-        let q: Affine<Fq2> = Affine::<Fq2>::new_input([2, 3, 4, 5]);
+        let _p: Affine<Fq> = Affine::<Fq>::new_input([0, 1]);
+        let _q: Affine<Fq2> = Affine::<Fq2>::new_input([2, 3, 4, 5]);
 
         
     }
@@ -1,24 +1,55 @@
 use crate::fields::{affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2};
+use ate_miller::{ate_miller_loop, multi_miller_loop};
+use final_exp::final_exponentiation;
 
 pub mod line;
-mod ate_miller;
+pub mod ate_miller;
+pub mod final_exp;
+pub mod fixed;
+pub mod bls12_381;
+pub mod projective;
 
 pub trait MillerPrecompute {
     type Precompute; 
-    fn precompute(g1: Affine<Fq>, g2: Affine<Fq2>, inp: Option<[usize; 6]>) -> (Self::Precompute, Affine<Fq2>);
+    fn precompute(g1: Affine<Fq>, g2: Affine<Fq2>) -> (Self::Precompute, Affine<Fq2>);
 }
 
+/// Steps of the Miller loop, `Acc` is the multiple of Q the lines go through
 pub trait MillerSteps {
-    fn sqr_target(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12);
-    fn miller_first_second(&mut self, i1: u32, i2: u32, acc: &mut Affine<Fq2>) -> Fq12;
-    fn miller_bit_o(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12);
-    fn miller_bit_p(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12);
-    fn miller_bit_n(&mut self, i: u32, acc: &mut Affine<Fq2>, f: &mut Fq12);
-    fn miller_last(&mut self, acc: &mut Affine<Fq2>, f: &mut Fq12, pi_idx: [usize; 6]);
+    type Acc;
+    fn sqr_target(&mut self, i: u32, acc: &mut Self::Acc, f: &mut Fq12);
+    fn miller_first_second(&mut self, i1: u32, i2: u32, acc: &mut Self::Acc) -> Fq12;
+    fn miller_bit_o(&mut self, i: u32, acc: &mut Self::Acc, f: &mut Fq12);
+    fn miller_bit_p(&mut self, i: u32, acc: &mut Self::Acc, f: &mut Fq12);
+    fn miller_bit_n(&mut self, i: u32, acc: &mut Self::Acc, f: &mut Fq12);
+    fn miller_last(&mut self, acc: &mut Self::Acc, f: &mut Fq12);
 }
 
 pub fn single_ate_pairing(p: Affine<Fq>, q: Affine<Fq2>) -> Fq12 {
-    todo!()
+    final_exponentiation(&ate_miller_loop(p, q))
 }
 
-// Todo: Make input idx into an option for unused indices instead of defaulting to 0. 
\ No newline at end of file
+/// Product of the pairings of every pair, with a single final exponentiation
+pub fn multi_pairing(pairs: Vec<(Affine<Fq>, Affine<Fq2>)>) -> Fq12 {
+    final_exponentiation(&multi_miller_loop(pairs))
+}
+
+#[cfg(test)]
+mod test {
+    use super::single_ate_pairing;
+    use crate::circuit::evaluator::bn254_scalar_modulus;
+    use crate::reference::{Field, Fq, Fq12, G2Affine};
+    use crate::utils::utils::{eval, fq12_of, g1_input, g2_input};
+
+    #[test]
+    fn test_single_ate_pairing() {
+        let e = single_ate_pairing(g1_input(0), g2_input(2));
+        let (x, y, q) = (Fq::from_u64(1), Fq::from_u64(2), G2Affine::generator());
+        let pairing = |q: &G2Affine| fq12_of(&eval(&e, &[x.values(), y.values(), q.values()]));
+
+        let e1 = pairing(&q);
+        assert_ne!(e1, Fq12::one());
+        assert_eq!(pairing(&q.double()), e1.sqr());
+        assert_eq!(e1.pow(&bn254_scalar_modulus()), Fq12::one());
+    }
+}
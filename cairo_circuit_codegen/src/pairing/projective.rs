@@ -0,0 +1,244 @@
+// Miller steps on a homogeneous projective accumulator. The doubling and mixed addition formulas
+// of Costello, Lange and Naehrig compute the point and its line together without the Fq2
+// inversion of every affine slope, lines then keep an Fq2 factor the final exponentiation removes
+use crate::curve::{Bn254, Curve};
+use crate::fields::{
+    affine::Affine, fq::Fq, fq12::Fq12, fq2::Fq2, fq6::Fq6, projective::G2Projective, sparse::Fq12Sparse034Scaled,
+    FieldConstants, FieldOps, FieldUtils,
+};
+
+use super::ate_miller::{ate_miller_loop_steps, multi_miller_loop_steps, BitType, ATE_LOOP};
+use super::line::{PPre, Precompute};
+use super::{MillerPrecompute, MillerSteps};
+
+/// k·(y - slope·x + c) for the line y = slope·x - c and an unknown k in Fq2
+#[derive(Debug, Clone)]
+pub struct ProjectiveLine<C: Curve = Bn254> {
+    k: Fq2<C>,
+    slope: Fq2<C>,
+    c: Fq2<C>,
+}
+
+impl<C: Curve> ProjectiveLine<C> {
+    pub fn k(&self) -> &Fq2<C> {
+        &self.k
+    }
+
+    pub fn slope(&self) -> &Fq2<C> {
+        &self.slope
+    }
+
+    pub fn c(&self) -> &Fq2<C> {
+        &self.c
+    }
+
+    /// The line evaluated at P on a D-twist and divided by y: k + k·slope·(-x/y)·w + k·c/y·vw
+    pub fn line_fn_at_p(&self, p_pre: &PPre) -> Fq12Sparse034Scaled<C> {
+        Fq12Sparse034Scaled::new(self.k.clone(), self.slope.scale(p_pre.neg_x_over_y()), self.c.scale(p_pre.y_inv()))
+    }
+
+    /// Tangent at T then T = 2T, the coordinates are scaled by 4 to avoid halving:
+    /// X₃ = 2XY(B - F), Y₃ = (B + F)² - 12E², Z₃ = 4BH with B = Y², E = 3b'Z², F = 3E, H = 2YZ.
+    /// The line is scaled by H: (H, 3X², B - E)
+    pub fn step_double(acc: &mut G2Projective<C>) -> Self {
+        let (x, y, z) = (acc.x(), acc.y(), acc.z());
+        let b_twist = Fq2::constant(C::G2_B_NAMES, C::g2_b());
+        let three = |a: &Fq2<C>| &(a + a) + a;
+
+        let a = x * y;
+        let b = y.sqr();
+        let c = z.sqr();
+        let e = three(&(&b_twist * &c));
+        let f = three(&e);
+        let h = &(y + z).sqr() - &(&b + &c);
+        let x_sqr = x.sqr();
+
+        let line = Self { k: h.clone(), slope: three(&x_sqr), c: &b - &e };
+
+        let e_sqr = e.sqr();
+        let e_sqr_4 = &(&e_sqr + &e_sqr) + &(&e_sqr + &e_sqr);
+        let x3 = &a * &(&b - &f);
+        let bh = &b * &h;
+        let bh2 = &bh + &bh;
+        *acc = G2Projective::new(&x3 + &x3, &(&b + &f).sqr() - &three(&e_sqr_4), &bh2 + &bh2);
+        line
+    }
+
+    /// Chord of T and an affine Q then T = T + Q: with θ = Y - y₂Z and λ = X - x₂Z,
+    /// X₃ = λH, Y₃ = θ(G - H) - YE, Z₃ = ZE where D = λ², E = λD, G = XD, H = E + Zθ² - 2G.
+    /// The line is scaled by λ: (λ, θ, θx₂ - λy₂)
+    pub fn step_add(acc: &mut G2Projective<C>, q: &Affine<Fq2<C>>) -> Self {
+        let line = Self::line_add(acc, q);
+        let (x, y, z) = (acc.x(), acc.y(), acc.z());
+        let (lambda, theta) = (&line.k, &line.slope);
+
+        let d = lambda.sqr();
+        let e = lambda * &d;
+        let g = x * &d;
+        let h = &(&e + &(z * &theta.sqr())) - &(&g + &g);
+        *acc = G2Projective::new(lambda * &h, &(theta * &(&g - &h)) - &(y * &e), z * &e);
+        line
+    }
+
+    /// Chord of T and Q, T is not updated
+    pub fn line_add(acc: &G2Projective<C>, q: &Affine<Fq2<C>>) -> Self {
+        let theta = acc.y() - &(q.y() * acc.z());
+        let lambda = acc.x() - &(q.x() * acc.z());
+        Self { c: &(&theta * q.x()) - &(&lambda * q.y()), k: lambda, slope: theta }
+    }
+}
+
+/// A scaled line as an Fq12 element, the first line of the loop is squared in full
+fn line_to_fq12<C: Curve>(l: &Fq12Sparse034Scaled<C>) -> Fq12<C> {
+    let zero = || Fq2::new(Fq::zero(), Fq::zero());
+    Fq12::new(Fq6::new(l.c0().clone(), zero(), zero()), Fq6::new(l.c3().clone(), l.c4().clone(), zero()))
+}
+
+pub mod projective_utils {
+    use super::{line_to_fq12, ProjectiveLine};
+    use crate::fields::{affine::Affine, fq12::Fq12, fq2::Fq2, projective::G2Projective, ECOperations, FieldOps, Frobenius};
+    use crate::pairing::line::PPre;
+
+    /// Bits 64 (O) and 63 (N) from f = 1, the first square is skipped: f = l₀²·l₁·l₂
+    pub fn miller_first_second(acc: &mut G2Projective, p_pre: &PPre, neg_q: &Affine<Fq2>) -> Fq12 {
+        let l0 = ProjectiveLine::step_double(acc).line_fn_at_p(p_pre);
+        let l1 = ProjectiveLine::step_double(acc).line_fn_at_p(p_pre);
+        let l2 = ProjectiveLine::step_add(acc, neg_q).line_fn_at_p(p_pre);
+        line_to_fq12(&l0).sqr().mul_034_scaled(&l1).mul_034_scaled(&l2)
+    }
+
+    pub fn step_double_to_f(acc: &mut G2Projective, f: &mut Fq12, p_pre: &PPre) {
+        *f = f.mul_034_scaled(&ProjectiveLine::step_double(acc).line_fn_at_p(p_pre));
+    }
+
+    /// T = 2T ± Q through the tangent at T and the chord of 2T and ±Q
+    pub fn step_dbl_add_to_f(acc: &mut G2Projective, f: &mut Fq12, p_pre: &PPre, q: &Affine<Fq2>) {
+        step_double_to_f(acc, f, p_pre);
+        *f = f.mul_034_scaled(&ProjectiveLine::step_add(acc, q).line_fn_at_p(p_pre));
+    }
+
+    /// Lines through ψ(Q) and -ψ²(Q), the accumulator is left at T + ψ(Q)
+    pub fn correction_step_to_f(acc: &mut G2Projective, f: &mut Fq12, p_pre: &PPre, q: &Affine<Fq2>) {
+        let q1 = q.frobenius(1);
+        let q2 = q.frobenius(2).neg();
+        let d = ProjectiveLine::step_add(acc, &q1).line_fn_at_p(p_pre);
+        let e = ProjectiveLine::line_add(acc, &q2).line_fn_at_p(p_pre);
+        *f = f.mul_034_scaled(&d).mul_034_scaled(&e);
+    }
+}
+
+/// The steps of `Precompute` on a projective accumulator
+#[derive(Debug, Clone)]
+pub struct ProjectiveSteps {
+    precompute: Precompute,
+}
+
+impl ProjectiveSteps {
+    pub fn new(p: Affine<Fq>, q: Affine<Fq2>) -> (Self, G2Projective) {
+        let (precompute, q) = <Precompute as MillerPrecompute>::precompute(p, q);
+        (Self { precompute }, G2Projective::from_affine(&q))
+    }
+}
+
+impl MillerSteps for ProjectiveSteps {
+    type Acc = G2Projective;
+
+    fn sqr_target(&mut self, _i: u32, _acc: &mut Self::Acc, f: &mut Fq12) {
+        *f = f.sqr();
+    }
+
+    fn miller_first_second(&mut self, i1: u32, i2: u32, acc: &mut Self::Acc) -> Fq12 {
+        debug_assert_eq!((ATE_LOOP[i1 as usize], ATE_LOOP[i2 as usize]), (BitType::O, BitType::N));
+        projective_utils::miller_first_second(acc, self.precompute.ppc(), self.precompute.neg_q())
+    }
+
+    fn miller_bit_o(&mut self, _i: u32, acc: &mut Self::Acc, f: &mut Fq12) {
+        projective_utils::step_double_to_f(acc, f, self.precompute.ppc());
+    }
+
+    fn miller_bit_p(&mut self, _i: u32, acc: &mut Self::Acc, f: &mut Fq12) {
+        projective_utils::step_dbl_add_to_f(acc, f, self.precompute.ppc(), self.precompute.q());
+    }
+
+    fn miller_bit_n(&mut self, _i: u32, acc: &mut Self::Acc, f: &mut Fq12) {
+        projective_utils::step_dbl_add_to_f(acc, f, self.precompute.ppc(), self.precompute.neg_q());
+    }
+
+    fn miller_last(&mut self, acc: &mut Self::Acc, f: &mut Fq12) {
+        projective_utils::correction_step_to_f(acc, f, self.precompute.ppc(), self.precompute.q());
+    }
+}
+
+/// Miller loop with a projective accumulator, it differs from `ate_miller_loop` by factors the
+/// final exponentiation removes
+pub fn projective_miller_loop(p: Affine<Fq>, q: Affine<Fq2>) -> Fq12 {
+    let (mut steps, mut acc) = ProjectiveSteps::new(p, q);
+    ate_miller_loop_steps(&mut steps, &mut acc)
+}
+
+pub fn multi_projective_miller_loop(pairs: Vec<(Affine<Fq>, Affine<Fq2>)>) -> Fq12 {
+    let (mut steps, mut accs): (Vec<ProjectiveSteps>, Vec<G2Projective>) =
+        pairs.into_iter().map(|(p, q)| ProjectiveSteps::new(p, q)).unzip();
+    multi_miller_loop_steps(&mut steps, &mut accs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{projective_miller_loop, ProjectiveLine};
+    use crate::fields::{self, projective::G2Projective, sparse::Fq12Sparse034Scaled};
+    use crate::pairing::{final_exp::final_exponentiation, line::PPre, single_ate_pairing};
+    use crate::reference::{Field, Fq, Fq12, Fq2, Fq6, G2Affine};
+    use crate::utils::utils::{eval, fq12_input, fq2_input, fq2_of, g1_input, g2_input, random_fq12, random_fq2, rng};
+
+    #[test]
+    fn test_projective_steps() {
+        let g = G2Affine::generator();
+        let (a, q) = (g.double().double(), g.double());
+        let z = random_fq2(&mut rng());
+        let (x, y) = (Fq::from_u64(1), Fq::from_u64(2));
+        let inputs = [a.x.mul(&z).values(), a.y.mul(&z).values(), z.values(), q.values(), x.neg().div(&y).values(), y.inv().values()];
+        let acc_in = G2Projective::new_input(std::array::from_fn(|i| i));
+        let ppc = PPre::new(fields::fq::Fq::new_input(10), fields::fq::Fq::new_input(11));
+        assert_eq!(eval(&acc_in.to_affine(), &inputs), a.values());
+
+        // Divided by k a line is y - slope·x + c with c = slope·x - y at the accumulator
+        let check_line = |line: &ProjectiveLine, slope: &Fq2| {
+            let k_inv = fq2_of(&eval(line.k(), &inputs)).inv();
+            assert_eq!(fq2_of(&eval(line.slope(), &inputs)).mul(&k_inv), *slope);
+            assert_eq!(fq2_of(&eval(line.c(), &inputs)).mul(&k_inv), slope.mul(&a.x).sub(&a.y));
+
+            let l = line.line_fn_at_p(&ppc);
+            let (c3, c4) = (fq2_of(&eval(l.c3(), &inputs)), fq2_of(&eval(l.c4(), &inputs)));
+            assert_eq!(c3.mul(&k_inv), slope.scale(&x.neg().div(&y)));
+            assert_eq!(c4.mul(&k_inv), slope.mul(&a.x).sub(&a.y).scale(&y.inv()));
+        };
+
+        let mut acc = acc_in.clone();
+        let tangent = ProjectiveLine::step_double(&mut acc);
+        assert_eq!(eval(&acc.to_affine(), &inputs), a.double().values());
+        let x_sqr = a.x.sqr();
+        check_line(&tangent, &x_sqr.add(&x_sqr).add(&x_sqr).div(&a.y.add(&a.y)));
+
+        let mut acc = acc_in.clone();
+        let chord = ProjectiveLine::step_add(&mut acc, &g2_input(6));
+        assert_eq!(eval(&acc.to_affine(), &inputs), a.add(&q).values());
+        check_line(&chord, &q.y.sub(&a.y).div(&q.x.sub(&a.x)));
+        check_line(&ProjectiveLine::line_add(&acc_in, &g2_input(6)), &q.y.sub(&a.y).div(&q.x.sub(&a.x)));
+    }
+
+    #[test]
+    fn test_projective_miller_loop() {
+        let (x, y, q) = (Fq::from_u64(1), Fq::from_u64(2), G2Affine::generator());
+        let inputs = [x.values(), y.values(), q.values()];
+        let f = final_exponentiation(&projective_miller_loop(g1_input(0), g2_input(2)));
+        assert_eq!(eval(&f, &inputs), eval(&single_ate_pairing(g1_input(0), g2_input(2)), &inputs));
+
+        // f·l for a scaled 034 line, as the sparse product without a division by c0
+        let mut rng = rng();
+        let (f, c0, c3, c4) = (random_fq12(&mut rng), random_fq2(&mut rng), random_fq2(&mut rng), random_fq2(&mut rng));
+        let line = Fq12Sparse034Scaled::new(fq2_input(12), fq2_input(14), fq2_input(16));
+        let dense = Fq12::new(Fq6::new(c0.clone(), Fq2::zero(), Fq2::zero()), Fq6::new(c3.clone(), c4.clone(), Fq2::zero()));
+        let inputs = [f.values(), c0.values(), c3.values(), c4.values()];
+        assert_eq!(eval(&fq12_input(0).mul_034_scaled(&line), &inputs), f.mul(&dense).values());
+    }
+}
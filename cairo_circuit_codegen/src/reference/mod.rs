@@ -0,0 +1,571 @@
+//! Straightforward BN254 arithmetic on concrete values. Fq, Fq2 and the curve points precompute
+//! the constants generators embed, the rest of the tower only checks generated circuits.
+//!
+//! Fq2 = Fq[u]/(u² + 1), Fq6 = Fq2[v]/(v³ - ξ) with ξ = 9 + u and Fq12 = Fq6[w]/(w² - v).
+//! Multiplications are schoolbook on purpose, so they share no formulas with the circuits.
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+use crate::circuit::evaluator::bn254_modulus;
+
+#[cfg(test)]
+mod tower;
+#[cfg(test)]
+pub use tower::{Fq12, Fq6};
+
+pub trait Field: Clone + PartialEq + std::fmt::Debug {
+    fn zero() -> Self;
+    fn one() -> Self;
+    fn add(&self, rhs: &Self) -> Self;
+    fn sub(&self, rhs: &Self) -> Self;
+    fn mul(&self, rhs: &Self) -> Self;
+    fn neg(&self) -> Self;
+    /// Panics on zero
+    fn inv(&self) -> Self;
+
+    /// Coordinates over Fq, in the same order as the circuit outputs of the field type
+    fn values(&self) -> Vec<BigUint>;
+
+    fn sqr(&self) -> Self {
+        self.mul(self)
+    }
+
+    fn div(&self, rhs: &Self) -> Self {
+        self.mul(&rhs.inv())
+    }
+
+    #[cfg(test)]
+    fn pow(&self, exp: &BigUint) -> Self {
+        let mut acc = Self::one();
+        for i in (0..exp.bits()).rev() {
+            acc = acc.sqr();
+            if exp.bit(i) {
+                acc = acc.mul(self);
+            }
+        }
+        acc
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fq(pub BigUint);
+
+impl Fq {
+    pub fn new(value: BigUint) -> Self {
+        Fq(value % bn254_modulus())
+    }
+
+    pub fn from_u64(value: u64) -> Self {
+        Fq::new(BigUint::from(value))
+    }
+}
+
+impl Field for Fq {
+    fn zero() -> Self {
+        Fq(BigUint::zero())
+    }
+
+    fn one() -> Self {
+        Fq(BigUint::one())
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        Fq::new(&self.0 + &rhs.0)
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        Fq::new(&self.0 + bn254_modulus() - &rhs.0)
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        Fq::new(&self.0 * &rhs.0)
+    }
+
+    fn neg(&self) -> Self {
+        Fq::zero().sub(self)
+    }
+
+    // Fermat's little theorem
+    fn inv(&self) -> Self {
+        assert!(!self.0.is_zero(), "inverse of zero");
+        let p = bn254_modulus();
+        Fq(self.0.modpow(&(&p - 2u32), &p))
+    }
+
+    fn values(&self) -> Vec<BigUint> {
+        vec![self.0.clone()]
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fq2 {
+    pub c0: Fq,
+    pub c1: Fq,
+}
+
+impl Fq2 {
+    pub fn new(c0: Fq, c1: Fq) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// The non-residue ξ = 9 + u
+    pub fn xi() -> Self {
+        Self::new(Fq::from_u64(9), Fq::one())
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0.clone(), self.c1.neg())
+    }
+
+    pub fn scale(&self, by: &Fq) -> Self {
+        Self::new(self.c0.mul(by), self.c1.mul(by))
+    }
+}
+
+impl Field for Fq2 {
+    fn zero() -> Self {
+        Self::new(Fq::zero(), Fq::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(Fq::one(), Fq::zero())
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        Self::new(self.c0.add(&rhs.c0), self.c1.add(&rhs.c1))
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        Self::new(self.c0.sub(&rhs.c0), self.c1.sub(&rhs.c1))
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        // u² = -1
+        Self::new(
+            self.c0.mul(&rhs.c0).sub(&self.c1.mul(&rhs.c1)),
+            self.c0.mul(&rhs.c1).add(&self.c1.mul(&rhs.c0)),
+        )
+    }
+
+    fn neg(&self) -> Self {
+        Self::new(self.c0.neg(), self.c1.neg())
+    }
+
+    fn inv(&self) -> Self {
+        let norm = self.c0.sqr().add(&self.c1.sqr());
+        self.conjugate().scale(&norm.inv())
+    }
+
+    fn values(&self) -> Vec<BigUint> {
+        [&self.c0, &self.c1].iter().flat_map(|c| c.values()).collect()
+    }
+}
+
+/// Point on the sextic twist E'(Fq2): y² = x³ + 3/ξ, the point at infinity is not represented
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G2Affine {
+    pub x: Fq2,
+    pub y: Fq2,
+}
+
+impl G2Affine {
+    pub fn generator() -> Self {
+        let fq = |value: &str| Fq::new(value.parse().unwrap());
+        Self {
+            x: Fq2::new(
+                fq("10857046999023057135944570762232829481370756359578518086990519993285655852781"),
+                fq("11559732032986387107991004021392285783925812861821192530917403151452391805634"),
+            ),
+            y: Fq2::new(
+                fq("8495653923123431417604973247489272438418190587263600148770280649306958101930"),
+                fq("4082367875863433681332203403145435568316851327593401208105741076214120093531"),
+            ),
+        }
+    }
+
+    /// Coefficient of the twist y² = x³ + 3/ξ
+    pub fn b() -> Fq2 {
+        Fq2::new(Fq::from_u64(3), Fq::zero()).div(&Fq2::xi())
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        self.y.sqr() == self.x.sqr().mul(&self.x).add(&Self::b())
+    }
+
+    /// Affine addition of distinct points with distinct x
+    pub fn add(&self, rhs: &Self) -> Self {
+        let slope = rhs.y.sub(&self.y).div(&rhs.x.sub(&self.x));
+        self.on_slope(&slope, &rhs.x)
+    }
+
+    pub fn double(&self) -> Self {
+        let x_sqr = self.x.sqr();
+        let slope = x_sqr.add(&x_sqr).add(&x_sqr).div(&self.y.add(&self.y));
+        self.on_slope(&slope, &self.x)
+    }
+
+    pub fn neg(&self) -> Self {
+        Self { x: self.x.clone(), y: self.y.neg() }
+    }
+
+    fn on_slope(&self, slope: &Fq2, x2: &Fq2) -> Self {
+        let x = slope.sqr().sub(&self.x).sub(x2);
+        let y = slope.mul(&self.x.sub(&x)).sub(&self.y);
+        Self { x, y }
+    }
+
+    pub fn values(&self) -> Vec<BigUint> {
+        [self.x.values(), self.y.values()].concat()
+    }
+}
+
+/// Point of E(Fq): y² = x³ + 3, the point at infinity is not represented
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct G1Affine {
+    pub x: Fq,
+    pub y: Fq,
+}
+
+impl G1Affine {
+    pub fn new(x: Fq, y: Fq) -> Self {
+        Self { x, y }
+    }
+
+    pub fn generator() -> Self {
+        Self::new(Fq::from_u64(1), Fq::from_u64(2))
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        self.y.sqr() == self.x.sqr().mul(&self.x).add(&Fq::from_u64(3))
+    }
+
+    /// Affine addition of points with distinct x
+    pub fn add(&self, rhs: &Self) -> Self {
+        let slope = rhs.y.sub(&self.y).div(&rhs.x.sub(&self.x));
+        self.on_slope(&slope, &rhs.x)
+    }
+
+    pub fn double(&self) -> Self {
+        let x_sqr = self.x.sqr();
+        let slope = x_sqr.add(&x_sqr).add(&x_sqr).div(&self.y.add(&self.y));
+        self.on_slope(&slope, &self.x)
+    }
+
+    pub fn neg(&self) -> Self {
+        Self::new(self.x.clone(), self.y.neg())
+    }
+
+    /// k·P by double-and-add, k is non-zero modulo the order of P
+    pub fn mul(&self, k: &BigUint) -> Self {
+        let mut acc: Option<Self> = None;
+        for i in (0..k.bits()).rev() {
+            acc = acc.map(|acc| acc.double());
+            if k.bit(i) {
+                acc = Some(match acc {
+                    Some(acc) if acc == *self => acc.double(),
+                    Some(acc) => acc.add(self),
+                    None => self.clone(),
+                });
+            }
+        }
+        acc.expect("k·P is the point at infinity")
+    }
+
+    fn on_slope(&self, slope: &Fq, x2: &Fq) -> Self {
+        let x = slope.sqr().sub(&self.x).sub(x2);
+        let y = slope.mul(&self.x.sub(&x)).sub(&self.y);
+        Self::new(x, y)
+    }
+
+    pub fn values(&self) -> Vec<BigUint> {
+        [self.x.values(), self.y.values()].concat()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use num_bigint::BigUint;
+    use rand::RngCore;
+
+    use super::{Field, Fq, Fq12, Fq2, Fq6, G2Affine};
+    use crate::circuit::inputs::InputAllocator;
+    use crate::circuit::evaluator::{bn254_modulus, bn254_scalar_modulus};
+    use crate::fields::{self, ECOperations, FieldOps, FieldUtils, Frobenius};
+    use crate::fields::sparse::{Fq12Sparse01234, Fq12Sparse034, Fq6Sparse01};
+    use crate::pairing::line::LineFn;
+    use crate::utils::utils::{
+        eval, eval_mod, fq12_input, fq2_input, fq6_input, g2_input, random_cyclotomic, random_fq, random_fq12, random_fq2, random_fq6, rng,
+        ROUNDS,
+    };
+
+    fn sparse_034(c3: &Fq2, c4: &Fq2) -> Fq12 {
+        Fq12::new(Fq6::one(), Fq6::new(c3.clone(), c4.clone(), Fq2::zero()))
+    }
+
+    fn sparse_01(c0: &Fq2, c1: &Fq2) -> Fq6 {
+        Fq6::new(c0.clone(), c1.clone(), Fq2::zero())
+    }
+
+    #[test]
+    fn test_reference_field_axioms() {
+        let mut rng = rng();
+        for _ in 0..ROUNDS {
+            let (a, b, c) = (random_fq12(&mut rng), random_fq12(&mut rng), random_fq12(&mut rng));
+            assert_eq!(a.mul(&b.add(&c)), a.mul(&b).add(&a.mul(&c)));
+            assert_eq!(a.mul(&b).mul(&c), a.mul(&b.mul(&c)));
+            assert_eq!(a.mul(&a.inv()), Fq12::one());
+            // The Frobenius endomorphism is the identity on Fq only
+            let p = bn254_modulus();
+            assert_eq!(a.c0.c0.c0.pow(&p), a.c0.c0.c0);
+        }
+        assert!(G2Affine::generator().is_on_curve());
+    }
+
+    #[test]
+    fn test_fq2_ops() {
+        let mut rng = rng();
+        for _ in 0..ROUNDS {
+            let (a, b, s) = (random_fq2(&mut rng), random_fq2(&mut rng), random_fq(&mut rng));
+            let (lhs, rhs) = (fq2_input(0), fq2_input(2));
+            let inputs = [a.values(), b.values(), s.values()];
+            let scalar = fields::fq::Fq::new_input(4);
+
+            assert_eq!(eval(&lhs.add(&rhs), &inputs), a.add(&b).values());
+            assert_eq!(eval(&lhs.sub(&rhs), &inputs), a.sub(&b).values());
+            assert_eq!(eval(&lhs.mul(&rhs), &inputs), a.mul(&b).values());
+            assert_eq!(eval(&lhs.sqr(), &inputs), a.sqr().values());
+            assert_eq!(eval(&lhs.div(&rhs), &inputs), a.div(&b).values());
+            assert_eq!(eval(&lhs.inv(), &inputs), a.inv().values());
+            assert_eq!(eval(&FieldOps::neg(&lhs), &inputs), a.neg().values());
+            assert_eq!(eval(&lhs.conjugate(), &inputs), a.conjugate().values());
+            assert_eq!(eval(&lhs.scale(&scalar), &inputs), a.scale(&s).values());
+            assert_eq!(eval(&lhs.mul_by_xi(), &inputs), a.mul(&Fq2::xi()).values());
+        }
+    }
+
+    #[test]
+    fn test_fq6_ops() {
+        let mut rng = rng();
+        for _ in 0..ROUNDS {
+            let (a, b) = (random_fq6(&mut rng), random_fq6(&mut rng));
+            let (lhs, rhs) = (fq6_input(0), fq6_input(6));
+            let inputs = [a.values(), b.values()];
+
+            assert_eq!(eval(&lhs.add(&rhs), &inputs), a.add(&b).values());
+            assert_eq!(eval(&lhs.sub(&rhs), &inputs), a.sub(&b).values());
+            assert_eq!(eval(&lhs.mul(&rhs), &inputs), a.mul(&b).values());
+            assert_eq!(eval(&lhs.sqr(), &inputs), a.sqr().values());
+            assert_eq!(eval(&lhs.div(&rhs), &inputs), a.div(&b).values());
+            assert_eq!(eval(&lhs.inv(), &inputs), a.inv().values());
+            assert_eq!(eval(&FieldOps::neg(&lhs), &inputs), a.neg().values());
+            assert_eq!(eval(&lhs.mul_by_v(), &inputs), a.mul(&Fq6::v()).values());
+
+            let sparse = Fq6Sparse01::new(fq2_input(6), fq2_input(8));
+            assert_eq!(eval(&lhs.mul_01(&sparse), &inputs), a.mul(&sparse_01(&b.c0, &b.c1)).values());
+        }
+    }
+
+    #[test]
+    fn test_fq12_ops() {
+        let mut rng = rng();
+        for _ in 0..ROUNDS {
+            let (a, b) = (random_fq12(&mut rng), random_fq12(&mut rng));
+            let (lhs, rhs) = (fq12_input(0), fq12_input(12));
+            let inputs = [a.values(), b.values()];
+
+            assert_eq!(eval(&lhs.add(&rhs), &inputs), a.add(&b).values());
+            assert_eq!(eval(&lhs.sub(&rhs), &inputs), a.sub(&b).values());
+            assert_eq!(eval(&lhs.mul(&rhs), &inputs), a.mul(&b).values());
+            assert_eq!(eval(&lhs.sqr(), &inputs), a.sqr().values());
+            assert_eq!(eval(&lhs.div(&rhs), &inputs), a.div(&b).values());
+            assert_eq!(eval(&lhs.inv(), &inputs), a.inv().values());
+            assert_eq!(eval(&FieldOps::neg(&lhs), &inputs), a.neg().values());
+
+            let (c3, c4) = (b.c0.c0.clone(), b.c0.c1.clone());
+            let sparse = Fq12Sparse034::new(fq2_input(12), fq2_input(14));
+            assert_eq!(eval(&lhs.mul_034(&sparse), &inputs), a.mul(&sparse_034(&c3, &c4)).values());
+
+            let sparse = Fq12Sparse01234::new(fq6_input(12), Fq6Sparse01::new(fq2_input(18), fq2_input(20)));
+            let dense = Fq12::new(b.c0.clone(), sparse_01(&b.c1.c0, &b.c1.c1));
+            assert_eq!(eval(&lhs.mul_01234(sparse), &inputs), a.mul(&dense).values());
+        }
+    }
+
+    #[test]
+    fn test_sparse_ops() {
+        let mut rng = rng();
+        for _ in 0..ROUNDS {
+            let (c3, c4, d3, d4) = (random_fq2(&mut rng), random_fq2(&mut rng), random_fq2(&mut rng), random_fq2(&mut rng));
+            let inputs = [c3.values(), c4.values(), d3.values(), d4.values()];
+            let lhs = Fq12Sparse034::new(fq2_input(0), fq2_input(2));
+            let rhs = Fq12Sparse034::new(fq2_input(4), fq2_input(6));
+            let product = sparse_034(&c3, &c4).mul(&sparse_034(&d3, &d4));
+
+            // The 1 of the constant coefficient is left to the caller
+            let out = lhs.mul_034_by_034(&rhs);
+            let mut expected = product.clone();
+            expected.c0.c0.c0 = expected.c0.c0.c0.sub(&Fq::one());
+            assert_eq!(eval(out.c0(), &inputs), expected.c0.values());
+            assert_eq!(eval(out.c1().c0(), &inputs), expected.c1.c0.values());
+            assert_eq!(eval(out.c1().c1(), &inputs), expected.c1.c1.values());
+            assert_eq!(expected.c1.c2, Fq2::zero());
+
+            let out = lhs.sqr_034();
+            let expected = sparse_034(&c3, &c4).sqr();
+            assert_eq!(eval(out.c0(), &inputs), expected.c0.values());
+            assert_eq!(eval(out.c1().c0(), &inputs), expected.c1.c0.values());
+            assert_eq!(eval(out.c1().c1(), &inputs), expected.c1.c1.values());
+
+            let lhs01 = Fq6Sparse01::new(fq2_input(0), fq2_input(2));
+            let rhs01 = Fq6Sparse01::new(fq2_input(4), fq2_input(6));
+            let expected = sparse_01(&c3, &c4).mul(&sparse_01(&d3, &d4));
+            assert_eq!(eval(&lhs01.mul_01_by_01(&rhs01), &inputs), expected.values());
+
+            // Products of two 034 elements multiplied together
+            let (e3, e4) = (random_fq2(&mut rng), random_fq2(&mut rng));
+            let (f3, f4) = (random_fq2(&mut rng), random_fq2(&mut rng));
+            let inputs = [inputs.concat(), e3.values(), e4.values(), f3.values(), f4.values()];
+            let lhs = Fq12Sparse034::new(fq2_input(0), fq2_input(2)).sqr_034();
+            let rhs = Fq12Sparse034::new(fq2_input(8), fq2_input(10)).sqr_034();
+            let expected = sparse_034(&c3, &c4).sqr().mul(&sparse_034(&e3, &e4).sqr());
+            assert_eq!(eval(&lhs.mul_01234_01234(&rhs), &inputs), expected.values());
+        }
+    }
+
+    #[test]
+    fn test_karabina() {
+        let mut rng = rng();
+        let krbn: fields::fq12_squaring::Krbn2345 = fields::fq12_squaring::Krbn2345::new_input([0, 1, 2, 3, 4, 5, 6, 7]);
+        for _ in 0..ROUNDS / 2 {
+            // Fq12 { c0: (g0, g4, g3), c1: (g2, g1, g5) }
+            let f = random_cyclotomic(&mut rng);
+            let compress = |f: &Fq12| [f.c1.c0.values(), f.c0.c2.values(), f.c0.c1.values(), f.c1.c2.values()];
+            let inputs = compress(&f);
+
+            assert_eq!(eval(&krbn.sqr_krbn(), &inputs), compress(&f.sqr()).concat());
+
+            // g0 is returned without its final + 1
+            let (g0, g1) = krbn.krbn_decompress_else();
+            assert_eq!(eval(&g0, &inputs), f.c0.c0.sub(&Fq2::one()).values());
+            assert_eq!(eval(&g1, &inputs), f.c1.c1.values());
+
+            // g2 = 0 case, inputs start at g3: g1 = 2g4g5/g3 and g0 = (2g1² - 3g3g4)ξ
+            let (g3, g4, g5) = (&f.c0.c2, &f.c0.c1, &f.c1.c2);
+            let g1 = g4.mul(g5).add(&g4.mul(g5)).div(g3);
+            let g3g4 = g3.mul(g4);
+            let g0 = g1.sqr().add(&g1.sqr()).sub(&g3g4.add(&g3g4).add(&g3g4)).mul(&Fq2::xi());
+            let (out0, out1) = krbn.krbn_decompress_if_zero();
+            let inputs = &inputs[1..];
+            assert_eq!(eval(&out0, inputs), g0.values());
+            assert_eq!(eval(&out1, inputs), g1.values());
+        }
+    }
+
+    #[test]
+    fn test_affine_ops() {
+        let g = G2Affine::generator();
+        let (p, q) = (g.double(), g.double().double().add(&g));
+        assert!(p.is_on_curve() && q.is_on_curve());
+        assert_eq!(p.add(&q).add(&g), p.add(&q.add(&g)));
+
+        let (lhs, rhs) = (g2_input(0), g2_input(4));
+        let inputs = [p.values(), q.values()];
+        let chord = q.y.sub(&p.y).div(&q.x.sub(&p.x));
+
+        assert_eq!(eval(&lhs.chord(&rhs), &inputs), chord.values());
+        assert_eq!(eval(&lhs.add(&rhs), &inputs), p.add(&q).values());
+        assert_eq!(eval(&lhs.double(), &inputs), p.double().values());
+        assert_eq!(eval(&ECOperations::neg(&lhs), &inputs), p.neg().values());
+
+        let x_sqr = p.x.sqr();
+        let tangent = x_sqr.add(&x_sqr).add(&x_sqr).div(&p.y.add(&p.y));
+        assert_eq!(eval(&lhs.tangent(), &inputs), tangent.values());
+
+        // Slope and x read from the second point, as in the affine generator
+        let (slope, x) = (fq2_input(4), fq2_input(6));
+        let x3 = q.x.sqr().sub(&p.x).sub(&q.y);
+        let y3 = q.x.mul(&p.x.sub(&q.y)).sub(&p.y);
+        assert_eq!(eval(&lhs.x_on_slope(&slope, &x), &inputs), x3.values());
+        assert_eq!(eval(&lhs.y_on_slope(&slope, &x), &inputs), y3.values());
+    }
+
+    #[test]
+    fn test_curve_constants() {
+        let g = G2Affine::generator();
+        let q = g2_input(0);
+        let inputs = [g.values()];
+        assert_eq!(eval(&q.on_curve(), &inputs), Fq2::zero().values());
+        assert_ne!(eval(&q.on_curve(), &[g.double().values()[..2].to_vec(), g.y.values()]), Fq2::zero().values());
+
+        let g1 = fields::affine::Affine::<fields::fq::Fq>::new_input([0, 1]);
+        assert_eq!(eval(&g1.on_curve(), &[Fq::from_u64(1).values(), Fq::from_u64(2).values()]), Fq::zero().values());
+
+        // ψ(Q) and -ψ²(Q) as built by the correction step stay on the twist
+        let q1 = q.frobenius(1);
+        let q2 = ECOperations::neg(&q.frobenius(2));
+        assert_eq!(eval(&q1.on_curve(), &inputs), Fq2::zero().values());
+        assert_eq!(eval(&q2.on_curve(), &inputs), Fq2::zero().values());
+        assert_ne!(eval(&q1, &inputs), g.values());
+    }
+
+    #[test]
+    fn test_step_dbl_add() {
+        let g = G2Affine::generator();
+        let (a, q) = (g.double().double(), g);
+        let inputs = [a.values(), q.values()];
+        let a_plus_q = a.add(&q);
+        let slope1 = q.y.sub(&a.y).div(&q.x.sub(&a.x));
+        let slope2 = a_plus_q.y.sub(&a.y).div(&a_plus_q.x.sub(&a.x));
+        let acc_out = a_plus_q.add(&a);
+
+        let mut acc = g2_input(0);
+        let (lf1, lf2) = LineFn::step_dbl_add(&mut acc, &g2_input(4));
+        assert_eq!(eval(&acc, &inputs), acc_out.values());
+        assert_eq!(eval(&lf1, &inputs), [slope1.values(), slope1.mul(&a.x).sub(&a.y).values()].concat());
+        // Both lines go through a, the new accumulator is not on them
+        assert_eq!(eval(&lf2, &inputs), [slope2.values(), slope2.mul(&a.x).sub(&a.y).values()].concat());
+
+        // Intermediate values of the step_dbl_add_slopes generator
+        let (acc, q_in) = (g2_input(0), g2_input(4));
+        let s1 = acc.chord(&q_in);
+        let x1 = acc.x_on_slope(&s1, q_in.x());
+        let s2 = FieldOps::neg(&s1).sub(&acc.y().add(acc.y()).div(&x1.sub(acc.x())));
+        assert_eq!(eval(&s1, &inputs), slope1.values());
+        assert_eq!(eval(&x1, &inputs), a_plus_q.x.values());
+        assert_eq!(eval(&s2, &inputs), slope2.values());
+    }
+
+    #[test]
+    fn test_plonk_scalars() {
+        // Same inputs and formulas as the compute_d_partial and compute_r0 generators
+        let r = bn254_scalar_modulus();
+        let mut rng = rng();
+        let mut random = || {
+            let mut bytes = [0u8; 48];
+            rng.fill_bytes(&mut bytes);
+            BigUint::from_bytes_be(&bytes) % &r
+        };
+        let values: Vec<BigUint> = (0..14).map(|_| random()).collect();
+        let v = |i: usize| values[i].clone();
+        let inputs = [values.clone()];
+
+        let d_partial = crate::circuit::utils::compute_d_partial_circuits(&mut InputAllocator::new());
+        let (beta, xi, a, gamma, k1, b, k2, c, alpha, l1, u, s1, s2, zw) =
+            (v(0), v(1), v(2), v(3), v(4), v(5), v(6), v(7), v(8), v(9), v(10), v(11), v(12), v(13));
+        let betaxi = &beta * &xi;
+        let d2ab = (&a + &betaxi + &gamma) * (&b + &betaxi * &k1 + &gamma) * (&c + &betaxi * &k2 + &gamma) * &alpha
+            + &l1 * &alpha * &alpha
+            + &u;
+        let d3ab = (&a + &beta * &s1 + &gamma) * (&b + &beta * &s2 + &gamma) * &alpha * &beta * &zw;
+        assert_eq!(eval_mod(&d_partial.0, &inputs, r.clone()), vec![d2ab % &r]);
+        assert_eq!(eval_mod(&d_partial.1, &inputs, r.clone()), vec![d3ab % &r]);
+
+        let (pi, l1, alpha, a, beta, s1, gamma, b, s2, c, zw) =
+            (v(0), v(1), v(2), v(3), v(4), v(5), v(6), v(7), v(8), v(9), v(10));
+        let e2 = &l1 * &alpha * &alpha;
+        let e3 = (&a + &beta * &s1 + &gamma) * (&b + &beta * &s2 + &gamma) * (&c + &gamma) * &zw * &alpha;
+        let r0 = (&pi + (&r - e2 % &r) + (&r - e3 % &r)) % &r;
+        assert_eq!(eval_mod(&crate::circuit::utils::compute_r0_circuit(&mut InputAllocator::new()), &inputs, r.clone()), vec![r0]);
+    }
+}
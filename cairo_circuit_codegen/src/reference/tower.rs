@@ -0,0 +1,131 @@
+// Fq6 and Fq12, only the differential tests of the circuits compute in them
+use num_bigint::BigUint;
+
+use super::{Field, Fq2};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fq6 {
+    pub c0: Fq2,
+    pub c1: Fq2,
+    pub c2: Fq2,
+}
+
+impl Fq6 {
+    pub fn new(c0: Fq2, c1: Fq2, c2: Fq2) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// v, the generator of Fq6 over Fq2
+    pub fn v() -> Self {
+        Self::new(Fq2::zero(), Fq2::one(), Fq2::zero())
+    }
+}
+
+impl Field for Fq6 {
+    fn zero() -> Self {
+        Self::new(Fq2::zero(), Fq2::zero(), Fq2::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(Fq2::one(), Fq2::zero(), Fq2::zero())
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        Self::new(self.c0.add(&rhs.c0), self.c1.add(&rhs.c1), self.c2.add(&rhs.c2))
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        Self::new(self.c0.sub(&rhs.c0), self.c1.sub(&rhs.c1), self.c2.sub(&rhs.c2))
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        // Schoolbook product, then v³ = ξ and v⁴ = ξv fold the high terms back
+        let (a, b) = ([&self.c0, &self.c1, &self.c2], [&rhs.c0, &rhs.c1, &rhs.c2]);
+        let mut terms = vec![Fq2::zero(); 5];
+        for i in 0..3 {
+            for j in 0..3 {
+                terms[i + j] = terms[i + j].add(&a[i].mul(b[j]));
+            }
+        }
+        let xi = Fq2::xi();
+        Self::new(
+            terms[0].add(&terms[3].mul(&xi)),
+            terms[1].add(&terms[4].mul(&xi)),
+            terms[2].clone(),
+        )
+    }
+
+    fn neg(&self) -> Self {
+        Self::new(self.c0.neg(), self.c1.neg(), self.c2.neg())
+    }
+
+    fn inv(&self) -> Self {
+        let xi = Fq2::xi();
+        let t0 = self.c0.sqr().sub(&self.c1.mul(&self.c2).mul(&xi));
+        let t1 = self.c2.sqr().mul(&xi).sub(&self.c0.mul(&self.c1));
+        let t2 = self.c1.sqr().sub(&self.c0.mul(&self.c2));
+        let norm = self.c0.mul(&t0).add(&self.c2.mul(&t1).add(&self.c1.mul(&t2)).mul(&xi));
+        let norm_inv = norm.inv();
+        Self::new(t0.mul(&norm_inv), t1.mul(&norm_inv), t2.mul(&norm_inv))
+    }
+
+    fn values(&self) -> Vec<BigUint> {
+        [&self.c0, &self.c1, &self.c2].iter().flat_map(|c| c.values()).collect()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Fq12 {
+    pub c0: Fq6,
+    pub c1: Fq6,
+}
+
+impl Fq12 {
+    pub fn new(c0: Fq6, c1: Fq6) -> Self {
+        Self { c0, c1 }
+    }
+
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0.clone(), self.c1.neg())
+    }
+}
+
+impl Field for Fq12 {
+    fn zero() -> Self {
+        Self::new(Fq6::zero(), Fq6::zero())
+    }
+
+    fn one() -> Self {
+        Self::new(Fq6::one(), Fq6::zero())
+    }
+
+    fn add(&self, rhs: &Self) -> Self {
+        Self::new(self.c0.add(&rhs.c0), self.c1.add(&rhs.c1))
+    }
+
+    fn sub(&self, rhs: &Self) -> Self {
+        Self::new(self.c0.sub(&rhs.c0), self.c1.sub(&rhs.c1))
+    }
+
+    fn mul(&self, rhs: &Self) -> Self {
+        // w² = v
+        Self::new(
+            self.c0.mul(&rhs.c0).add(&self.c1.mul(&rhs.c1).mul(&Fq6::v())),
+            self.c0.mul(&rhs.c1).add(&self.c1.mul(&rhs.c0)),
+        )
+    }
+
+    fn neg(&self) -> Self {
+        Self::new(self.c0.neg(), self.c1.neg())
+    }
+
+    fn inv(&self) -> Self {
+        let norm = self.c0.sqr().sub(&self.c1.sqr().mul(&Fq6::v()));
+        let norm_inv = norm.inv();
+        Self::new(self.c0.mul(&norm_inv), self.c1.neg().mul(&norm_inv))
+    }
+
+    fn values(&self) -> Vec<BigUint> {
+        [&self.c0, &self.c1].iter().flat_map(|c| c.values()).collect()
+    }
+}
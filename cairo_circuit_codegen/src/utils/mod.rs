@@ -1 +1,2 @@
-pub(crate) mod utils; 
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub(crate) mod utils;
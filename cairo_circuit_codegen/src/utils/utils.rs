@@ -1,6 +1,107 @@
-use std::fs;
+use std::{collections::HashSet, fs};
 
 pub fn write_stdout(path: &str, code: String) {
     fs::write(path, code).expect("Unable to write file");
     println!("Cairo code generated successfully and written to out.cairo");
-}
\ No newline at end of file
+}
+// Random reference values and circuit inputs shared by the differential tests of the circuits
+
+use num_bigint::BigUint;
+use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+use crate::circuit::{adder::CairoCodeAdder, evaluator::{bn254_modulus, Evaluator}};
+use crate::fields;
+use crate::reference::{Field, Fq, Fq12, Fq2, Fq6, G2Affine};
+
+pub const ROUNDS: usize = 8;
+
+pub fn rng() -> StdRng {
+    StdRng::seed_from_u64(0xb254)
+}
+
+pub fn random_fq(rng: &mut StdRng) -> Fq {
+    let mut bytes = [0u8; 48];
+    rng.fill_bytes(&mut bytes);
+    Fq::new(BigUint::from_bytes_be(&bytes))
+}
+
+pub fn random_fq2(rng: &mut StdRng) -> Fq2 {
+    Fq2::new(random_fq(rng), random_fq(rng))
+}
+
+pub fn random_fq6(rng: &mut StdRng) -> Fq6 {
+    Fq6::new(random_fq2(rng), random_fq2(rng), random_fq2(rng))
+}
+
+pub fn random_fq12(rng: &mut StdRng) -> Fq12 {
+    Fq12::new(random_fq6(rng), random_fq6(rng))
+}
+
+// f^((p⁶ - 1)(p² + 1)), an element of the cyclotomic subgroup
+pub fn random_cyclotomic(rng: &mut StdRng) -> Fq12 {
+    let f = random_fq12(rng);
+    let f = f.conjugate().div(&f);
+    let p = bn254_modulus();
+    f.pow(&(&p * &p)).mul(&f)
+}
+
+pub fn eval<A: CairoCodeAdder>(out: &A, inputs: &[Vec<BigUint>]) -> Vec<BigUint> {
+    eval_mod(out, inputs, bn254_modulus())
+}
+
+pub fn eval_mod<A: CairoCodeAdder>(out: &A, inputs: &[Vec<BigUint>], modulus: BigUint) -> Vec<BigUint> {
+    Evaluator::new(inputs.concat(), modulus).eval_all(&out.circuits()).unwrap()
+}
+
+pub fn fq2_input(idx: usize) -> fields::fq2::Fq2 {
+    fields::fq2::Fq2::new_input([idx, idx + 1])
+}
+
+pub fn fq6_input(idx: usize) -> fields::fq6::Fq6 {
+    fields::fq6::Fq6::new_input(std::array::from_fn(|i| idx + i))
+}
+
+pub fn fq12_input(idx: usize) -> fields::fq12::Fq12 {
+    fields::fq12::Fq12::new_input(std::array::from_fn(|i| idx + i))
+}
+
+pub fn g2_input(idx: usize) -> fields::affine::Affine<fields::fq2::Fq2> {
+    fields::affine::Affine::<fields::fq2::Fq2>::new_input([idx, idx + 1, idx + 2, idx + 3])
+}
+
+pub fn g1_input(idx: usize) -> fields::affine::Affine<fields::fq::Fq> {
+    fields::affine::Affine::<fields::fq::Fq>::new_input([idx, idx + 1])
+}
+
+pub fn fq2_of(values: &[BigUint]) -> Fq2 {
+    Fq2::new(Fq::new(values[0].clone()), Fq::new(values[1].clone()))
+}
+
+pub fn fq12_of(values: &[BigUint]) -> Fq12 {
+    let fq6 = |k: usize| Fq6::new(fq2_of(&values[k..]), fq2_of(&values[k + 2..]), fq2_of(&values[k + 4..]));
+    Fq12::new(fq6(0), fq6(6))
+}
+
+// x, y of P and Q followed by x, ±y of P and Q'
+pub fn two_pairs(neg: bool, q0: &G2Affine, q1: &G2Affine) -> Vec<Vec<BigUint>> {
+    let (x, y) = (Fq::from_u64(1), Fq::from_u64(2));
+    let y1 = if neg { y.neg() } else { y.clone() };
+    vec![x.values(), y.values(), q0.values(), x.values(), y1.values(), q1.values()]
+}
+
+pub fn multi_pairs() -> Vec<(fields::affine::Affine<fields::fq::Fq>, fields::affine::Affine<fields::fq2::Fq2>)> {
+    vec![(g1_input(0), g2_input(2)), (g1_input(6), g2_input(8))]
+}
+
+// Every function the `entry` function calls is defined in `code`, or is one of `extra`. Returns the calls
+pub fn assert_calls_emitted<'a>(code: &'a str, entry: &str, extra: &[&str]) -> Vec<&'a str> {
+    let defined: HashSet<&str> =
+        code.lines().filter_map(|line| line.strip_prefix("fn ")?.split_once('(')).map(|(name, _)| name).collect();
+    let entry = code.split(&format!("fn {}(", entry)).nth(1).unwrap();
+    let calls: Vec<&str> =
+        entry.lines().filter_map(|line| line.split_once(" = ")?.1.split_once('(')).map(|(name, _)| name).collect();
+    for call in &calls {
+        assert!(defined.contains(call) || extra.contains(call), "{} is not emitted", call);
+    }
+    calls
+}
@@ -1,41 +1,154 @@
-use clap::{Parser, Subcommand};
+use crate::commands::history::HistoryFormat;
+use clap::{Args, Parser, Subcommand};
+use clap_complete::Shell;
 use std::path::PathBuf;
 
+const PATH_RESOLUTION: &str = "Input files: a bare file name (e.g. proof.json) is read from \
+./data/temp, any path with a directory component (./proof.json, dir/proof.json, \
+/abs/proof.json) is used as given.";
+
 #[derive(Parser)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, after_help = PATH_RESOLUTION)]
 pub struct Cli {
     #[command(subcommand)]
     pub command: Commands,
 }
 
+/// The snarkjs files describing a proof
+#[derive(Args)]
+#[command(after_help = PATH_RESOLUTION)]
+pub struct InputFiles {
+    /// Verification key file
+    #[arg(long = "vk", default_value = "verification_key.json")]
+    pub verification_key: PathBuf,
+
+    /// Proof file
+    #[arg(long = "proof", default_value = "proof.json")]
+    pub proof: PathBuf,
+
+    /// Public inputs file
+    #[arg(long = "public", default_value = "public.json")]
+    pub public_inputs: PathBuf,
+}
+
 #[derive(Subcommand)]
 pub enum Commands {
+    /// Submit a proof to the verifier contract and wait for the result
     Verify {
-        /// Verification key filename or path
+        #[command(flatten)]
+        files: InputFiles,
+
+        /// Seconds to wait for the transaction receipt
+        #[arg(
+            long = "timeout",
+            help = "Seconds to wait for the transaction to be accepted",
+            default_value_t = 300
+        )]
+        timeout: u64,
+
+        /// Retries on transient RPC errors or nonce conflicts
         #[arg(
-            long = "vk",
-            help = "Verification key filename or path (default: verification_key.json)",
-            long_help = "The verification key file (default: ./data/temp/verification_key.json)",
-            default_value = "verification_key.json"
+            long = "max-retries",
+            help = "Number of retries on transient RPC errors or nonce conflicts",
+            default_value_t = 3
         )]
-        verification_key: PathBuf,
+        max_retries: u32,
+    },
+
+    /// List past verifications emitted by the deployed contracts
+    History {
+        /// First block to scan
+        #[arg(long = "from-block", help = "First block to scan", default_value_t = 0)]
+        from_block: u64,
+
+        /// Last block to scan
+        #[arg(long = "to-block", help = "Last block to scan (default: latest)")]
+        to_block: Option<u64>,
 
-        /// Proof filename or path
+        /// Also scan the pairing contract at PAIRING_ADDRESS
         #[arg(
-            long = "proof",
-            help = "Proof filename or path (default: proof.json)",
-            long_help = "The proof file (default: ./data/temp/proof.json)",
-            default_value = "proof.json"
+            long = "pairing",
+            help = "Also scan the pairing contract set in PAIRING_ADDRESS"
         )]
-        proof: PathBuf,
+        include_pairing: bool,
+
+        /// Output format
+        #[arg(
+            long = "format",
+            value_enum,
+            help = "Output format",
+            default_value_t = HistoryFormat::Table
+        )]
+        format: HistoryFormat,
+
+        /// Write the output to a file instead of stdout
+        #[arg(long = "output", help = "Write the output to a file instead of stdout")]
+        output: Option<PathBuf>,
+    },
+
+    /// Check that tampered versions of a valid proof are rejected
+    Fuzz {
+        #[command(flatten)]
+        files: InputFiles,
 
-        /// Public inputs filename or path
+        /// Also run every mutant through the deployed verifier
         #[arg(
-            long = "public",
-            help = "Public inputs filename or path (default: public.json)",
-            long_help = "The public inputs file (default: ./data/temp/public.json)",
-            default_value = "public.json"
+            long = "on-chain",
+            help = "Also check every mutant against CONTRACT_ADDRESS with a read-only call"
         )]
-        public_inputs: PathBuf,
+        on_chain: bool,
+
+        /// Print every mutant, not only the accepted ones
+        #[arg(long = "verbose", short = 'v', help = "Print rejected mutants as well")]
+        verbose: bool,
+    },
+
+    /// Run a local HTTP/JSON service for proof submission
+    Serve {
+        /// Address to bind
+        #[arg(long = "host", help = "Address to bind", default_value = "127.0.0.1")]
+        host: String,
+
+        /// Port to listen on
+        #[arg(long = "port", help = "Port to listen on", default_value_t = 8080)]
+        port: u16,
+
+        /// Maximum number of pending submissions
+        #[arg(
+            long = "queue-size",
+            help = "Maximum number of pending submissions before requests are rejected",
+            default_value_t = 16
+        )]
+        queue_size: usize,
+
+        /// Seconds to wait for each transaction receipt
+        #[arg(
+            long = "timeout",
+            help = "Seconds to wait for each transaction to be accepted",
+            default_value_t = 300
+        )]
+        timeout: u64,
+
+        /// Retries on transient RPC errors or nonce conflicts
+        #[arg(
+            long = "max-retries",
+            help = "Number of retries on transient RPC errors or nonce conflicts",
+            default_value_t = 3
+        )]
+        max_retries: u32,
+    },
+
+    /// Print a shell completion script to stdout
+    Completions {
+        /// Shell to generate the script for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+
+    /// Generate man pages
+    Man {
+        /// Write one page per command to this directory instead of printing the main page
+        #[arg(long = "out-dir")]
+        out_dir: Option<PathBuf>,
     },
 }
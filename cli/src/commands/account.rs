@@ -0,0 +1,75 @@
+use crate::commands::utils::read_env_var;
+use crate::error::CliError;
+use dotenv::dotenv;
+use starknet::{
+    accounts::{ExecutionEncoding, SingleOwnerAccount},
+    core::{
+        chain_id,
+        types::{BlockId, BlockTag, Call, Felt},
+        utils::get_selector_from_name,
+    },
+    providers::{
+        jsonrpc::{HttpTransport, JsonRpcClient},
+        Url,
+    },
+    signers::{LocalWallet, SigningKey},
+};
+
+pub type VerifierAccount = SingleOwnerAccount<JsonRpcClient<HttpTransport>, LocalWallet>;
+
+/// Connection settings read from the environment (or `.env`)
+#[derive(Debug, Clone)]
+pub struct StarknetConfig {
+    pub rpc_url: Url,
+    pub private_key: Felt,
+    pub account_address: Felt,
+    pub contract_address: Felt,
+}
+
+fn parse_felt(name: &str, value: &str) -> Result<Felt, CliError> {
+    Felt::from_hex(value).map_err(|e| CliError::InvalidInput(format!("Invalid {}: {}", name, e)))
+}
+
+impl StarknetConfig {
+    pub fn from_env() -> Result<Self, CliError> {
+        dotenv().ok();
+
+        let rpc_url = read_env_var("RPC_URL")?;
+        Ok(Self {
+            rpc_url: Url::parse(&rpc_url)
+                .map_err(|e| CliError::InvalidInput(format!("Invalid RPC_URL: {}", e)))?,
+            private_key: parse_felt("PRIVATE_KEY", &read_env_var("PRIVATE_KEY")?)?,
+            account_address: parse_felt("ACCOUNT_ADDRESS", &read_env_var("ACCOUNT_ADDRESS")?)?,
+            contract_address: parse_felt("CONTRACT_ADDRESS", &read_env_var("CONTRACT_ADDRESS")?)?,
+        })
+    }
+
+    pub fn provider(&self) -> JsonRpcClient<HttpTransport> {
+        JsonRpcClient::new(HttpTransport::new(self.rpc_url.clone()))
+    }
+
+    /// Builds the signing account, targeting the pending block.
+    pub fn account(&self) -> VerifierAccount {
+        let signer = LocalWallet::from(SigningKey::from_secret_scalar(self.private_key));
+
+        let mut account = SingleOwnerAccount::new(
+            self.provider(),
+            signer,
+            self.account_address,
+            chain_id::SEPOLIA,
+            ExecutionEncoding::New,
+        );
+        account.set_block_id(BlockId::Tag(BlockTag::Pending));
+
+        account
+    }
+
+    /// The `verify` call on the verifier contract.
+    pub fn verify_call(&self, calldata: Vec<Felt>) -> Call {
+        Call {
+            to: self.contract_address,
+            selector: get_selector_from_name("verify").unwrap(),
+            calldata,
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::commands::type_conversion::{convert_low_high_to_u384, convert_u384_to_low_high};
+use crate::commands::types::{PLONKProof, VerificationKey};
+use crate::error::CliError;
+use starknet::core::{crypto::compute_hash_on_elements, types::Felt};
+
+/// Number of felts used by the serialized `PlonkVerificationKey`
+/// (6 scalars, 8 G1 points, 1 G2 point and `w`, every u384 split into two felts)
+pub const VK_CALLDATA_LEN: usize = 6 * 2 + 8 * 4 + 8 + 2;
+
+/// Number of felts used by the serialized `PlonkProof` (9 G1 points and 6 evaluations)
+pub const PROOF_CALLDATA_LEN: usize = 9 * 4 + 6 * 2;
+
+fn push_u384(calldata: &mut Vec<Felt>, value: &str) -> Result<(), CliError> {
+    let (low, high) = convert_u384_to_low_high(value)?;
+    for part in [low, high] {
+        let felt = Felt::from_dec_str(&part)
+            .map_err(|e| CliError::InvalidInput(format!("Invalid value {}: {}", value, e)))?;
+        calldata.push(felt);
+    }
+    Ok(())
+}
+
+/// Serializes the inputs of the verifier's `verify` entry point.
+pub fn build_calldata(
+    vk: &VerificationKey,
+    proof: &PLONKProof,
+    public_signals: &[String],
+) -> Result<Vec<Felt>, CliError> {
+    let mut calldata: Vec<Felt> = vec![];
+
+    // Add verification key fields
+    for scalar in [
+        &vk.n,
+        &vk.power,
+        &vk.k1,
+        &vk.k2,
+        &vk.n_public,
+        &vk.n_lagrange,
+    ] {
+        push_u384(&mut calldata, scalar)?;
+    }
+
+    // Add G1 points for Qm, Qc, Ql, Qr, Qo, S1, S2, S3
+    let g1_points = [
+        &vk.qm, &vk.qc, &vk.ql, &vk.qr, &vk.qo, &vk.s1, &vk.s2, &vk.s3,
+    ];
+
+    for point in g1_points {
+        // Process the first two values in each point
+        for value in &point[0..2] {
+            push_u384(&mut calldata, value)?;
+        }
+    }
+
+    // Add G2 points for X_2
+    for sub_vector in &vk.x_2[0..2] {
+        for value in sub_vector {
+            push_u384(&mut calldata, value)?;
+        }
+    }
+
+    push_u384(&mut calldata, &vk.w)?;
+
+    // Add proof fields
+    let proof_field_points = [
+        &proof.a,
+        &proof.b,
+        &proof.c,
+        &proof.z,
+        &proof.t1,
+        &proof.t2,
+        &proof.t3,
+        &proof.wxi,
+        &proof.wxiw,
+    ];
+
+    for point in proof_field_points {
+        for value in &point[0..2] {
+            push_u384(&mut calldata, value)?;
+        }
+    }
+
+    // Add scalar proof fields
+    let proof_scalar_fields = [
+        &proof.eval_a,
+        &proof.eval_b,
+        &proof.eval_c,
+        &proof.eval_s1,
+        &proof.eval_s2,
+        &proof.eval_zw,
+    ];
+
+    for scalar in proof_scalar_fields {
+        push_u384(&mut calldata, scalar)?;
+    }
+
+    // Add public signals
+    calldata.push(Felt::from(public_signals.len()));
+
+    for signal in public_signals {
+        push_u384(&mut calldata, signal)?;
+    }
+
+    Ok(calldata)
+}
+
+/// The parts of a `verify` call recovered from its calldata.
+#[derive(Debug, Clone)]
+pub struct DecodedVerifyCall {
+    /// Pedersen hash over the serialized verification key
+    pub vk_hash: Felt,
+    /// Public signals as decimal strings
+    pub public_signals: Vec<String>,
+}
+
+/// Decodes calldata produced by [`build_calldata`].
+pub fn decode_calldata(calldata: &[Felt]) -> Result<DecodedVerifyCall, CliError> {
+    let public_offset = VK_CALLDATA_LEN + PROOF_CALLDATA_LEN;
+    if calldata.len() <= public_offset {
+        return Err(CliError::ParseError(format!(
+            "Calldata too short for a verify call: {} felts",
+            calldata.len()
+        )));
+    }
+
+    let vk_hash = compute_hash_on_elements(&calldata[..VK_CALLDATA_LEN]);
+
+    let n_signals: usize = calldata[public_offset]
+        .try_into()
+        .map_err(|_| CliError::ParseError("Invalid public signal count".to_string()))?;
+    let signals = &calldata[public_offset + 1..];
+    if signals.len() != n_signals * 2 {
+        return Err(CliError::ParseError(format!(
+            "Expected {} public signals, found {} felts",
+            n_signals,
+            signals.len()
+        )));
+    }
+
+    let public_signals = signals
+        .chunks(2)
+        .map(|limbs| convert_low_high_to_u384(&limbs[0], &limbs[1]))
+        .collect();
+
+    Ok(DecodedVerifyCall {
+        vk_hash,
+        public_signals,
+    })
+}
+
+/// Finds the call to `to`/`selector` inside an account `__execute__` calldata (Cairo 1 encoding)
+/// and returns its inner calldata.
+pub fn find_call_in_execute_calldata(
+    execute_calldata: &[Felt],
+    to: Felt,
+    selector: Felt,
+) -> Option<&[Felt]> {
+    let n_calls: usize = execute_calldata.first()?.to_owned().try_into().ok()?;
+    let mut offset = 1;
+
+    for _ in 0..n_calls {
+        let call_to = *execute_calldata.get(offset)?;
+        let call_selector = *execute_calldata.get(offset + 1)?;
+        let len: usize = execute_calldata
+            .get(offset + 2)?
+            .to_owned()
+            .try_into()
+            .ok()?;
+        let data = execute_calldata.get(offset + 3..offset + 3 + len)?;
+
+        if call_to == to && call_selector == selector {
+            return Some(data);
+        }
+        offset += 3 + len;
+    }
+
+    None
+}
@@ -0,0 +1,28 @@
+use crate::cli::Cli;
+use crate::error::CliError;
+use clap::CommandFactory;
+use clap_complete::Shell;
+use std::path::Path;
+
+/// Writes the completion script for `shell` to stdout.
+pub fn completions(shell: Shell) {
+    let mut command = Cli::command();
+    let name = command.get_name().to_string();
+    clap_complete::generate(shell, &mut command, name, &mut std::io::stdout());
+}
+
+/// Prints the main man page, or writes a page per subcommand to `out_dir`.
+pub fn man(out_dir: Option<&Path>) -> Result<(), CliError> {
+    let command = Cli::command();
+
+    match out_dir {
+        Some(dir) => {
+            std::fs::create_dir_all(dir)?;
+            clap_mangen::generate_to(command, dir)?;
+            println!("Wrote man pages to {}", dir.display());
+        }
+        None => clap_mangen::Man::new(command).render(&mut std::io::stdout())?,
+    }
+
+    Ok(())
+}
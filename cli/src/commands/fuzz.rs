@@ -0,0 +1,512 @@
+use crate::commands::calldata::build_calldata;
+use crate::commands::plonk::verify_proof;
+use crate::commands::types::{PLONKProof, VerificationKey};
+use crate::commands::utils::read_env_var;
+use crate::commands::verify::load_inputs;
+use crate::error::CliError;
+use dotenv::dotenv;
+use num_bigint::BigUint;
+use starknet::{
+    core::{
+        types::{BlockId, BlockTag, Felt, FunctionCall, StarknetError},
+        utils::get_selector_from_name,
+    },
+    providers::{
+        jsonrpc::{HttpTransport, JsonRpcClient},
+        Provider, ProviderError, Url,
+    },
+};
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// BN254 base field modulus
+const FIELD_MODULUS: &str =
+    "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+
+/// BN254 scalar field modulus
+const ORDER: &str = "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+
+/// A tampered copy of the verifier inputs
+#[derive(Debug, Clone)]
+pub struct Mutant {
+    /// Mutated field, e.g. `proof.A` or `public[2]`
+    pub field: String,
+    pub description: String,
+    pub vk: VerificationKey,
+    pub proof: PLONKProof,
+    pub public_signals: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Accepted,
+    Rejected(String),
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Accepted => write!(f, "accepted"),
+            Verdict::Rejected(reason) => write!(f, "rejected ({})", reason),
+        }
+    }
+}
+
+/// Read-only access to the deployed verifier
+struct OnChainVerifier {
+    provider: JsonRpcClient<HttpTransport>,
+    contract_address: Felt,
+}
+
+impl OnChainVerifier {
+    fn from_env() -> Result<Self, CliError> {
+        dotenv().ok();
+
+        let rpc_url = read_env_var("RPC_URL")?;
+        let contract_address = read_env_var("CONTRACT_ADDRESS")?;
+        Ok(Self {
+            provider: JsonRpcClient::new(HttpTransport::new(
+                Url::parse(&rpc_url)
+                    .map_err(|e| CliError::InvalidInput(format!("Invalid RPC_URL: {}", e)))?,
+            )),
+            contract_address: Felt::from_hex(&contract_address)
+                .map_err(|e| CliError::InvalidInput(format!("Invalid CONTRACT_ADDRESS: {}", e)))?,
+        })
+    }
+
+    /// Simulates `verify` with `starknet_call`, nothing is submitted.
+    async fn verify(
+        &self,
+        vk: &VerificationKey,
+        proof: &PLONKProof,
+        public_signals: &[String],
+    ) -> Result<Verdict, CliError> {
+        let request = FunctionCall {
+            contract_address: self.contract_address,
+            entry_point_selector: get_selector_from_name("verify").unwrap(),
+            calldata: build_calldata(vk, proof, public_signals)?,
+        };
+
+        match self
+            .provider
+            .call(request, BlockId::Tag(BlockTag::Latest))
+            .await
+        {
+            Ok(result) if result.first() == Some(&Felt::ONE) => Ok(Verdict::Accepted),
+            Ok(_) => Ok(Verdict::Rejected("returned false".to_string())),
+            // The verifier panics on some malformed inputs, which is a rejection as well
+            Err(ProviderError::StarknetError(StarknetError::ContractError(_))) => {
+                Ok(Verdict::Rejected("execution failed".to_string()))
+            }
+            Err(e) => Err(CliError::TransactionError(format!(
+                "Failed to call the verifier: {}",
+                e
+            ))),
+        }
+    }
+}
+
+fn local_verdict(vk: &VerificationKey, proof: &PLONKProof, public_signals: &[String]) -> Verdict {
+    match verify_proof(vk, proof, public_signals) {
+        Ok(()) => Verdict::Accepted,
+        Err(rejection) => Verdict::Rejected(rejection.to_string()),
+    }
+}
+
+pub async fn fuzz(
+    vk_path: PathBuf,
+    proof_path: PathBuf,
+    public_inputs_path: PathBuf,
+    on_chain: bool,
+    verbose: bool,
+) -> Result<(), CliError> {
+    let (vk, proof, public_signals) = load_inputs(vk_path, proof_path, public_inputs_path)?;
+    let on_chain = match on_chain {
+        true => Some(OnChainVerifier::from_env()?),
+        false => None,
+    };
+
+    // Mutants are only meaningful if the original inputs are accepted
+    println!("Checking the original proof...");
+    if let Verdict::Rejected(reason) = local_verdict(&vk, &proof, &public_signals) {
+        return Err(CliError::InvalidInput(format!(
+            "The original proof is rejected by the local verifier: {}",
+            reason
+        )));
+    }
+    if let Some(verifier) = &on_chain {
+        if let Verdict::Rejected(reason) = verifier.verify(&vk, &proof, &public_signals).await? {
+            return Err(CliError::InvalidInput(format!(
+                "The original proof is rejected on-chain: {}",
+                reason
+            )));
+        }
+    }
+
+    let mutants = mutants(&vk, &proof, &public_signals)?;
+    println!("Running {} mutants...", mutants.len());
+
+    let mut accepted = vec![];
+    for mutant in &mutants {
+        let local = local_verdict(&mutant.vk, &mutant.proof, &mutant.public_signals);
+        let remote = match &on_chain {
+            Some(verifier) => Some(
+                verifier
+                    .verify(&mutant.vk, &mutant.proof, &mutant.public_signals)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        let is_accepted = local == Verdict::Accepted || remote.as_ref() == Some(&Verdict::Accepted);
+        if is_accepted || verbose {
+            let mark = if is_accepted { "⚠️ " } else { "✅" };
+            print!(
+                "{} {} {}: local {}",
+                mark, mutant.field, mutant.description, local
+            );
+            match &remote {
+                Some(remote) => println!(", on-chain {}", remote),
+                None => println!(),
+            }
+        }
+        if is_accepted {
+            accepted.push(mutant);
+        }
+    }
+
+    println!(
+        "\n{} mutant(s) run, {} rejected, {} accepted",
+        mutants.len(),
+        mutants.len() - accepted.len(),
+        accepted.len()
+    );
+
+    if !accepted.is_empty() {
+        return Err(CliError::VerificationError(format!(
+            "{} tampered proof(s) were accepted",
+            accepted.len()
+        )));
+    }
+
+    println!("✅ Every tampered proof was rejected");
+    Ok(())
+}
+
+fn proof_points(proof: &mut PLONKProof) -> [(&'static str, &mut [String; 3]); 9] {
+    [
+        ("A", &mut proof.a),
+        ("B", &mut proof.b),
+        ("C", &mut proof.c),
+        ("Z", &mut proof.z),
+        ("T1", &mut proof.t1),
+        ("T2", &mut proof.t2),
+        ("T3", &mut proof.t3),
+        ("Wxi", &mut proof.wxi),
+        ("Wxiw", &mut proof.wxiw),
+    ]
+}
+
+fn proof_evaluations(proof: &mut PLONKProof) -> [(&'static str, &mut String); 6] {
+    [
+        ("eval_a", &mut proof.eval_a),
+        ("eval_b", &mut proof.eval_b),
+        ("eval_c", &mut proof.eval_c),
+        ("eval_s1", &mut proof.eval_s1),
+        ("eval_s2", &mut proof.eval_s2),
+        ("eval_zw", &mut proof.eval_zw),
+    ]
+}
+
+fn vk_points(vk: &mut VerificationKey) -> [(&'static str, &mut [String; 3]); 8] {
+    [
+        ("Qm", &mut vk.qm),
+        ("Ql", &mut vk.ql),
+        ("Qr", &mut vk.qr),
+        ("Qo", &mut vk.qo),
+        ("Qc", &mut vk.qc),
+        ("S1", &mut vk.s1),
+        ("S2", &mut vk.s2),
+        ("S3", &mut vk.s3),
+    ]
+}
+
+fn vk_scalars(vk: &mut VerificationKey) -> [(&'static str, &mut String); 4] {
+    [
+        ("k1", &mut vk.k1),
+        ("k2", &mut vk.k2),
+        ("w", &mut vk.w),
+        ("power", &mut vk.power),
+    ]
+}
+
+fn big(value: &str) -> Result<BigUint, CliError> {
+    BigUint::from_str(value)
+        .map_err(|e| CliError::InvalidInput(format!("Invalid number '{}': {}", value, e)))
+}
+
+/// Tampered versions of a G1 point: off-curve, negated, non-canonical and substituted.
+fn point_mutations(
+    point: &[String; 3],
+    p: &BigUint,
+) -> Result<Vec<(&'static str, [String; 3])>, CliError> {
+    let (x, y) = (big(&point[0])?, big(&point[1])?);
+    let one = BigUint::from(1u32);
+    let z = point[2].clone();
+
+    Ok(vec![
+        (
+            "x + 1",
+            [((&x + &one) % p).to_string(), point[1].clone(), z.clone()],
+        ),
+        (
+            "y + 1",
+            [point[0].clone(), ((&y + &one) % p).to_string(), z.clone()],
+        ),
+        (
+            "negated",
+            [point[0].clone(), ((p - &y) % p).to_string(), z.clone()],
+        ),
+        (
+            "x + p (not reduced)",
+            [(&x + p).to_string(), point[1].clone(), z.clone()],
+        ),
+        (
+            "y + p (not reduced)",
+            [point[0].clone(), (&y + p).to_string(), z.clone()],
+        ),
+        (
+            "replaced by the generator",
+            ["1".to_string(), "2".to_string(), z],
+        ),
+    ])
+}
+
+/// Tampered versions of a scalar: shifted, non-canonical and zeroed.
+fn scalar_mutations(value: &str, r: &BigUint) -> Result<Vec<(&'static str, String)>, CliError> {
+    let value = big(value)?;
+    let mut mutations = vec![
+        ("+ 1", ((&value + 1u32) % r).to_string()),
+        ("+ r (not reduced)", (&value + r).to_string()),
+    ];
+    if value != BigUint::default() {
+        mutations.push(("set to 0", "0".to_string()));
+    }
+    Ok(mutations)
+}
+
+/// Enumerates every mutant of the verifier inputs.
+/// Fails if one of the values is not a decimal number.
+pub fn mutants(
+    vk: &VerificationKey,
+    proof: &PLONKProof,
+    public_signals: &[String],
+) -> Result<Vec<Mutant>, CliError> {
+    let p = big(FIELD_MODULUS)?;
+    let r = big(ORDER)?;
+    let mut mutants = vec![];
+
+    let mut push = |field: String, description: String, vk, proof, public_signals| {
+        mutants.push(Mutant {
+            field,
+            description,
+            vk,
+            proof,
+            public_signals,
+        })
+    };
+
+    // Proof commitments
+    let points: Vec<(&str, [String; 3])> = proof_points(&mut proof.clone())
+        .into_iter()
+        .map(|(name, point)| (name, point.clone()))
+        .collect();
+    for (i, (name, point)) in points.iter().enumerate() {
+        for (description, mutated) in point_mutations(point, &p)? {
+            let mut tampered = proof.clone();
+            *proof_points(&mut tampered)[i].1 = mutated;
+            push(
+                format!("proof.{}", name),
+                description.to_string(),
+                vk.clone(),
+                tampered,
+                public_signals.to_vec(),
+            );
+        }
+    }
+
+    // Proof evaluations, tampered one at a time and swapped pairwise
+    let evals: Vec<(&str, String)> = proof_evaluations(&mut proof.clone())
+        .into_iter()
+        .map(|(name, value)| (name, value.clone()))
+        .collect();
+    for (i, (name, value)) in evals.iter().enumerate() {
+        for (description, mutated) in scalar_mutations(value, &r)? {
+            let mut tampered = proof.clone();
+            *proof_evaluations(&mut tampered)[i].1 = mutated;
+            push(
+                format!("proof.{}", name),
+                description.to_string(),
+                vk.clone(),
+                tampered,
+                public_signals.to_vec(),
+            );
+        }
+        for (j, (other, other_value)) in evals.iter().enumerate().skip(i + 1) {
+            if value == other_value {
+                continue;
+            }
+            let mut tampered = proof.clone();
+            *proof_evaluations(&mut tampered)[i].1 = other_value.clone();
+            *proof_evaluations(&mut tampered)[j].1 = value.clone();
+            push(
+                format!("proof.{}", name),
+                format!("swapped with {}", other),
+                vk.clone(),
+                tampered,
+                public_signals.to_vec(),
+            );
+        }
+    }
+
+    // Public signals
+    for (i, signal) in public_signals.iter().enumerate() {
+        for (description, mutated) in scalar_mutations(signal, &r)? {
+            let mut tampered = public_signals.to_vec();
+            tampered[i] = mutated;
+            push(
+                format!("public[{}]", i),
+                description.to_string(),
+                vk.clone(),
+                proof.clone(),
+                tampered,
+            );
+        }
+        if let Some(next) = public_signals.get(i + 1).filter(|next| *next != signal) {
+            let mut tampered = public_signals.to_vec();
+            tampered[i] = next.clone();
+            tampered[i + 1] = signal.clone();
+            push(
+                format!("public[{}]", i),
+                format!("swapped with public[{}]", i + 1),
+                vk.clone(),
+                proof.clone(),
+                tampered,
+            );
+        }
+    }
+    if !public_signals.is_empty() {
+        push(
+            "public".to_string(),
+            "last signal dropped".to_string(),
+            vk.clone(),
+            proof.clone(),
+            public_signals[..public_signals.len() - 1].to_vec(),
+        );
+    }
+    let mut extended = public_signals.to_vec();
+    extended.push("0".to_string());
+    push(
+        "public".to_string(),
+        "extra signal appended".to_string(),
+        vk.clone(),
+        proof.clone(),
+        extended.clone(),
+    );
+
+    // Verification key
+    let points: Vec<(&str, [String; 3])> = vk_points(&mut vk.clone())
+        .into_iter()
+        .map(|(name, point)| (name, point.clone()))
+        .collect();
+    for (i, (name, point)) in points.iter().enumerate() {
+        for (description, mutated) in point_mutations(point, &p)? {
+            let mut tampered = vk.clone();
+            *vk_points(&mut tampered)[i].1 = mutated;
+            push(
+                format!("vk.{}", name),
+                description.to_string(),
+                tampered,
+                proof.clone(),
+                public_signals.to_vec(),
+            );
+        }
+    }
+    let scalars: Vec<(&str, String)> = vk_scalars(&mut vk.clone())
+        .into_iter()
+        .map(|(name, value)| (name, value.clone()))
+        .collect();
+    for (i, (name, value)) in scalars.iter().enumerate() {
+        let mut tampered = vk.clone();
+        *vk_scalars(&mut tampered)[i].1 = (big(value)? + 1u32).to_string();
+        push(
+            format!("vk.{}", name),
+            "+ 1".to_string(),
+            tampered,
+            proof.clone(),
+            public_signals.to_vec(),
+        );
+    }
+
+    let mut tampered = vk.clone();
+    tampered.x_2[0][0] = ((big(&vk.x_2[0][0])? + 1u32) % &p).to_string();
+    push(
+        "vk.X_2".to_string(),
+        "x.c0 + 1".to_string(),
+        tampered,
+        proof.clone(),
+        public_signals.to_vec(),
+    );
+    let mut tampered = vk.clone();
+    for coordinate in tampered.x_2[1].iter_mut() {
+        *coordinate = ((&p - big(coordinate)?) % &p).to_string();
+    }
+    push(
+        "vk.X_2".to_string(),
+        "negated".to_string(),
+        tampered,
+        proof.clone(),
+        public_signals.to_vec(),
+    );
+
+    let mut tampered = vk.clone();
+    tampered.n_public = (big(&vk.n_public)? + 1u32).to_string();
+    push(
+        "vk.nPublic".to_string(),
+        "+ 1 with an extra signal".to_string(),
+        tampered,
+        proof.clone(),
+        extended,
+    );
+
+    Ok(mutants)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{local_verdict, mutants, Verdict};
+    use crate::commands::plonk::tests::fixture;
+    use crate::error::CliError;
+
+    #[test]
+    fn test_mutants_rejected() {
+        let (vk, proof, public_signals) = fixture();
+        let mutants = mutants(&vk, &proof, &public_signals).unwrap();
+        assert!(!mutants.is_empty());
+        for mutant in mutants {
+            let verdict = local_verdict(&mutant.vk, &mutant.proof, &mutant.public_signals);
+            assert_ne!(verdict, Verdict::Accepted, "{} {}", mutant.field, mutant.description);
+        }
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let (vk, mut proof, public_signals) = fixture();
+        proof.eval_a = "0x12".to_string();
+        assert!(matches!(
+            mutants(&vk, &proof, &public_signals),
+            Err(CliError::InvalidInput(_))
+        ));
+    }
+}
@@ -0,0 +1,234 @@
+use crate::commands::calldata::{decode_calldata, find_call_in_execute_calldata};
+use crate::commands::utils::read_env_var;
+use crate::error::CliError;
+use clap::ValueEnum;
+use dotenv::dotenv;
+use serde::Serialize;
+use starknet::{
+    core::{
+        types::{BlockId, EmittedEvent, EventFilter, Felt, InvokeTransaction, Transaction},
+        utils::get_selector_from_name,
+    },
+    providers::{
+        jsonrpc::{HttpTransport, JsonRpcClient},
+        Provider, Url,
+    },
+};
+use std::collections::{hash_map::Entry, HashMap};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+const EVENTS_CHUNK_SIZE: u64 = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum HistoryFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+/// A single `PairingCheck` event together with the decoded `verify` call that emitted it.
+#[derive(Debug, Clone, Serialize)]
+pub struct VerificationRecord {
+    pub block_number: Option<u64>,
+    pub transaction_hash: String,
+    pub contract_address: String,
+    pub result: bool,
+    /// Hash of the verification key, only available for calls to the verifier's `verify`
+    pub vk_hash: Option<String>,
+    pub public_signals: Vec<String>,
+}
+
+pub async fn history(
+    from_block: u64,
+    to_block: Option<u64>,
+    include_pairing: bool,
+    format: HistoryFormat,
+    output: Option<PathBuf>,
+) -> Result<(), CliError> {
+    dotenv().ok();
+
+    let rpc_url = read_env_var("RPC_URL")?;
+    let mut addresses = vec![parse_address(&read_env_var("CONTRACT_ADDRESS")?)?];
+    if include_pairing {
+        addresses.push(parse_address(&read_env_var("PAIRING_ADDRESS")?)?);
+    }
+
+    let provider = JsonRpcClient::new(HttpTransport::new(
+        Url::parse(&rpc_url)
+            .map_err(|e| CliError::InvalidInput(format!("Invalid RPC_URL: {}", e)))?,
+    ));
+
+    let mut records = vec![];
+    for address in addresses {
+        let events = fetch_pairing_events(&provider, address, from_block, to_block).await?;
+        records.extend(decode_events(&provider, events).await?);
+    }
+    // Pending events have no block yet, they come after every included one
+    records.sort_by_key(|r| (r.block_number.is_none(), r.block_number));
+
+    let rendered = match format {
+        HistoryFormat::Table => render_table(&records),
+        HistoryFormat::Json => serde_json::to_string_pretty(&records)?,
+        HistoryFormat::Csv => render_csv(&records),
+    };
+
+    match output {
+        Some(path) => {
+            std::fs::write(&path, rendered)?;
+            println!("Wrote {} record(s) to {}", records.len(), path.display());
+        }
+        None => print!("{}", rendered),
+    }
+
+    Ok(())
+}
+
+fn parse_address(address: &str) -> Result<Felt, CliError> {
+    Felt::from_hex(address)
+        .map_err(|e| CliError::InvalidInput(format!("Invalid contract address {}: {}", address, e)))
+}
+
+async fn fetch_pairing_events<P: Provider + Sync>(
+    provider: &P,
+    address: Felt,
+    from_block: u64,
+    to_block: Option<u64>,
+) -> Result<Vec<EmittedEvent>, CliError> {
+    let to_block = match to_block {
+        Some(block) => block,
+        None => provider.block_number().await.map_err(|e| {
+            CliError::TransactionError(format!("Failed to fetch block number: {}", e))
+        })?,
+    };
+
+    let filter = EventFilter {
+        from_block: Some(BlockId::Number(from_block)),
+        to_block: Some(BlockId::Number(to_block)),
+        address: Some(address),
+        keys: Some(vec![vec![get_selector_from_name("PairingCheck").unwrap()]]),
+    };
+
+    let mut events = vec![];
+    let mut continuation_token = None;
+    loop {
+        let page = provider
+            .get_events(filter.clone(), continuation_token, EVENTS_CHUNK_SIZE)
+            .await
+            .map_err(|e| CliError::TransactionError(format!("Failed to fetch events: {}", e)))?;
+        events.extend(page.events);
+
+        match page.continuation_token {
+            Some(token) => continuation_token = Some(token),
+            None => break,
+        }
+    }
+
+    Ok(events)
+}
+
+async fn decode_events<P: Provider + Sync>(
+    provider: &P,
+    events: Vec<EmittedEvent>,
+) -> Result<Vec<VerificationRecord>, CliError> {
+    let verify_selector = get_selector_from_name("verify").unwrap();
+    let mut calls: HashMap<Felt, Option<Vec<Felt>>> = HashMap::new();
+    let mut records = vec![];
+
+    for event in events {
+        let result = event.data.first().is_some_and(|res| *res != Felt::ZERO);
+
+        if let Entry::Vacant(entry) = calls.entry(event.transaction_hash) {
+            let transaction = provider
+                .get_transaction_by_hash(event.transaction_hash)
+                .await
+                .map_err(|e| {
+                    CliError::TransactionError(format!(
+                        "Failed to fetch transaction {:#064x}: {}",
+                        event.transaction_hash, e
+                    ))
+                })?;
+            let verify_calldata = execute_calldata(&transaction).and_then(|calldata| {
+                find_call_in_execute_calldata(calldata, event.from_address, verify_selector)
+                    .map(|call| call.to_vec())
+            });
+            entry.insert(verify_calldata);
+        }
+
+        let decoded = calls[&event.transaction_hash]
+            .as_deref()
+            .and_then(|calldata| decode_calldata(calldata).ok());
+
+        records.push(VerificationRecord {
+            block_number: event.block_number,
+            transaction_hash: format!("{:#064x}", event.transaction_hash),
+            contract_address: format!("{:#064x}", event.from_address),
+            result,
+            vk_hash: decoded.as_ref().map(|d| format!("{:#064x}", d.vk_hash)),
+            public_signals: decoded.map(|d| d.public_signals).unwrap_or_default(),
+        });
+    }
+
+    Ok(records)
+}
+
+fn execute_calldata(transaction: &Transaction) -> Option<&[Felt]> {
+    match transaction {
+        Transaction::Invoke(InvokeTransaction::V1(tx)) => Some(&tx.calldata),
+        Transaction::Invoke(InvokeTransaction::V3(tx)) => Some(&tx.calldata),
+        _ => None,
+    }
+}
+
+fn render_table(records: &[VerificationRecord]) -> String {
+    let mut out = String::new();
+    for record in records {
+        let block = record
+            .block_number
+            .map_or("pending".to_string(), |b| b.to_string());
+        let status = if record.result {
+            "✅ valid"
+        } else {
+            "❌ invalid"
+        };
+        writeln!(
+            out,
+            "Block {} | {} | {}",
+            block, record.transaction_hash, status
+        )
+        .unwrap();
+        if let Some(vk_hash) = &record.vk_hash {
+            writeln!(out, "  vk hash: {}", vk_hash).unwrap();
+        }
+        if !record.public_signals.is_empty() {
+            writeln!(
+                out,
+                "  public signals: [{}]",
+                record.public_signals.join(", ")
+            )
+            .unwrap();
+        }
+    }
+    writeln!(out, "{} verification(s) found", records.len()).unwrap();
+    out
+}
+
+fn render_csv(records: &[VerificationRecord]) -> String {
+    let mut out = "block_number,transaction_hash,contract_address,result,vk_hash,public_signals\n"
+        .to_string();
+    for record in records {
+        writeln!(
+            out,
+            "{},{},{},{},{},{}",
+            record.block_number.map_or(String::new(), |b| b.to_string()),
+            record.transaction_hash,
+            record.contract_address,
+            record.result,
+            record.vk_hash.as_deref().unwrap_or(""),
+            // Signals are decimal so `;` never clashes with the values
+            record.public_signals.join(";")
+        )
+        .unwrap();
+    }
+    out
+}
@@ -1,3 +1,11 @@
+pub mod account;
+pub mod calldata;
+pub mod completions;
+pub mod fuzz;
+pub mod history;
+pub mod plonk;
+pub mod serve;
+pub mod submit;
 pub mod type_conversion;
 pub mod types;
 pub mod utils;
@@ -0,0 +1,499 @@
+//! Native PLONK verifier following the same steps as `plonk-verifier/src/plonk/verify.cairo`.
+
+use crate::commands::types::{PLONKProof, VerificationKey};
+use ark_bn254::{Bn254, Fq, Fq2, Fr, G1Affine, G1Projective, G2Affine};
+use ark_ec::{pairing::Pairing, AffineRepr, CurveGroup};
+use ark_ff::{Field, One, PrimeField, Zero};
+use num_bigint::BigUint;
+use sha3::{Digest, Keccak256};
+use std::fmt;
+use std::str::FromStr;
+
+/// Why a proof was rejected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejection {
+    /// A value is not a decimal number
+    Malformed(String),
+    /// A coordinate or scalar is not reduced modulo its field
+    NotInField(String),
+    /// A point does not satisfy the curve equation (or is outside the G2 subgroup)
+    NotOnCurve(String),
+    /// The number of public signals differs from `nPublic`
+    PublicInputsLength { expected: usize, found: usize },
+    /// A denominator of the Lagrange evaluations vanished
+    DivisionByZero,
+    /// The final pairing check failed
+    Pairing,
+}
+
+impl fmt::Display for Rejection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rejection::Malformed(name) => write!(f, "{} is malformed", name),
+            Rejection::NotInField(name) => write!(f, "{} is not in the field", name),
+            Rejection::NotOnCurve(name) => write!(f, "{} is not on the curve", name),
+            Rejection::PublicInputsLength { expected, found } => {
+                write!(f, "expected {} public signals, found {}", expected, found)
+            }
+            Rejection::DivisionByZero => write!(f, "division by zero"),
+            Rejection::Pairing => write!(f, "pairing check failed"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Challenges {
+    beta: Fr,
+    gamma: Fr,
+    alpha: Fr,
+    xi: Fr,
+    xin: Fr,
+    zh: Fr,
+    u: Fr,
+}
+
+struct Evaluations {
+    a: Fr,
+    b: Fr,
+    c: Fr,
+    s1: Fr,
+    s2: Fr,
+    zw: Fr,
+}
+
+fn parse(name: &str, value: &str) -> Result<BigUint, Rejection> {
+    BigUint::from_str(value).map_err(|_| Rejection::Malformed(name.to_string()))
+}
+
+fn modulus<F: PrimeField>() -> BigUint {
+    F::MODULUS.into()
+}
+
+/// Parses a value that must be strictly lower than the modulus of `F`.
+fn canonical<F: PrimeField>(name: &str, value: &str) -> Result<F, Rejection> {
+    let value = parse(name, value)?;
+    if value >= modulus::<F>() {
+        return Err(Rejection::NotInField(name.to_string()));
+    }
+    Ok(F::from(value))
+}
+
+fn g1(name: &str, point: &[String; 3]) -> Result<G1Affine, Rejection> {
+    let x = canonical::<Fq>(name, &point[0])?;
+    let y = canonical::<Fq>(name, &point[1])?;
+    let point = G1Affine::new_unchecked(x, y);
+    // BN254 G1 has cofactor 1, being on the curve is enough
+    if !point.is_on_curve() {
+        return Err(Rejection::NotOnCurve(name.to_string()));
+    }
+    Ok(point)
+}
+
+fn g2(name: &str, point: &[[String; 2]; 3]) -> Result<G2Affine, Rejection> {
+    let x = Fq2::new(
+        canonical(name, &point[0][0])?,
+        canonical(name, &point[0][1])?,
+    );
+    let y = Fq2::new(
+        canonical(name, &point[1][0])?,
+        canonical(name, &point[1][1])?,
+    );
+    let point = G2Affine::new_unchecked(x, y);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(Rejection::NotOnCurve(name.to_string()));
+    }
+    Ok(point)
+}
+
+/// Keccak256 transcript, every element is absorbed as 32 big-endian bytes.
+#[derive(Default)]
+struct Transcript {
+    buffer: Vec<u8>,
+}
+
+impl Transcript {
+    fn add_scalar(&mut self, scalar: &BigUint) {
+        let bytes = scalar.to_bytes_be();
+        self.buffer
+            .extend(std::iter::repeat_n(0, 32usize.saturating_sub(bytes.len())));
+        self.buffer.extend(bytes);
+    }
+
+    fn add_fr(&mut self, scalar: Fr) {
+        self.add_scalar(&scalar.into());
+    }
+
+    fn add_point(&mut self, point: G1Affine) {
+        self.add_scalar(&point.x.into());
+        self.add_scalar(&point.y.into());
+    }
+
+    fn challenge(self) -> Fr {
+        Fr::from_be_bytes_mod_order(&Keccak256::digest(&self.buffer))
+    }
+}
+
+/// Verifies a snarkjs PLONK proof, returning the first failed check.
+pub fn verify_proof(
+    vk: &VerificationKey,
+    proof: &PLONKProof,
+    public_signals: &[String],
+) -> Result<(), Rejection> {
+    // Step 1: proof commitments are valid G1 points
+    let a = g1("A", &proof.a)?;
+    let b = g1("B", &proof.b)?;
+    let c = g1("C", &proof.c)?;
+    let z = g1("Z", &proof.z)?;
+    let t1 = g1("T1", &proof.t1)?;
+    let t2 = g1("T2", &proof.t2)?;
+    let t3 = g1("T3", &proof.t3)?;
+    let wxi = g1("Wxi", &proof.wxi)?;
+    let wxiw = g1("Wxiw", &proof.wxiw)?;
+
+    // Step 2: evaluations are in the scalar field
+    let evals = Evaluations {
+        a: canonical("eval_a", &proof.eval_a)?,
+        b: canonical("eval_b", &proof.eval_b)?,
+        c: canonical("eval_c", &proof.eval_c)?,
+        s1: canonical("eval_s1", &proof.eval_s1)?,
+        s2: canonical("eval_s2", &proof.eval_s2)?,
+        zw: canonical("eval_zw", &proof.eval_zw)?,
+    };
+
+    // Step 3: public signals match the verification key
+    let n_public: usize = parse("nPublic", &vk.n_public)?
+        .try_into()
+        .map_err(|_| Rejection::Malformed("nPublic".to_string()))?;
+    if n_public != public_signals.len() {
+        return Err(Rejection::PublicInputsLength {
+            expected: n_public,
+            found: public_signals.len(),
+        });
+    }
+    let publics = public_signals
+        .iter()
+        .enumerate()
+        .map(|(i, signal)| canonical::<Fr>(&format!("public signal {}", i), signal))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let qm = g1("Qm", &vk.qm)?;
+    let ql = g1("Ql", &vk.ql)?;
+    let qr = g1("Qr", &vk.qr)?;
+    let qo = g1("Qo", &vk.qo)?;
+    let qc = g1("Qc", &vk.qc)?;
+    let s1 = g1("S1", &vk.s1)?;
+    let s2 = g1("S2", &vk.s2)?;
+    let s3 = g1("S3", &vk.s3)?;
+    let x_2 = g2("X_2", &vk.x_2)?;
+    let k1: Fr = canonical("k1", &vk.k1)?;
+    let k2: Fr = canonical("k2", &vk.k2)?;
+    let w: Fr = canonical("w", &vk.w)?;
+    let power: u32 = parse("power", &vk.power)?
+        .try_into()
+        .map_err(|_| Rejection::Malformed("power".to_string()))?;
+
+    // Step 4: challenges
+    let mut transcript = Transcript::default();
+    for point in [qm, ql, qr, qo, qc, s1, s2, s3] {
+        transcript.add_point(point);
+    }
+    for public in &publics {
+        transcript.add_fr(*public);
+    }
+    for point in [a, b, c] {
+        transcript.add_point(point);
+    }
+    let beta = transcript.challenge();
+
+    let mut transcript = Transcript::default();
+    transcript.add_fr(beta);
+    let gamma = transcript.challenge();
+
+    let mut transcript = Transcript::default();
+    transcript.add_fr(beta);
+    transcript.add_fr(gamma);
+    transcript.add_point(z);
+    let alpha = transcript.challenge();
+
+    let mut transcript = Transcript::default();
+    transcript.add_fr(alpha);
+    for point in [t1, t2, t3] {
+        transcript.add_point(point);
+    }
+    let xi = transcript.challenge();
+
+    let mut transcript = Transcript::default();
+    transcript.add_fr(xi);
+    for eval in [evals.a, evals.b, evals.c, evals.s1, evals.s2, evals.zw] {
+        transcript.add_fr(eval);
+    }
+    let v1 = transcript.challenge();
+    let v = [v1, v1.pow([2]), v1.pow([3]), v1.pow([4]), v1.pow([5])];
+
+    let mut transcript = Transcript::default();
+    transcript.add_point(wxi);
+    transcript.add_point(wxiw);
+    let u = transcript.challenge();
+
+    // Steps 5 and 6: vanishing polynomial and Lagrange evaluations
+    let mut xin = xi;
+    let mut domain_size = Fr::one();
+    for _ in 0..power {
+        xin.square_in_place();
+        domain_size.double_in_place();
+    }
+    let zh = xin - Fr::one();
+
+    let challenges = Challenges {
+        beta,
+        gamma,
+        alpha,
+        xi,
+        xin,
+        zh,
+        u,
+    };
+
+    let mut lagrange = vec![];
+    let mut root = Fr::one();
+    for _ in 0..n_public.max(1) {
+        let denominator = (domain_size * (xi - root))
+            .inverse()
+            .ok_or(Rejection::DivisionByZero)?;
+        lagrange.push(root * zh * denominator);
+        root *= w;
+    }
+
+    // Step 7: public input polynomial
+    let pi = -publics
+        .iter()
+        .zip(&lagrange)
+        .map(|(public, l)| *public * l)
+        .sum::<Fr>();
+
+    // Step 8: constant part of the linearization polynomial
+    let l1 = lagrange[0];
+    let e3 = (evals.a + beta * evals.s1 + gamma)
+        * (evals.b + beta * evals.s2 + gamma)
+        * (evals.c + gamma)
+        * evals.zw
+        * alpha;
+    let r0 = pi - l1 * alpha.square() - e3;
+
+    // Steps 9 and 10: batched commitments D and F
+    let d = compute_d(
+        &challenges,
+        &evals,
+        l1,
+        k1,
+        k2,
+        [qm, ql, qr, qo, qc, s3],
+        z,
+        [t1, t2, t3],
+    );
+    let f = d + a * v[0] + b * v[1] + c * v[2] + s1 * v[3] + s2 * v[4];
+
+    // Step 11: group-encoded batch evaluation E
+    let e = -r0
+        + v[0] * evals.a
+        + v[1] * evals.b
+        + v[2] * evals.c
+        + v[3] * evals.s1
+        + v[4] * evals.s2
+        + u * evals.zw;
+    let e = G1Affine::generator() * e;
+
+    // Step 12: e(-A1, X_2) * e(B1, [1]_2) == 1
+    let a1 = G1Projective::from(wxi) + wxiw * u;
+    let b1 = wxi * xi + wxiw * (u * xi * w) + f - e;
+
+    let pairing = Bn254::multi_pairing(
+        [(-a1).into_affine(), b1.into_affine()],
+        [x_2, G2Affine::generator()],
+    );
+    if !pairing.is_zero() {
+        return Err(Rejection::Pairing);
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn compute_d(
+    challenges: &Challenges,
+    evals: &Evaluations,
+    l1: Fr,
+    k1: Fr,
+    k2: Fr,
+    [qm, ql, qr, qo, qc, s3]: [G1Affine; 6],
+    z: G1Affine,
+    [t1, t2, t3]: [G1Affine; 3],
+) -> G1Projective {
+    let Challenges {
+        beta,
+        gamma,
+        alpha,
+        xi,
+        xin,
+        zh,
+        u,
+    } = *challenges;
+
+    let d1 = qm * (evals.a * evals.b) + ql * evals.a + qr * evals.b + qo * evals.c + qc;
+
+    let betaxi = beta * xi;
+    let d2 = (evals.a + betaxi + gamma)
+        * (evals.b + betaxi * k1 + gamma)
+        * (evals.c + betaxi * k2 + gamma)
+        * alpha
+        + l1 * alpha.square()
+        + u;
+
+    let d3 = (evals.a + beta * evals.s1 + gamma)
+        * (evals.b + beta * evals.s2 + gamma)
+        * alpha
+        * beta
+        * evals.zw;
+
+    let d4 = (G1Projective::from(t1) + t2 * xin + t3 * xin.square()) * zh;
+
+    d1 + z * d2 - s3 * d3 - d4
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::{verify_proof, Rejection};
+    use crate::commands::types::{PLONKProof, VerificationKey};
+    use serde_json::json;
+
+    /// The proof of `plonk-verifier/src/plonk/constants.cairo`
+    pub(crate) fn fixture() -> (VerificationKey, PLONKProof, Vec<String>) {
+        let g1 = |x: &str, y: &str| json!([x, y, "1"]);
+        let vk = json!({
+            "protocol": "plonk",
+            "curve": "bn128",
+            "nPublic": 5,
+            "power": 12,
+            "k1": "2",
+            "k2": "3",
+            "Qm": g1(
+                "11240482550383658688279521830679253871322560915360199636916520528135605482444",
+                "3431122764236897545617224694179094663789418620279588141187823308980051568523",
+            ),
+            "Ql": g1(
+                "19520750151267480379403043633816096744187706921431743101456667401780936673048",
+                "2904046169698301367666378414613480674999945406696556734725765383213671080350",
+            ),
+            "Qr": g1(
+                "5361193342619395087772132966137528554334571919393439342516983798992339846953",
+                "10621881966959679780791508865914482336415559306143030367209878920501383639883",
+            ),
+            "Qo": g1(
+                "19736095359050112872741097174535800702036232155053734659598153018982327851919",
+                "9875023053467384224267823499571286781146309118843035684902387371421534071923",
+            ),
+            "Qc": g1(
+                "4168551058994119169098599756126849341890494780709237376665473361288000628985",
+                "16318122550996159765231944745563350163224658415565022435373632000800032685915",
+            ),
+            "S1": g1(
+                "19470080834542947757713990185384842568927167697587581102802424986481100757727",
+                "9463077273605123182680585591052993934200194532393159841270184988908955846072",
+            ),
+            "S2": g1(
+                "16816859138521257634566357998076491135190740882013031315753941298090146677616",
+                "9082632736126755326333353351746099363611335761325714850511274468208520863106",
+            ),
+            "S3": g1(
+                "12367430526798682210810421015155635850495584234395224982416917765827629877906",
+                "6135953264122108771254717049243374646557646249824573987481865482904018160665",
+            ),
+            "X_2": [
+                [
+                    "2046742093474138364318819827031777645206433195128565824360788617741298981525",
+                    "1433753357665853869090569273359618677040253248059110079322274768858965861594",
+                ],
+                [
+                    "1012593656704398130331921245405877456331931988986547477234119259528482165497",
+                    "4191056764018303486822079644163839762717699764181526746691927713416713155706",
+                ],
+                ["1", "0"],
+            ],
+            "w": "4158865282786404163413953114870269622875596290766033564087307867933865333818",
+        });
+        let proof = json!({
+            "A": g1(
+                "10145682537857657061034453444250060542160735082214568006588482889852729429283",
+                "4636457826231461981000328909301601529288749856075547745551595540417983094478",
+            ),
+            "B": g1(
+                "21660564883979151853810406117414553745840873935312762552528448427975132123801",
+                "431924083945838741254227896932504399854190081296419391191918676581449466215",
+            ),
+            "C": g1(
+                "300524113486380036443084320133730704976390221208117258926514144708824506219",
+                "21619727022743235605210745845312693737352347919465737051661426044094665308814",
+            ),
+            "Z": g1(
+                "11621036917319382368213455539721258895318811708043524885256212235043994348221",
+                "21246039196291803367698080346255509005358193717060539557589380305575336049896",
+            ),
+            "T1": g1(
+                "17107906628706336518761596247056028047831631661889424881370327119591791568242",
+                "4376713913108253438479025804063534613295822606800001423899857948301712836950",
+            ),
+            "T2": g1(
+                "4946882210289577446362628360627239417903541212231788376323842461238273988498",
+                "6108750262973085054062535975592877457236993968361694004663488360844602093018",
+            ),
+            "T3": g1(
+                "18739110241153474740846380343626289353986477668973757185874134865481030609116",
+                "16807204443624490686473433635066127740967762692116088923481529964422349920723",
+            ),
+            "Wxi": g1(
+                "249137365643743198372736343396439639356782224949744369780220478170143087608",
+                "7204659473206915962965531490350212209027925663732793950265779901148420044018",
+            ),
+            "Wxiw": g1(
+                "11635031187290428307047811112227226487860489293794570551122061051173340592497",
+                "19858679635667039421260152403491512225506984963090642670774417328490692605798",
+            ),
+            "eval_a": "2571844106989263132471587893183233146441179658087841933671176168068791528026",
+            "eval_b": "1745727970934740515253692627472813536705686771154120819099934574357205996472",
+            "eval_c": "21569936656389818010443741721610486034148258376828241332837239799889300662695",
+            "eval_s1": "7125532818257293020292427451672312101472703975447783515659573445866782468653",
+            "eval_s2": "5073230176805731877376434031491636687409869382035533898445070196212679413525",
+            "eval_zw": "9997443779015206626452921708994799579253015848032484174058762432410849598559",
+            "protocol": "plonk",
+            "curve": "bn128",
+        });
+        let public_signals = [
+            "18830187580832391953292633656724590808884826987965006042179076864562655717112",
+            "3142850441180811825929099504508009930706757625639242073235848449635957522737",
+            "1390849295786071768276380950238675083608645509734",
+            "642829559307850963015472508762062935916233390536",
+            "0",
+        ];
+        (
+            serde_json::from_value(vk).unwrap(),
+            serde_json::from_value(proof).unwrap(),
+            public_signals.iter().map(|s| s.to_string()).collect(),
+        )
+    }
+
+    #[test]
+    fn test_verify_proof() {
+        let (vk, proof, public_signals) = fixture();
+        assert_eq!(verify_proof(&vk, &proof, &public_signals), Ok(()));
+    }
+
+    #[test]
+    fn test_tampered_public_signal() {
+        let (vk, proof, mut public_signals) = fixture();
+        public_signals[4] = "1".to_string();
+        assert_eq!(
+            verify_proof(&vk, &proof, &public_signals),
+            Err(Rejection::Pairing)
+        );
+    }
+}
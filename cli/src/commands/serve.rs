@@ -0,0 +1,372 @@
+use crate::commands::account::{StarknetConfig, VerifierAccount};
+use crate::commands::calldata::build_calldata;
+use crate::commands::submit::{
+    fetch_nonce, submit, wait_for_receipt, SubmissionOutcome, SubmitConfig,
+};
+use crate::commands::types::{PLONKProof, VerificationKey};
+use crate::error::CliError;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use starknet::{
+    core::types::{ExecutionResult, Felt, StarknetError, TransactionFinalityStatus},
+    providers::{
+        jsonrpc::{HttpTransport, JsonRpcClient},
+        Provider, ProviderError,
+    },
+};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{
+    mpsc::{self, error::TrySendError},
+    oneshot,
+};
+
+/// Body of `POST /verify` and `POST /calldata`, using the snarkjs JSON layouts.
+#[derive(Debug, Deserialize)]
+pub struct ProofRequest {
+    pub verification_key: VerificationKey,
+    pub proof: PLONKProof,
+    pub public_signals: Vec<String>,
+}
+
+impl ProofRequest {
+    fn calldata(&self) -> Result<Vec<Felt>, CliError> {
+        self.verification_key
+            .validate()
+            .map_err(CliError::InvalidInput)?;
+        build_calldata(&self.verification_key, &self.proof, &self.public_signals)
+    }
+}
+
+/// Lifecycle of a transaction submitted by the service
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum TransactionStatus {
+    Submitted,
+    Accepted { finality_status: String },
+    Reverted { reason: String },
+    Failed { error: String },
+}
+
+impl From<Result<SubmissionOutcome, CliError>> for TransactionStatus {
+    fn from(outcome: Result<SubmissionOutcome, CliError>) -> Self {
+        match outcome {
+            Ok(SubmissionOutcome::Accepted {
+                finality_status, ..
+            }) => TransactionStatus::Accepted {
+                finality_status: finality_label(finality_status),
+            },
+            Ok(SubmissionOutcome::Reverted { reason, .. }) => {
+                TransactionStatus::Reverted { reason }
+            }
+            Err(error) => TransactionStatus::Failed {
+                error: error.to_string(),
+            },
+        }
+    }
+}
+
+fn finality_label(status: TransactionFinalityStatus) -> String {
+    match status {
+        TransactionFinalityStatus::AcceptedOnL2 => "ACCEPTED_ON_L2",
+        TransactionFinalityStatus::AcceptedOnL1 => "ACCEPTED_ON_L1",
+    }
+    .to_string()
+}
+
+#[derive(Debug, Serialize)]
+struct StatusResponse {
+    transaction_hash: String,
+    #[serde(flatten)]
+    status: TransactionStatus,
+}
+
+impl StatusResponse {
+    fn new(hash: Felt, status: TransactionStatus) -> Self {
+        Self {
+            transaction_hash: format!("{:#064x}", hash),
+            status,
+        }
+    }
+}
+
+/// How long the status of a finished transaction is kept, older ones are read from the node
+const STATUS_TTL: Duration = Duration::from_secs(60 * 60);
+/// Most finished statuses kept at once, the oldest are dropped first
+const MAX_FINISHED_STATUSES: usize = 10_000;
+
+/// Statuses of the transactions submitted by the service. Pending ones are kept until their
+/// receipt is known, finished ones for `STATUS_TTL` and at most `MAX_FINISHED_STATUSES` of them.
+#[derive(Debug, Default)]
+struct StatusCache {
+    entries: HashMap<Felt, TransactionStatus>,
+    /// Finished transactions, oldest first
+    finished: VecDeque<(Instant, Felt)>,
+}
+
+impl StatusCache {
+    fn get(&self, hash: &Felt) -> Option<TransactionStatus> {
+        self.entries.get(hash).cloned()
+    }
+
+    fn insert(&mut self, hash: Felt, status: TransactionStatus) {
+        let now = Instant::now();
+        if !matches!(status, TransactionStatus::Submitted) {
+            self.finished.push_back((now, hash));
+        }
+        self.entries.insert(hash, status);
+        self.evict(now);
+    }
+
+    fn evict(&mut self, now: Instant) {
+        while let Some(&(at, hash)) = self.finished.front() {
+            if now.duration_since(at) < STATUS_TTL && self.finished.len() <= MAX_FINISHED_STATUSES {
+                break;
+            }
+            self.finished.pop_front();
+            self.entries.remove(&hash);
+        }
+    }
+}
+
+type Statuses = Arc<Mutex<StatusCache>>;
+
+struct Job {
+    calldata: Vec<Felt>,
+    reply: oneshot::Sender<Result<Felt, CliError>>,
+}
+
+#[derive(Clone)]
+struct AppState {
+    jobs: mpsc::Sender<Job>,
+    statuses: Statuses,
+    provider: Arc<JsonRpcClient<HttpTransport>>,
+}
+
+struct ApiError(StatusCode, String);
+
+impl From<CliError> for ApiError {
+    fn from(error: CliError) -> Self {
+        let status = match error {
+            CliError::InvalidInput(_) | CliError::ParseError(_) => StatusCode::BAD_REQUEST,
+            CliError::TransactionError(_) => StatusCode::BAD_GATEWAY,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+        ApiError(status, error.to_string())
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        (self.0, Json(json!({ "error": self.1 }))).into_response()
+    }
+}
+
+/// Runs the HTTP service until the process is stopped.
+///
+/// Submissions are pushed to a bounded queue drained by a single worker that owns the account
+/// and tracks its nonce locally, so concurrent requests never race for the same nonce.
+pub async fn serve(
+    host: String,
+    port: u16,
+    queue_size: usize,
+    submit_config: SubmitConfig,
+) -> Result<(), CliError> {
+    let config = StarknetConfig::from_env()?;
+    let statuses: Statuses = Arc::default();
+    let provider = Arc::new(config.provider());
+
+    let (jobs, queue) = mpsc::channel(queue_size.max(1));
+    tokio::spawn(run_worker(
+        config.clone(),
+        config.account(),
+        queue,
+        statuses.clone(),
+        provider.clone(),
+        submit_config,
+    ));
+
+    let app = Router::new()
+        .route("/verify", post(verify_handler))
+        .route("/calldata", post(calldata_handler))
+        .route("/status/:tx", get(status_handler))
+        .with_state(AppState {
+            jobs,
+            statuses,
+            provider,
+        });
+
+    let listener = tokio::net::TcpListener::bind((host.as_str(), port)).await?;
+    println!("Listening on http://{}", listener.local_addr()?);
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+async fn run_worker(
+    config: StarknetConfig,
+    account: VerifierAccount,
+    mut queue: mpsc::Receiver<Job>,
+    statuses: Statuses,
+    provider: Arc<JsonRpcClient<HttpTransport>>,
+    submit_config: SubmitConfig,
+) {
+    let mut next_nonce: Option<Felt> = None;
+
+    while let Some(job) = queue.recv().await {
+        let nonce = match next_nonce {
+            Some(nonce) => nonce,
+            None => match fetch_nonce(&account).await {
+                Ok(nonce) => nonce,
+                Err(error) => {
+                    let _ = job.reply.send(Err(error));
+                    continue;
+                }
+            },
+        };
+
+        let call = config.verify_call(job.calldata);
+        match submit(&account, vec![call], nonce, &submit_config).await {
+            Ok(submitted) => {
+                next_nonce = Some(submitted.nonce + Felt::ONE);
+                let hash = submitted.transaction_hash;
+                statuses
+                    .lock()
+                    .unwrap()
+                    .insert(hash, TransactionStatus::Submitted);
+
+                let (statuses, provider, submit_config) =
+                    (statuses.clone(), provider.clone(), submit_config.clone());
+                tokio::spawn(async move {
+                    let outcome = wait_for_receipt(provider.as_ref(), hash, &submit_config).await;
+                    statuses.lock().unwrap().insert(hash, outcome.into());
+                });
+
+                let _ = job.reply.send(Ok(hash));
+            }
+            Err(error) => {
+                // The node's view of the nonce is unknown after a failure, refetch next time
+                next_nonce = None;
+                let _ = job.reply.send(Err(error));
+            }
+        }
+    }
+}
+
+async fn calldata_handler(Json(request): Json<ProofRequest>) -> Result<Response, ApiError> {
+    let calldata = request.calldata()?;
+    let calldata: Vec<String> = calldata.iter().map(|felt| format!("{:#x}", felt)).collect();
+
+    Ok(Json(json!({ "calldata": calldata })).into_response())
+}
+
+async fn verify_handler(
+    State(state): State<AppState>,
+    Json(request): Json<ProofRequest>,
+) -> Result<Response, ApiError> {
+    let calldata = request.calldata()?;
+
+    let (reply, response) = oneshot::channel();
+    state
+        .jobs
+        .try_send(Job { calldata, reply })
+        .map_err(|error| match error {
+            TrySendError::Full(_) => ApiError(
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Submission queue is full, retry later".to_string(),
+            ),
+            TrySendError::Closed(_) => ApiError(
+                StatusCode::INTERNAL_SERVER_ERROR,
+                "Submission worker stopped".to_string(),
+            ),
+        })?;
+
+    let hash = response.await.map_err(|_| {
+        ApiError(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "Submission worker stopped".to_string(),
+        )
+    })??;
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(StatusResponse::new(hash, TransactionStatus::Submitted)),
+    )
+        .into_response())
+}
+
+async fn status_handler(
+    State(state): State<AppState>,
+    Path(tx): Path<String>,
+) -> Result<Response, ApiError> {
+    let hash = Felt::from_hex(&tx).map_err(|e| {
+        ApiError(
+            StatusCode::BAD_REQUEST,
+            format!("Invalid transaction hash: {}", e),
+        )
+    })?;
+
+    let known = state.statuses.lock().unwrap().get(&hash);
+    let status = match known {
+        Some(status) => status,
+        // Not submitted by this service, ask the node directly
+        None => match state.provider.get_transaction_receipt(hash).await {
+            Ok(receipt) => match receipt.receipt.execution_result() {
+                ExecutionResult::Succeeded => TransactionStatus::Accepted {
+                    finality_status: finality_label(*receipt.receipt.finality_status()),
+                },
+                ExecutionResult::Reverted { reason } => TransactionStatus::Reverted {
+                    reason: reason.clone(),
+                },
+            },
+            Err(ProviderError::StarknetError(StarknetError::TransactionHashNotFound)) => {
+                return Err(ApiError(
+                    StatusCode::NOT_FOUND,
+                    format!("Transaction {:#064x} not found", hash),
+                ))
+            }
+            Err(error) => return Err(ApiError(StatusCode::BAD_GATEWAY, error.to_string())),
+        },
+    };
+
+    Ok(Json(StatusResponse::new(hash, status)).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{StatusCache, TransactionStatus, MAX_FINISHED_STATUSES, STATUS_TTL};
+    use starknet::core::types::Felt;
+    use std::time::Instant;
+
+    fn reverted() -> TransactionStatus {
+        TransactionStatus::Reverted {
+            reason: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_status_cache_eviction() {
+        let mut cache = StatusCache::default();
+        cache.insert(Felt::ONE, TransactionStatus::Submitted);
+        cache.insert(Felt::TWO, reverted());
+
+        // Finished statuses expire, pending ones are kept until their receipt
+        cache.evict(Instant::now() + STATUS_TTL);
+        assert!(cache.get(&Felt::ONE).is_some() && cache.get(&Felt::TWO).is_none());
+
+        for i in 0..MAX_FINISHED_STATUSES as u64 + 10 {
+            cache.insert(Felt::from(100 + i), reverted());
+        }
+        assert_eq!(cache.entries.len(), MAX_FINISHED_STATUSES + 1);
+        assert!(cache.get(&Felt::from(100u64)).is_none());
+        assert!(cache.get(&Felt::ONE).is_some());
+    }
+}
@@ -0,0 +1,352 @@
+use crate::error::CliError;
+use starknet::{
+    accounts::{AccountError, ConnectedAccount},
+    core::types::{
+        Call, ExecutionResult, Felt, StarknetError, TransactionFinalityStatus,
+        TransactionReceiptWithBlockInfo,
+    },
+    providers::{Provider, ProviderError},
+};
+use std::time::{Duration, Instant};
+
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const DEFAULT_INITIAL_BACKOFF: Duration = Duration::from_secs(2);
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(300);
+/// Margin on the estimated gas amount and price, the default of `ExecutionV3`
+const GAS_ESTIMATE_MULTIPLIER: f64 = 1.5;
+
+/// Controls how a transaction is submitted and how long we wait for it.
+#[derive(Debug, Clone)]
+pub struct SubmitConfig {
+    /// Number of times a failed `send()` is retried on a retryable error
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled after every attempt
+    pub initial_backoff: Duration,
+    /// Delay between two `get_transaction_receipt` calls
+    pub poll_interval: Duration,
+    /// Maximum time spent waiting for the receipt once submitted
+    pub timeout: Duration,
+}
+
+impl Default for SubmitConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_MAX_RETRIES,
+            initial_backoff: DEFAULT_INITIAL_BACKOFF,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+}
+
+impl SubmitConfig {
+    pub fn new(max_retries: u32, timeout_secs: u64) -> Self {
+        Self {
+            max_retries,
+            timeout: Duration::from_secs(timeout_secs),
+            ..Default::default()
+        }
+    }
+
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff.saturating_mul(1 << attempt.min(16))
+    }
+}
+
+/// Final state of a submitted transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmissionOutcome {
+    Accepted {
+        transaction_hash: Felt,
+        finality_status: TransactionFinalityStatus,
+    },
+    Reverted {
+        transaction_hash: Felt,
+        reason: String,
+    },
+}
+
+impl SubmissionOutcome {
+    pub fn transaction_hash(&self) -> Felt {
+        match self {
+            SubmissionOutcome::Accepted {
+                transaction_hash, ..
+            }
+            | SubmissionOutcome::Reverted {
+                transaction_hash, ..
+            } => *transaction_hash,
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        matches!(self, SubmissionOutcome::Accepted { .. })
+    }
+}
+
+enum Retry {
+    /// Retry with the same nonce (transport errors, rate limiting)
+    Backoff,
+    /// Refetch the nonce before retrying
+    RefreshNonce,
+    /// Give up immediately
+    Fatal,
+}
+
+fn classify_provider_error(error: &ProviderError) -> Retry {
+    match error {
+        ProviderError::RateLimited | ProviderError::Other(_) => Retry::Backoff,
+        ProviderError::StarknetError(StarknetError::InvalidTransactionNonce) => Retry::RefreshNonce,
+        ProviderError::StarknetError(StarknetError::UnexpectedError(_))
+        | ProviderError::StarknetError(StarknetError::FailedToReceiveTransaction) => Retry::Backoff,
+        _ => Retry::Fatal,
+    }
+}
+
+fn classify_account_error<S>(error: &AccountError<S>) -> Retry {
+    match error {
+        AccountError::Provider(error) => classify_provider_error(error),
+        _ => Retry::Fatal,
+    }
+}
+
+/// Submits `calls` from `account` and waits until the transaction is either accepted or
+/// reverted.
+///
+/// The nonce is fetched explicitly so that a nonce conflict can be resolved by refetching it,
+/// transient RPC errors are retried with exponential backoff, and the receipt is polled until
+/// `config.timeout` elapses. Each stage is reported on stdout.
+pub async fn submit_and_wait<A>(
+    account: &A,
+    calls: Vec<Call>,
+    config: &SubmitConfig,
+) -> Result<SubmissionOutcome, CliError>
+where
+    A: ConnectedAccount + Sync,
+    A::SignError: std::fmt::Display,
+{
+    let nonce = fetch_nonce(account).await?;
+    let submitted = submit(account, calls, nonce, config).await?;
+
+    wait_for_receipt(account.provider(), submitted.transaction_hash, config).await
+}
+
+/// A transaction accepted by the RPC node, along with the nonce it was sent with.
+#[derive(Debug, Clone, Copy)]
+pub struct Submitted {
+    pub transaction_hash: Felt,
+    pub nonce: Felt,
+}
+
+/// Sends `calls` with `nonce`, retrying with backoff on retryable errors.
+///
+/// The fee is estimated once and the transaction hash computed before the first send, so a
+/// retry resends the very same signed transaction: if an earlier attempt did reach the
+/// sequencer, the duplicate is rejected instead of executing twice. Before any retry the node is
+/// asked whether one of the hashes already sent is known, and the nonce is only refetched once
+/// none of them is. The returned nonce may therefore differ from the given one.
+pub async fn submit<A>(
+    account: &A,
+    calls: Vec<Call>,
+    mut nonce: Felt,
+    config: &SubmitConfig,
+) -> Result<Submitted, CliError>
+where
+    A: ConnectedAccount + Sync,
+    A::SignError: std::fmt::Display,
+{
+    let mut attempt = 0;
+    let mut sent: Vec<(Felt, Felt)> = vec![];
+    let (gas, gas_price, estimated_nonce) = estimate_gas(account, &calls, nonce, config).await?;
+    nonce = estimated_nonce;
+
+    let (transaction_hash, nonce) = loop {
+        let execution = account
+            .execute_v3(calls.clone())
+            .nonce(nonce)
+            .gas(gas)
+            .gas_price(gas_price)
+            .prepared()
+            .expect("nonce, gas and gas price are set");
+        let transaction_hash = execution.transaction_hash(false);
+        sent.push((transaction_hash, nonce));
+
+        let error = match execution.send().await {
+            Ok(result) => break (result.transaction_hash, nonce),
+            Err(error) => error,
+        };
+        let retry = classify_account_error(&error);
+        if matches!(retry, Retry::Fatal) || attempt >= config.max_retries {
+            return Err(CliError::TransactionError(format!(
+                "Failed to submit transaction after {} attempt(s): {}",
+                attempt + 1,
+                error
+            )));
+        }
+
+        let delay = config.backoff(attempt);
+        println!(
+            "⚠️  Submission failed ({}), retrying in {}s...",
+            error,
+            delay.as_secs()
+        );
+        tokio::time::sleep(delay).await;
+
+        // The error may have been returned after the node received the transaction
+        if let Some(known) = find_sent(account.provider(), &sent).await {
+            break known;
+        }
+        if matches!(retry, Retry::RefreshNonce) {
+            nonce = fetch_nonce(account).await?;
+        }
+        attempt += 1;
+    };
+
+    println!("Transaction submitted: {:#064x}", transaction_hash);
+
+    Ok(Submitted {
+        transaction_hash,
+        nonce,
+    })
+}
+
+/// The first of the sent `(hash, nonce)` pairs the node knows about and did not reject, a
+/// rejected transaction is sent again
+async fn find_sent<P>(provider: &P, sent: &[(Felt, Felt)]) -> Option<(Felt, Felt)>
+where
+    P: Provider + Sync,
+{
+    for &(transaction_hash, nonce) in sent {
+        match provider.get_transaction_status(transaction_hash).await {
+            Ok(status) if !status.is_rejected() => return Some((transaction_hash, nonce)),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Gas amount and price of the transaction with the same margins as `ExecutionV3::send`,
+/// estimated once so that every attempt signs the same transaction. Nothing is sent yet, so
+/// transient errors are simply retried, and a stale nonce is fetched again. Returns the nonce
+/// the estimate was made with.
+async fn estimate_gas<A>(
+    account: &A,
+    calls: &[Call],
+    mut nonce: Felt,
+    config: &SubmitConfig,
+) -> Result<(u64, u128, Felt), CliError>
+where
+    A: ConnectedAccount + Sync,
+    A::SignError: std::fmt::Display,
+{
+    let mut attempt = 0;
+    let estimate = loop {
+        match account.execute_v3(calls.to_vec()).nonce(nonce).estimate_fee().await {
+            Ok(estimate) => break estimate,
+            Err(error) => {
+                let retry = classify_account_error(&error);
+                if matches!(retry, Retry::Fatal) || attempt >= config.max_retries {
+                    return Err(CliError::TransactionError(format!(
+                        "Failed to estimate the transaction fee: {}",
+                        error
+                    )));
+                }
+                tokio::time::sleep(config.backoff(attempt)).await;
+                if matches!(retry, Retry::RefreshNonce) {
+                    nonce = fetch_nonce(account).await?;
+                }
+                attempt += 1;
+            }
+        }
+    };
+
+    let to_u64 = |value: Felt| {
+        u64::try_from(value).map_err(|_| {
+            CliError::TransactionError(format!("Fee estimate {} out of range", value))
+        })
+    };
+    let (overall_fee, gas_price) = (to_u64(estimate.overall_fee)?, to_u64(estimate.gas_price)?);
+    if gas_price == 0 {
+        return Err(CliError::TransactionError("Fee estimate with a zero gas price".to_string()));
+    }
+    let gas = (overall_fee.div_ceil(gas_price) as f64 * GAS_ESTIMATE_MULTIPLIER) as u64;
+    let gas_price = (gas_price as f64 * GAS_ESTIMATE_MULTIPLIER) as u128;
+    Ok((gas, gas_price, nonce))
+}
+
+pub async fn fetch_nonce<A>(account: &A) -> Result<Felt, CliError>
+where
+    A: ConnectedAccount + Sync,
+{
+    account
+        .get_nonce()
+        .await
+        .map_err(|e| CliError::TransactionError(format!("Failed to fetch account nonce: {}", e)))
+}
+
+/// Polls `get_transaction_receipt` for `transaction_hash` until the transaction is included or
+/// `config.timeout` elapses.
+pub async fn wait_for_receipt<P>(
+    provider: &P,
+    transaction_hash: Felt,
+    config: &SubmitConfig,
+) -> Result<SubmissionOutcome, CliError>
+where
+    P: Provider + Sync,
+{
+    let deadline = Instant::now() + config.timeout;
+
+    loop {
+        match provider.get_transaction_receipt(transaction_hash).await {
+            Ok(receipt) => return Ok(report_receipt(transaction_hash, receipt)),
+            Err(error) => match error {
+                ProviderError::StarknetError(StarknetError::TransactionHashNotFound) => {}
+                error if !matches!(classify_provider_error(&error), Retry::Fatal) => {
+                    println!("⚠️  Receipt query failed ({}), still waiting...", error);
+                }
+                error => {
+                    return Err(CliError::TransactionError(format!(
+                        "Failed to fetch receipt for {:#064x}: {}",
+                        transaction_hash, error
+                    )))
+                }
+            },
+        }
+
+        if Instant::now() + config.poll_interval > deadline {
+            return Err(CliError::TransactionError(format!(
+                "Timed out after {}s waiting for transaction {:#064x}",
+                config.timeout.as_secs(),
+                transaction_hash
+            )));
+        }
+        tokio::time::sleep(config.poll_interval).await;
+    }
+}
+
+fn report_receipt(
+    transaction_hash: Felt,
+    receipt: TransactionReceiptWithBlockInfo,
+) -> SubmissionOutcome {
+    let finality_status = *receipt.receipt.finality_status();
+
+    match receipt.receipt.execution_result() {
+        ExecutionResult::Succeeded => {
+            match finality_status {
+                TransactionFinalityStatus::AcceptedOnL2 => println!("Transaction accepted on L2"),
+                TransactionFinalityStatus::AcceptedOnL1 => println!("Transaction accepted on L1"),
+            }
+            SubmissionOutcome::Accepted {
+                transaction_hash,
+                finality_status,
+            }
+        }
+        ExecutionResult::Reverted { reason } => {
+            println!("Transaction reverted");
+            SubmissionOutcome::Reverted {
+                transaction_hash,
+                reason: reason.clone(),
+            }
+        }
+    }
+}
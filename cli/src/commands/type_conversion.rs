@@ -1,11 +1,20 @@
+use crate::error::CliError;
 use num_bigint::BigUint;
 use num_traits::One;
 use primitive_types::U256;
+use starknet::core::types::Felt;
 use std::str::FromStr;
 
-pub fn convert_u384_to_low_high(input: &str) -> (String, String) {
+pub fn convert_u384_to_low_high(input: &str) -> Result<(String, String), CliError> {
     // Parse the input decimal string into a BigUint
-    let num = BigUint::from_str(input).expect("Invalid decimal input");
+    let num = BigUint::from_str(input)
+        .map_err(|e| CliError::InvalidInput(format!("Invalid decimal value {:?}: {}", input, e)))?;
+    if num.bits() > 384 {
+        return Err(CliError::InvalidInput(format!(
+            "Value {} does not fit in a u384",
+            input
+        )));
+    }
 
     // Define the 192-bit mask
     let mask_192 = (BigUint::one() << 192) - BigUint::one();
@@ -20,12 +29,22 @@ pub fn convert_u384_to_low_high(input: &str) -> (String, String) {
     let low_string = low.to_str_radix(10);
     let high_string = high.to_str_radix(10);
 
-    (low_string, high_string)
+    Ok((low_string, high_string))
 }
 
-pub fn convert_u256_to_low_high(input: &str) -> (String, String) {
+pub fn convert_low_high_to_u384(low: &Felt, high: &Felt) -> String {
+    // Inverse of convert_u384_to_low_high: value = low + high * 2^192
+    let low = BigUint::from_bytes_be(&low.to_bytes_be());
+    let high = BigUint::from_bytes_be(&high.to_bytes_be());
+
+    let value: BigUint = (high << 192) + low;
+    value.to_str_radix(10)
+}
+
+pub fn convert_u256_to_low_high(input: &str) -> Result<(String, String), CliError> {
     // Parse the input decimal string into a U256
-    let num = U256::from_dec_str(input).expect("Invalid decimal input");
+    let num = U256::from_dec_str(input)
+        .map_err(|e| CliError::InvalidInput(format!("Invalid decimal value {:?}: {}", input, e)))?;
 
     // Get low and high parts as u128
     let low_128 = num.low_u128(); // Lower 128 bits
@@ -35,5 +54,31 @@ pub fn convert_u256_to_low_high(input: &str) -> (String, String) {
     let low_string = low_128.to_string();
     let high_string = high_128.to_string();
 
-    (low_string, high_string)
+    Ok((low_string, high_string))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{convert_low_high_to_u384, convert_u384_to_low_high};
+    use crate::error::CliError;
+    use starknet::core::types::Felt;
+
+    #[test]
+    fn test_u384_round_trip() {
+        let value = "21888242871839275222246405745257275088696311157297823662689037894645226208583";
+        let (low, high) = convert_u384_to_low_high(value).unwrap();
+        let (low, high) = (Felt::from_dec_str(&low).unwrap(), Felt::from_dec_str(&high).unwrap());
+        assert_eq!(convert_low_high_to_u384(&low, &high), value);
+    }
+
+    #[test]
+    fn test_u384_invalid() {
+        let too_large = (num_bigint::BigUint::from(1u32) << 384usize).to_string();
+        for input in ["", "12ab", "-1", too_large.as_str()] {
+            assert!(matches!(
+                convert_u384_to_low_high(input),
+                Err(CliError::InvalidInput(_))
+            ));
+        }
+    }
 }
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Deserializer, Serialize};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PLONKProof {
     #[serde(rename = "A")]
     pub a: [String; 3],
@@ -53,7 +53,7 @@ fn default_n_lagrange() -> String {
     "5".to_string()
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VerificationKey {
     #[serde(default = "default_n", deserialize_with = "deserialize_as_string")]
     pub n: String,
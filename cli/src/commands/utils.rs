@@ -39,3 +39,8 @@ pub fn get_data_dir() -> Result<std::path::PathBuf, CliError> {
 pub fn get_temp_dir() -> Result<std::path::PathBuf, CliError> {
     Ok(get_data_dir()?.join("temp"))
 }
+
+pub fn read_env_var(name: &str) -> Result<String, CliError> {
+    std::env::var(name)
+        .map_err(|_| CliError::InvalidInput(format!("{} environment variable is not set", name)))
+}
@@ -7,6 +7,7 @@ pub enum CliError {
     ParseError(String),
     VerificationError(String),
     InvalidInput(String),
+    TransactionError(String),
 }
 
 impl fmt::Display for CliError {
@@ -16,6 +17,7 @@ impl fmt::Display for CliError {
             CliError::ParseError(msg) => write!(f, "Parse Error: {}", msg),
             CliError::VerificationError(msg) => write!(f, "Verification Error: {}", msg),
             CliError::InvalidInput(msg) => write!(f, "Invalid Input: {}", msg),
+            CliError::TransactionError(msg) => write!(f, "Transaction Error: {}", msg),
         }
     }
 }
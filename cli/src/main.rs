@@ -1,9 +1,9 @@
 use clap::Parser;
 use cli::{
     cli::{Cli, Commands},
-    commands, CliError,
+    commands::{self, submit::SubmitConfig},
+    CliError,
 };
-use tokio;
 
 #[tokio::main]
 async fn main() -> Result<(), CliError> {
@@ -11,17 +11,69 @@ async fn main() -> Result<(), CliError> {
 
     match &cli.command {
         Commands::Verify {
-            verification_key,
-            proof,
-            public_inputs,
+            files,
+            timeout,
+            max_retries,
         } => {
             commands::verify::verify(
-                verification_key.clone(),
-                proof.clone(),
-                public_inputs.clone(),
+                files.verification_key.clone(),
+                files.proof.clone(),
+                files.public_inputs.clone(),
+                SubmitConfig::new(*max_retries, *timeout),
             )
             .await?;
         }
+        Commands::History {
+            from_block,
+            to_block,
+            include_pairing,
+            format,
+            output,
+        } => {
+            commands::history::history(
+                *from_block,
+                *to_block,
+                *include_pairing,
+                *format,
+                output.clone(),
+            )
+            .await?;
+        }
+        Commands::Fuzz {
+            files,
+            on_chain,
+            verbose,
+        } => {
+            commands::fuzz::fuzz(
+                files.verification_key.clone(),
+                files.proof.clone(),
+                files.public_inputs.clone(),
+                *on_chain,
+                *verbose,
+            )
+            .await?;
+        }
+        Commands::Serve {
+            host,
+            port,
+            queue_size,
+            timeout,
+            max_retries,
+        } => {
+            commands::serve::serve(
+                host.clone(),
+                *port,
+                *queue_size,
+                SubmitConfig::new(*max_retries, *timeout),
+            )
+            .await?;
+        }
+        Commands::Completions { shell } => {
+            commands::completions::completions(*shell);
+        }
+        Commands::Man { out_dir } => {
+            commands::completions::man(out_dir.as_deref())?;
+        }
     }
 
     Ok(())
@@ -9,6 +9,8 @@ use plonk_verifier::curve::constants::{FIELD_U384, ORDER_U384};
 
 const ZERO: u384 = u384 { limb0: 0, limb1: 0, limb2: 0, limb3: 0 };
 const ONE: u384 = u384 { limb0: 1, limb1: 0, limb2: 0, limb3: 0 };
+// Non-residue of the Fq6 tower, the generated circuits read it as their NINE input
+const NINE: u384 = u384 { limb0: 9, limb1: 0, limb2: 0, limb3: 0 };
 
 // #[inline(always)]
 fn add_c(mut a: u384, mut b: u384, m: CircuitModulus) -> u384 {
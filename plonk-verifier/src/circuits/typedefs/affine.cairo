@@ -7,27 +7,152 @@ use core::circuit::{
 	CircuitElement as CE,
 };
 
+// @generated affine_fq2 begin, run `cargo run -- regen` in cairo_circuit_codegen to update
+// Affine Fq2
+// x_on_slope
+// CI<0>: pt.x.c0.c0
+// CI<1>: pt.x.c1.c0
+// CI<2>: slope.c0.c0
+// CI<3>: slope.c1.c0
+// CI<4>: x.c0.c0
+// CI<5>: x.c1.c0
+type AffineFq2XOnSlopeC0 = CE<S<S<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, CI<0>>, CI<4>>>;
+type AffineFq2XOnSlopeC1 = CE<S<S<M<A<CI<2>, CI<2>>, CI<3>>, CI<1>>, CI<5>>>;
 // y_on_slope
-type AffFq2YSlopeC0 = CE<S<S<M<CI<4>, S<CI<0>, CI<6>>>, M<CI<5>, S<CI<1>, CI<7>>>>, CI<2>>>;
-type AffFq2YSlopeC1 = CE<S<S<M<A<CI<4>, CI<5>>, A<S<CI<0>, CI<6>>, S<CI<1>, CI<7>>>>, A<M<CI<4>, S<CI<0>, CI<6>>>, M<CI<5>, S<CI<1>, CI<7>>>>>, CI<3>>>;
+// CI<0>: pt.x.c0.c0
+// CI<1>: pt.x.c1.c0
+// CI<2>: pt.y.c0.c0
+// CI<3>: pt.y.c1.c0
+// CI<4>: slope.c0.c0
+// CI<5>: slope.c1.c0
+// CI<6>: x.c0.c0
+// CI<7>: x.c1.c0
+type AffineFq2YOnSlopeC0_t0 = S<CI<0>, CI<6>>;
+type AffineFq2YOnSlopeC0_t1 = M<CI<4>, AffineFq2YOnSlopeC0_t0>;
+type AffineFq2YOnSlopeC0_t2 = S<CI<1>, CI<7>>;
+type AffineFq2YOnSlopeC0_t3 = M<CI<5>, AffineFq2YOnSlopeC0_t2>;
+type AffineFq2YOnSlopeC0 = CE<S<S<AffineFq2YOnSlopeC0_t1, AffineFq2YOnSlopeC0_t3>, CI<2>>>;
+type AffineFq2YOnSlopeC1 = CE<S<S<M<A<CI<4>, CI<5>>, A<AffineFq2YOnSlopeC0_t0, AffineFq2YOnSlopeC0_t2>>, A<AffineFq2YOnSlopeC0_t1, AffineFq2YOnSlopeC0_t3>>, CI<3>>>;
 // pt_on_slope
-type AffFq2PTSlopeX0 = CE<S<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<0>>, CI<6>>>;
-type AffFq2PTSlopeX1 = CE<S<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<1>>, CI<7>>>;
-type AffFq2PTSlopeY0 = CE<S<S<M<CI<4>, S<CI<0>, S<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<0>>, CI<6>>>>, M<CI<5>, S<CI<1>, S<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<1>>, CI<7>>>>>, CI<2>>>;
-type AffFq2PTSlopeY1 = CE<S<S<M<A<CI<4>, CI<5>>, A<S<CI<0>, S<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<0>>, CI<6>>>, S<CI<1>, S<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<1>>, CI<7>>>>>, A<M<CI<4>, S<CI<0>, S<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<0>>, CI<6>>>>, M<CI<5>, S<CI<1>, S<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<1>>, CI<7>>>>>>, CI<3>>>;
+// CI<0>: pt.x.c0.c0
+// CI<1>: pt.x.c1.c0
+// CI<2>: pt.y.c0.c0
+// CI<3>: pt.y.c1.c0
+// CI<4>: slope.c0.c0
+// CI<5>: slope.c1.c0
+// CI<6>: x.c0.c0
+// CI<7>: x.c1.c0
+type AffineFq2PtOnSlopeX0_t0 = A<CI<4>, CI<5>>;
+type AffineFq2PtOnSlopeX0_t1 = S<S<M<AffineFq2PtOnSlopeX0_t0, S<CI<4>, CI<5>>>, CI<0>>, CI<6>>;
+type AffineFq2PtOnSlopeX0 = CE<AffineFq2PtOnSlopeX0_t1>;
+type AffineFq2PtOnSlopeX0_t2 = S<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<1>>, CI<7>>;
+type AffineFq2PtOnSlopeX1 = CE<AffineFq2PtOnSlopeX0_t2>;
+type AffineFq2PtOnSlopeX0_t3 = S<CI<0>, AffineFq2PtOnSlopeX0_t1>;
+type AffineFq2PtOnSlopeX0_t4 = M<CI<4>, AffineFq2PtOnSlopeX0_t3>;
+type AffineFq2PtOnSlopeX0_t5 = S<CI<1>, AffineFq2PtOnSlopeX0_t2>;
+type AffineFq2PtOnSlopeX0_t6 = M<CI<5>, AffineFq2PtOnSlopeX0_t5>;
+type AffineFq2PtOnSlopeY0 = CE<S<S<AffineFq2PtOnSlopeX0_t4, AffineFq2PtOnSlopeX0_t6>, CI<2>>>;
+type AffineFq2PtOnSlopeY1 = CE<S<S<M<AffineFq2PtOnSlopeX0_t0, A<AffineFq2PtOnSlopeX0_t3, AffineFq2PtOnSlopeX0_t5>>, A<AffineFq2PtOnSlopeX0_t4, AffineFq2PtOnSlopeX0_t6>>, CI<3>>>;
 // chord
-type AffFq2ChordC0 = CE<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>;
-type AffFq2ChordC1 = CE<S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>;
+// CI<0>: lhs.x.c0.c0
+// CI<1>: lhs.x.c1.c0
+// CI<2>: lhs.y.c0.c0
+// CI<3>: lhs.y.c1.c0
+// CI<4>: rhs.x.c0.c0
+// CI<5>: rhs.x.c1.c0
+// CI<6>: rhs.y.c0.c0
+// CI<7>: rhs.y.c1.c0
+// CI<8>: ZERO = 0
+type AffineFq2ChordC0_t0 = S<CI<6>, CI<2>>;
+type AffineFq2ChordC0_t1 = S<CI<4>, CI<0>>;
+type AffineFq2ChordC0_t2 = S<CI<5>, CI<1>>;
+type AffineFq2ChordC0_t3 = I<A<M<AffineFq2ChordC0_t1, AffineFq2ChordC0_t1>, M<AffineFq2ChordC0_t2, AffineFq2ChordC0_t2>>>;
+type AffineFq2ChordC0_t4 = M<AffineFq2ChordC0_t1, AffineFq2ChordC0_t3>;
+type AffineFq2ChordC0_t5 = M<AffineFq2ChordC0_t0, AffineFq2ChordC0_t4>;
+type AffineFq2ChordC0_t6 = S<CI<7>, CI<3>>;
+type AffineFq2ChordC0_t7 = M<AffineFq2ChordC0_t2, S<CI<8>, AffineFq2ChordC0_t3>>;
+type AffineFq2ChordC0_t8 = M<AffineFq2ChordC0_t6, AffineFq2ChordC0_t7>;
+type AffineFq2ChordC0 = CE<S<AffineFq2ChordC0_t5, AffineFq2ChordC0_t8>>;
+type AffineFq2ChordC1 = CE<S<M<A<AffineFq2ChordC0_t0, AffineFq2ChordC0_t6>, A<AffineFq2ChordC0_t4, AffineFq2ChordC0_t7>>, A<AffineFq2ChordC0_t5, AffineFq2ChordC0_t8>>>;
 // add
-type AffFq2AddX0 = CE<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>>;
-type AffFq2AddX1 = CE<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>>;
-type AffFq2AddY0 = CE<S<S<M<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<CI<0>, S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>>>, M<S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<CI<1>, S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>>>>, CI<2>>>;
-type AffFq2AddY1 = CE<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, A<S<CI<0>, S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>>, S<CI<1>, S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>>>>, A<M<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<CI<0>, S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>>>, M<S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<CI<1>, S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>>>>>, CI<3>>>;
+// CI<0>: lhs.x.c0.c0
+// CI<1>: lhs.x.c1.c0
+// CI<2>: lhs.y.c0.c0
+// CI<3>: lhs.y.c1.c0
+// CI<4>: rhs.x.c0.c0
+// CI<5>: rhs.x.c1.c0
+// CI<6>: rhs.y.c0.c0
+// CI<7>: rhs.y.c1.c0
+// CI<8>: ZERO = 0
+type AffineFq2AddX0_t0 = S<CI<6>, CI<2>>;
+type AffineFq2AddX0_t1 = S<CI<4>, CI<0>>;
+type AffineFq2AddX0_t2 = S<CI<5>, CI<1>>;
+type AffineFq2AddX0_t3 = I<A<M<AffineFq2AddX0_t1, AffineFq2AddX0_t1>, M<AffineFq2AddX0_t2, AffineFq2AddX0_t2>>>;
+type AffineFq2AddX0_t4 = M<AffineFq2AddX0_t1, AffineFq2AddX0_t3>;
+type AffineFq2AddX0_t5 = M<AffineFq2AddX0_t0, AffineFq2AddX0_t4>;
+type AffineFq2AddX0_t6 = S<CI<7>, CI<3>>;
+type AffineFq2AddX0_t7 = M<AffineFq2AddX0_t2, S<CI<8>, AffineFq2AddX0_t3>>;
+type AffineFq2AddX0_t8 = M<AffineFq2AddX0_t6, AffineFq2AddX0_t7>;
+type AffineFq2AddX0_t9 = S<AffineFq2AddX0_t5, AffineFq2AddX0_t8>;
+type AffineFq2AddX0_t10 = S<M<A<AffineFq2AddX0_t0, AffineFq2AddX0_t6>, A<AffineFq2AddX0_t4, AffineFq2AddX0_t7>>, A<AffineFq2AddX0_t5, AffineFq2AddX0_t8>>;
+type AffineFq2AddX0_t11 = A<AffineFq2AddX0_t9, AffineFq2AddX0_t10>;
+type AffineFq2AddX0_t12 = S<S<M<AffineFq2AddX0_t11, S<AffineFq2AddX0_t9, AffineFq2AddX0_t10>>, CI<0>>, CI<4>>;
+type AffineFq2AddX0 = CE<AffineFq2AddX0_t12>;
+type AffineFq2AddX0_t13 = S<S<M<A<AffineFq2AddX0_t9, AffineFq2AddX0_t9>, AffineFq2AddX0_t10>, CI<1>>, CI<5>>;
+type AffineFq2AddX1 = CE<AffineFq2AddX0_t13>;
+type AffineFq2AddX0_t14 = S<CI<0>, AffineFq2AddX0_t12>;
+type AffineFq2AddX0_t15 = M<AffineFq2AddX0_t9, AffineFq2AddX0_t14>;
+type AffineFq2AddX0_t16 = S<CI<1>, AffineFq2AddX0_t13>;
+type AffineFq2AddX0_t17 = M<AffineFq2AddX0_t10, AffineFq2AddX0_t16>;
+type AffineFq2AddY0 = CE<S<S<AffineFq2AddX0_t15, AffineFq2AddX0_t17>, CI<2>>>;
+type AffineFq2AddY1 = CE<S<S<M<AffineFq2AddX0_t11, A<AffineFq2AddX0_t14, AffineFq2AddX0_t16>>, A<AffineFq2AddX0_t15, AffineFq2AddX0_t17>>, CI<3>>>;
 // tangent
-type AffFq2TanC0 = CE<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>;
-type AffFq2TanC1 = CE<S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>;
+// CI<0>: lhs.x.c0.c0
+// CI<1>: lhs.x.c1.c0
+// CI<2>: lhs.y.c0.c0
+// CI<3>: lhs.y.c1.c0
+// CI<4>: ZERO = 0
+type AffineFq2TangentC0_t0 = M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>;
+type AffineFq2TangentC0_t1 = A<A<AffineFq2TangentC0_t0, AffineFq2TangentC0_t0>, AffineFq2TangentC0_t0>;
+type AffineFq2TangentC0_t2 = A<CI<2>, CI<2>>;
+type AffineFq2TangentC0_t3 = A<CI<3>, CI<3>>;
+type AffineFq2TangentC0_t4 = I<A<M<AffineFq2TangentC0_t2, AffineFq2TangentC0_t2>, M<AffineFq2TangentC0_t3, AffineFq2TangentC0_t3>>>;
+type AffineFq2TangentC0_t5 = M<AffineFq2TangentC0_t2, AffineFq2TangentC0_t4>;
+type AffineFq2TangentC0_t6 = M<AffineFq2TangentC0_t1, AffineFq2TangentC0_t5>;
+type AffineFq2TangentC0_t7 = M<A<CI<0>, CI<0>>, CI<1>>;
+type AffineFq2TangentC0_t8 = A<A<AffineFq2TangentC0_t7, AffineFq2TangentC0_t7>, AffineFq2TangentC0_t7>;
+type AffineFq2TangentC0_t9 = M<AffineFq2TangentC0_t3, S<CI<4>, AffineFq2TangentC0_t4>>;
+type AffineFq2TangentC0_t10 = M<AffineFq2TangentC0_t8, AffineFq2TangentC0_t9>;
+type AffineFq2TangentC0 = CE<S<AffineFq2TangentC0_t6, AffineFq2TangentC0_t10>>;
+type AffineFq2TangentC1 = CE<S<M<A<AffineFq2TangentC0_t1, AffineFq2TangentC0_t8>, A<AffineFq2TangentC0_t5, AffineFq2TangentC0_t9>>, A<AffineFq2TangentC0_t6, AffineFq2TangentC0_t10>>>;
 // double
-type AffFq2DoubleX0 = CE<S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, S<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>>, CI<0>>, CI<0>>>;
-type AffFq2DoubleX1 = CE<S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, CI<1>>, CI<1>>>;
-type AffFq2DoubleY0 = CE<S<S<M<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<CI<0>, S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, S<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>>, CI<0>>, CI<0>>>>, M<S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>, S<CI<1>, S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, CI<1>>, CI<1>>>>>, CI<2>>>;
-type AffFq2DoubleY1 = CE<S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, A<S<CI<0>, S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, S<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>>, CI<0>>, CI<0>>>, S<CI<1>, S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, CI<1>>, CI<1>>>>>, A<M<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<CI<0>, S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, S<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>>, CI<0>>, CI<0>>>>, M<S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>, S<CI<1>, S<S<M<A<S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, S<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>, S<M<A<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>>, A<M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>, A<M<A<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<2>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>, M<A<A<M<A<CI<0>, CI<0>>, CI<1>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<3>, CI<3>>, S<S<CI<0>, CI<0>>, I<A<M<A<CI<2>, CI<2>>, A<CI<2>, CI<2>>>, M<A<CI<3>, CI<3>>, A<CI<3>, CI<3>>>>>>>>>>>, CI<1>>, CI<1>>>>>>, CI<3>>>;
+// CI<0>: lhs.x.c0.c0
+// CI<1>: lhs.x.c1.c0
+// CI<2>: lhs.y.c0.c0
+// CI<3>: lhs.y.c1.c0
+// CI<4>: ZERO = 0
+type AffineFq2DoubleX0_t0 = M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>;
+type AffineFq2DoubleX0_t1 = A<A<AffineFq2DoubleX0_t0, AffineFq2DoubleX0_t0>, AffineFq2DoubleX0_t0>;
+type AffineFq2DoubleX0_t2 = A<CI<2>, CI<2>>;
+type AffineFq2DoubleX0_t3 = A<CI<3>, CI<3>>;
+type AffineFq2DoubleX0_t4 = I<A<M<AffineFq2DoubleX0_t2, AffineFq2DoubleX0_t2>, M<AffineFq2DoubleX0_t3, AffineFq2DoubleX0_t3>>>;
+type AffineFq2DoubleX0_t5 = M<AffineFq2DoubleX0_t2, AffineFq2DoubleX0_t4>;
+type AffineFq2DoubleX0_t6 = M<AffineFq2DoubleX0_t1, AffineFq2DoubleX0_t5>;
+type AffineFq2DoubleX0_t7 = M<A<CI<0>, CI<0>>, CI<1>>;
+type AffineFq2DoubleX0_t8 = A<A<AffineFq2DoubleX0_t7, AffineFq2DoubleX0_t7>, AffineFq2DoubleX0_t7>;
+type AffineFq2DoubleX0_t9 = M<AffineFq2DoubleX0_t3, S<CI<4>, AffineFq2DoubleX0_t4>>;
+type AffineFq2DoubleX0_t10 = M<AffineFq2DoubleX0_t8, AffineFq2DoubleX0_t9>;
+type AffineFq2DoubleX0_t11 = S<AffineFq2DoubleX0_t6, AffineFq2DoubleX0_t10>;
+type AffineFq2DoubleX0_t12 = S<M<A<AffineFq2DoubleX0_t1, AffineFq2DoubleX0_t8>, A<AffineFq2DoubleX0_t5, AffineFq2DoubleX0_t9>>, A<AffineFq2DoubleX0_t6, AffineFq2DoubleX0_t10>>;
+type AffineFq2DoubleX0_t13 = A<AffineFq2DoubleX0_t11, AffineFq2DoubleX0_t12>;
+type AffineFq2DoubleX0_t14 = S<S<M<AffineFq2DoubleX0_t13, S<AffineFq2DoubleX0_t11, AffineFq2DoubleX0_t12>>, CI<0>>, CI<0>>;
+type AffineFq2DoubleX0 = CE<AffineFq2DoubleX0_t14>;
+type AffineFq2DoubleX0_t15 = S<S<M<A<AffineFq2DoubleX0_t11, AffineFq2DoubleX0_t11>, AffineFq2DoubleX0_t12>, CI<1>>, CI<1>>;
+type AffineFq2DoubleX1 = CE<AffineFq2DoubleX0_t15>;
+type AffineFq2DoubleX0_t16 = S<CI<0>, AffineFq2DoubleX0_t14>;
+type AffineFq2DoubleX0_t17 = M<AffineFq2DoubleX0_t11, AffineFq2DoubleX0_t16>;
+type AffineFq2DoubleX0_t18 = S<CI<1>, AffineFq2DoubleX0_t15>;
+type AffineFq2DoubleX0_t19 = M<AffineFq2DoubleX0_t12, AffineFq2DoubleX0_t18>;
+type AffineFq2DoubleY0 = CE<S<S<AffineFq2DoubleX0_t17, AffineFq2DoubleX0_t19>, CI<2>>>;
+type AffineFq2DoubleY1 = CE<S<S<M<AffineFq2DoubleX0_t13, A<AffineFq2DoubleX0_t16, AffineFq2DoubleX0_t18>>, A<AffineFq2DoubleX0_t17, AffineFq2DoubleX0_t19>>, CI<3>>>;
+// @generated affine_fq2 end
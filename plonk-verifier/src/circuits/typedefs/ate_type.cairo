@@ -7,13 +7,43 @@ use core::circuit::{
 	CircuitElement as CE,
 };
 
+// @generated step_dbl_add_slopes begin, run `cargo run -- regen` in cairo_circuit_codegen to update
 // step_dbl_add_slopes
-// Fq2
-type Slope1_C0 = CE<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>;
-type Slope1_C1 = CE<S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>;
-// Fq2
-type X1_C0 = CE<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>>;
-type X1_C1 = CE<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>>;
-// Fq2
-type Slope2_C0 = CE<S<S<S<CI<0>, CI<0>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<CI<2>, CI<2>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, I<A<M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>>>>>>, M<A<CI<3>, CI<3>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>>>>>>>>>>;
-type Slope2_C1 = CE<S<S<S<CI<0>, CI<0>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<M<A<A<CI<2>, CI<2>>, A<CI<3>, CI<3>>>, A<M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, I<A<M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>>>>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>>>>>>>>, A<M<A<CI<2>, CI<2>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, I<A<M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>>>>>>, M<A<CI<3>, CI<3>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, S<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>>, CI<0>>, CI<4>>, CI<0>>>, M<S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>, S<S<S<M<A<S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, S<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>, S<M<A<S<CI<6>, CI<2>>, S<CI<7>, CI<3>>>, A<M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>, A<M<S<CI<6>, CI<2>>, M<S<CI<4>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>, M<S<CI<7>, CI<3>>, M<S<CI<5>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<S<CI<4>, CI<0>>, S<CI<4>, CI<0>>>, M<S<CI<5>, CI<1>>, S<CI<5>, CI<1>>>>>>>>>>>, CI<1>>, CI<5>>, CI<1>>>>>>>>>>>>;
+// CI<0>: acc.x.c0.c0
+// CI<1>: acc.x.c1.c0
+// CI<2>: acc.y.c0.c0
+// CI<3>: acc.y.c1.c0
+// CI<4>: q.x.c0.c0
+// CI<5>: q.x.c1.c0
+// CI<6>: q.y.c0.c0
+// CI<7>: q.y.c1.c0
+// CI<8>: ZERO = 0
+type Slope1_C0_t0 = S<CI<6>, CI<2>>;
+type Slope1_C0_t1 = S<CI<4>, CI<0>>;
+type Slope1_C0_t2 = S<CI<5>, CI<1>>;
+type Slope1_C0_t3 = I<A<M<Slope1_C0_t1, Slope1_C0_t1>, M<Slope1_C0_t2, Slope1_C0_t2>>>;
+type Slope1_C0_t4 = M<Slope1_C0_t1, Slope1_C0_t3>;
+type Slope1_C0_t5 = M<Slope1_C0_t0, Slope1_C0_t4>;
+type Slope1_C0_t6 = S<CI<7>, CI<3>>;
+type Slope1_C0_t7 = M<Slope1_C0_t2, S<CI<8>, Slope1_C0_t3>>;
+type Slope1_C0_t8 = M<Slope1_C0_t6, Slope1_C0_t7>;
+type Slope1_C0_t9 = S<Slope1_C0_t5, Slope1_C0_t8>;
+type Slope1_C0 = CE<Slope1_C0_t9>;
+type Slope1_C0_t10 = S<M<A<Slope1_C0_t0, Slope1_C0_t6>, A<Slope1_C0_t4, Slope1_C0_t7>>, A<Slope1_C0_t5, Slope1_C0_t8>>;
+type Slope1_C1 = CE<Slope1_C0_t10>;
+type Slope1_C0_t11 = S<S<M<A<Slope1_C0_t9, Slope1_C0_t10>, S<Slope1_C0_t9, Slope1_C0_t10>>, CI<0>>, CI<4>>;
+type X1_C0 = CE<Slope1_C0_t11>;
+type Slope1_C0_t12 = S<S<M<A<Slope1_C0_t9, Slope1_C0_t9>, Slope1_C0_t10>, CI<1>>, CI<5>>;
+type X1_C1 = CE<Slope1_C0_t12>;
+type Slope1_C0_t13 = A<CI<2>, CI<2>>;
+type Slope1_C0_t14 = S<Slope1_C0_t11, CI<0>>;
+type Slope1_C0_t15 = S<Slope1_C0_t12, CI<1>>;
+type Slope1_C0_t16 = I<A<M<Slope1_C0_t14, Slope1_C0_t14>, M<Slope1_C0_t15, Slope1_C0_t15>>>;
+type Slope1_C0_t17 = M<Slope1_C0_t14, Slope1_C0_t16>;
+type Slope1_C0_t18 = M<Slope1_C0_t13, Slope1_C0_t17>;
+type Slope1_C0_t19 = A<CI<3>, CI<3>>;
+type Slope1_C0_t20 = M<Slope1_C0_t15, S<CI<8>, Slope1_C0_t16>>;
+type Slope1_C0_t21 = M<Slope1_C0_t19, Slope1_C0_t20>;
+type Slope2_C0 = CE<S<S<CI<8>, Slope1_C0_t9>, S<Slope1_C0_t18, Slope1_C0_t21>>>;
+type Slope2_C1 = CE<S<S<CI<8>, Slope1_C0_t10>, S<M<A<Slope1_C0_t13, Slope1_C0_t19>, A<Slope1_C0_t17, Slope1_C0_t20>>, A<Slope1_C0_t18, Slope1_C0_t21>>>>;
+// @generated step_dbl_add_slopes end
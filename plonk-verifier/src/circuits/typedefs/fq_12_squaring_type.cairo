@@ -6,53 +6,133 @@ use core::circuit::{
 	CircuitInput as CI,
 	CircuitElement as CE,
 };
-// Scaling factor optimized to add instead of mul ie. (2x = x + x)
-
-// // core::internal::revoke_ap_tracking();
-// // Input: self = (a2 +a3s)t+(a4 +a5s)t2 ∈ Gφ6(Fp2)
-// // Output: self^2 = (c2 +c3s)t+(c4 +c5s)t2 ∈ Gφ6 (Fp2 ).
-// let Krbn2345 { g2, g3, g4, g5 } = self;
-
-// let S2 = g2.sqr(m);
-// let S3 = g3.sqr(m);
-// let S4 = g4.sqr(m);
-// let S5 = g5.sqr(m);
-// let S4_5 = g4.add(g5, m).sqr(m);
-// let S2_3 = g2.add(g3, m).sqr(m);
-
-// let Tmp = mul_by_xi_nz_as_circuit(S4_5.sub(S4.add(S5, m), m), m);
-// let h2 = Tmp.add(g2, m).scale(TWO, m).add(Tmp, m);
-
-// let Tmp = S4.add(mul_by_xi_nz_as_circuit(S5, m), m);
-// let h3 = Tmp.sub(g3, m).scale(TWO, m).add(Tmp, m);
-
-// let Tmp = S2.add(mul_by_xi_nz_as_circuit(S3, m), m);
-// let h4 = Tmp.sub(g4, m).scale(TWO, m).add(Tmp, m);
-
-// let Tmp = S2_3.sub(S2, m).sub(S3, m);
-// let h5 = Tmp.add(g5, m).scale(TWO, m).add(Tmp, m);
-
-// Krbn2345 { g2: h2, g3: h3, g4: h4, g5: h5, }
-type KrbnG2C0 = CE<A<A<A<S<A<A<A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>, A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, CI<0>>, A<S<A<A<A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>, A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, CI<0>>>, S<A<A<A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>, A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>>;
-type KrbnG2C1 = CE<A<A<A<A<A<A<A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>, A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, CI<1>>, A<A<A<A<A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>, A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, CI<1>>>, A<A<A<A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>, A<A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, A<S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<4>, CI<6>>>, A<CI<5>, CI<7>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, S<M<A<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>, S<A<CI<4>, CI<6>>, A<CI<5>, CI<7>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>>;
-type KrbnG3C0 = CE<A<A<S<A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>>, CI<2>>, S<A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>>, CI<2>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>>>>;
-type KrbnG3C1 = CE<A<A<S<A<M<A<CI<4>, CI<4>>, CI<5>>, A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, CI<3>>, S<A<M<A<CI<4>, CI<4>>, CI<5>>, A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, CI<3>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>>;
-type KrbnG4C0 = CE<A<A<S<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, S<A<A<A<A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>, A<A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, M<A<CI<2>, CI<2>>, CI<3>>>>, CI<4>>, S<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, S<A<A<A<A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>, A<A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, M<A<CI<2>, CI<2>>, CI<3>>>>, CI<4>>>, A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, S<A<A<A<A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>, A<A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, A<M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, M<A<CI<2>, CI<2>>, CI<3>>>>>>;
-type KrbnG4C1 = CE<A<A<S<A<M<A<CI<0>, CI<0>>, CI<1>>, A<A<A<A<A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>, A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>>, A<A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>, A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>>>, M<A<CI<2>, CI<2>>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>, CI<5>>, S<A<M<A<CI<0>, CI<0>>, CI<1>>, A<A<A<A<A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>, A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>>, A<A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>, A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>>>, M<A<CI<2>, CI<2>>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>, CI<5>>>, A<M<A<CI<0>, CI<0>>, CI<1>>, A<A<A<A<A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>, A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>>, A<A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>, A<M<A<CI<2>, CI<2>>, CI<3>>, M<A<CI<2>, CI<2>>, CI<3>>>>>, M<A<CI<2>, CI<2>>, CI<3>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>>>;
-type KrbnG5C0 = CE<A<A<A<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, S<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, CI<6>>, A<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, S<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>, CI<6>>>, S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, S<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>>>>;
-type KrbnG5C1 = CE<A<A<A<S<S<M<A<A<CI<0>, CI<2>>, A<CI<0>, CI<2>>>, A<CI<1>, CI<3>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<2>, CI<2>>, CI<3>>>, CI<7>>, A<S<S<M<A<A<CI<0>, CI<2>>, A<CI<0>, CI<2>>>, A<CI<1>, CI<3>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<2>, CI<2>>, CI<3>>>, CI<7>>>, S<S<M<A<A<CI<0>, CI<2>>, A<CI<0>, CI<2>>>, A<CI<1>, CI<3>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<2>, CI<2>>, CI<3>>>>>;
 
+// @generated karabina begin, run `cargo run -- regen` in cairo_circuit_codegen to update
+// krbn_sqr2345
+// CI<0>: kr.g2.c0.c0
+// CI<1>: kr.g2.c1.c0
+// CI<2>: kr.g3.c0.c0
+// CI<3>: kr.g3.c1.c0
+// CI<4>: kr.g4.c0.c0
+// CI<5>: kr.g4.c1.c0
+// CI<6>: kr.g5.c0.c0
+// CI<7>: kr.g5.c1.c0
+// CI<8>: NINE = 9
+type KrbnG2C0_t0 = A<CI<4>, CI<6>>;
+type KrbnG2C0_t1 = A<CI<5>, CI<7>>;
+type KrbnG2C0_t2 = M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>;
+type KrbnG2C0_t3 = M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>;
+type KrbnG2C0_t4 = S<M<A<KrbnG2C0_t0, KrbnG2C0_t1>, S<KrbnG2C0_t0, KrbnG2C0_t1>>, A<KrbnG2C0_t2, KrbnG2C0_t3>>;
+type KrbnG2C0_t5 = M<A<CI<4>, CI<4>>, CI<5>>;
+type KrbnG2C0_t6 = M<A<CI<6>, CI<6>>, CI<7>>;
+type KrbnG2C0_t7 = S<M<A<KrbnG2C0_t0, KrbnG2C0_t0>, KrbnG2C0_t1>, A<KrbnG2C0_t5, KrbnG2C0_t6>>;
+type KrbnG2C0_t8 = S<M<KrbnG2C0_t4, CI<8>>, KrbnG2C0_t7>;
+type KrbnG2C0_t9 = A<KrbnG2C0_t8, CI<0>>;
+type KrbnG2C0 = CE<A<A<KrbnG2C0_t9, KrbnG2C0_t9>, KrbnG2C0_t8>>;
+type KrbnG2C0_t10 = A<M<KrbnG2C0_t7, CI<8>>, KrbnG2C0_t4>;
+type KrbnG2C0_t11 = A<KrbnG2C0_t10, CI<1>>;
+type KrbnG2C1 = CE<A<A<KrbnG2C0_t11, KrbnG2C0_t11>, KrbnG2C0_t10>>;
+type KrbnG2C0_t12 = A<KrbnG2C0_t2, S<M<KrbnG2C0_t3, CI<8>>, KrbnG2C0_t6>>;
+type KrbnG2C0_t13 = S<KrbnG2C0_t12, CI<2>>;
+type KrbnG3C0 = CE<A<A<KrbnG2C0_t13, KrbnG2C0_t13>, KrbnG2C0_t12>>;
+type KrbnG2C0_t14 = A<KrbnG2C0_t5, A<M<KrbnG2C0_t6, CI<8>>, KrbnG2C0_t3>>;
+type KrbnG2C0_t15 = S<KrbnG2C0_t14, CI<3>>;
+type KrbnG3C1 = CE<A<A<KrbnG2C0_t15, KrbnG2C0_t15>, KrbnG2C0_t14>>;
+type KrbnG2C0_t16 = M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>;
+type KrbnG2C0_t17 = M<A<CI<2>, CI<3>>, S<CI<2>, CI<3>>>;
+type KrbnG2C0_t18 = M<A<CI<2>, CI<2>>, CI<3>>;
+type KrbnG2C0_t19 = A<KrbnG2C0_t16, S<M<KrbnG2C0_t17, CI<8>>, KrbnG2C0_t18>>;
+type KrbnG2C0_t20 = S<KrbnG2C0_t19, CI<4>>;
+type KrbnG4C0 = CE<A<A<KrbnG2C0_t20, KrbnG2C0_t20>, KrbnG2C0_t19>>;
+type KrbnG2C0_t21 = M<A<CI<0>, CI<0>>, CI<1>>;
+type KrbnG2C0_t22 = A<KrbnG2C0_t21, A<M<KrbnG2C0_t18, CI<8>>, KrbnG2C0_t17>>;
+type KrbnG2C0_t23 = S<KrbnG2C0_t22, CI<5>>;
+type KrbnG4C1 = CE<A<A<KrbnG2C0_t23, KrbnG2C0_t23>, KrbnG2C0_t22>>;
+type KrbnG2C0_t24 = A<CI<0>, CI<2>>;
+type KrbnG2C0_t25 = A<CI<1>, CI<3>>;
+type KrbnG2C0_t26 = S<S<M<A<KrbnG2C0_t24, KrbnG2C0_t25>, S<KrbnG2C0_t24, KrbnG2C0_t25>>, KrbnG2C0_t16>, KrbnG2C0_t17>;
+type KrbnG2C0_t27 = A<KrbnG2C0_t26, CI<6>>;
+type KrbnG5C0 = CE<A<A<KrbnG2C0_t27, KrbnG2C0_t27>, KrbnG2C0_t26>>;
+type KrbnG2C0_t28 = S<S<M<A<KrbnG2C0_t24, KrbnG2C0_t24>, KrbnG2C0_t25>, KrbnG2C0_t21>, KrbnG2C0_t18>;
+type KrbnG2C0_t29 = A<KrbnG2C0_t28, CI<7>>;
+type KrbnG5C1 = CE<A<A<KrbnG2C0_t29, KrbnG2C0_t29>, KrbnG2C0_t28>>;
 // krbn_decompress
-// g0
-type KbrnDecompZeroG0C0 = CE<S<A<S<M<A<S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, S<M<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, A<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>>, S<S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, S<M<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, A<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, S<M<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, A<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>>, S<S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, S<M<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, A<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, S<A<A<A<A<S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, A<S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, A<A<S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, A<S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>>>;
-type KbrnDecompZeroG0C1 = CE<S<A<S<M<A<S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>, S<M<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, A<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, S<M<A<S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>, S<M<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, A<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>>, A<A<A<A<A<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, A<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>>, A<A<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, A<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>>;
-// g1
-type KbrnDecompZeroG1C0 = CE<S<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>;
-type KbrnDecompZeroG1C1 = CE<S<M<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>, A<M<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>, M<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>>>>;
-
-// g0
-type KbrnDecompNonZeroG0C0 = CE<S<A<A<A<A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>, A<A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>>;
-type KbrnDecompNonZeroG0C1 = CE<A<A<A<A<A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>, A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>>, A<A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>, A<A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, S<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>, A<A<S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>, S<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, S<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>;
-// g1
-type KbrnDecompNonZeroG1C0 = CE<S<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>;
-type KbrnDecompNonZeroG1C1 = CE<S<M<A<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>>, A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>, A<M<A<S<A<A<A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>, A<A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, A<S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>, S<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, CI<2>>>>>, M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>, M<A<A<A<A<A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>, A<A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>, A<M<A<CI<6>, CI<6>>, CI<7>>, M<A<CI<6>, CI<6>>, CI<7>>>>>, M<A<CI<6>, CI<6>>, CI<7>>>, M<A<CI<6>, CI<7>>, S<CI<6>, CI<7>>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, A<S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>, S<M<A<CI<4>, CI<4>>, CI<5>>, CI<3>>>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, S<S<CI<0>, CI<0>>, I<A<M<A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>, A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>>, M<A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>, A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>>>>>>>>>>;
+// CI<0>: kr.g2.c0.c0
+// CI<1>: kr.g2.c1.c0
+// CI<2>: kr.g3.c0.c0
+// CI<3>: kr.g3.c1.c0
+// CI<4>: kr.g4.c0.c0
+// CI<5>: kr.g4.c1.c0
+// CI<6>: NINE = 9
+// CI<7>: ZERO = 0
+type KbrnDecompZeroG0C0_t0 = M<CI<2>, CI<4>>;
+type KbrnDecompZeroG0C0_t1 = M<CI<3>, CI<5>>;
+type KbrnDecompZeroG0C0_t2 = S<KbrnDecompZeroG0C0_t0, KbrnDecompZeroG0C0_t1>;
+type KbrnDecompZeroG0C0_t3 = A<KbrnDecompZeroG0C0_t2, KbrnDecompZeroG0C0_t2>;
+type KbrnDecompZeroG0C0_t4 = I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>;
+type KbrnDecompZeroG0C0_t5 = M<CI<0>, KbrnDecompZeroG0C0_t4>;
+type KbrnDecompZeroG0C0_t6 = M<KbrnDecompZeroG0C0_t3, KbrnDecompZeroG0C0_t5>;
+type KbrnDecompZeroG0C0_t7 = A<CI<2>, CI<3>>;
+type KbrnDecompZeroG0C0_t8 = S<M<KbrnDecompZeroG0C0_t7, A<CI<4>, CI<5>>>, A<KbrnDecompZeroG0C0_t0, KbrnDecompZeroG0C0_t1>>;
+type KbrnDecompZeroG0C0_t9 = A<KbrnDecompZeroG0C0_t8, KbrnDecompZeroG0C0_t8>;
+type KbrnDecompZeroG0C0_t10 = M<CI<1>, S<CI<7>, KbrnDecompZeroG0C0_t4>>;
+type KbrnDecompZeroG0C0_t11 = M<KbrnDecompZeroG0C0_t9, KbrnDecompZeroG0C0_t10>;
+type KbrnDecompZeroG0C0_t12 = S<KbrnDecompZeroG0C0_t6, KbrnDecompZeroG0C0_t11>;
+type KbrnDecompZeroG0C0_t13 = S<M<A<KbrnDecompZeroG0C0_t3, KbrnDecompZeroG0C0_t9>, A<KbrnDecompZeroG0C0_t5, KbrnDecompZeroG0C0_t10>>, A<KbrnDecompZeroG0C0_t6, KbrnDecompZeroG0C0_t11>>;
+type KbrnDecompZeroG0C0_t14 = M<CI<0>, CI<2>>;
+type KbrnDecompZeroG0C0_t15 = M<CI<1>, CI<3>>;
+type KbrnDecompZeroG0C0_t16 = S<KbrnDecompZeroG0C0_t14, KbrnDecompZeroG0C0_t15>;
+type KbrnDecompZeroG0C0_t17 = S<M<A<KbrnDecompZeroG0C0_t12, KbrnDecompZeroG0C0_t13>, S<KbrnDecompZeroG0C0_t12, KbrnDecompZeroG0C0_t13>>, KbrnDecompZeroG0C0_t16>;
+type KbrnDecompZeroG0C0_t18 = S<A<KbrnDecompZeroG0C0_t17, KbrnDecompZeroG0C0_t17>, KbrnDecompZeroG0C0_t16>;
+type KbrnDecompZeroG0C0_t19 = S<M<A<CI<0>, CI<1>>, KbrnDecompZeroG0C0_t7>, A<KbrnDecompZeroG0C0_t14, KbrnDecompZeroG0C0_t15>>;
+type KbrnDecompZeroG0C0_t20 = S<M<A<KbrnDecompZeroG0C0_t12, KbrnDecompZeroG0C0_t12>, KbrnDecompZeroG0C0_t13>, KbrnDecompZeroG0C0_t19>;
+type KbrnDecompZeroG0C0_t21 = S<A<KbrnDecompZeroG0C0_t20, KbrnDecompZeroG0C0_t20>, KbrnDecompZeroG0C0_t19>;
+type KbrnDecompZeroG0C0 = CE<S<M<KbrnDecompZeroG0C0_t18, CI<6>>, KbrnDecompZeroG0C0_t21>>;
+type KbrnDecompZeroG0C1 = CE<A<M<KbrnDecompZeroG0C0_t21, CI<6>>, KbrnDecompZeroG0C0_t18>>;
+type KbrnDecompZeroG1C0 = CE<KbrnDecompZeroG0C0_t12>;
+type KbrnDecompZeroG1C1 = CE<KbrnDecompZeroG0C0_t13>;
+// krbn_decompress
+// CI<0>: kr.g2.c0.c0
+// CI<1>: kr.g2.c1.c0
+// CI<2>: kr.g3.c0.c0
+// CI<3>: kr.g3.c1.c0
+// CI<4>: kr.g4.c0.c0
+// CI<5>: kr.g4.c1.c0
+// CI<6>: kr.g5.c0.c0
+// CI<7>: kr.g5.c1.c0
+// CI<8>: NINE = 9
+// CI<9>: ZERO = 0
+type KbrnDecompNonZeroG0C0_t0 = A<CI<6>, CI<7>>;
+type KbrnDecompNonZeroG0C0_t1 = M<KbrnDecompNonZeroG0C0_t0, S<CI<6>, CI<7>>>;
+type KbrnDecompNonZeroG0C0_t2 = M<A<CI<6>, CI<6>>, CI<7>>;
+type KbrnDecompNonZeroG0C0_t3 = A<CI<4>, CI<5>>;
+type KbrnDecompNonZeroG0C0_t4 = M<KbrnDecompNonZeroG0C0_t3, S<CI<4>, CI<5>>>;
+type KbrnDecompNonZeroG0C0_t5 = S<KbrnDecompNonZeroG0C0_t4, CI<2>>;
+type KbrnDecompNonZeroG0C0_t6 = A<S<M<KbrnDecompNonZeroG0C0_t1, CI<8>>, KbrnDecompNonZeroG0C0_t2>, A<KbrnDecompNonZeroG0C0_t4, A<KbrnDecompNonZeroG0C0_t5, KbrnDecompNonZeroG0C0_t5>>>;
+type KbrnDecompNonZeroG0C0_t7 = A<A<A<CI<0>, CI<0>>, CI<0>>, CI<0>>;
+type KbrnDecompNonZeroG0C0_t8 = A<A<A<CI<1>, CI<1>>, CI<1>>, CI<1>>;
+type KbrnDecompNonZeroG0C0_t9 = I<A<M<KbrnDecompNonZeroG0C0_t7, KbrnDecompNonZeroG0C0_t7>, M<KbrnDecompNonZeroG0C0_t8, KbrnDecompNonZeroG0C0_t8>>>;
+type KbrnDecompNonZeroG0C0_t10 = M<KbrnDecompNonZeroG0C0_t7, KbrnDecompNonZeroG0C0_t9>;
+type KbrnDecompNonZeroG0C0_t11 = M<KbrnDecompNonZeroG0C0_t6, KbrnDecompNonZeroG0C0_t10>;
+type KbrnDecompNonZeroG0C0_t12 = M<A<CI<4>, CI<4>>, CI<5>>;
+type KbrnDecompNonZeroG0C0_t13 = S<KbrnDecompNonZeroG0C0_t12, CI<3>>;
+type KbrnDecompNonZeroG0C0_t14 = A<A<M<KbrnDecompNonZeroG0C0_t2, CI<8>>, KbrnDecompNonZeroG0C0_t1>, A<KbrnDecompNonZeroG0C0_t12, A<KbrnDecompNonZeroG0C0_t13, KbrnDecompNonZeroG0C0_t13>>>;
+type KbrnDecompNonZeroG0C0_t15 = M<KbrnDecompNonZeroG0C0_t8, S<CI<9>, KbrnDecompNonZeroG0C0_t9>>;
+type KbrnDecompNonZeroG0C0_t16 = M<KbrnDecompNonZeroG0C0_t14, KbrnDecompNonZeroG0C0_t15>;
+type KbrnDecompNonZeroG0C0_t17 = S<KbrnDecompNonZeroG0C0_t11, KbrnDecompNonZeroG0C0_t16>;
+type KbrnDecompNonZeroG0C0_t18 = S<M<A<KbrnDecompNonZeroG0C0_t6, KbrnDecompNonZeroG0C0_t14>, A<KbrnDecompNonZeroG0C0_t10, KbrnDecompNonZeroG0C0_t15>>, A<KbrnDecompNonZeroG0C0_t11, KbrnDecompNonZeroG0C0_t16>>;
+type KbrnDecompNonZeroG0C0_t19 = M<CI<2>, CI<4>>;
+type KbrnDecompNonZeroG0C0_t20 = M<CI<3>, CI<5>>;
+type KbrnDecompNonZeroG0C0_t21 = S<KbrnDecompNonZeroG0C0_t19, KbrnDecompNonZeroG0C0_t20>;
+type KbrnDecompNonZeroG0C0_t22 = S<M<A<KbrnDecompNonZeroG0C0_t17, KbrnDecompNonZeroG0C0_t18>, S<KbrnDecompNonZeroG0C0_t17, KbrnDecompNonZeroG0C0_t18>>, KbrnDecompNonZeroG0C0_t21>;
+type KbrnDecompNonZeroG0C0_t23 = M<CI<0>, CI<6>>;
+type KbrnDecompNonZeroG0C0_t24 = M<CI<1>, CI<7>>;
+type KbrnDecompNonZeroG0C0_t25 = A<A<KbrnDecompNonZeroG0C0_t22, KbrnDecompNonZeroG0C0_t22>, S<S<KbrnDecompNonZeroG0C0_t23, KbrnDecompNonZeroG0C0_t24>, KbrnDecompNonZeroG0C0_t21>>;
+type KbrnDecompNonZeroG0C0_t26 = S<M<A<CI<2>, CI<3>>, KbrnDecompNonZeroG0C0_t3>, A<KbrnDecompNonZeroG0C0_t19, KbrnDecompNonZeroG0C0_t20>>;
+type KbrnDecompNonZeroG0C0_t27 = S<M<A<KbrnDecompNonZeroG0C0_t17, KbrnDecompNonZeroG0C0_t17>, KbrnDecompNonZeroG0C0_t18>, KbrnDecompNonZeroG0C0_t26>;
+type KbrnDecompNonZeroG0C0_t28 = A<A<KbrnDecompNonZeroG0C0_t27, KbrnDecompNonZeroG0C0_t27>, S<S<M<A<CI<0>, CI<1>>, KbrnDecompNonZeroG0C0_t0>, A<KbrnDecompNonZeroG0C0_t23, KbrnDecompNonZeroG0C0_t24>>, KbrnDecompNonZeroG0C0_t26>>;
+type KbrnDecompNonZeroG0C0 = CE<S<M<KbrnDecompNonZeroG0C0_t25, CI<8>>, KbrnDecompNonZeroG0C0_t28>>;
+type KbrnDecompNonZeroG0C1 = CE<A<M<KbrnDecompNonZeroG0C0_t28, CI<8>>, KbrnDecompNonZeroG0C0_t25>>;
+type KbrnDecompNonZeroG1C0 = CE<KbrnDecompNonZeroG0C0_t17>;
+type KbrnDecompNonZeroG1C1 = CE<KbrnDecompNonZeroG0C0_t18>;
+// @generated karabina end
@@ -7,49 +7,371 @@ use core::circuit::{
 	CircuitElement as CE,
 };
 
-// Mul
-// let Fq12 { c0: a0, c1: a1 } = self;
-// let Fq12 { c0: b0, c1: b1 } = rhs;
-
-// let U = Fq6Ops::mul(a0, b0);
-// let V = Fq6Ops::mul(a1, b1);
-// let c0 = Fq6Ops::add(mul_by_v_nz_as_circuit(V), U);
-// let c1 = Fq6Ops::sub(Fq6Ops::sub(Fq6Ops::mul(a0 + a1, b0 + b1), U), V);
-// Fq12 { c0: c0, c1: c1 }
-type Fq12MulC0 = CE<A<S<A<A<A<A<S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<A<S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>, S<A<A<A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>>>;
-type Fq12MulC1 = CE<A<A<A<A<A<A<S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, A<A<S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>>, S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<M<A<CI<0>, CI<1>>, A<CI<12>, CI<13>>>, A<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>>;
-type Fq12MulC2 = CE<A<A<S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>, S<A<A<A<A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, A<S<S<S<M<A<CI<0>, CI<2>>, A<CI<12>, CI<14>>>, M<A<CI<1>, CI<3>>, A<CI<13>, CI<15>>>>, S<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<A<A<A<A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>>;
-type Fq12MulC3 = CE<A<A<S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, A<A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, A<A<CI<12>, CI<14>>, A<CI<13>, CI<15>>>>, A<M<A<CI<0>, CI<2>>, A<CI<12>, CI<14>>>, M<A<CI<1>, CI<3>>, A<CI<13>, CI<15>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<12>, CI<13>>>, A<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, A<A<A<A<A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>;
-type Fq12MulC4 = CE<A<A<S<S<S<M<A<CI<6>, CI<8>>, A<CI<18>, CI<20>>>, M<A<CI<7>, CI<9>>, A<CI<19>, CI<21>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<A<A<A<A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<12>, CI<16>>>, M<A<CI<1>, CI<5>>, A<CI<13>, CI<17>>>>, S<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>;
-type Fq12MulC5 = CE<A<A<S<S<S<M<A<A<CI<6>, CI<8>>, A<CI<7>, CI<9>>>, A<A<CI<18>, CI<20>>, A<CI<19>, CI<21>>>>, A<M<A<CI<6>, CI<8>>, A<CI<18>, CI<20>>>, M<A<CI<7>, CI<9>>, A<CI<19>, CI<21>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, A<A<A<A<A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<12>, CI<16>>, A<CI<13>, CI<17>>>>, A<M<A<CI<0>, CI<4>>, A<CI<12>, CI<16>>>, M<A<CI<1>, CI<5>>, A<CI<13>, CI<17>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<12>, CI<13>>>, A<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>;
-type Fq12MulC6 = CE<S<S<A<S<M<A<CI<0>, CI<6>>, A<CI<12>, CI<18>>>, M<A<CI<1>, CI<7>>, A<CI<13>, CI<19>>>>, S<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>>, A<S<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>, S<A<A<A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>>, A<S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>, S<A<A<A<A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>>>;
-type Fq12MulC7 = CE<S<S<A<S<M<A<A<CI<0>, CI<6>>, A<CI<1>, CI<7>>>, A<A<CI<12>, CI<18>>, A<CI<13>, CI<19>>>>, A<M<A<CI<0>, CI<6>>, A<CI<12>, CI<18>>>, M<A<CI<1>, CI<7>>, A<CI<13>, CI<19>>>>>, A<A<A<A<A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>>, A<A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<14>, CI<20>>, A<CI<16>, CI<22>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<15>, CI<21>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, A<S<M<A<CI<0>, CI<1>>, A<CI<12>, CI<13>>>, A<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<14>, CI<16>>, A<CI<15>, CI<17>>>>, A<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<14>, CI<16>>>, M<A<CI<3>, CI<5>>, A<CI<15>, CI<17>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, A<S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, A<A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>>, S<S<S<M<A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>, A<A<CI<20>, CI<22>>, A<CI<21>, CI<23>>>>, A<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, S<S<S<M<A<CI<8>, CI<10>>, A<CI<20>, CI<22>>>, M<A<CI<9>, CI<11>>, A<CI<21>, CI<23>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>>;
-type Fq12MulC8 = CE<S<S<A<S<S<S<M<A<A<CI<0>, CI<6>>, A<CI<2>, CI<8>>>, A<A<CI<12>, CI<18>>, A<CI<14>, CI<20>>>>, M<A<A<CI<1>, CI<7>>, A<CI<3>, CI<9>>>, A<A<CI<13>, CI<19>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<0>, CI<6>>, A<CI<12>, CI<18>>>, M<A<CI<1>, CI<7>>, A<CI<13>, CI<19>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<A<A<A<A<S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, A<S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, A<A<S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, A<S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, A<S<S<S<M<A<CI<0>, CI<2>>, A<CI<12>, CI<14>>>, M<A<CI<1>, CI<3>>, A<CI<13>, CI<15>>>>, S<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<A<A<A<A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, A<S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, A<S<S<S<M<A<CI<6>, CI<8>>, A<CI<18>, CI<20>>>, M<A<CI<7>, CI<9>>, A<CI<19>, CI<21>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<A<A<A<A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, A<S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>>;
-type Fq12MulC9 = CE<S<S<A<S<S<S<M<A<A<A<CI<0>, CI<6>>, A<CI<2>, CI<8>>>, A<A<CI<1>, CI<7>>, A<CI<3>, CI<9>>>>, A<A<A<CI<12>, CI<18>>, A<CI<14>, CI<20>>>, A<A<CI<13>, CI<19>>, A<CI<15>, CI<21>>>>>, A<M<A<A<CI<0>, CI<6>>, A<CI<2>, CI<8>>>, A<A<CI<12>, CI<18>>, A<CI<14>, CI<20>>>>, M<A<A<CI<1>, CI<7>>, A<CI<3>, CI<9>>>, A<A<CI<13>, CI<19>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<0>, CI<6>>, A<CI<1>, CI<7>>>, A<A<CI<12>, CI<18>>, A<CI<13>, CI<19>>>>, A<M<A<CI<0>, CI<6>>, A<CI<12>, CI<18>>>, M<A<CI<1>, CI<7>>, A<CI<13>, CI<19>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, A<A<A<A<A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>, A<A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, A<S<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, A<A<CI<12>, CI<14>>, A<CI<13>, CI<15>>>>, A<M<A<CI<0>, CI<2>>, A<CI<12>, CI<14>>>, M<A<CI<1>, CI<3>>, A<CI<13>, CI<15>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<12>, CI<13>>>, A<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, A<A<A<A<A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, A<S<S<S<M<A<A<CI<6>, CI<8>>, A<CI<7>, CI<9>>>, A<A<CI<18>, CI<20>>, A<CI<19>, CI<21>>>>, A<M<A<CI<6>, CI<8>>, A<CI<18>, CI<20>>>, M<A<CI<7>, CI<9>>, A<CI<19>, CI<21>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, A<A<A<A<A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>, A<A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, A<S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>;
-type Fq12MulC10 = CE<S<S<S<A<S<S<M<A<A<CI<0>, CI<6>>, A<CI<4>, CI<10>>>, A<A<CI<12>, CI<18>>, A<CI<16>, CI<22>>>>, M<A<A<CI<1>, CI<7>>, A<CI<5>, CI<11>>>, A<A<CI<13>, CI<19>>, A<CI<17>, CI<23>>>>>, S<M<A<CI<0>, CI<6>>, A<CI<12>, CI<18>>>, M<A<CI<1>, CI<7>>, A<CI<13>, CI<19>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<12>, CI<16>>>, M<A<CI<1>, CI<5>>, A<CI<13>, CI<17>>>>, S<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>, S<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>, S<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>, S<A<S<S<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>, S<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>, S<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>, S<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>;
-type Fq12MulC11 = CE<S<S<S<A<S<S<M<A<A<A<CI<0>, CI<6>>, A<CI<4>, CI<10>>>, A<A<CI<1>, CI<7>>, A<CI<5>, CI<11>>>>, A<A<A<CI<12>, CI<18>>, A<CI<16>, CI<22>>>, A<A<CI<13>, CI<19>>, A<CI<17>, CI<23>>>>>, A<M<A<A<CI<0>, CI<6>>, A<CI<4>, CI<10>>>, A<A<CI<12>, CI<18>>, A<CI<16>, CI<22>>>>, M<A<A<CI<1>, CI<7>>, A<CI<5>, CI<11>>>, A<A<CI<13>, CI<19>>, A<CI<17>, CI<23>>>>>>, S<M<A<A<CI<0>, CI<6>>, A<CI<1>, CI<7>>>, A<A<CI<12>, CI<18>>, A<CI<13>, CI<19>>>>, A<M<A<CI<0>, CI<6>>, A<CI<12>, CI<18>>>, M<A<CI<1>, CI<7>>, A<CI<13>, CI<19>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<14>, CI<20>>, A<CI<15>, CI<21>>>>, A<M<A<CI<2>, CI<8>>, A<CI<14>, CI<20>>>, M<A<CI<3>, CI<9>>, A<CI<15>, CI<21>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<16>, CI<22>>, A<CI<17>, CI<23>>>>, A<M<A<CI<4>, CI<10>>, A<CI<16>, CI<22>>>, M<A<CI<5>, CI<11>>, A<CI<17>, CI<23>>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<12>, CI<16>>, A<CI<13>, CI<17>>>>, A<M<A<CI<0>, CI<4>>, A<CI<12>, CI<16>>>, M<A<CI<1>, CI<5>>, A<CI<13>, CI<17>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<12>, CI<13>>>, A<M<CI<0>, CI<12>>, M<CI<1>, CI<13>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<M<CI<2>, CI<14>>, M<CI<3>, CI<15>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<M<CI<4>, CI<16>>, M<CI<5>, CI<17>>>>>>, S<A<S<S<M<A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>, A<A<CI<18>, CI<22>>, A<CI<19>, CI<23>>>>, A<M<A<CI<6>, CI<10>>, A<CI<18>, CI<22>>>, M<A<CI<7>, CI<11>>, A<CI<19>, CI<23>>>>>, S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<M<CI<6>, CI<18>>, M<CI<7>, CI<19>>>>>, S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<M<CI<8>, CI<20>>, M<CI<9>, CI<21>>>>>, S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<M<CI<10>, CI<22>>, M<CI<11>, CI<23>>>>>>>;
-
-// Sqr	   
-// let Fq12 { c0: a0, c1: a1 } = self;
-// let V = Fq6Ops::mul(a0, a1);
-// let c0 = Fq6Ops::sub(
-//     Fq6Ops::sub(
-//         Fq6Ops::mul(Fq6Ops::add(a0, a1), Fq6Ops::add(a0, a1.mul_by_nonresidue())), V
-//     ),
-//     mul_by_v_nz_as_circuit(V)
-// );
-// let c1 = Fq6Ops::add(V, V);
-
-// Fq12 { c0: c0, c1: c1 }
-type Fq12SqrC0 = CE<S<S<A<S<M<A<CI<0>, CI<6>>, A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>>, M<A<CI<1>, CI<7>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>, S<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>>, A<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<A<A<A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>, S<A<A<A<A<S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>;
-type Fq12SqrC1 = CE<S<S<A<S<M<A<A<CI<0>, CI<6>>, A<CI<1>, CI<7>>>, A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>, A<M<A<CI<0>, CI<6>>, A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>>, M<A<CI<1>, CI<7>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>>, A<A<A<A<A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>>, A<A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, A<S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>>>, S<S<S<M<A<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>>, A<A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, S<S<S<M<A<A<CI<2>, CI<8>>, A<CI<4>, CI<10>>>, A<A<CI<2>, CI<6>>, A<CI<4>, CI<8>>>>, M<A<A<CI<3>, CI<9>>, A<CI<5>, CI<11>>>, A<A<CI<3>, CI<7>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, A<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<A<A<A<A<S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<A<S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>;
-type Fq12SqrC2 = CE<S<S<A<S<S<S<M<A<A<CI<0>, CI<6>>, A<CI<2>, CI<8>>>, A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<2>, CI<6>>>>, M<A<A<CI<1>, CI<7>>, A<CI<3>, CI<9>>>, A<A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<0>, CI<6>>, A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>>, M<A<CI<1>, CI<7>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<A<A<A<A<S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, A<S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, A<A<S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, A<S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, A<S<S<S<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<A<A<A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<A<A<A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>>;
-type Fq12SqrC3 = CE<S<S<A<S<S<S<M<A<A<A<CI<0>, CI<6>>, A<CI<2>, CI<8>>>, A<A<CI<1>, CI<7>>, A<CI<3>, CI<9>>>>, A<A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<2>, CI<6>>>, A<A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>, A<CI<3>, CI<7>>>>>, A<M<A<A<CI<0>, CI<6>>, A<CI<2>, CI<8>>>, A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<2>, CI<6>>>>, M<A<A<CI<1>, CI<7>>, A<CI<3>, CI<9>>>, A<A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<0>, CI<6>>, A<CI<1>, CI<7>>>, A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>, A<M<A<CI<0>, CI<6>>, A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>>, M<A<CI<1>, CI<7>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, A<A<A<A<A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>, A<A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, A<S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, A<S<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, A<A<CI<6>, CI<8>>, A<CI<7>, CI<9>>>>, A<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, A<A<A<A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>;
-type Fq12SqrC4 = CE<S<S<S<A<S<S<M<A<A<CI<0>, CI<6>>, A<CI<4>, CI<10>>>, A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<4>, CI<8>>>>, M<A<A<CI<1>, CI<7>>, A<CI<5>, CI<11>>>, A<A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>, A<CI<5>, CI<9>>>>>, S<M<A<CI<0>, CI<6>>, A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>>, M<A<CI<1>, CI<7>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>>, S<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>, S<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<A<A<A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>;
-type Fq12SqrC5 = CE<S<S<S<A<S<S<M<A<A<A<CI<0>, CI<6>>, A<CI<4>, CI<10>>>, A<A<CI<1>, CI<7>>, A<CI<5>, CI<11>>>>, A<A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<4>, CI<8>>>, A<A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>, A<CI<5>, CI<9>>>>>, A<M<A<A<CI<0>, CI<6>>, A<CI<4>, CI<10>>>, A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<4>, CI<8>>>>, M<A<A<CI<1>, CI<7>>, A<CI<5>, CI<11>>>, A<A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>, A<CI<5>, CI<9>>>>>>, S<M<A<A<CI<0>, CI<6>>, A<CI<1>, CI<7>>>, A<A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>, A<M<A<CI<0>, CI<6>>, A<CI<0>, S<A<A<A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>, A<A<CI<10>, CI<10>>, A<CI<10>, CI<10>>>>, CI<10>>, CI<11>>>>, M<A<CI<1>, CI<7>>, A<CI<1>, A<A<A<A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>, A<A<CI<11>, CI<11>>, A<CI<11>, CI<11>>>>, CI<11>>, CI<10>>>>>>>, S<M<A<A<CI<2>, CI<8>>, A<CI<3>, CI<9>>>, A<A<CI<2>, CI<6>>, A<CI<3>, CI<7>>>>, A<M<A<CI<2>, CI<8>>, A<CI<2>, CI<6>>>, M<A<CI<3>, CI<9>>, A<CI<3>, CI<7>>>>>>, S<M<A<A<CI<4>, CI<10>>, A<CI<5>, CI<11>>>, A<A<CI<4>, CI<8>>, A<CI<5>, CI<9>>>>, A<M<A<CI<4>, CI<10>>, A<CI<4>, CI<8>>>, M<A<CI<5>, CI<11>>, A<CI<5>, CI<9>>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, A<A<CI<6>, CI<8>>, A<CI<7>, CI<9>>>>, A<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, A<A<A<A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>;
-type Fq12SqrC6 = CE<A<A<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<A<A<A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<A<A<A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>>;
-type Fq12SqrC7 = CE<A<A<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>;
-type Fq12SqrC8 = CE<A<A<S<S<S<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<A<A<A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<A<A<A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>;
-type Fq12SqrC9 = CE<A<A<S<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, A<A<CI<6>, CI<8>>, A<CI<7>, CI<9>>>>, A<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, A<A<A<A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, A<A<CI<6>, CI<8>>, A<CI<7>, CI<9>>>>, A<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, A<A<A<A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>;
-type Fq12SqrC10 = CE<A<S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>;
-type Fq12SqrC11 = CE<A<S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>;
+// @generated fq12 begin, run `cargo run -- regen` in cairo_circuit_codegen to update
+// Fq12 Add
+// CI<0>: lhs.c0.c0.c0.c0
+// CI<1>: lhs.c0.c0.c1.c0
+// CI<2>: lhs.c0.c1.c0.c0
+// CI<3>: lhs.c0.c1.c1.c0
+// CI<4>: lhs.c0.c2.c0.c0
+// CI<5>: lhs.c0.c2.c1.c0
+// CI<6>: lhs.c1.c0.c0.c0
+// CI<7>: lhs.c1.c0.c1.c0
+// CI<8>: lhs.c1.c1.c0.c0
+// CI<9>: lhs.c1.c1.c1.c0
+// CI<10>: lhs.c1.c2.c0.c0
+// CI<11>: lhs.c1.c2.c1.c0
+// CI<12>: rhs.c0.c0.c0.c0
+// CI<13>: rhs.c0.c0.c1.c0
+// CI<14>: rhs.c0.c1.c0.c0
+// CI<15>: rhs.c0.c1.c1.c0
+// CI<16>: rhs.c0.c2.c0.c0
+// CI<17>: rhs.c0.c2.c1.c0
+// CI<18>: rhs.c1.c0.c0.c0
+// CI<19>: rhs.c1.c0.c1.c0
+// CI<20>: rhs.c1.c1.c0.c0
+// CI<21>: rhs.c1.c1.c1.c0
+// CI<22>: rhs.c1.c2.c0.c0
+// CI<23>: rhs.c1.c2.c1.c0
+type Fq12AddC0 = CE<A<CI<0>, CI<12>>>;
+type Fq12AddC1 = CE<A<CI<1>, CI<13>>>;
+type Fq12AddC2 = CE<A<CI<2>, CI<14>>>;
+type Fq12AddC3 = CE<A<CI<3>, CI<15>>>;
+type Fq12AddC4 = CE<A<CI<4>, CI<16>>>;
+type Fq12AddC5 = CE<A<CI<5>, CI<17>>>;
+type Fq12AddC6 = CE<A<CI<6>, CI<18>>>;
+type Fq12AddC7 = CE<A<CI<7>, CI<19>>>;
+type Fq12AddC8 = CE<A<CI<8>, CI<20>>>;
+type Fq12AddC9 = CE<A<CI<9>, CI<21>>>;
+type Fq12AddC10 = CE<A<CI<10>, CI<22>>>;
+type Fq12AddC11 = CE<A<CI<11>, CI<23>>>;
+// Fq12 Sub
+// CI<0>: lhs.c0.c0.c0.c0
+// CI<1>: lhs.c0.c0.c1.c0
+// CI<2>: lhs.c0.c1.c0.c0
+// CI<3>: lhs.c0.c1.c1.c0
+// CI<4>: lhs.c0.c2.c0.c0
+// CI<5>: lhs.c0.c2.c1.c0
+// CI<6>: lhs.c1.c0.c0.c0
+// CI<7>: lhs.c1.c0.c1.c0
+// CI<8>: lhs.c1.c1.c0.c0
+// CI<9>: lhs.c1.c1.c1.c0
+// CI<10>: lhs.c1.c2.c0.c0
+// CI<11>: lhs.c1.c2.c1.c0
+// CI<12>: rhs.c0.c0.c0.c0
+// CI<13>: rhs.c0.c0.c1.c0
+// CI<14>: rhs.c0.c1.c0.c0
+// CI<15>: rhs.c0.c1.c1.c0
+// CI<16>: rhs.c0.c2.c0.c0
+// CI<17>: rhs.c0.c2.c1.c0
+// CI<18>: rhs.c1.c0.c0.c0
+// CI<19>: rhs.c1.c0.c1.c0
+// CI<20>: rhs.c1.c1.c0.c0
+// CI<21>: rhs.c1.c1.c1.c0
+// CI<22>: rhs.c1.c2.c0.c0
+// CI<23>: rhs.c1.c2.c1.c0
+type Fq12SubC0 = CE<S<CI<0>, CI<12>>>;
+type Fq12SubC1 = CE<S<CI<1>, CI<13>>>;
+type Fq12SubC2 = CE<S<CI<2>, CI<14>>>;
+type Fq12SubC3 = CE<S<CI<3>, CI<15>>>;
+type Fq12SubC4 = CE<S<CI<4>, CI<16>>>;
+type Fq12SubC5 = CE<S<CI<5>, CI<17>>>;
+type Fq12SubC6 = CE<S<CI<6>, CI<18>>>;
+type Fq12SubC7 = CE<S<CI<7>, CI<19>>>;
+type Fq12SubC8 = CE<S<CI<8>, CI<20>>>;
+type Fq12SubC9 = CE<S<CI<9>, CI<21>>>;
+type Fq12SubC10 = CE<S<CI<10>, CI<22>>>;
+type Fq12SubC11 = CE<S<CI<11>, CI<23>>>;
+// Fq12 Neg
+// CI<0>: lhs.c0.c0.c0.c0
+// CI<1>: lhs.c0.c0.c1.c0
+// CI<2>: lhs.c0.c1.c0.c0
+// CI<3>: lhs.c0.c1.c1.c0
+// CI<4>: lhs.c0.c2.c0.c0
+// CI<5>: lhs.c0.c2.c1.c0
+// CI<6>: lhs.c1.c0.c0.c0
+// CI<7>: lhs.c1.c0.c1.c0
+// CI<8>: lhs.c1.c1.c0.c0
+// CI<9>: lhs.c1.c1.c1.c0
+// CI<10>: lhs.c1.c2.c0.c0
+// CI<11>: lhs.c1.c2.c1.c0
+// CI<12>: ZERO = 0
+type Fq12NegC0 = CE<S<CI<12>, CI<0>>>;
+type Fq12NegC1 = CE<S<CI<12>, CI<1>>>;
+type Fq12NegC2 = CE<S<CI<12>, CI<2>>>;
+type Fq12NegC3 = CE<S<CI<12>, CI<3>>>;
+type Fq12NegC4 = CE<S<CI<12>, CI<4>>>;
+type Fq12NegC5 = CE<S<CI<12>, CI<5>>>;
+type Fq12NegC6 = CE<S<CI<12>, CI<6>>>;
+type Fq12NegC7 = CE<S<CI<12>, CI<7>>>;
+type Fq12NegC8 = CE<S<CI<12>, CI<8>>>;
+type Fq12NegC9 = CE<S<CI<12>, CI<9>>>;
+type Fq12NegC10 = CE<S<CI<12>, CI<10>>>;
+type Fq12NegC11 = CE<S<CI<12>, CI<11>>>;
+// Fq12 Mul
+// CI<0>: lhs.c0.c0.c0.c0
+// CI<1>: lhs.c0.c0.c1.c0
+// CI<2>: lhs.c0.c1.c0.c0
+// CI<3>: lhs.c0.c1.c1.c0
+// CI<4>: lhs.c0.c2.c0.c0
+// CI<5>: lhs.c0.c2.c1.c0
+// CI<6>: lhs.c1.c0.c0.c0
+// CI<7>: lhs.c1.c0.c1.c0
+// CI<8>: lhs.c1.c1.c0.c0
+// CI<9>: lhs.c1.c1.c1.c0
+// CI<10>: lhs.c1.c2.c0.c0
+// CI<11>: lhs.c1.c2.c1.c0
+// CI<12>: rhs.c0.c0.c0.c0
+// CI<13>: rhs.c0.c0.c1.c0
+// CI<14>: rhs.c0.c1.c0.c0
+// CI<15>: rhs.c0.c1.c1.c0
+// CI<16>: rhs.c0.c2.c0.c0
+// CI<17>: rhs.c0.c2.c1.c0
+// CI<18>: rhs.c1.c0.c0.c0
+// CI<19>: rhs.c1.c0.c1.c0
+// CI<20>: rhs.c1.c1.c0.c0
+// CI<21>: rhs.c1.c1.c1.c0
+// CI<22>: rhs.c1.c2.c0.c0
+// CI<23>: rhs.c1.c2.c1.c0
+// CI<24>: NINE = 9
+type Fq12MulC0_t0 = A<CI<6>, CI<10>>;
+type Fq12MulC0_t1 = A<CI<18>, CI<22>>;
+type Fq12MulC0_t2 = M<Fq12MulC0_t0, Fq12MulC0_t1>;
+type Fq12MulC0_t3 = A<CI<7>, CI<11>>;
+type Fq12MulC0_t4 = A<CI<19>, CI<23>>;
+type Fq12MulC0_t5 = M<Fq12MulC0_t3, Fq12MulC0_t4>;
+type Fq12MulC0_t6 = M<CI<6>, CI<18>>;
+type Fq12MulC0_t7 = M<CI<7>, CI<19>>;
+type Fq12MulC0_t8 = S<Fq12MulC0_t6, Fq12MulC0_t7>;
+type Fq12MulC0_t9 = M<CI<8>, CI<20>>;
+type Fq12MulC0_t10 = M<CI<9>, CI<21>>;
+type Fq12MulC0_t11 = S<Fq12MulC0_t9, Fq12MulC0_t10>;
+type Fq12MulC0_t12 = M<CI<10>, CI<22>>;
+type Fq12MulC0_t13 = M<CI<11>, CI<23>>;
+type Fq12MulC0_t14 = S<Fq12MulC0_t12, Fq12MulC0_t13>;
+type Fq12MulC0_t15 = S<A<S<S<Fq12MulC0_t2, Fq12MulC0_t5>, Fq12MulC0_t8>, Fq12MulC0_t11>, Fq12MulC0_t14>;
+type Fq12MulC0_t16 = S<M<A<CI<6>, CI<7>>, A<CI<18>, CI<19>>>, A<Fq12MulC0_t6, Fq12MulC0_t7>>;
+type Fq12MulC0_t17 = S<M<A<CI<8>, CI<9>>, A<CI<20>, CI<21>>>, A<Fq12MulC0_t9, Fq12MulC0_t10>>;
+type Fq12MulC0_t18 = S<M<A<CI<10>, CI<11>>, A<CI<22>, CI<23>>>, A<Fq12MulC0_t12, Fq12MulC0_t13>>;
+type Fq12MulC0_t19 = S<A<S<S<M<A<Fq12MulC0_t0, Fq12MulC0_t3>, A<Fq12MulC0_t1, Fq12MulC0_t4>>, A<Fq12MulC0_t2, Fq12MulC0_t5>>, Fq12MulC0_t16>, Fq12MulC0_t17>, Fq12MulC0_t18>;
+type Fq12MulC0_t20 = M<CI<0>, CI<12>>;
+type Fq12MulC0_t21 = M<CI<1>, CI<13>>;
+type Fq12MulC0_t22 = S<Fq12MulC0_t20, Fq12MulC0_t21>;
+type Fq12MulC0_t23 = A<CI<2>, CI<4>>;
+type Fq12MulC0_t24 = A<CI<14>, CI<16>>;
+type Fq12MulC0_t25 = M<Fq12MulC0_t23, Fq12MulC0_t24>;
+type Fq12MulC0_t26 = A<CI<3>, CI<5>>;
+type Fq12MulC0_t27 = A<CI<15>, CI<17>>;
+type Fq12MulC0_t28 = M<Fq12MulC0_t26, Fq12MulC0_t27>;
+type Fq12MulC0_t29 = M<CI<2>, CI<14>>;
+type Fq12MulC0_t30 = M<CI<3>, CI<15>>;
+type Fq12MulC0_t31 = S<Fq12MulC0_t29, Fq12MulC0_t30>;
+type Fq12MulC0_t32 = M<CI<4>, CI<16>>;
+type Fq12MulC0_t33 = M<CI<5>, CI<17>>;
+type Fq12MulC0_t34 = S<Fq12MulC0_t32, Fq12MulC0_t33>;
+type Fq12MulC0_t35 = S<S<S<Fq12MulC0_t25, Fq12MulC0_t28>, Fq12MulC0_t31>, Fq12MulC0_t34>;
+type Fq12MulC0_t36 = S<M<A<CI<2>, CI<3>>, A<CI<14>, CI<15>>>, A<Fq12MulC0_t29, Fq12MulC0_t30>>;
+type Fq12MulC0_t37 = S<M<A<CI<4>, CI<5>>, A<CI<16>, CI<17>>>, A<Fq12MulC0_t32, Fq12MulC0_t33>>;
+type Fq12MulC0_t38 = S<S<S<M<A<Fq12MulC0_t23, Fq12MulC0_t26>, A<Fq12MulC0_t24, Fq12MulC0_t27>>, A<Fq12MulC0_t25, Fq12MulC0_t28>>, Fq12MulC0_t36>, Fq12MulC0_t37>;
+type Fq12MulC0_t39 = A<Fq12MulC0_t22, S<M<Fq12MulC0_t35, CI<24>>, Fq12MulC0_t38>>;
+type Fq12MulC0 = CE<A<S<M<Fq12MulC0_t15, CI<24>>, Fq12MulC0_t19>, Fq12MulC0_t39>>;
+type Fq12MulC0_t40 = S<M<A<CI<0>, CI<1>>, A<CI<12>, CI<13>>>, A<Fq12MulC0_t20, Fq12MulC0_t21>>;
+type Fq12MulC0_t41 = A<Fq12MulC0_t40, A<M<Fq12MulC0_t38, CI<24>>, Fq12MulC0_t35>>;
+type Fq12MulC1 = CE<A<A<M<Fq12MulC0_t19, CI<24>>, Fq12MulC0_t15>, Fq12MulC0_t41>>;
+type Fq12MulC0_t42 = A<CI<8>, CI<10>>;
+type Fq12MulC0_t43 = A<CI<20>, CI<22>>;
+type Fq12MulC0_t44 = M<Fq12MulC0_t42, Fq12MulC0_t43>;
+type Fq12MulC0_t45 = A<CI<9>, CI<11>>;
+type Fq12MulC0_t46 = A<CI<21>, CI<23>>;
+type Fq12MulC0_t47 = M<Fq12MulC0_t45, Fq12MulC0_t46>;
+type Fq12MulC0_t48 = S<S<S<Fq12MulC0_t44, Fq12MulC0_t47>, Fq12MulC0_t11>, Fq12MulC0_t14>;
+type Fq12MulC0_t49 = S<S<S<M<A<Fq12MulC0_t42, Fq12MulC0_t45>, A<Fq12MulC0_t43, Fq12MulC0_t46>>, A<Fq12MulC0_t44, Fq12MulC0_t47>>, Fq12MulC0_t17>, Fq12MulC0_t18>;
+type Fq12MulC0_t50 = A<Fq12MulC0_t8, S<M<Fq12MulC0_t48, CI<24>>, Fq12MulC0_t49>>;
+type Fq12MulC0_t51 = A<CI<0>, CI<2>>;
+type Fq12MulC0_t52 = A<CI<12>, CI<14>>;
+type Fq12MulC0_t53 = M<Fq12MulC0_t51, Fq12MulC0_t52>;
+type Fq12MulC0_t54 = A<CI<1>, CI<3>>;
+type Fq12MulC0_t55 = A<CI<13>, CI<15>>;
+type Fq12MulC0_t56 = M<Fq12MulC0_t54, Fq12MulC0_t55>;
+type Fq12MulC0_t57 = A<S<S<S<Fq12MulC0_t53, Fq12MulC0_t56>, Fq12MulC0_t22>, Fq12MulC0_t31>, S<M<Fq12MulC0_t34, CI<24>>, Fq12MulC0_t37>>;
+type Fq12MulC2 = CE<A<Fq12MulC0_t50, Fq12MulC0_t57>>;
+type Fq12MulC0_t58 = A<Fq12MulC0_t16, A<M<Fq12MulC0_t49, CI<24>>, Fq12MulC0_t48>>;
+type Fq12MulC0_t59 = A<S<S<S<M<A<Fq12MulC0_t51, Fq12MulC0_t54>, A<Fq12MulC0_t52, Fq12MulC0_t55>>, A<Fq12MulC0_t53, Fq12MulC0_t56>>, Fq12MulC0_t40>, Fq12MulC0_t36>, A<M<Fq12MulC0_t37, CI<24>>, Fq12MulC0_t34>>;
+type Fq12MulC3 = CE<A<Fq12MulC0_t58, Fq12MulC0_t59>>;
+type Fq12MulC0_t60 = A<CI<6>, CI<8>>;
+type Fq12MulC0_t61 = A<CI<18>, CI<20>>;
+type Fq12MulC0_t62 = M<Fq12MulC0_t60, Fq12MulC0_t61>;
+type Fq12MulC0_t63 = A<CI<7>, CI<9>>;
+type Fq12MulC0_t64 = A<CI<19>, CI<21>>;
+type Fq12MulC0_t65 = M<Fq12MulC0_t63, Fq12MulC0_t64>;
+type Fq12MulC0_t66 = A<S<S<S<Fq12MulC0_t62, Fq12MulC0_t65>, Fq12MulC0_t8>, Fq12MulC0_t11>, S<M<Fq12MulC0_t14, CI<24>>, Fq12MulC0_t18>>;
+type Fq12MulC0_t67 = A<CI<0>, CI<4>>;
+type Fq12MulC0_t68 = A<CI<12>, CI<16>>;
+type Fq12MulC0_t69 = M<Fq12MulC0_t67, Fq12MulC0_t68>;
+type Fq12MulC0_t70 = A<CI<1>, CI<5>>;
+type Fq12MulC0_t71 = A<CI<13>, CI<17>>;
+type Fq12MulC0_t72 = M<Fq12MulC0_t70, Fq12MulC0_t71>;
+type Fq12MulC0_t73 = S<A<S<S<Fq12MulC0_t69, Fq12MulC0_t72>, Fq12MulC0_t22>, Fq12MulC0_t31>, Fq12MulC0_t34>;
+type Fq12MulC4 = CE<A<Fq12MulC0_t66, Fq12MulC0_t73>>;
+type Fq12MulC0_t74 = A<S<S<S<M<A<Fq12MulC0_t60, Fq12MulC0_t63>, A<Fq12MulC0_t61, Fq12MulC0_t64>>, A<Fq12MulC0_t62, Fq12MulC0_t65>>, Fq12MulC0_t16>, Fq12MulC0_t17>, A<M<Fq12MulC0_t18, CI<24>>, Fq12MulC0_t14>>;
+type Fq12MulC0_t75 = S<A<S<S<M<A<Fq12MulC0_t67, Fq12MulC0_t70>, A<Fq12MulC0_t68, Fq12MulC0_t71>>, A<Fq12MulC0_t69, Fq12MulC0_t72>>, Fq12MulC0_t40>, Fq12MulC0_t36>, Fq12MulC0_t37>;
+type Fq12MulC5 = CE<A<Fq12MulC0_t74, Fq12MulC0_t75>>;
+type Fq12MulC0_t76 = A<CI<0>, CI<6>>;
+type Fq12MulC0_t77 = A<CI<12>, CI<18>>;
+type Fq12MulC0_t78 = M<Fq12MulC0_t76, Fq12MulC0_t77>;
+type Fq12MulC0_t79 = A<CI<1>, CI<7>>;
+type Fq12MulC0_t80 = A<CI<13>, CI<19>>;
+type Fq12MulC0_t81 = M<Fq12MulC0_t79, Fq12MulC0_t80>;
+type Fq12MulC0_t82 = S<Fq12MulC0_t78, Fq12MulC0_t81>;
+type Fq12MulC0_t83 = A<CI<2>, CI<8>>;
+type Fq12MulC0_t84 = A<CI<4>, CI<10>>;
+type Fq12MulC0_t85 = A<Fq12MulC0_t83, Fq12MulC0_t84>;
+type Fq12MulC0_t86 = A<CI<14>, CI<20>>;
+type Fq12MulC0_t87 = A<CI<16>, CI<22>>;
+type Fq12MulC0_t88 = A<Fq12MulC0_t86, Fq12MulC0_t87>;
+type Fq12MulC0_t89 = M<Fq12MulC0_t85, Fq12MulC0_t88>;
+type Fq12MulC0_t90 = A<CI<3>, CI<9>>;
+type Fq12MulC0_t91 = A<CI<5>, CI<11>>;
+type Fq12MulC0_t92 = A<Fq12MulC0_t90, Fq12MulC0_t91>;
+type Fq12MulC0_t93 = A<CI<15>, CI<21>>;
+type Fq12MulC0_t94 = A<CI<17>, CI<23>>;
+type Fq12MulC0_t95 = A<Fq12MulC0_t93, Fq12MulC0_t94>;
+type Fq12MulC0_t96 = M<Fq12MulC0_t92, Fq12MulC0_t95>;
+type Fq12MulC0_t97 = M<Fq12MulC0_t83, Fq12MulC0_t86>;
+type Fq12MulC0_t98 = M<Fq12MulC0_t90, Fq12MulC0_t93>;
+type Fq12MulC0_t99 = S<Fq12MulC0_t97, Fq12MulC0_t98>;
+type Fq12MulC0_t100 = M<Fq12MulC0_t84, Fq12MulC0_t87>;
+type Fq12MulC0_t101 = M<Fq12MulC0_t91, Fq12MulC0_t94>;
+type Fq12MulC0_t102 = S<Fq12MulC0_t100, Fq12MulC0_t101>;
+type Fq12MulC0_t103 = S<S<S<Fq12MulC0_t89, Fq12MulC0_t96>, Fq12MulC0_t99>, Fq12MulC0_t102>;
+type Fq12MulC0_t104 = S<M<A<Fq12MulC0_t83, Fq12MulC0_t90>, A<Fq12MulC0_t86, Fq12MulC0_t93>>, A<Fq12MulC0_t97, Fq12MulC0_t98>>;
+type Fq12MulC0_t105 = S<M<A<Fq12MulC0_t84, Fq12MulC0_t91>, A<Fq12MulC0_t87, Fq12MulC0_t94>>, A<Fq12MulC0_t100, Fq12MulC0_t101>>;
+type Fq12MulC0_t106 = S<S<S<M<A<Fq12MulC0_t85, Fq12MulC0_t92>, A<Fq12MulC0_t88, Fq12MulC0_t95>>, A<Fq12MulC0_t89, Fq12MulC0_t96>>, Fq12MulC0_t104>, Fq12MulC0_t105>;
+type Fq12MulC6 = CE<S<S<A<Fq12MulC0_t82, S<M<Fq12MulC0_t103, CI<24>>, Fq12MulC0_t106>>, Fq12MulC0_t39>, Fq12MulC0_t50>>;
+type Fq12MulC0_t107 = S<M<A<Fq12MulC0_t76, Fq12MulC0_t79>, A<Fq12MulC0_t77, Fq12MulC0_t80>>, A<Fq12MulC0_t78, Fq12MulC0_t81>>;
+type Fq12MulC7 = CE<S<S<A<Fq12MulC0_t107, A<M<Fq12MulC0_t106, CI<24>>, Fq12MulC0_t103>>, Fq12MulC0_t41>, Fq12MulC0_t58>>;
+type Fq12MulC0_t108 = A<Fq12MulC0_t76, Fq12MulC0_t83>;
+type Fq12MulC0_t109 = A<Fq12MulC0_t77, Fq12MulC0_t86>;
+type Fq12MulC0_t110 = M<Fq12MulC0_t108, Fq12MulC0_t109>;
+type Fq12MulC0_t111 = A<Fq12MulC0_t79, Fq12MulC0_t90>;
+type Fq12MulC0_t112 = A<Fq12MulC0_t80, Fq12MulC0_t93>;
+type Fq12MulC0_t113 = M<Fq12MulC0_t111, Fq12MulC0_t112>;
+type Fq12MulC8 = CE<S<S<A<S<S<S<Fq12MulC0_t110, Fq12MulC0_t113>, Fq12MulC0_t82>, Fq12MulC0_t99>, S<M<Fq12MulC0_t102, CI<24>>, Fq12MulC0_t105>>, Fq12MulC0_t57>, Fq12MulC0_t66>>;
+type Fq12MulC9 = CE<S<S<A<S<S<S<M<A<Fq12MulC0_t108, Fq12MulC0_t111>, A<Fq12MulC0_t109, Fq12MulC0_t112>>, A<Fq12MulC0_t110, Fq12MulC0_t113>>, Fq12MulC0_t107>, Fq12MulC0_t104>, A<M<Fq12MulC0_t105, CI<24>>, Fq12MulC0_t102>>, Fq12MulC0_t59>, Fq12MulC0_t74>>;
+type Fq12MulC0_t114 = A<Fq12MulC0_t76, Fq12MulC0_t84>;
+type Fq12MulC0_t115 = A<Fq12MulC0_t77, Fq12MulC0_t87>;
+type Fq12MulC0_t116 = M<Fq12MulC0_t114, Fq12MulC0_t115>;
+type Fq12MulC0_t117 = A<Fq12MulC0_t79, Fq12MulC0_t91>;
+type Fq12MulC0_t118 = A<Fq12MulC0_t80, Fq12MulC0_t94>;
+type Fq12MulC0_t119 = M<Fq12MulC0_t117, Fq12MulC0_t118>;
+type Fq12MulC10 = CE<S<S<S<A<S<S<Fq12MulC0_t116, Fq12MulC0_t119>, Fq12MulC0_t82>, Fq12MulC0_t99>, Fq12MulC0_t102>, Fq12MulC0_t73>, Fq12MulC0_t15>>;
+type Fq12MulC11 = CE<S<S<S<A<S<S<M<A<Fq12MulC0_t114, Fq12MulC0_t117>, A<Fq12MulC0_t115, Fq12MulC0_t118>>, A<Fq12MulC0_t116, Fq12MulC0_t119>>, Fq12MulC0_t107>, Fq12MulC0_t104>, Fq12MulC0_t105>, Fq12MulC0_t75>, Fq12MulC0_t19>>;
+// Fq12 Sqr
+// CI<0>: lhs.c0.c0.c0.c0
+// CI<1>: lhs.c0.c0.c1.c0
+// CI<2>: lhs.c0.c1.c0.c0
+// CI<3>: lhs.c0.c1.c1.c0
+// CI<4>: lhs.c0.c2.c0.c0
+// CI<5>: lhs.c0.c2.c1.c0
+// CI<6>: lhs.c1.c0.c0.c0
+// CI<7>: lhs.c1.c0.c1.c0
+// CI<8>: lhs.c1.c1.c0.c0
+// CI<9>: lhs.c1.c1.c1.c0
+// CI<10>: lhs.c1.c2.c0.c0
+// CI<11>: lhs.c1.c2.c1.c0
+// CI<12>: NINE = 9
+type Fq12SqrC0_t0 = A<CI<0>, CI<6>>;
+type Fq12SqrC0_t1 = A<CI<0>, S<M<CI<10>, CI<12>>, CI<11>>>;
+type Fq12SqrC0_t2 = M<Fq12SqrC0_t0, Fq12SqrC0_t1>;
+type Fq12SqrC0_t3 = A<CI<1>, CI<7>>;
+type Fq12SqrC0_t4 = A<CI<1>, A<M<CI<11>, CI<12>>, CI<10>>>;
+type Fq12SqrC0_t5 = M<Fq12SqrC0_t3, Fq12SqrC0_t4>;
+type Fq12SqrC0_t6 = S<Fq12SqrC0_t2, Fq12SqrC0_t5>;
+type Fq12SqrC0_t7 = A<CI<2>, CI<8>>;
+type Fq12SqrC0_t8 = A<CI<4>, CI<10>>;
+type Fq12SqrC0_t9 = A<Fq12SqrC0_t7, Fq12SqrC0_t8>;
+type Fq12SqrC0_t10 = A<CI<2>, CI<6>>;
+type Fq12SqrC0_t11 = A<CI<4>, CI<8>>;
+type Fq12SqrC0_t12 = A<Fq12SqrC0_t10, Fq12SqrC0_t11>;
+type Fq12SqrC0_t13 = M<Fq12SqrC0_t9, Fq12SqrC0_t12>;
+type Fq12SqrC0_t14 = A<CI<3>, CI<9>>;
+type Fq12SqrC0_t15 = A<CI<5>, CI<11>>;
+type Fq12SqrC0_t16 = A<Fq12SqrC0_t14, Fq12SqrC0_t15>;
+type Fq12SqrC0_t17 = A<CI<3>, CI<7>>;
+type Fq12SqrC0_t18 = A<CI<5>, CI<9>>;
+type Fq12SqrC0_t19 = A<Fq12SqrC0_t17, Fq12SqrC0_t18>;
+type Fq12SqrC0_t20 = M<Fq12SqrC0_t16, Fq12SqrC0_t19>;
+type Fq12SqrC0_t21 = M<Fq12SqrC0_t7, Fq12SqrC0_t10>;
+type Fq12SqrC0_t22 = M<Fq12SqrC0_t14, Fq12SqrC0_t17>;
+type Fq12SqrC0_t23 = S<Fq12SqrC0_t21, Fq12SqrC0_t22>;
+type Fq12SqrC0_t24 = M<Fq12SqrC0_t8, Fq12SqrC0_t11>;
+type Fq12SqrC0_t25 = M<Fq12SqrC0_t15, Fq12SqrC0_t18>;
+type Fq12SqrC0_t26 = S<Fq12SqrC0_t24, Fq12SqrC0_t25>;
+type Fq12SqrC0_t27 = S<S<S<Fq12SqrC0_t13, Fq12SqrC0_t20>, Fq12SqrC0_t23>, Fq12SqrC0_t26>;
+type Fq12SqrC0_t28 = S<M<A<Fq12SqrC0_t7, Fq12SqrC0_t14>, A<Fq12SqrC0_t10, Fq12SqrC0_t17>>, A<Fq12SqrC0_t21, Fq12SqrC0_t22>>;
+type Fq12SqrC0_t29 = S<M<A<Fq12SqrC0_t8, Fq12SqrC0_t15>, A<Fq12SqrC0_t11, Fq12SqrC0_t18>>, A<Fq12SqrC0_t24, Fq12SqrC0_t25>>;
+type Fq12SqrC0_t30 = S<S<S<M<A<Fq12SqrC0_t9, Fq12SqrC0_t16>, A<Fq12SqrC0_t12, Fq12SqrC0_t19>>, A<Fq12SqrC0_t13, Fq12SqrC0_t20>>, Fq12SqrC0_t28>, Fq12SqrC0_t29>;
+type Fq12SqrC0_t31 = M<CI<0>, CI<6>>;
+type Fq12SqrC0_t32 = M<CI<1>, CI<7>>;
+type Fq12SqrC0_t33 = S<Fq12SqrC0_t31, Fq12SqrC0_t32>;
+type Fq12SqrC0_t34 = A<CI<2>, CI<4>>;
+type Fq12SqrC0_t35 = A<CI<8>, CI<10>>;
+type Fq12SqrC0_t36 = M<Fq12SqrC0_t34, Fq12SqrC0_t35>;
+type Fq12SqrC0_t37 = A<CI<3>, CI<5>>;
+type Fq12SqrC0_t38 = A<CI<9>, CI<11>>;
+type Fq12SqrC0_t39 = M<Fq12SqrC0_t37, Fq12SqrC0_t38>;
+type Fq12SqrC0_t40 = M<CI<2>, CI<8>>;
+type Fq12SqrC0_t41 = M<CI<3>, CI<9>>;
+type Fq12SqrC0_t42 = S<Fq12SqrC0_t40, Fq12SqrC0_t41>;
+type Fq12SqrC0_t43 = M<CI<4>, CI<10>>;
+type Fq12SqrC0_t44 = M<CI<5>, CI<11>>;
+type Fq12SqrC0_t45 = S<Fq12SqrC0_t43, Fq12SqrC0_t44>;
+type Fq12SqrC0_t46 = S<S<S<Fq12SqrC0_t36, Fq12SqrC0_t39>, Fq12SqrC0_t42>, Fq12SqrC0_t45>;
+type Fq12SqrC0_t47 = S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<Fq12SqrC0_t40, Fq12SqrC0_t41>>;
+type Fq12SqrC0_t48 = S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<Fq12SqrC0_t43, Fq12SqrC0_t44>>;
+type Fq12SqrC0_t49 = S<S<S<M<A<Fq12SqrC0_t34, Fq12SqrC0_t37>, A<Fq12SqrC0_t35, Fq12SqrC0_t38>>, A<Fq12SqrC0_t36, Fq12SqrC0_t39>>, Fq12SqrC0_t47>, Fq12SqrC0_t48>;
+type Fq12SqrC0_t50 = A<Fq12SqrC0_t33, S<M<Fq12SqrC0_t46, CI<12>>, Fq12SqrC0_t49>>;
+type Fq12SqrC0_t51 = A<CI<0>, CI<4>>;
+type Fq12SqrC0_t52 = A<CI<6>, CI<10>>;
+type Fq12SqrC0_t53 = M<Fq12SqrC0_t51, Fq12SqrC0_t52>;
+type Fq12SqrC0_t54 = A<CI<1>, CI<5>>;
+type Fq12SqrC0_t55 = A<CI<7>, CI<11>>;
+type Fq12SqrC0_t56 = M<Fq12SqrC0_t54, Fq12SqrC0_t55>;
+type Fq12SqrC0_t57 = S<A<S<S<Fq12SqrC0_t53, Fq12SqrC0_t56>, Fq12SqrC0_t33>, Fq12SqrC0_t42>, Fq12SqrC0_t45>;
+type Fq12SqrC0_t58 = S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<Fq12SqrC0_t31, Fq12SqrC0_t32>>;
+type Fq12SqrC0_t59 = S<A<S<S<M<A<Fq12SqrC0_t51, Fq12SqrC0_t54>, A<Fq12SqrC0_t52, Fq12SqrC0_t55>>, A<Fq12SqrC0_t53, Fq12SqrC0_t56>>, Fq12SqrC0_t58>, Fq12SqrC0_t47>, Fq12SqrC0_t48>;
+type Fq12SqrC0 = CE<S<S<A<Fq12SqrC0_t6, S<M<Fq12SqrC0_t27, CI<12>>, Fq12SqrC0_t30>>, Fq12SqrC0_t50>, S<M<Fq12SqrC0_t57, CI<12>>, Fq12SqrC0_t59>>>;
+type Fq12SqrC0_t60 = S<M<A<Fq12SqrC0_t0, Fq12SqrC0_t3>, A<Fq12SqrC0_t1, Fq12SqrC0_t4>>, A<Fq12SqrC0_t2, Fq12SqrC0_t5>>;
+type Fq12SqrC0_t61 = A<Fq12SqrC0_t58, A<M<Fq12SqrC0_t49, CI<12>>, Fq12SqrC0_t46>>;
+type Fq12SqrC1 = CE<S<S<A<Fq12SqrC0_t60, A<M<Fq12SqrC0_t30, CI<12>>, Fq12SqrC0_t27>>, Fq12SqrC0_t61>, A<M<Fq12SqrC0_t59, CI<12>>, Fq12SqrC0_t57>>>;
+type Fq12SqrC0_t62 = A<Fq12SqrC0_t0, Fq12SqrC0_t7>;
+type Fq12SqrC0_t63 = A<Fq12SqrC0_t1, Fq12SqrC0_t10>;
+type Fq12SqrC0_t64 = M<Fq12SqrC0_t62, Fq12SqrC0_t63>;
+type Fq12SqrC0_t65 = A<Fq12SqrC0_t3, Fq12SqrC0_t14>;
+type Fq12SqrC0_t66 = A<Fq12SqrC0_t4, Fq12SqrC0_t17>;
+type Fq12SqrC0_t67 = M<Fq12SqrC0_t65, Fq12SqrC0_t66>;
+type Fq12SqrC0_t68 = A<CI<0>, CI<2>>;
+type Fq12SqrC0_t69 = A<CI<6>, CI<8>>;
+type Fq12SqrC0_t70 = M<Fq12SqrC0_t68, Fq12SqrC0_t69>;
+type Fq12SqrC0_t71 = A<CI<1>, CI<3>>;
+type Fq12SqrC0_t72 = A<CI<7>, CI<9>>;
+type Fq12SqrC0_t73 = M<Fq12SqrC0_t71, Fq12SqrC0_t72>;
+type Fq12SqrC0_t74 = A<S<S<S<Fq12SqrC0_t70, Fq12SqrC0_t73>, Fq12SqrC0_t33>, Fq12SqrC0_t42>, S<M<Fq12SqrC0_t45, CI<12>>, Fq12SqrC0_t48>>;
+type Fq12SqrC2 = CE<S<S<A<S<S<S<Fq12SqrC0_t64, Fq12SqrC0_t67>, Fq12SqrC0_t6>, Fq12SqrC0_t23>, S<M<Fq12SqrC0_t26, CI<12>>, Fq12SqrC0_t29>>, Fq12SqrC0_t74>, Fq12SqrC0_t50>>;
+type Fq12SqrC0_t75 = A<S<S<S<M<A<Fq12SqrC0_t68, Fq12SqrC0_t71>, A<Fq12SqrC0_t69, Fq12SqrC0_t72>>, A<Fq12SqrC0_t70, Fq12SqrC0_t73>>, Fq12SqrC0_t58>, Fq12SqrC0_t47>, A<M<Fq12SqrC0_t48, CI<12>>, Fq12SqrC0_t45>>;
+type Fq12SqrC3 = CE<S<S<A<S<S<S<M<A<Fq12SqrC0_t62, Fq12SqrC0_t65>, A<Fq12SqrC0_t63, Fq12SqrC0_t66>>, A<Fq12SqrC0_t64, Fq12SqrC0_t67>>, Fq12SqrC0_t60>, Fq12SqrC0_t28>, A<M<Fq12SqrC0_t29, CI<12>>, Fq12SqrC0_t26>>, Fq12SqrC0_t75>, Fq12SqrC0_t61>>;
+type Fq12SqrC0_t76 = A<Fq12SqrC0_t0, Fq12SqrC0_t8>;
+type Fq12SqrC0_t77 = A<Fq12SqrC0_t1, Fq12SqrC0_t11>;
+type Fq12SqrC0_t78 = M<Fq12SqrC0_t76, Fq12SqrC0_t77>;
+type Fq12SqrC0_t79 = A<Fq12SqrC0_t3, Fq12SqrC0_t15>;
+type Fq12SqrC0_t80 = A<Fq12SqrC0_t4, Fq12SqrC0_t18>;
+type Fq12SqrC0_t81 = M<Fq12SqrC0_t79, Fq12SqrC0_t80>;
+type Fq12SqrC4 = CE<S<S<S<A<S<S<Fq12SqrC0_t78, Fq12SqrC0_t81>, Fq12SqrC0_t6>, Fq12SqrC0_t23>, Fq12SqrC0_t26>, Fq12SqrC0_t57>, Fq12SqrC0_t74>>;
+type Fq12SqrC5 = CE<S<S<S<A<S<S<M<A<Fq12SqrC0_t76, Fq12SqrC0_t79>, A<Fq12SqrC0_t77, Fq12SqrC0_t80>>, A<Fq12SqrC0_t78, Fq12SqrC0_t81>>, Fq12SqrC0_t60>, Fq12SqrC0_t28>, Fq12SqrC0_t29>, Fq12SqrC0_t59>, Fq12SqrC0_t75>>;
+type Fq12SqrC6 = CE<A<Fq12SqrC0_t50, Fq12SqrC0_t50>>;
+type Fq12SqrC7 = CE<A<Fq12SqrC0_t61, Fq12SqrC0_t61>>;
+type Fq12SqrC8 = CE<A<Fq12SqrC0_t74, Fq12SqrC0_t74>>;
+type Fq12SqrC9 = CE<A<Fq12SqrC0_t75, Fq12SqrC0_t75>>;
+type Fq12SqrC10 = CE<A<Fq12SqrC0_t57, Fq12SqrC0_t57>>;
+type Fq12SqrC11 = CE<A<Fq12SqrC0_t59, Fq12SqrC0_t59>>;
+// @generated fq12 end
@@ -7,14 +7,59 @@ use core::circuit::{
 	CircuitElement as CE,
 };
 
-type Fq2MulC0 = CE<S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>;
-type Fq2MulC1 = CE<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>;
-
+// @generated fq2 begin, run `cargo run -- regen` in cairo_circuit_codegen to update
+// Fq2 Add
+// CI<0>: lhs.c0.c0
+// CI<1>: lhs.c1.c0
+// CI<2>: rhs.c0.c0
+// CI<3>: rhs.c1.c0
+type Fq2AddC0 = CE<A<CI<0>, CI<2>>>;
+type Fq2AddC1 = CE<A<CI<1>, CI<3>>>;
+// Fq2 Sub
+// CI<0>: lhs.c0.c0
+// CI<1>: lhs.c1.c0
+// CI<2>: rhs.c0.c0
+// CI<3>: rhs.c1.c0
+type Fq2SubC0 = CE<S<CI<0>, CI<2>>>;
+type Fq2SubC1 = CE<S<CI<1>, CI<3>>>;
+// Fq2 Mul
+// CI<0>: lhs.c0.c0
+// CI<1>: lhs.c1.c0
+// CI<2>: rhs.c0.c0
+// CI<3>: rhs.c1.c0
+type Fq2MulC0_t0 = M<CI<0>, CI<2>>;
+type Fq2MulC0_t1 = M<CI<1>, CI<3>>;
+type Fq2MulC0 = CE<S<Fq2MulC0_t0, Fq2MulC0_t1>>;
+type Fq2MulC1 = CE<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<Fq2MulC0_t0, Fq2MulC0_t1>>>;
+// Fq2 Sqr
+// CI<0>: lhs.c0.c0
+// CI<1>: lhs.c1.c0
 type Fq2SqrC0 = CE<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>;
 type Fq2SqrC1 = CE<M<A<CI<0>, CI<0>>, CI<1>>>;
-
-type Fq2DivC0 = CE<S<M<CI<0>, M<CI<2>, I<A<M<CI<2>, CI<2>>, M<CI<3>, CI<3>>>>>>, M<CI<1>, M<CI<3>, S<S<CI<0>, CI<0>>, I<A<M<CI<2>, CI<2>>, M<CI<3>, CI<3>>>>>>>>>;
-type Fq2DivC1 = CE<S<M<A<CI<0>, CI<1>>, A<M<CI<2>, I<A<M<CI<2>, CI<2>>, M<CI<3>, CI<3>>>>>, M<CI<3>, S<S<CI<0>, CI<0>>, I<A<M<CI<2>, CI<2>>, M<CI<3>, CI<3>>>>>>>>, A<M<CI<0>, M<CI<2>, I<A<M<CI<2>, CI<2>>, M<CI<3>, CI<3>>>>>>, M<CI<1>, M<CI<3>, S<S<CI<0>, CI<0>>, I<A<M<CI<2>, CI<2>>, M<CI<3>, CI<3>>>>>>>>>>;
-
-type Fq2InvC0 = CE<M<CI<0>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>;
-type Fq2InvC1 = CE<M<CI<1>, S<S<CI<0>, CI<0>>, I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>>>>;
\ No newline at end of file
+// Fq2 Div
+// CI<0>: lhs.c0.c0
+// CI<1>: lhs.c1.c0
+// CI<2>: rhs.c0.c0
+// CI<3>: rhs.c1.c0
+// CI<4>: ZERO = 0
+type Fq2DivC0_t0 = I<A<M<CI<2>, CI<2>>, M<CI<3>, CI<3>>>>;
+type Fq2DivC0_t1 = M<CI<2>, Fq2DivC0_t0>;
+type Fq2DivC0_t2 = M<CI<0>, Fq2DivC0_t1>;
+type Fq2DivC0_t3 = M<CI<3>, S<CI<4>, Fq2DivC0_t0>>;
+type Fq2DivC0_t4 = M<CI<1>, Fq2DivC0_t3>;
+type Fq2DivC0 = CE<S<Fq2DivC0_t2, Fq2DivC0_t4>>;
+type Fq2DivC1 = CE<S<M<A<CI<0>, CI<1>>, A<Fq2DivC0_t1, Fq2DivC0_t3>>, A<Fq2DivC0_t2, Fq2DivC0_t4>>>;
+// Fq2 Inv
+// CI<0>: lhs.c0.c0
+// CI<1>: lhs.c1.c0
+// CI<2>: ZERO = 0
+type Fq2InvC0_t0 = I<A<M<CI<0>, CI<0>>, M<CI<1>, CI<1>>>>;
+type Fq2InvC0 = CE<M<CI<0>, Fq2InvC0_t0>>;
+type Fq2InvC1 = CE<M<CI<1>, S<CI<2>, Fq2InvC0_t0>>>;
+// Fq2 Neg
+// CI<0>: lhs.c0.c0
+// CI<1>: lhs.c1.c0
+// CI<2>: ZERO = 0
+type Fq2NegC0 = CE<S<CI<2>, CI<0>>>;
+type Fq2NegC1 = CE<S<CI<2>, CI<1>>>;
+// @generated fq2 end
@@ -7,18 +7,302 @@ use core::circuit::{
 	CircuitElement as CE,
 };
 
+// @generated fq6 begin, run `cargo run -- regen` in cairo_circuit_codegen to update
+// Fq6 Add
+// CI<0>: lhs.c0.c0.c0
+// CI<1>: lhs.c0.c1.c0
+// CI<2>: lhs.c1.c0.c0
+// CI<3>: lhs.c1.c1.c0
+// CI<4>: lhs.c2.c0.c0
+// CI<5>: lhs.c2.c1.c0
+// CI<6>: rhs.c0.c0.c0
+// CI<7>: rhs.c0.c1.c0
+// CI<8>: rhs.c1.c0.c0
+// CI<9>: rhs.c1.c1.c0
+// CI<10>: rhs.c2.c0.c0
+// CI<11>: rhs.c2.c1.c0
+type Fq6AddC0 = CE<A<CI<0>, CI<6>>>;
+type Fq6AddC1 = CE<A<CI<1>, CI<7>>>;
+type Fq6AddC2 = CE<A<CI<2>, CI<8>>>;
+type Fq6AddC3 = CE<A<CI<3>, CI<9>>>;
+type Fq6AddC4 = CE<A<CI<4>, CI<10>>>;
+type Fq6AddC5 = CE<A<CI<5>, CI<11>>>;
+// Fq6 Sub
+// CI<0>: lhs.c0.c0.c0
+// CI<1>: lhs.c0.c1.c0
+// CI<2>: lhs.c1.c0.c0
+// CI<3>: lhs.c1.c1.c0
+// CI<4>: lhs.c2.c0.c0
+// CI<5>: lhs.c2.c1.c0
+// CI<6>: rhs.c0.c0.c0
+// CI<7>: rhs.c0.c1.c0
+// CI<8>: rhs.c1.c0.c0
+// CI<9>: rhs.c1.c1.c0
+// CI<10>: rhs.c2.c0.c0
+// CI<11>: rhs.c2.c1.c0
+type Fq6SubC0 = CE<S<CI<0>, CI<6>>>;
+type Fq6SubC1 = CE<S<CI<1>, CI<7>>>;
+type Fq6SubC2 = CE<S<CI<2>, CI<8>>>;
+type Fq6SubC3 = CE<S<CI<3>, CI<9>>>;
+type Fq6SubC4 = CE<S<CI<4>, CI<10>>>;
+type Fq6SubC5 = CE<S<CI<5>, CI<11>>>;
 // Fq6 Mul
-type Fq6MulC0 = CE<A<S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>, S<A<A<A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>;
-type Fq6MulC1 = CE<A<S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, A<A<A<A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, A<A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>>, S<S<S<M<A<A<CI<2>, CI<4>>, A<CI<3>, CI<5>>>, A<A<CI<8>, CI<10>>, A<CI<9>, CI<11>>>>, A<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<S<S<M<A<CI<2>, CI<4>>, A<CI<8>, CI<10>>>, M<A<CI<3>, CI<5>>, A<CI<9>, CI<11>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>;
-type Fq6MulC2 = CE<A<S<S<S<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<A<A<A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, A<S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>;
-type Fq6MulC3 = CE<A<S<S<S<M<A<A<CI<0>, CI<2>>, A<CI<1>, CI<3>>>, A<A<CI<6>, CI<8>>, A<CI<7>, CI<9>>>>, A<M<A<CI<0>, CI<2>>, A<CI<6>, CI<8>>>, M<A<CI<1>, CI<3>>, A<CI<7>, CI<9>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, A<A<A<A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>, A<A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, A<S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>;
-type Fq6MulC4 = CE<S<A<S<S<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>, S<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>, S<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>, S<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>;
-type Fq6MulC5 = CE<S<A<S<S<M<A<A<CI<0>, CI<4>>, A<CI<1>, CI<5>>>, A<A<CI<6>, CI<10>>, A<CI<7>, CI<11>>>>, A<M<A<CI<0>, CI<4>>, A<CI<6>, CI<10>>>, M<A<CI<1>, CI<5>>, A<CI<7>, CI<11>>>>>, S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<M<CI<0>, CI<6>>, M<CI<1>, CI<7>>>>>, S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<M<CI<2>, CI<8>>, M<CI<3>, CI<9>>>>>, S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<M<CI<4>, CI<10>>, M<CI<5>, CI<11>>>>>>;
-
+// CI<0>: lhs.c0.c0.c0
+// CI<1>: lhs.c0.c1.c0
+// CI<2>: lhs.c1.c0.c0
+// CI<3>: lhs.c1.c1.c0
+// CI<4>: lhs.c2.c0.c0
+// CI<5>: lhs.c2.c1.c0
+// CI<6>: rhs.c0.c0.c0
+// CI<7>: rhs.c0.c1.c0
+// CI<8>: rhs.c1.c0.c0
+// CI<9>: rhs.c1.c1.c0
+// CI<10>: rhs.c2.c0.c0
+// CI<11>: rhs.c2.c1.c0
+// CI<12>: NINE = 9
+type Fq6MulC0_t0 = M<CI<0>, CI<6>>;
+type Fq6MulC0_t1 = M<CI<1>, CI<7>>;
+type Fq6MulC0_t2 = S<Fq6MulC0_t0, Fq6MulC0_t1>;
+type Fq6MulC0_t3 = A<CI<2>, CI<4>>;
+type Fq6MulC0_t4 = A<CI<8>, CI<10>>;
+type Fq6MulC0_t5 = M<Fq6MulC0_t3, Fq6MulC0_t4>;
+type Fq6MulC0_t6 = A<CI<3>, CI<5>>;
+type Fq6MulC0_t7 = A<CI<9>, CI<11>>;
+type Fq6MulC0_t8 = M<Fq6MulC0_t6, Fq6MulC0_t7>;
+type Fq6MulC0_t9 = M<CI<2>, CI<8>>;
+type Fq6MulC0_t10 = M<CI<3>, CI<9>>;
+type Fq6MulC0_t11 = S<Fq6MulC0_t9, Fq6MulC0_t10>;
+type Fq6MulC0_t12 = M<CI<4>, CI<10>>;
+type Fq6MulC0_t13 = M<CI<5>, CI<11>>;
+type Fq6MulC0_t14 = S<Fq6MulC0_t12, Fq6MulC0_t13>;
+type Fq6MulC0_t15 = S<S<S<Fq6MulC0_t5, Fq6MulC0_t8>, Fq6MulC0_t11>, Fq6MulC0_t14>;
+type Fq6MulC0_t16 = S<M<A<CI<2>, CI<3>>, A<CI<8>, CI<9>>>, A<Fq6MulC0_t9, Fq6MulC0_t10>>;
+type Fq6MulC0_t17 = S<M<A<CI<4>, CI<5>>, A<CI<10>, CI<11>>>, A<Fq6MulC0_t12, Fq6MulC0_t13>>;
+type Fq6MulC0_t18 = S<S<S<M<A<Fq6MulC0_t3, Fq6MulC0_t6>, A<Fq6MulC0_t4, Fq6MulC0_t7>>, A<Fq6MulC0_t5, Fq6MulC0_t8>>, Fq6MulC0_t16>, Fq6MulC0_t17>;
+type Fq6MulC0 = CE<A<Fq6MulC0_t2, S<M<Fq6MulC0_t15, CI<12>>, Fq6MulC0_t18>>>;
+type Fq6MulC0_t19 = S<M<A<CI<0>, CI<1>>, A<CI<6>, CI<7>>>, A<Fq6MulC0_t0, Fq6MulC0_t1>>;
+type Fq6MulC1 = CE<A<Fq6MulC0_t19, A<M<Fq6MulC0_t18, CI<12>>, Fq6MulC0_t15>>>;
+type Fq6MulC0_t20 = A<CI<0>, CI<2>>;
+type Fq6MulC0_t21 = A<CI<6>, CI<8>>;
+type Fq6MulC0_t22 = M<Fq6MulC0_t20, Fq6MulC0_t21>;
+type Fq6MulC0_t23 = A<CI<1>, CI<3>>;
+type Fq6MulC0_t24 = A<CI<7>, CI<9>>;
+type Fq6MulC0_t25 = M<Fq6MulC0_t23, Fq6MulC0_t24>;
+type Fq6MulC2 = CE<A<S<S<S<Fq6MulC0_t22, Fq6MulC0_t25>, Fq6MulC0_t2>, Fq6MulC0_t11>, S<M<Fq6MulC0_t14, CI<12>>, Fq6MulC0_t17>>>;
+type Fq6MulC3 = CE<A<S<S<S<M<A<Fq6MulC0_t20, Fq6MulC0_t23>, A<Fq6MulC0_t21, Fq6MulC0_t24>>, A<Fq6MulC0_t22, Fq6MulC0_t25>>, Fq6MulC0_t19>, Fq6MulC0_t16>, A<M<Fq6MulC0_t17, CI<12>>, Fq6MulC0_t14>>>;
+type Fq6MulC0_t26 = A<CI<0>, CI<4>>;
+type Fq6MulC0_t27 = A<CI<6>, CI<10>>;
+type Fq6MulC0_t28 = M<Fq6MulC0_t26, Fq6MulC0_t27>;
+type Fq6MulC0_t29 = A<CI<1>, CI<5>>;
+type Fq6MulC0_t30 = A<CI<7>, CI<11>>;
+type Fq6MulC0_t31 = M<Fq6MulC0_t29, Fq6MulC0_t30>;
+type Fq6MulC4 = CE<S<A<S<S<Fq6MulC0_t28, Fq6MulC0_t31>, Fq6MulC0_t2>, Fq6MulC0_t11>, Fq6MulC0_t14>>;
+type Fq6MulC5 = CE<S<A<S<S<M<A<Fq6MulC0_t26, Fq6MulC0_t29>, A<Fq6MulC0_t27, Fq6MulC0_t30>>, A<Fq6MulC0_t28, Fq6MulC0_t31>>, Fq6MulC0_t19>, Fq6MulC0_t16>, Fq6MulC0_t17>>;
 // Fq6 Sqr
-type Fq6SqrC0 = CE<A<M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>, S<A<A<A<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>, A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>>;
-type Fq6SqrC1 = CE<A<M<A<CI<0>, CI<0>>, CI<1>>, A<A<A<A<A<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>, A<A<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>>;
-type Fq6SqrC2 = CE<A<A<S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<A<A<A<A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>>>, A<A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>>, A<M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>, M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>>>>, M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>>, M<A<CI<4>, CI<4>>, CI<5>>>>>;
-type Fq6SqrC3 = CE<A<A<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, A<A<A<A<A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<4>, CI<4>>, CI<5>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<4>, CI<4>>, CI<5>>>>, A<A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<4>, CI<4>>, CI<5>>>, A<M<A<CI<4>, CI<4>>, CI<5>>, M<A<CI<4>, CI<4>>, CI<5>>>>>, M<A<CI<4>, CI<4>>, CI<5>>>, M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>>>>;
-type Fq6SqrC4 = CE<S<S<A<A<A<S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>, S<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, M<A<S<A<CI<0>, CI<4>>, CI<2>>, S<A<CI<1>, CI<5>>, CI<3>>>, S<S<A<CI<0>, CI<4>>, CI<2>>, S<A<CI<1>, CI<5>>, CI<3>>>>>, A<S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>, S<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>, M<A<CI<0>, CI<1>>, S<CI<0>, CI<1>>>>, M<A<CI<4>, CI<5>>, S<CI<4>, CI<5>>>>>;
-type Fq6SqrC5 = CE<S<S<A<A<A<S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>, S<M<A<CI<0>, CI<1>>, A<CI<2>, CI<3>>>, A<M<CI<0>, CI<2>>, M<CI<1>, CI<3>>>>>, M<A<S<A<CI<0>, CI<4>>, CI<2>>, S<A<CI<0>, CI<4>>, CI<2>>>, S<A<CI<1>, CI<5>>, CI<3>>>>, A<S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>, S<M<A<CI<2>, CI<3>>, A<CI<4>, CI<5>>>, A<M<CI<2>, CI<4>>, M<CI<3>, CI<5>>>>>>, M<A<CI<0>, CI<0>>, CI<1>>>, M<A<CI<4>, CI<4>>, CI<5>>>>;
\ No newline at end of file
+// CI<0>: lhs.c0.c0.c0
+// CI<1>: lhs.c0.c1.c0
+// CI<2>: lhs.c1.c0.c0
+// CI<3>: lhs.c1.c1.c0
+// CI<4>: lhs.c2.c0.c0
+// CI<5>: lhs.c2.c1.c0
+// CI<6>: NINE = 9
+type Fq6SqrC0_t0 = A<CI<0>, CI<1>>;
+type Fq6SqrC0_t1 = M<Fq6SqrC0_t0, S<CI<0>, CI<1>>>;
+type Fq6SqrC0_t2 = M<CI<2>, CI<4>>;
+type Fq6SqrC0_t3 = M<CI<3>, CI<5>>;
+type Fq6SqrC0_t4 = S<Fq6SqrC0_t2, Fq6SqrC0_t3>;
+type Fq6SqrC0_t5 = A<Fq6SqrC0_t4, Fq6SqrC0_t4>;
+type Fq6SqrC0_t6 = A<CI<2>, CI<3>>;
+type Fq6SqrC0_t7 = A<CI<4>, CI<5>>;
+type Fq6SqrC0_t8 = S<M<Fq6SqrC0_t6, Fq6SqrC0_t7>, A<Fq6SqrC0_t2, Fq6SqrC0_t3>>;
+type Fq6SqrC0_t9 = A<Fq6SqrC0_t8, Fq6SqrC0_t8>;
+type Fq6SqrC0 = CE<A<Fq6SqrC0_t1, S<M<Fq6SqrC0_t5, CI<6>>, Fq6SqrC0_t9>>>;
+type Fq6SqrC0_t10 = M<A<CI<0>, CI<0>>, CI<1>>;
+type Fq6SqrC1 = CE<A<Fq6SqrC0_t10, A<M<Fq6SqrC0_t9, CI<6>>, Fq6SqrC0_t5>>>;
+type Fq6SqrC0_t11 = M<CI<0>, CI<2>>;
+type Fq6SqrC0_t12 = M<CI<1>, CI<3>>;
+type Fq6SqrC0_t13 = S<Fq6SqrC0_t11, Fq6SqrC0_t12>;
+type Fq6SqrC0_t14 = A<Fq6SqrC0_t13, Fq6SqrC0_t13>;
+type Fq6SqrC0_t15 = M<Fq6SqrC0_t7, S<CI<4>, CI<5>>>;
+type Fq6SqrC0_t16 = M<A<CI<4>, CI<4>>, CI<5>>;
+type Fq6SqrC2 = CE<A<Fq6SqrC0_t14, S<M<Fq6SqrC0_t15, CI<6>>, Fq6SqrC0_t16>>>;
+type Fq6SqrC0_t17 = S<M<Fq6SqrC0_t0, Fq6SqrC0_t6>, A<Fq6SqrC0_t11, Fq6SqrC0_t12>>;
+type Fq6SqrC0_t18 = A<Fq6SqrC0_t17, Fq6SqrC0_t17>;
+type Fq6SqrC3 = CE<A<Fq6SqrC0_t18, A<M<Fq6SqrC0_t16, CI<6>>, Fq6SqrC0_t15>>>;
+type Fq6SqrC0_t19 = S<A<CI<0>, CI<4>>, CI<2>>;
+type Fq6SqrC0_t20 = S<A<CI<1>, CI<5>>, CI<3>>;
+type Fq6SqrC4 = CE<S<S<A<A<Fq6SqrC0_t14, M<A<Fq6SqrC0_t19, Fq6SqrC0_t20>, S<Fq6SqrC0_t19, Fq6SqrC0_t20>>>, Fq6SqrC0_t5>, Fq6SqrC0_t1>, Fq6SqrC0_t15>>;
+type Fq6SqrC5 = CE<S<S<A<A<Fq6SqrC0_t18, M<A<Fq6SqrC0_t19, Fq6SqrC0_t19>, Fq6SqrC0_t20>>, Fq6SqrC0_t9>, Fq6SqrC0_t10>, Fq6SqrC0_t16>>;
+// Fq6 Div
+// CI<0>: lhs.c0.c0.c0
+// CI<1>: lhs.c0.c1.c0
+// CI<2>: lhs.c1.c0.c0
+// CI<3>: lhs.c1.c1.c0
+// CI<4>: lhs.c2.c0.c0
+// CI<5>: lhs.c2.c1.c0
+// CI<6>: rhs.c0.c0.c0
+// CI<7>: rhs.c0.c1.c0
+// CI<8>: rhs.c1.c0.c0
+// CI<9>: rhs.c1.c1.c0
+// CI<10>: rhs.c2.c0.c0
+// CI<11>: rhs.c2.c1.c0
+// CI<12>: NINE = 9
+// CI<13>: ZERO = 0
+type Fq6DivC0_t0 = A<CI<6>, CI<7>>;
+type Fq6DivC0_t1 = M<CI<8>, CI<10>>;
+type Fq6DivC0_t2 = M<CI<9>, CI<11>>;
+type Fq6DivC0_t3 = S<Fq6DivC0_t1, Fq6DivC0_t2>;
+type Fq6DivC0_t4 = A<CI<8>, CI<9>>;
+type Fq6DivC0_t5 = A<CI<10>, CI<11>>;
+type Fq6DivC0_t6 = S<M<Fq6DivC0_t4, Fq6DivC0_t5>, A<Fq6DivC0_t1, Fq6DivC0_t2>>;
+type Fq6DivC0_t7 = S<M<Fq6DivC0_t0, S<CI<6>, CI<7>>>, S<M<Fq6DivC0_t3, CI<12>>, Fq6DivC0_t6>>;
+type Fq6DivC0_t8 = M<Fq6DivC0_t5, S<CI<10>, CI<11>>>;
+type Fq6DivC0_t9 = M<A<CI<10>, CI<10>>, CI<11>>;
+type Fq6DivC0_t10 = M<CI<6>, CI<8>>;
+type Fq6DivC0_t11 = M<CI<7>, CI<9>>;
+type Fq6DivC0_t12 = S<S<M<Fq6DivC0_t8, CI<12>>, Fq6DivC0_t9>, S<Fq6DivC0_t10, Fq6DivC0_t11>>;
+type Fq6DivC0_t13 = M<CI<10>, Fq6DivC0_t12>;
+type Fq6DivC0_t14 = S<A<M<Fq6DivC0_t9, CI<12>>, Fq6DivC0_t8>, S<M<Fq6DivC0_t0, Fq6DivC0_t4>, A<Fq6DivC0_t10, Fq6DivC0_t11>>>;
+type Fq6DivC0_t15 = M<CI<11>, Fq6DivC0_t14>;
+type Fq6DivC0_t16 = M<CI<6>, CI<10>>;
+type Fq6DivC0_t17 = M<CI<7>, CI<11>>;
+type Fq6DivC0_t18 = S<M<Fq6DivC0_t4, S<CI<8>, CI<9>>>, S<Fq6DivC0_t16, Fq6DivC0_t17>>;
+type Fq6DivC0_t19 = M<CI<8>, Fq6DivC0_t18>;
+type Fq6DivC0_t20 = S<M<A<CI<8>, CI<8>>, CI<9>>, S<M<Fq6DivC0_t0, Fq6DivC0_t5>, A<Fq6DivC0_t16, Fq6DivC0_t17>>>;
+type Fq6DivC0_t21 = M<CI<9>, Fq6DivC0_t20>;
+type Fq6DivC0_t22 = A<S<Fq6DivC0_t13, Fq6DivC0_t15>, S<Fq6DivC0_t19, Fq6DivC0_t21>>;
+type Fq6DivC0_t23 = A<Fq6DivC0_t12, Fq6DivC0_t14>;
+type Fq6DivC0_t24 = A<Fq6DivC0_t18, Fq6DivC0_t20>;
+type Fq6DivC0_t25 = A<S<M<Fq6DivC0_t5, Fq6DivC0_t23>, A<Fq6DivC0_t13, Fq6DivC0_t15>>, S<M<Fq6DivC0_t4, Fq6DivC0_t24>, A<Fq6DivC0_t19, Fq6DivC0_t21>>>;
+type Fq6DivC0_t26 = M<CI<6>, Fq6DivC0_t7>;
+type Fq6DivC0_t27 = S<M<A<CI<6>, CI<6>>, CI<7>>, A<M<Fq6DivC0_t6, CI<12>>, Fq6DivC0_t3>>;
+type Fq6DivC0_t28 = M<CI<7>, Fq6DivC0_t27>;
+type Fq6DivC0_t29 = A<S<M<Fq6DivC0_t22, CI<12>>, Fq6DivC0_t25>, S<Fq6DivC0_t26, Fq6DivC0_t28>>;
+type Fq6DivC0_t30 = A<Fq6DivC0_t7, Fq6DivC0_t27>;
+type Fq6DivC0_t31 = A<A<M<Fq6DivC0_t25, CI<12>>, Fq6DivC0_t22>, S<M<Fq6DivC0_t0, Fq6DivC0_t30>, A<Fq6DivC0_t26, Fq6DivC0_t28>>>;
+type Fq6DivC0_t32 = I<A<M<Fq6DivC0_t29, Fq6DivC0_t29>, M<Fq6DivC0_t31, Fq6DivC0_t31>>>;
+type Fq6DivC0_t33 = M<Fq6DivC0_t29, Fq6DivC0_t32>;
+type Fq6DivC0_t34 = M<Fq6DivC0_t7, Fq6DivC0_t33>;
+type Fq6DivC0_t35 = M<Fq6DivC0_t31, S<CI<13>, Fq6DivC0_t32>>;
+type Fq6DivC0_t36 = M<Fq6DivC0_t27, Fq6DivC0_t35>;
+type Fq6DivC0_t37 = S<Fq6DivC0_t34, Fq6DivC0_t36>;
+type Fq6DivC0_t38 = M<CI<0>, Fq6DivC0_t37>;
+type Fq6DivC0_t39 = A<Fq6DivC0_t33, Fq6DivC0_t35>;
+type Fq6DivC0_t40 = S<M<Fq6DivC0_t30, Fq6DivC0_t39>, A<Fq6DivC0_t34, Fq6DivC0_t36>>;
+type Fq6DivC0_t41 = M<CI<1>, Fq6DivC0_t40>;
+type Fq6DivC0_t42 = S<Fq6DivC0_t38, Fq6DivC0_t41>;
+type Fq6DivC0_t43 = A<CI<2>, CI<4>>;
+type Fq6DivC0_t44 = M<Fq6DivC0_t12, Fq6DivC0_t33>;
+type Fq6DivC0_t45 = M<Fq6DivC0_t14, Fq6DivC0_t35>;
+type Fq6DivC0_t46 = S<Fq6DivC0_t44, Fq6DivC0_t45>;
+type Fq6DivC0_t47 = M<Fq6DivC0_t18, Fq6DivC0_t33>;
+type Fq6DivC0_t48 = M<Fq6DivC0_t20, Fq6DivC0_t35>;
+type Fq6DivC0_t49 = S<Fq6DivC0_t47, Fq6DivC0_t48>;
+type Fq6DivC0_t50 = A<Fq6DivC0_t46, Fq6DivC0_t49>;
+type Fq6DivC0_t51 = M<Fq6DivC0_t43, Fq6DivC0_t50>;
+type Fq6DivC0_t52 = A<CI<3>, CI<5>>;
+type Fq6DivC0_t53 = S<M<Fq6DivC0_t23, Fq6DivC0_t39>, A<Fq6DivC0_t44, Fq6DivC0_t45>>;
+type Fq6DivC0_t54 = S<M<Fq6DivC0_t24, Fq6DivC0_t39>, A<Fq6DivC0_t47, Fq6DivC0_t48>>;
+type Fq6DivC0_t55 = A<Fq6DivC0_t53, Fq6DivC0_t54>;
+type Fq6DivC0_t56 = M<Fq6DivC0_t52, Fq6DivC0_t55>;
+type Fq6DivC0_t57 = M<CI<2>, Fq6DivC0_t46>;
+type Fq6DivC0_t58 = M<CI<3>, Fq6DivC0_t53>;
+type Fq6DivC0_t59 = S<Fq6DivC0_t57, Fq6DivC0_t58>;
+type Fq6DivC0_t60 = M<CI<4>, Fq6DivC0_t49>;
+type Fq6DivC0_t61 = M<CI<5>, Fq6DivC0_t54>;
+type Fq6DivC0_t62 = S<Fq6DivC0_t60, Fq6DivC0_t61>;
+type Fq6DivC0_t63 = S<S<S<Fq6DivC0_t51, Fq6DivC0_t56>, Fq6DivC0_t59>, Fq6DivC0_t62>;
+type Fq6DivC0_t64 = S<M<A<CI<2>, CI<3>>, A<Fq6DivC0_t46, Fq6DivC0_t53>>, A<Fq6DivC0_t57, Fq6DivC0_t58>>;
+type Fq6DivC0_t65 = S<M<A<CI<4>, CI<5>>, A<Fq6DivC0_t49, Fq6DivC0_t54>>, A<Fq6DivC0_t60, Fq6DivC0_t61>>;
+type Fq6DivC0_t66 = S<S<S<M<A<Fq6DivC0_t43, Fq6DivC0_t52>, A<Fq6DivC0_t50, Fq6DivC0_t55>>, A<Fq6DivC0_t51, Fq6DivC0_t56>>, Fq6DivC0_t64>, Fq6DivC0_t65>;
+type Fq6DivC0 = CE<A<Fq6DivC0_t42, S<M<Fq6DivC0_t63, CI<12>>, Fq6DivC0_t66>>>;
+type Fq6DivC0_t67 = S<M<A<CI<0>, CI<1>>, A<Fq6DivC0_t37, Fq6DivC0_t40>>, A<Fq6DivC0_t38, Fq6DivC0_t41>>;
+type Fq6DivC1 = CE<A<Fq6DivC0_t67, A<M<Fq6DivC0_t66, CI<12>>, Fq6DivC0_t63>>>;
+type Fq6DivC0_t68 = A<CI<0>, CI<2>>;
+type Fq6DivC0_t69 = A<Fq6DivC0_t37, Fq6DivC0_t46>;
+type Fq6DivC0_t70 = M<Fq6DivC0_t68, Fq6DivC0_t69>;
+type Fq6DivC0_t71 = A<CI<1>, CI<3>>;
+type Fq6DivC0_t72 = A<Fq6DivC0_t40, Fq6DivC0_t53>;
+type Fq6DivC0_t73 = M<Fq6DivC0_t71, Fq6DivC0_t72>;
+type Fq6DivC2 = CE<A<S<S<S<Fq6DivC0_t70, Fq6DivC0_t73>, Fq6DivC0_t42>, Fq6DivC0_t59>, S<M<Fq6DivC0_t62, CI<12>>, Fq6DivC0_t65>>>;
+type Fq6DivC3 = CE<A<S<S<S<M<A<Fq6DivC0_t68, Fq6DivC0_t71>, A<Fq6DivC0_t69, Fq6DivC0_t72>>, A<Fq6DivC0_t70, Fq6DivC0_t73>>, Fq6DivC0_t67>, Fq6DivC0_t64>, A<M<Fq6DivC0_t65, CI<12>>, Fq6DivC0_t62>>>;
+type Fq6DivC0_t74 = A<CI<0>, CI<4>>;
+type Fq6DivC0_t75 = A<Fq6DivC0_t37, Fq6DivC0_t49>;
+type Fq6DivC0_t76 = M<Fq6DivC0_t74, Fq6DivC0_t75>;
+type Fq6DivC0_t77 = A<CI<1>, CI<5>>;
+type Fq6DivC0_t78 = A<Fq6DivC0_t40, Fq6DivC0_t54>;
+type Fq6DivC0_t79 = M<Fq6DivC0_t77, Fq6DivC0_t78>;
+type Fq6DivC4 = CE<S<A<S<S<Fq6DivC0_t76, Fq6DivC0_t79>, Fq6DivC0_t42>, Fq6DivC0_t59>, Fq6DivC0_t62>>;
+type Fq6DivC5 = CE<S<A<S<S<M<A<Fq6DivC0_t74, Fq6DivC0_t77>, A<Fq6DivC0_t75, Fq6DivC0_t78>>, A<Fq6DivC0_t76, Fq6DivC0_t79>>, Fq6DivC0_t67>, Fq6DivC0_t64>, Fq6DivC0_t65>>;
+// Fq6 Inv
+// CI<0>: lhs.c0.c0.c0
+// CI<1>: lhs.c0.c1.c0
+// CI<2>: lhs.c1.c0.c0
+// CI<3>: lhs.c1.c1.c0
+// CI<4>: lhs.c2.c0.c0
+// CI<5>: lhs.c2.c1.c0
+// CI<6>: NINE = 9
+// CI<7>: ZERO = 0
+type Fq6InvC0_t0 = A<CI<0>, CI<1>>;
+type Fq6InvC0_t1 = M<CI<2>, CI<4>>;
+type Fq6InvC0_t2 = M<CI<3>, CI<5>>;
+type Fq6InvC0_t3 = S<Fq6InvC0_t1, Fq6InvC0_t2>;
+type Fq6InvC0_t4 = A<CI<2>, CI<3>>;
+type Fq6InvC0_t5 = A<CI<4>, CI<5>>;
+type Fq6InvC0_t6 = S<M<Fq6InvC0_t4, Fq6InvC0_t5>, A<Fq6InvC0_t1, Fq6InvC0_t2>>;
+type Fq6InvC0_t7 = S<M<Fq6InvC0_t0, S<CI<0>, CI<1>>>, S<M<Fq6InvC0_t3, CI<6>>, Fq6InvC0_t6>>;
+type Fq6InvC0_t8 = M<Fq6InvC0_t5, S<CI<4>, CI<5>>>;
+type Fq6InvC0_t9 = M<A<CI<4>, CI<4>>, CI<5>>;
+type Fq6InvC0_t10 = M<CI<0>, CI<2>>;
+type Fq6InvC0_t11 = M<CI<1>, CI<3>>;
+type Fq6InvC0_t12 = S<S<M<Fq6InvC0_t8, CI<6>>, Fq6InvC0_t9>, S<Fq6InvC0_t10, Fq6InvC0_t11>>;
+type Fq6InvC0_t13 = M<CI<4>, Fq6InvC0_t12>;
+type Fq6InvC0_t14 = S<A<M<Fq6InvC0_t9, CI<6>>, Fq6InvC0_t8>, S<M<Fq6InvC0_t0, Fq6InvC0_t4>, A<Fq6InvC0_t10, Fq6InvC0_t11>>>;
+type Fq6InvC0_t15 = M<CI<5>, Fq6InvC0_t14>;
+type Fq6InvC0_t16 = M<CI<0>, CI<4>>;
+type Fq6InvC0_t17 = M<CI<1>, CI<5>>;
+type Fq6InvC0_t18 = S<M<Fq6InvC0_t4, S<CI<2>, CI<3>>>, S<Fq6InvC0_t16, Fq6InvC0_t17>>;
+type Fq6InvC0_t19 = M<CI<2>, Fq6InvC0_t18>;
+type Fq6InvC0_t20 = S<M<A<CI<2>, CI<2>>, CI<3>>, S<M<Fq6InvC0_t0, Fq6InvC0_t5>, A<Fq6InvC0_t16, Fq6InvC0_t17>>>;
+type Fq6InvC0_t21 = M<CI<3>, Fq6InvC0_t20>;
+type Fq6InvC0_t22 = A<S<Fq6InvC0_t13, Fq6InvC0_t15>, S<Fq6InvC0_t19, Fq6InvC0_t21>>;
+type Fq6InvC0_t23 = A<Fq6InvC0_t12, Fq6InvC0_t14>;
+type Fq6InvC0_t24 = A<Fq6InvC0_t18, Fq6InvC0_t20>;
+type Fq6InvC0_t25 = A<S<M<Fq6InvC0_t5, Fq6InvC0_t23>, A<Fq6InvC0_t13, Fq6InvC0_t15>>, S<M<Fq6InvC0_t4, Fq6InvC0_t24>, A<Fq6InvC0_t19, Fq6InvC0_t21>>>;
+type Fq6InvC0_t26 = M<CI<0>, Fq6InvC0_t7>;
+type Fq6InvC0_t27 = S<M<A<CI<0>, CI<0>>, CI<1>>, A<M<Fq6InvC0_t6, CI<6>>, Fq6InvC0_t3>>;
+type Fq6InvC0_t28 = M<CI<1>, Fq6InvC0_t27>;
+type Fq6InvC0_t29 = A<S<M<Fq6InvC0_t22, CI<6>>, Fq6InvC0_t25>, S<Fq6InvC0_t26, Fq6InvC0_t28>>;
+type Fq6InvC0_t30 = A<Fq6InvC0_t7, Fq6InvC0_t27>;
+type Fq6InvC0_t31 = A<A<M<Fq6InvC0_t25, CI<6>>, Fq6InvC0_t22>, S<M<Fq6InvC0_t0, Fq6InvC0_t30>, A<Fq6InvC0_t26, Fq6InvC0_t28>>>;
+type Fq6InvC0_t32 = I<A<M<Fq6InvC0_t29, Fq6InvC0_t29>, M<Fq6InvC0_t31, Fq6InvC0_t31>>>;
+type Fq6InvC0_t33 = M<Fq6InvC0_t29, Fq6InvC0_t32>;
+type Fq6InvC0_t34 = M<Fq6InvC0_t7, Fq6InvC0_t33>;
+type Fq6InvC0_t35 = M<Fq6InvC0_t31, S<CI<7>, Fq6InvC0_t32>>;
+type Fq6InvC0_t36 = M<Fq6InvC0_t27, Fq6InvC0_t35>;
+type Fq6InvC0 = CE<S<Fq6InvC0_t34, Fq6InvC0_t36>>;
+type Fq6InvC0_t37 = A<Fq6InvC0_t33, Fq6InvC0_t35>;
+type Fq6InvC1 = CE<S<M<Fq6InvC0_t30, Fq6InvC0_t37>, A<Fq6InvC0_t34, Fq6InvC0_t36>>>;
+type Fq6InvC0_t38 = M<Fq6InvC0_t12, Fq6InvC0_t33>;
+type Fq6InvC0_t39 = M<Fq6InvC0_t14, Fq6InvC0_t35>;
+type Fq6InvC2 = CE<S<Fq6InvC0_t38, Fq6InvC0_t39>>;
+type Fq6InvC3 = CE<S<M<Fq6InvC0_t23, Fq6InvC0_t37>, A<Fq6InvC0_t38, Fq6InvC0_t39>>>;
+type Fq6InvC0_t40 = M<Fq6InvC0_t18, Fq6InvC0_t33>;
+type Fq6InvC0_t41 = M<Fq6InvC0_t20, Fq6InvC0_t35>;
+type Fq6InvC4 = CE<S<Fq6InvC0_t40, Fq6InvC0_t41>>;
+type Fq6InvC5 = CE<S<M<Fq6InvC0_t24, Fq6InvC0_t37>, A<Fq6InvC0_t40, Fq6InvC0_t41>>>;
+// Fq6 Neg
+// CI<0>: lhs.c0.c0.c0
+// CI<1>: lhs.c0.c1.c0
+// CI<2>: lhs.c1.c0.c0
+// CI<3>: lhs.c1.c1.c0
+// CI<4>: lhs.c2.c0.c0
+// CI<5>: lhs.c2.c1.c0
+// CI<6>: ZERO = 0
+type Fq6NegC0 = CE<S<CI<6>, CI<0>>>;
+type Fq6NegC1 = CE<S<CI<6>, CI<1>>>;
+type Fq6NegC2 = CE<S<CI<6>, CI<2>>>;
+type Fq6NegC3 = CE<S<CI<6>, CI<3>>>;
+type Fq6NegC4 = CE<S<CI<6>, CI<4>>>;
+type Fq6NegC5 = CE<S<CI<6>, CI<5>>>;
+// @generated fq6 end
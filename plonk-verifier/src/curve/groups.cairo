@@ -9,16 +9,18 @@ use core::circuit::{
     SubModGate as S,
 };
 
+use plonk_verifier::circuits::fq_circuits::ZERO;
 use plonk_verifier::circuits::affine_circuits::{
     fq2_add_circuit, fq2_chord_circuit, fq2_double_circuit, fq2_pt_on_slope_circuit,
     fq2_tangent_circuit, fq2_y_on_slope_circuit,
 };
-use plonk_verifier::curve::constants::{ONE, FIELD_U384, TWO, XC0, XC1, YC0, YC1};
+use plonk_verifier::curve::constants::{ONE, FIELD_U384, ORDER, TWO, XC0, XC1, YC0, YC1};
 use plonk_verifier::fields::{fq, fq2, Fq, Fq2};
 use plonk_verifier::traits::{FieldOps as FOps};
 
 type AffineG1 = Affine<Fq>;
 type AffineG2 = Affine<Fq2>;
+type ProjectiveG2 = Projective<Fq2>;
 
 // #[inline(always)]
 fn affine_fq1(c0: u384, c1: u384) -> Affine<Fq> {
@@ -30,12 +32,59 @@ fn affine_fq2(c0: u384, c1: u384, c2: u384, c3: u384) -> Affine<Fq2> {
     Affine { x: fq2(c0, c1), y: fq2(c2, c3) }
 }
 
+// #[inline(always)]
+fn projective_fq2(c0: u384, c1: u384, c2: u384, c3: u384, c4: u384, c5: u384) -> Projective<Fq2> {
+    Projective { x: fq2(c0, c1), y: fq2(c2, c3), z: fq2(c4, c5) }
+}
+
+// Zero-free w-bit digits of a scalar, least significant first, as read by the generated G1 MSM
+// entry points: (scalar - offset) mod r split into windows, plus one and plus two for the top one
+fn zero_free_digits(scalar: u384, w: u32, windows: u32, offset: u256) -> Array<u32> {
+    let mut radix: u256 = 1;
+    let mut i = 0;
+    while i != w {
+        radix = radix * 2;
+        i += 1;
+    };
+    let radix: NonZero<u256> = radix.try_into().unwrap();
+    let order: NonZero<u256> = ORDER.try_into().unwrap();
+
+    let scalar: u256 = scalar.try_into().unwrap();
+    let (_, scalar, _) = integer::u256_safe_divmod(scalar, order);
+    let (_, mut rest, _) = integer::u256_safe_divmod(scalar + ORDER - offset, order);
+
+    let mut digits = array![];
+    let mut j = 0;
+    while j != windows {
+        let (q, digit, _) = integer::u256_safe_divmod(rest, radix);
+        let digit: u32 = digit.try_into().unwrap();
+        digits.append(if j == windows - 1 { digit + 2 } else { digit + 1 });
+        rest = q;
+        j += 1;
+    };
+    digits
+}
+
+// d·radix^j·P read from the constant table of a fixed base, x then y of every entry
+fn fixed_table_entry(table: Span<u384>, radix: u32, j: u32, d: u32) -> Affine<Fq> {
+    let k = 2 * (radix * j + d - 1);
+    affine_fq1(*table[k], *table[k + 1])
+}
+
 #[derive(Copy, Drop, Serde)]
 struct Affine<T> {
     x: T,
     y: T
 }
 
+// Homogeneous projective point, x = X/Z and y = Y/Z
+#[derive(Copy, Drop, Serde)]
+struct Projective<T> {
+    x: T,
+    y: T,
+    z: T
+}
+
 trait ECGroup<TCoord> {
     fn one() -> Affine<TCoord>;
 }
@@ -380,6 +429,7 @@ impl AffineOpsFq2Circuit of ECOperationsCircuitFq2 {
                 .next(rhs.x.c1.c0)
                 .next(rhs.y.c0.c0)
                 .next(rhs.y.c1.c0)
+                .next(ZERO)
                 .done()
                 .eval(m) {
             Result::Ok(outputs) => { outputs },
@@ -403,6 +453,7 @@ impl AffineOpsFq2Circuit of ECOperationsCircuitFq2 {
                 .next(rhs.x.c1.c0)
                 .next(rhs.y.c0.c0)
                 .next(rhs.y.c1.c0)
+                .next(ZERO)
                 .done()
                 .eval(m) {
             Result::Ok(outputs) => { outputs },
@@ -422,6 +473,7 @@ impl AffineOpsFq2Circuit of ECOperationsCircuitFq2 {
                 .next(*self.x.c1.c0)
                 .next(*self.y.c0.c0)
                 .next(*self.y.c1.c0)
+                .next(ZERO)
                 .done()
                 .eval(m) {
             Result::Ok(outputs) => { outputs },
@@ -441,6 +493,7 @@ impl AffineOpsFq2Circuit of ECOperationsCircuitFq2 {
                 .next(*self.x.c1.c0)
                 .next(*self.y.c0.c0)
                 .next(*self.y.c1.c0)
+                .next(ZERO)
                 .done()
                 .eval(m) {
             Result::Ok(outputs) => { outputs },
@@ -131,6 +131,7 @@ mod line_fn {
         //     .next(q.x.c1.c0)
         //     .next(q.y.c0.c0)
         //     .next(q.y.c1.c0)
+        //     .next(ZERO)
         //     .done().eval(m) {
         //         Result::Ok(outputs) => { outputs },
         //         Result::Err(_) => { panic!("Expected success") }
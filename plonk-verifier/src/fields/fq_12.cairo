@@ -5,6 +5,7 @@ use core::circuit::{
     circuit_inverse, circuit_mul, circuit_sub, u384,
 };
 
+use plonk_verifier::circuits::fq_circuits::NINE;
 use plonk_verifier::circuits::fq_12_circuits::{
     add_circuit, mul_circuit, neg_circuit, sqr_circuit, sub_circuit,
 };
@@ -251,6 +252,7 @@ impl Fq12Ops of FieldOps<Fq12, CircuitModulus> {
             .next(rhs.c1.c1.c1.c0)
             .next(rhs.c1.c2.c0.c0)
             .next(rhs.c1.c2.c1.c0)
+            .next(NINE)
             .done().eval(m) {
                 Result::Ok(outputs) => { outputs },
                 Result::Err(_) => { panic!("Expected success") }
@@ -285,6 +287,7 @@ impl Fq12Ops of FieldOps<Fq12, CircuitModulus> {
             .next(self.c1.c1.c1.c0)
             .next(self.c1.c2.c0.c0)
             .next(self.c1.c2.c1.c0)
+            .next(NINE)
             .done().eval(m) {
                 Result::Ok(outputs) => { outputs },
                 Result::Err(_) => { panic!("Expected success") }
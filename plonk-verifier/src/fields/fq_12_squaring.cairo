@@ -4,6 +4,7 @@ use core::circuit::{
     circuit_mul, circuit_sub, u384,
 };
 
+use plonk_verifier::circuits::fq_circuits::{NINE, ZERO};
 use plonk_verifier::circuits::fq_12_squaring_circuits::{decompress_zero_circuit, decompress_non_zero_circuit, sqr_circuit};
 use plonk_verifier::curve::{constants::{FIELD_U384, TWO}, mul_by_xi_nz_as_circuit};
 use plonk_verifier::fields::{
@@ -60,6 +61,8 @@ impl Fq12Squaring of Fq12SquaringTrait {
                 .next(self.g4.c1.c0)
                 .next(self.g5.c0.c0)
                 .next(self.g5.c1.c0)
+                .next(NINE)
+                .next(ZERO)
                 .done().eval(m) {
                     Result::Ok(outputs) => { outputs },
                     Result::Err(_) => { panic!("Expected success") }
@@ -88,6 +91,7 @@ impl Fq12Squaring of Fq12SquaringTrait {
             .next(self.g4.c1.c0)
             .next(self.g5.c0.c0)
             .next(self.g5.c1.c0)
+            .next(NINE)
             .done().eval(m) {
                 Result::Ok(outputs) => { outputs },
                 Result::Err(_) => { panic!("Expected success") }
@@ -172,6 +172,7 @@ impl Fq2Ops of FieldOps<Fq2, CircuitModulus> {
                 .next(self.c1.c0)
                 .next(rhs.c0.c0)
                 .next(rhs.c1.c0)
+                .next(ZERO)
                 .done()
                 .eval(m) {
             Result::Ok(outputs) => { outputs },
@@ -209,7 +210,14 @@ impl Fq2Ops of FieldOps<Fq2, CircuitModulus> {
     fn inv(self: Fq2, m: CircuitModulus) -> Fq2 {
         let (c0, c1) = inv_circuit();
 
-        let outputs = match (c0, c1).new_inputs().next(self.c0.c0).next(self.c1.c0).done().eval(m) {
+        let outputs =
+            match (c0, c1)
+                .new_inputs()
+                .next(self.c0.c0)
+                .next(self.c1.c0)
+                .next(ZERO)
+                .done()
+                .eval(m) {
             Result::Ok(outputs) => { outputs },
             Result::Err(_) => { panic!("Expected success") }
         };
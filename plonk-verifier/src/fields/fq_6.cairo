@@ -5,7 +5,7 @@ use core::circuit::{
     circuit_inverse, circuit_mul, circuit_sub, u384,
 };
 
-use plonk_verifier::circuits::fq_circuits::{ONE, ZERO};
+use plonk_verifier::circuits::fq_circuits::{NINE, ONE, ZERO};
 use plonk_verifier::circuits::fq_6_circuits::{
     add_circuit, mul_circuit, neg_circuit, sqr_circuit, sub_circuit,
 };
@@ -139,6 +139,7 @@ impl Fq6Ops of FieldOps<Fq6, CircuitModulus> {
             .next(rhs.c1.c1.c0)
             .next(rhs.c2.c0.c0)
             .next(rhs.c2.c1.c0)
+            .next(NINE)
             .done().eval(m) {
                 Result::Ok(outputs) => { outputs },
                 Result::Err(_) => { panic!("Expected success") }
@@ -171,6 +172,7 @@ impl Fq6Ops of FieldOps<Fq6, CircuitModulus> {
             .next(self.c1.c1.c0)
             .next(self.c2.c0.c0)
             .next(self.c2.c1.c0)
+            .next(NINE)
             .done().eval(m) {
                 Result::Ok(outputs) => { outputs },
                 Result::Err(_) => { panic!("Expected success") }
@@ -7,6 +7,7 @@ use core::circuit::{
 };
 use core::traits::TryInto;
 
+use plonk_verifier::circuits::fq_circuits::NINE;
 use plonk_verifier::circuits::sparse_circuits::{mul_034_by_034_circuit, mul_01_circuit};
 use plonk_verifier::curve::{constants::FIELD_U384, mul_by_v_nz_as_circuit, mul_by_xi_nz_as_circuit};
 use plonk_verifier::fields::{
@@ -99,6 +100,7 @@ impl FqSparse of FqSparseTrait {
             .next(rhs.c0.c1.c0)
             .next(rhs.c1.c0.c0)
             .next(rhs.c1.c1.c0)
+            .next(NINE)
             .done().eval(m) {
                 Result::Ok(outputs) => { outputs },
                 Result::Err(_) => { panic!("Expected success") }
@@ -162,6 +164,7 @@ impl FqSparse of FqSparseTrait {
             .next(rhs.c3.c1.c0)
             .next(rhs.c4.c0.c0)
             .next(rhs.c4.c1.c0)
+            .next(NINE)
             .done().eval(m) {
                 Result::Ok(outputs) => { outputs },
                 Result::Err(_) => { panic!("Expected success") }